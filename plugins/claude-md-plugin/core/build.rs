@@ -18,6 +18,9 @@ struct SectionDef {
     condition: String,
     #[serde(default)]
     allow_none: bool,
+    /// Content is a markdown table (at least one data row unless "None")
+    #[serde(default)]
+    table: bool,
     #[serde(default)]
     required_subsections: Option<Vec<String>>,
 }
@@ -53,6 +56,8 @@ struct SchemaRules {
     description: String,
     sections: HashMap<String, SectionDef>,
     #[serde(default)]
+    implements_sections: HashMap<String, SectionDef>,
+    #[serde(default)]
     convention_rules: Option<ConventionRules>,
     #[serde(default)]
     reference_rules: Option<ReferenceRules>,
@@ -99,6 +104,45 @@ fn main() {
 
     allow_none_sections.sort();
 
+    // Extract IMPLEMENTS.md required sections (same rule as CLAUDE.md)
+    let mut implements_required_sections: Vec<&str> = rules
+        .implements_sections
+        .values()
+        .filter(|s| s.required && s.condition == "always")
+        .map(|s| s.name.as_str())
+        .collect();
+
+    implements_required_sections.sort();
+
+    // Extract IMPLEMENTS.md sections that allow "None"
+    let mut implements_allow_none_sections: Vec<&str> = rules
+        .implements_sections
+        .values()
+        .filter(|s| s.allow_none)
+        .map(|s| s.name.as_str())
+        .collect();
+
+    implements_allow_none_sections.sort();
+
+    // IMPLEMENTS.md sections written as a markdown table
+    let mut implements_table_sections: Vec<&str> = rules
+        .implements_sections
+        .values()
+        .filter(|s| s.table)
+        .map(|s| s.name.as_str())
+        .collect();
+
+    implements_table_sections.sort();
+
+    // All known IMPLEMENTS.md sections (required and conditional)
+    let mut implements_sections: Vec<&str> = rules
+        .implements_sections
+        .values()
+        .map(|s| s.name.as_str())
+        .collect();
+
+    implements_sections.sort();
+
     // Extract required subsections for Project Convention
     let project_convention_subsections: Vec<&str> = rules
         .sections
@@ -151,6 +195,22 @@ pub const REQUIRED_SECTIONS: &[&str] = &{:?};
 #[allow(dead_code)]
 pub const ALLOW_NONE_SECTIONS: &[&str] = &{:?};
 
+/// Required sections in IMPLEMENTS.md (must always be present)
+#[allow(dead_code)]
+pub const IMPLEMENTS_REQUIRED_SECTIONS: &[&str] = &{:?};
+
+/// IMPLEMENTS.md sections that allow "None" as valid content
+#[allow(dead_code)]
+pub const IMPLEMENTS_ALLOW_NONE_SECTIONS: &[&str] = &{:?};
+
+/// IMPLEMENTS.md sections whose content is a markdown table
+#[allow(dead_code)]
+pub const IMPLEMENTS_TABLE_SECTIONS: &[&str] = &{:?};
+
+/// All sections defined for IMPLEMENTS.md (required and conditional)
+#[allow(dead_code)]
+pub const IMPLEMENTS_SECTIONS: &[&str] = &{:?};
+
 /// Required subsections for Project Convention section
 #[allow(dead_code)]
 pub const PROJECT_CONVENTION_REQUIRED_SUBSECTIONS: &[&str] = &{:?};
@@ -170,6 +230,10 @@ pub const FORBIDDEN_REFERENCE_PATTERNS: &[(&str, &str)] = &{:?};
         rules.version,
         required_sections,
         allow_none_sections,
+        implements_required_sections,
+        implements_allow_none_sections,
+        implements_table_sections,
+        implements_sections,
         project_convention_subsections,
         code_convention_subsections,
         module_root_markers,
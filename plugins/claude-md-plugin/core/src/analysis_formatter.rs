@@ -287,6 +287,8 @@ mod tests {
             name: "greet".to_string(),
             signature: "greet(name: string): string".to_string(),
             description: None,
+            location: None,
+            parsed_signature: None,
         });
         let result = format_analysis(&analysis);
         assert!(result.contains("## Exports"));
@@ -300,11 +302,13 @@ mod tests {
             input: "valid token".to_string(),
             output: "Claims object".to_string(),
             category: BehaviorCategory::Success,
+            location: None,
         });
         analysis.behaviors.push(Behavior {
             input: "expired token".to_string(),
             output: "TokenExpiredError".to_string(),
             category: BehaviorCategory::Error,
+            location: None,
         });
         let result = format_analysis(&analysis);
         assert!(result.contains("### Success"));
@@ -320,6 +324,7 @@ mod tests {
             input: "valid input".to_string(),
             output: "result".to_string(),
             category: BehaviorCategory::Success,
+            location: None,
         });
         let result = format_analysis(&analysis);
         assert!(result.contains("## Behaviors"));
@@ -375,6 +380,7 @@ mod tests {
                 invariants: Vec::new(),
                 throws: vec!["InvalidTokenError".to_string(), "SignatureError".to_string()],
             },
+            location: None,
         });
         let result = format_analysis(&analysis);
         assert!(result.contains("### validateToken"));
@@ -394,6 +400,7 @@ mod tests {
                 preconditions: vec!["z".to_string()],
                 ..Contract::default()
             },
+            location: None,
         });
         analysis.contracts.push(FunctionContract {
             function_name: "alpha".to_string(),
@@ -401,6 +408,7 @@ mod tests {
                 preconditions: vec!["a".to_string()],
                 ..Contract::default()
             },
+            location: None,
         });
         let result = format_analysis(&analysis);
         let alpha_pos = result.find("### alpha").unwrap();
@@ -503,17 +511,21 @@ mod tests {
             name: "validateToken".to_string(),
             signature: "validateToken(token: string): Promise<Claims>".to_string(),
             description: None,
+            location: None,
+            parsed_signature: None,
         });
 
         analysis.behaviors.push(Behavior {
             input: "valid JWT".to_string(),
             output: "Claims".to_string(),
             category: BehaviorCategory::Success,
+            location: None,
         });
         analysis.behaviors.push(Behavior {
             input: "invalid JWT".to_string(),
             output: "InvalidTokenError".to_string(),
             category: BehaviorCategory::Error,
+            location: None,
         });
 
         analysis.dependencies.external = vec!["jsonwebtoken".to_string()];
@@ -526,6 +538,7 @@ mod tests {
                 invariants: Vec::new(),
                 throws: vec!["InvalidTokenError".to_string()],
             },
+            location: None,
         });
 
         analysis.analyzed_files = vec!["index.ts".to_string(), "middleware.ts".to_string()];
@@ -108,6 +108,12 @@ impl BoundaryResolver {
         }
     }
 
+    /// Find Parent/Sibling reference violations in CLAUDE.md content for the directory `path`
+    pub fn check_references(&self, path: &Path, claude_md_content: &str) -> Vec<ReferenceViolation> {
+        let subdirs = self.get_subdirs(path);
+        self.find_violations(path, claude_md_content, &subdirs)
+    }
+
     fn get_direct_files(&self, path: &Path) -> Vec<FileInfo> {
         std::fs::read_dir(path)
             .map(|entries| {
@@ -255,8 +261,8 @@ impl BoundaryResolver {
             }
 
             // Detect H2 section headers
-            if trimmed.starts_with("## ") {
-                let section_name = trimmed[3..].trim().to_lowercase();
+            if let Some(heading) = trimmed.strip_prefix("## ") {
+                let section_name = heading.trim().to_lowercase();
                 in_target_section = section_name == "dependencies" || section_name == "structure";
                 continue;
             }
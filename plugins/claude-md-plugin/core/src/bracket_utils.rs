@@ -1,4 +1,4 @@
-/// Utility functions for parsing strings while respecting balanced brackets.
+//! Utility functions for parsing strings while respecting balanced brackets.
 
 /// Split a string by a delimiter, but ignore delimiters inside balanced brackets.
 /// Supports <>, (), [], {}
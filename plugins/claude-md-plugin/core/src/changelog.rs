@@ -0,0 +1,425 @@
+//! Per-module changelog built from the git history of each CLAUDE.md.
+//!
+//! Every commit that touched a CLAUDE.md is parsed and compared with its first parent,
+//! so the changelog lists API changes (exports, behaviors, contracts) instead of prose
+//! diffs. Side-branch commits are reported once, through the merge that brought them in.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use thiserror::Error;
+
+use crate::claude_md_parser::{BehaviorCategory, ClaudeMdParser, ClaudeMdSpec};
+use crate::compile_target_resolver::CompileTargetResolver;
+use crate::spec_diff::{self, diff_specs, ChangeKind, Severity, SpecDiffError};
+
+#[derive(Debug, Error)]
+pub enum ChangelogError {
+    #[error(transparent)]
+    Git(#[from] SpecDiffError),
+
+    #[error("Unexpected git log line: {0}")]
+    MalformedLog(String),
+}
+
+/// Added, removed and changed items of one kind.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ChangeGroup {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub added: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub removed: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changed: Vec<String>,
+}
+
+impl ChangeGroup {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// API changes made by one commit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangelogEntry {
+    /// Abbreviated commit hash
+    pub commit: String,
+    /// Author date, `YYYY-MM-DD`
+    pub date: String,
+    pub author: String,
+    pub subject: String,
+    /// Semver impact of the Exports, Contract and Protocol changes (see `diff-spec`)
+    pub bump: Option<Severity>,
+    pub exports: ChangeGroup,
+    pub behaviors: ChangeGroup,
+    pub contracts: ChangeGroup,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModuleChangelog {
+    /// Root-relative module directory (e.g., "src/auth")
+    pub module: String,
+    /// Entries, newest first
+    pub entries: Vec<ChangelogEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Changelog {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
+    /// Modules with at least one entry, sorted by directory
+    pub modules: Vec<ModuleChangelog>,
+}
+
+pub struct ChangelogGenerator {
+    parser: ClaudeMdParser,
+}
+
+/// A commit from `git log`
+struct Commit {
+    hash: String,
+    date: String,
+    author: String,
+    subject: String,
+}
+
+impl ChangelogGenerator {
+    pub fn new() -> Self {
+        Self {
+            parser: ClaudeMdParser::new(),
+        }
+    }
+
+    /// Changelog of every CLAUDE.md under `root`, optionally limited to commits after `since`.
+    pub fn generate(&self, root: &Path, since: Option<&str>) -> Result<Changelog, ChangelogError> {
+        let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+        if let Some(since) = since {
+            spec_diff::verify_revision(&root, since)?;
+        }
+
+        let mut dirs = CompileTargetResolver::new().scan_claude_md_dirs(&root);
+        dirs.sort();
+
+        let mut modules = Vec::new();
+        for dir in dirs {
+            let entries = self.module_entries(&root.join(&dir).join("CLAUDE.md"), since)?;
+            if !entries.is_empty() {
+                modules.push(ModuleChangelog { module: dir.to_string_lossy().to_string(), entries });
+            }
+        }
+        Ok(Changelog { since: since.map(str::to_string), modules })
+    }
+
+    /// Entries for one CLAUDE.md, newest first. Commits that change none of the tracked
+    /// sections are left out.
+    pub fn module_entries(&self, file: &Path, since: Option<&str>) -> Result<Vec<ChangelogEntry>, ChangelogError> {
+        let mut previous = match since {
+            Some(since) => self.parse_at(file, since)?,
+            None => ClaudeMdSpec::default(),
+        };
+
+        let mut entries = Vec::new();
+        for commit in commits(file, since)? {
+            let current = self.parse_at(file, &commit.hash)?;
+            let entry = changelog_entry(commit, &previous, &current);
+            if !(entry.exports.is_empty() && entry.behaviors.is_empty() && entry.contracts.is_empty()) {
+                entries.push(entry);
+            }
+            previous = current;
+        }
+        entries.reverse();
+        Ok(entries)
+    }
+
+    /// Spec at `revision`; an empty spec where the file did not exist.
+    fn parse_at(&self, file: &Path, revision: &str) -> Result<ClaudeMdSpec, ChangelogError> {
+        Ok(spec_diff::show_at_revision(file, revision)?
+            .map(|content| self.parser.parse_content_lenient(&content).spec)
+            .unwrap_or_default())
+    }
+}
+
+impl Default for ChangelogGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Commits along the first-parent chain that touched `file`, oldest first. Each one's
+/// version of the file is then compared with the previous entry, i.e. its first parent.
+fn commits(file: &Path, since: Option<&str>) -> Result<Vec<Commit>, ChangelogError> {
+    let range = since.map(|s| format!("{}..HEAD", s));
+    let mut args = vec!["log", "--first-parent", "--reverse", "--format=%h%x1f%aI%x1f%an%x1f%s"];
+    if let Some(range) = &range {
+        args.push(range);
+    }
+    args.extend(["--", "CLAUDE.md"]);
+
+    let output = spec_diff::git(spec_diff::containing_dir(file), &args)?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(SpecDiffError::GitError(stderr).into());
+    }
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let fields: Vec<&str> = line.splitn(4, '\u{1f}').collect();
+            match fields.as_slice() {
+                [hash, date, author, subject] => Ok(Commit {
+                    hash: hash.to_string(),
+                    date: date.chars().take(10).collect(),
+                    author: author.to_string(),
+                    subject: subject.to_string(),
+                }),
+                _ => Err(ChangelogError::MalformedLog(line.to_string())),
+            }
+        })
+        .collect()
+}
+
+fn changelog_entry(commit: Commit, previous: &ClaudeMdSpec, current: &ClaudeMdSpec) -> ChangelogEntry {
+    let changes = diff_specs(previous, current);
+
+    let mut exports = ChangeGroup::default();
+    for change in changes.iter().filter(|c| c.section == "Exports") {
+        match change.kind {
+            ChangeKind::ExportAdded => exports.added.push(export_text(&change.symbol, change.after.as_deref())),
+            ChangeKind::ExportRemoved => exports.removed.push(export_text(&change.symbol, change.before.as_deref())),
+            _ => exports.changed.push(change.message.clone()),
+        }
+    }
+
+    // Contract changes are per clause: a clause only after the commit was added
+    let mut contracts = ChangeGroup::default();
+    for change in changes.iter().filter(|c| c.section == "Contract") {
+        match (&change.before, &change.after) {
+            (None, Some(_)) => contracts.added.push(change.message.clone()),
+            (Some(_), None) => contracts.removed.push(change.message.clone()),
+            _ => contracts.changed.push(change.message.clone()),
+        }
+    }
+
+    ChangelogEntry {
+        commit: commit.hash,
+        date: commit.date,
+        author: commit.author,
+        subject: commit.subject,
+        bump: changes.iter().map(|c| c.severity).max(),
+        exports,
+        behaviors: diff_behaviors(previous, current),
+        contracts,
+    }
+}
+
+/// `validateToken(token: string): Claims`, or `Role: Admin | User` when the text omits the name.
+fn export_text(name: &str, text: Option<&str>) -> String {
+    match text.filter(|t| !t.is_empty()) {
+        Some(text) if text.contains(name) => text.to_string(),
+        Some(text) => format!("{}: {}", name, text),
+        None => name.to_string(),
+    }
+}
+
+/// Behaviors keyed by category and input; a new output for the same input is a change.
+fn diff_behaviors(previous: &ClaudeMdSpec, current: &ClaudeMdSpec) -> ChangeGroup {
+    let format = |input: &str, output: &str, category: &BehaviorCategory| match category {
+        BehaviorCategory::Success => format!("{} → {}", input, output),
+        BehaviorCategory::Error => format!("{} → {} (error)", input, output),
+    };
+    let mut group = ChangeGroup::default();
+    for before in &previous.behaviors {
+        match current.behaviors.iter().find(|b| b.category == before.category && b.input == before.input) {
+            None => group.removed.push(format(&before.input, &before.output, &before.category)),
+            Some(after) if after.output != before.output => group.changed.push(format!(
+                "{}: {} → {}", before.input, before.output, after.output
+            )),
+            Some(_) => {}
+        }
+    }
+    for after in &current.behaviors {
+        if !previous.behaviors.iter().any(|b| b.category == after.category && b.input == after.input) {
+            group.added.push(format(&after.input, &after.output, &after.category));
+        }
+    }
+    group
+}
+
+impl Changelog {
+    /// Render as markdown: one section per module, one subsection per commit.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from("# Changelog\n");
+        if let Some(since) = &self.since {
+            out.push_str(&format!("\nChanges since `{}`.\n", since));
+        }
+        for module in &self.modules {
+            out.push_str(&format!("\n## {}\n", module.module));
+            for entry in &module.entries {
+                let bump = entry.bump.map(|b| format!(", {}", severity_label(b))).unwrap_or_default();
+                out.push_str(&format!("\n### {} — {} (`{}`{})\n", entry.date, entry.subject, entry.commit, bump));
+                for (title, group) in [("Exports", &entry.exports), ("Behaviors", &entry.behaviors), ("Contracts", &entry.contracts)] {
+                    if group.is_empty() {
+                        continue;
+                    }
+                    out.push_str(&format!("\n#### {}\n\n", title));
+                    for (label, items) in [("Added", &group.added), ("Removed", &group.removed), ("Changed", &group.changed)] {
+                        for item in items {
+                            out.push_str(&format!("- {}: {}\n", label, item));
+                        }
+                    }
+                }
+            }
+        }
+        out
+    }
+}
+
+fn severity_label(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Major => "major",
+        Severity::Minor => "minor",
+        Severity::Patch => "patch",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    fn spec(exports: &str, behavior: &str, contract: &str) -> String {
+        format!(
+            "# auth\n\n## Purpose\nAuth.\n\n## Exports\n{}\n\n## Behavior\n{}\n\n\
+             ## Contract\n{}\n\n## Protocol\nNone\n\n## Domain Context\nNone\n",
+            exports, behavior, contract
+        )
+    }
+
+    fn commit(root: &Path, file: &str, content: &str, message: &str) {
+        let path = root.join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, content).unwrap();
+        for args in [vec!["add", "-A"], vec!["commit", "-q", "-m", message]] {
+            let output = Command::new("git")
+                .args(["-c", "user.name=Test", "-c", "user.email=test@test.com"])
+                .args(&args)
+                .current_dir(root)
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {:?} failed", args);
+        }
+    }
+
+    #[test]
+    fn test_changelog_from_history() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path();
+        Command::new("git").args(["init", "-q"]).current_dir(root).output().unwrap();
+
+        commit(root, "src/auth/CLAUDE.md", &spec(
+            "- `validateToken(token: string): Claims`",
+            "- valid token → Claims",
+            "None",
+        ), "Add auth spec");
+        commit(root, "src/auth/CLAUDE.md", &spec(
+            "- `validateToken(token: string): Claims`",
+            "- valid token → Claims",
+            "None",
+        ).replace("Auth.", "Authentication."), "Reword purpose");
+        commit(root, "src/auth/CLAUDE.md", &spec(
+            "- `validateToken(token: string, secret: string): Claims`\n- `revoke(token: string): void`",
+            "- valid token → Claims object\n- expired token → TokenExpiredError",
+            "### validateToken\n- **Preconditions**: token is non-empty",
+        ), "Require secret");
+
+        let changelog = ChangelogGenerator::new().generate(root, None).unwrap();
+        assert_eq!(changelog.modules.len(), 1);
+        let module = &changelog.modules[0];
+        assert_eq!(module.module, "src/auth");
+        // The purpose-only commit is left out
+        assert_eq!(module.entries.len(), 2);
+
+        let latest = &module.entries[0];
+        assert_eq!(latest.subject, "Require secret");
+        assert_eq!(latest.bump, Some(Severity::Major));
+        assert_eq!(latest.exports.added, vec!["revoke(token: string): void"]);
+        assert_eq!(latest.exports.changed, vec!["Required parameter 'secret' was added to 'validateToken'"]);
+        assert_eq!(latest.behaviors.added, vec!["expired token → TokenExpiredError (error)"]);
+        assert_eq!(latest.behaviors.changed, vec!["valid token: Claims → Claims object"]);
+        assert_eq!(latest.contracts.added, vec!["Precondition of 'validateToken' was added: token is non-empty"]);
+
+        let first = &module.entries[1];
+        assert_eq!(first.exports.added, vec!["validateToken(token: string): Claims"]);
+        assert_eq!(first.bump, Some(Severity::Minor));
+
+        let markdown = changelog.to_markdown();
+        assert!(markdown.contains("## src/auth\n"));
+        assert!(markdown.contains("— Require secret (`"));
+        assert!(markdown.contains("#### Exports\n\n- Added: revoke(token: string): void\n- Changed: Required parameter"));
+
+        let since_first = ChangelogGenerator::new().generate(root, Some("HEAD~2")).unwrap();
+        assert_eq!(since_first.modules[0].entries.len(), 1);
+    }
+
+    #[test]
+    fn test_merge_is_compared_with_first_parent() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path();
+        let git = |args: &[&str]| {
+            let output = Command::new("git")
+                .args(["-c", "user.name=Test", "-c", "user.email=test@test.com"])
+                .args(args)
+                .current_dir(root)
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {:?} failed", args);
+        };
+        let exports = |first: &str, last: &str| {
+            format!("{}- `a(): void`\n- `b(): void`\n- `c(): void`\n- `d(): void`{}", first, last)
+        };
+        git(&["init", "-q", "-b", "main"]);
+        commit(root, "src/auth/CLAUDE.md", &spec(&exports("", ""), "- a → b", "None"), "Add spec");
+
+        git(&["checkout", "-q", "-b", "side"]);
+        commit(root, "src/auth/CLAUDE.md", &spec(&exports("", "\n- `featB(): void`"), "- a → b", "None"), "Add featB");
+        git(&["checkout", "-q", "main"]);
+        commit(root, "src/auth/CLAUDE.md", &spec(&exports("- `featC(): void`\n", ""), "- a → b", "None"), "Add featC");
+        git(&["merge", "-q", "--no-edit", "side"]);
+
+        let changelog = ChangelogGenerator::new().generate(root, None).unwrap();
+        let entries = &changelog.modules[0].entries;
+        let subjects: Vec<&str> = entries.iter().map(|e| e.subject.as_str()).collect();
+        assert_eq!(subjects, vec!["Merge branch 'side'", "Add featC", "Add spec"]);
+
+        // Neither side's export is reported as removed
+        assert!(entries.iter().all(|e| e.exports.removed.is_empty()));
+        assert_eq!(entries[0].exports.added, vec!["featB(): void"]);
+        assert_eq!(entries[0].bump, Some(Severity::Minor));
+        assert_eq!(entries[1].exports.added, vec!["featC(): void"]);
+    }
+
+    #[test]
+    fn test_contract_clause_changes() {
+        let parser = ClaudeMdParser::new();
+        let before = parser.parse_content(&spec(
+            "- `validateToken(token: string): Claims`", "- a → b",
+            "### validateToken\n- **Preconditions**: token is non-empty\n- **Throws**: InvalidTokenError",
+        )).unwrap();
+        let after = parser.parse_content(&spec(
+            "- `validateToken(token: string): Claims`", "- a → b",
+            "### validateToken\n- **Preconditions**: token is a JWT\n- **Throws**:  InvalidTokenError",
+        )).unwrap();
+        let commit = Commit {
+            hash: "abc1234".to_string(),
+            date: "2024-01-01".to_string(),
+            author: "Test".to_string(),
+            subject: "Tighten".to_string(),
+        };
+        let entry = changelog_entry(commit, &before, &after);
+        // Whitespace-only clause edits are not changes
+        assert_eq!(entry.contracts.removed, vec!["Precondition of 'validateToken' was removed: token is non-empty"]);
+        assert_eq!(entry.contracts.added, vec!["Precondition of 'validateToken' was added: token is a JWT"]);
+        assert!(entry.contracts.changed.is_empty());
+        assert!(entry.behaviors.is_empty());
+    }
+}
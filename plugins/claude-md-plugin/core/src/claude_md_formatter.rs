@@ -0,0 +1,374 @@
+//! Canonical formatter for CLAUDE.md files.
+//!
+//! Rewrites layout only; prose and code blocks are kept verbatim, so formatting never
+//! changes what `ClaudeMdParser` extracts (apart from export order).
+//!
+//! # Rules
+//! - H2 sections in schema order (`SECTION_ORDER`), unknown sections after them in original order
+//! - Known H2 names in schema casing; all headings in ATX form
+//! - A blank line before every heading and after H1/H2; no blank run longer than one line
+//! - List markers `*` / `+` → `-`; trailing whitespace removed (outside code blocks)
+//! - Behavior: `->` separator → `→`
+//! - Exports: subsections in `exports_formatter` category order, items sorted by name
+//! - A section holding only a None marker (`- none`, `N/A`, ...) → `None`
+
+use regex::Regex;
+
+use crate::claude_md_parser::ClaudeMdParser;
+use crate::exports_formatter::CATEGORY_ORDER;
+use crate::markdown::{parse_sections, MarkdownSection};
+
+/// Canonical order of CLAUDE.md H2 sections.
+pub const SECTION_ORDER: &[&str] = &[
+    "Purpose",
+    "Structure",
+    "Exports",
+    "Dependencies",
+    "Behavior",
+    "Contract",
+    "Protocol",
+    "Domain Context",
+];
+
+/// A heading with its body lines, taken verbatim from the source.
+struct Part {
+    level: usize,
+    name: String,
+    body: Vec<String>,
+    code: Vec<bool>,
+}
+
+impl Part {
+    fn from_section(section: &MarkdownSection, lines: &[&str]) -> Self {
+        let start = section.content_start - 1;
+        let body: Vec<String> = lines[start..start + section.content.len()]
+            .iter()
+            .map(|l| l.to_string())
+            .collect();
+        let code = (0..body.len()).map(|i| section.is_code(i)).collect();
+        Self { level: section.level, name: section.name.clone(), body, code }
+    }
+
+    /// Indices of body lines outside code blocks.
+    fn text_lines(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.body.len()).filter(|&i| !self.code[i])
+    }
+
+    fn retain_lines(&mut self, keep: impl Fn(&Part, usize) -> bool) {
+        let kept: Vec<usize> = (0..self.body.len()).filter(|&i| keep(self, i)).collect();
+        self.body = kept.iter().map(|&i| self.body[i].clone()).collect();
+        self.code = kept.iter().map(|&i| self.code[i]).collect();
+    }
+}
+
+/// H1/H2 heading with all of its subsections.
+struct Block {
+    parts: Vec<Part>,
+}
+
+impl Block {
+    fn head(&self) -> &Part {
+        &self.parts[0]
+    }
+}
+
+/// CLAUDE.md formatter
+pub struct ClaudeMdFormatter {
+    parser: ClaudeMdParser,
+    arrow_pattern: Regex,
+}
+
+impl ClaudeMdFormatter {
+    pub fn new() -> Self {
+        Self {
+            parser: ClaudeMdParser::new(),
+            // Same separator the parser splits a behavior on
+            arrow_pattern: Regex::new(r"\s*(?:→|->)+\s*").unwrap_or_else(|_| Regex::new(r".^").unwrap()),
+        }
+    }
+
+    /// Format CLAUDE.md content. The result always ends with a single newline.
+    pub fn format(&self, content: &str) -> String {
+        let lines: Vec<&str> = content.lines().collect();
+        let sections = parse_sections(content);
+        let preamble_end = sections.first().map(|s| s.line - 1).unwrap_or(lines.len());
+
+        let mut out: Vec<String> = lines[..preamble_end].iter().map(|l| l.trim_end().to_string()).collect();
+        trim_blank_edges(&mut out);
+
+        // H1 blocks stay on top; H2 blocks are reordered
+        let mut titles: Vec<Block> = Vec::new();
+        let mut blocks: Vec<Block> = Vec::new();
+        let mut in_title = false;
+        for section in &sections {
+            let part = Part::from_section(section, &lines);
+            let parent = match part.level {
+                1 => {
+                    in_title = true;
+                    None
+                }
+                2 => {
+                    in_title = false;
+                    None
+                }
+                _ if in_title => titles.last_mut(),
+                _ => blocks.last_mut(),
+            };
+            match parent {
+                Some(block) => block.parts.push(part),
+                None if part.level == 1 => titles.push(Block { parts: vec![part] }),
+                None => blocks.push(Block { parts: vec![part] }),
+            }
+        }
+        blocks.sort_by_key(|block| section_rank(&block.head().name));
+
+        for mut block in titles.into_iter().chain(blocks) {
+            self.normalize_block(&mut block);
+            for part in &block.parts {
+                if !out.is_empty() {
+                    out.push(String::new());
+                }
+                out.push(format!("{} {}", "#".repeat(part.level), part.name));
+                if !part.body.is_empty() {
+                    if part.level <= 2 {
+                        out.push(String::new());
+                    }
+                    out.extend(part.body.iter().cloned());
+                }
+            }
+        }
+
+        let mut formatted = out.join("\n");
+        formatted.push('\n');
+        formatted
+    }
+
+    fn normalize_block(&self, block: &mut Block) {
+        let section = canonical_section_name(&block.head().name);
+        if let Some(name) = section {
+            block.parts[0].name = name.to_string();
+        }
+
+        for part in &mut block.parts {
+            normalize_lines(part);
+            if section == Some("Behavior") {
+                self.normalize_arrows(part);
+            }
+            let body: Vec<&str> = part.body.iter().map(|l| l.as_str()).collect();
+            if crate::is_none_marker_content(&body) {
+                part.body = vec!["None".to_string()];
+                part.code = vec![false];
+            }
+        }
+
+        if section == Some("Exports") {
+            self.sort_exports(block);
+        }
+    }
+
+    fn normalize_arrows(&self, part: &mut Part) {
+        let items: Vec<usize> = part.text_lines().filter(|&i| part.body[i].starts_with("- ")).collect();
+        for i in items {
+            part.body[i] = self.arrow_pattern.replace(&part.body[i], " → ").into_owned();
+        }
+    }
+
+    /// Order Exports subsections by category and the items of each list by name.
+    fn sort_exports(&self, block: &mut Block) {
+        let mut parts = std::mem::take(&mut block.parts).into_iter();
+        let mut head = parts.next().expect("block has a heading");
+        self.sort_items(&mut head);
+
+        // Each H3 keeps its deeper subsections, whose lists are details rather than exports
+        let mut subsections: Vec<Vec<Part>> = Vec::new();
+        for mut part in parts {
+            match subsections.last_mut() {
+                Some(group) if part.level > 3 => group.push(part),
+                _ => {
+                    self.sort_items(&mut part);
+                    subsections.push(vec![part]);
+                }
+            }
+        }
+        subsections.sort_by_key(|group| category_rank(&group[0].name));
+
+        block.parts = std::iter::once(head).chain(subsections.into_iter().flatten()).collect();
+    }
+
+    /// Sort top-level list items (with their indented continuation lines) by export name.
+    /// Lists mixed with prose or code are left alone.
+    fn sort_items(&self, part: &mut Part) {
+        let Some(first_item) = part.body.iter().position(|l| l.starts_with("- ")) else {
+            return;
+        };
+        let tail = first_item..part.body.len();
+        let sortable = tail.clone().all(|i| {
+            let line = &part.body[i];
+            !part.code[i] && (line.is_empty() || line.starts_with("- ") || line.starts_with([' ', '\t']))
+        });
+        if !sortable {
+            return;
+        }
+
+        let mut items: Vec<Vec<String>> = Vec::new();
+        for line in &part.body[tail] {
+            if line.starts_with("- ") {
+                items.push(vec![line.clone()]);
+            } else if !line.is_empty() {
+                items.last_mut().expect("list starts with an item").push(line.clone());
+            }
+        }
+        items.sort_by_cached_key(|item| {
+            let text = item[0].trim_start_matches("- ");
+            self.parser.export_name(text).unwrap_or_else(|| text.to_string())
+        });
+
+        part.body.truncate(first_item);
+        part.body.extend(items.into_iter().flatten());
+        part.code = vec![false; part.body.len()];
+    }
+}
+
+impl Default for ClaudeMdFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whitespace and list-marker cleanup outside code blocks.
+fn normalize_lines(part: &mut Part) {
+    let text: Vec<usize> = part.text_lines().collect();
+    for i in text {
+        let line = part.body[i].trim_end();
+        let indent = line.len() - line.trim_start().len();
+        let rest = &line[indent..];
+        part.body[i] = match rest.strip_prefix(['*', '+']) {
+            Some(item) if item.starts_with(' ') && !is_thematic_break(rest) => {
+                format!("{}-{}", &line[..indent], item)
+            }
+            _ => line.to_string(),
+        };
+    }
+
+    // Collapse blank runs, then drop blank lines at either end
+    part.retain_lines(|p, i| p.code[i] || !p.body[i].is_empty() || i == 0 || !p.body[i - 1].is_empty() || p.code[i - 1]);
+    let start = part.body.iter().position(|l| !l.is_empty()).unwrap_or(part.body.len());
+    let end = part.body.iter().rposition(|l| !l.is_empty()).map_or(start, |i| i + 1);
+    part.retain_lines(|_, i| (start..end).contains(&i));
+}
+
+/// `* * *`, `***`, `---` style horizontal rules.
+fn is_thematic_break(trimmed: &str) -> bool {
+    let marks: String = trimmed.chars().filter(|c| !c.is_whitespace()).collect();
+    marks.len() >= 3 && marks.chars().all(|c| c == marks.chars().next().unwrap_or('*'))
+}
+
+fn trim_blank_edges(lines: &mut Vec<String>) {
+    while lines.last().is_some_and(|l| l.is_empty()) {
+        lines.pop();
+    }
+    let leading = lines.iter().take_while(|l| l.is_empty()).count();
+    lines.drain(..leading);
+}
+
+fn canonical_section_name(name: &str) -> Option<&'static str> {
+    SECTION_ORDER.iter().copied().find(|s| s.eq_ignore_ascii_case(name))
+}
+
+fn section_rank(name: &str) -> usize {
+    SECTION_ORDER
+        .iter()
+        .position(|s| s.eq_ignore_ascii_case(name))
+        .unwrap_or(SECTION_ORDER.len())
+}
+
+/// Position of an Exports subsection in `CATEGORY_ORDER`, with the parser's aliases.
+fn category_rank(name: &str) -> usize {
+    let category = match name.to_lowercase().as_str() {
+        "functions" | "methods" => "Functions",
+        "types" | "structs" | "data classes" => "Types",
+        "classes" => "Classes",
+        "enums" => "Enums",
+        "variables" | "constants" => "Variables",
+        "re-exports" => "Re-exports",
+        _ => return CATEGORY_ORDER.len(),
+    };
+    CATEGORY_ORDER.iter().position(|c| *c == category).unwrap_or(CATEGORY_ORDER.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(content: &str) -> String {
+        ClaudeMdFormatter::new().format(content)
+    }
+
+    #[test]
+    fn test_sections_in_schema_order() {
+        let content = "# auth\n## behavior\n- a → b\n## Notes\nFree text.\n## Purpose\nHandles auth.\n\n\n## Exports\nNone\n";
+        assert_eq!(
+            format(content),
+            "# auth\n\n## Purpose\n\nHandles auth.\n\n## Exports\n\nNone\n\n## Behavior\n\n- a → b\n\n## Notes\n\nFree text.\n"
+        );
+    }
+
+    #[test]
+    fn test_list_markers_and_behavior_arrows() {
+        let content = "## Purpose\n* Handles -> auth.\n\n## Behavior\n\n* valid->Claims\n+ expired  ->  TokenExpiredError\n* * *\n```\n* kept -> as is\n```\n";
+        assert_eq!(
+            format(content),
+            "## Purpose\n\n- Handles -> auth.\n\n## Behavior\n\n- valid → Claims\n- expired → TokenExpiredError\n* * *\n```\n* kept -> as is\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_exports_sorted_like_exports_formatter() {
+        let content = "## Exports\n\n### Notes\nSee docs.\n\n### Classes\n- `Zeta(a: A)`\n- `Alpha()`\n\n### Functions\n\n- `validate(t: string): Claims`\n  Checks the token.\n- `authorize(u: User): bool`\n\n#### authorize\n- b\n- a\n\n### Structs\n- `Config { a: u8 }`\n";
+        assert_eq!(
+            format(content),
+            "## Exports\n\n### Functions\n- `authorize(u: User): bool`\n- `validate(t: string): Claims`\n  Checks the token.\n\n#### authorize\n- b\n- a\n\n### Structs\n- `Config { a: u8 }`\n\n### Classes\n- `Alpha()`\n- `Zeta(a: A)`\n\n### Notes\nSee docs.\n"
+        );
+    }
+
+    #[test]
+    fn test_list_with_prose_is_not_sorted() {
+        let content = "## Exports\n- `b(): void`\nSome prose.\n- `a(): void`\n";
+        assert_eq!(format(content), "## Exports\n\n- `b(): void`\nSome prose.\n- `a(): void`\n");
+    }
+
+    #[test]
+    fn test_none_markers_normalized() {
+        let content = "## Contract\n- n/a\n\n## Protocol\n\n*None*\n\n## Domain Context\n* none\n";
+        assert_eq!(
+            format(content),
+            "## Contract\n\nNone\n\n## Protocol\n\n*None*\n\n## Domain Context\n\nNone\n"
+        );
+    }
+
+    #[test]
+    fn test_setext_headings_and_comments() {
+        let content = "auth\n====\n\nPurpose\n-------\nHandles auth.\n<!--\n## Hidden\n-->\n";
+        let formatted = format(content);
+        assert_eq!(formatted, "# auth\n\n## Purpose\n\nHandles auth.\n<!--\n## Hidden\n-->\n");
+        assert_eq!(format(&formatted), formatted);
+    }
+
+    #[test]
+    fn test_format_is_idempotent_and_keeps_spec() {
+        let content = "# auth\n## Behavior\n### Error Cases\n* bad -> InvalidTokenError\n### Success Cases\n* ok -> Claims\n## Purpose\nAuth.\n## Exports\n### Types\n* `Claims {userId: string}`\n### Functions\n* `validate(t: string): Claims`\n## Contract\nNone\n## Protocol\nNone\n## Domain Context\nNone\n";
+        let formatted = format(content);
+        assert_eq!(format(&formatted), formatted);
+
+        let parser = ClaudeMdParser::new();
+        let before = parser.parse_content(content).unwrap();
+        let after = parser.parse_content(&formatted).unwrap();
+        assert_eq!(after.purpose, before.purpose);
+        assert_eq!(after.exports.functions[0].signature, before.exports.functions[0].signature);
+        assert_eq!(after.exports.types[0].definition, before.exports.types[0].definition);
+        let behaviors = |spec: &crate::claude_md_parser::ClaudeMdSpec| {
+            spec.behaviors.iter().map(|b| (b.input.clone(), b.output.clone(), b.category.clone())).collect::<Vec<_>>()
+        };
+        assert_eq!(behaviors(&after), behaviors(&before));
+    }
+}
@@ -5,6 +5,8 @@ use thiserror::Error;
 
 pub use crate::bracket_utils::split_respecting_brackets;
 use crate::bracket_utils::{find_matching_bracket, extract_parenthesized};
+use crate::markdown::{parse_sections, MarkdownSection};
+use crate::signature::{Language, Signature};
 
 // Include generated constants from schema-rules.yaml (SSOT)
 include!(concat!(env!("OUT_DIR"), "/schema_rules.rs"));
@@ -24,6 +26,75 @@ pub enum ParseError {
     InvalidSectionFormat { section: String, details: String },
 }
 
+/// What a parse diagnostic is about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticKind {
+    /// A required section is absent
+    MissingSection,
+    /// A section that must have content is marked "None"
+    NoneNotAllowed,
+    /// An Exports list item matches no export form
+    UnparseableExport,
+    /// A Behavior list item is not `input → output`
+    MalformedBehavior,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticSeverity {
+    /// Rejected by the fail-fast parser
+    Error,
+    /// Skipped silently by the fail-fast parser
+    Warning,
+}
+
+/// A problem found while parsing CLAUDE.md
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParseDiagnostic {
+    pub kind: DiagnosticKind,
+    pub severity: DiagnosticSeverity,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub section: Option<String>,
+    /// 1-based CLAUDE.md line
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+}
+
+impl ParseDiagnostic {
+    pub fn new(kind: DiagnosticKind, message: String, section: &str, line: Option<usize>) -> Self {
+        let severity = match kind {
+            DiagnosticKind::MissingSection | DiagnosticKind::NoneNotAllowed => DiagnosticSeverity::Error,
+            DiagnosticKind::UnparseableExport | DiagnosticKind::MalformedBehavior => DiagnosticSeverity::Warning,
+        };
+        Self {
+            kind,
+            severity,
+            message,
+            section: Some(section.to_string()),
+            line,
+        }
+    }
+
+    /// The error the fail-fast parser reports for this diagnostic.
+    fn into_parse_error(self) -> ParseError {
+        let section = self.section.unwrap_or_default();
+        match self.kind {
+            DiagnosticKind::MissingSection => ParseError::MissingRequiredSection { section },
+            _ => ParseError::InvalidSectionFormat { section, details: self.message },
+        }
+    }
+}
+
+/// Best-effort spec plus every problem found, from [`ClaudeMdParser::parse_content_lenient`]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct LenientParseResult {
+    #[serde(flatten)]
+    pub spec: ClaudeMdSpec,
+    pub diagnostics: Vec<ParseDiagnostic>,
+}
+
 /// Complete specification parsed from CLAUDE.md
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ClaudeMdSpec {
@@ -49,10 +120,28 @@ pub struct ClaudeMdSpec {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub structure: Option<StructureSpec>,
     /// Validation warnings (non-fatal issues found during parsing)
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub warnings: Vec<String>,
 }
 
+/// 1-based, inclusive range of CLAUDE.md lines an entry was parsed from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LineRange {
+    pub start_line: usize,
+    pub end_line: usize,
+}
+
+impl LineRange {
+    pub fn new(start_line: usize, end_line: usize) -> Self {
+        Self { start_line, end_line }
+    }
+
+    /// Range covering a single line.
+    pub fn line(line: usize) -> Self {
+        Self::new(line, line)
+    }
+}
+
 /// Exports specification
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ExportsSpec {
@@ -70,6 +159,11 @@ pub struct FunctionExport {
     pub signature: String,
     #[serde(default)]
     pub is_async: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<LineRange>,
+    /// `signature` broken into parameters, return type and generics
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parsed_signature: Option<Signature>,
 }
 
 /// Type export
@@ -79,6 +173,8 @@ pub struct TypeExport {
     pub definition: String,
     #[serde(default)]
     pub kind: TypeKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<LineRange>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -97,6 +193,8 @@ pub enum TypeKind {
 pub struct ClassExport {
     pub name: String,
     pub constructor_signature: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<LineRange>,
 }
 
 /// Enum export
@@ -104,6 +202,8 @@ pub struct ClassExport {
 pub struct EnumExport {
     pub name: String,
     pub variants: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<LineRange>,
 }
 
 /// Variable export
@@ -112,6 +212,8 @@ pub struct VariableExport {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<LineRange>,
 }
 
 /// A parsed internal dependency from CLAUDE.md
@@ -120,10 +222,12 @@ pub struct InternalDepSpec {
     /// CLAUDE.md path or raw import path
     pub path: String,
     /// Imported symbols (if specified after colon)
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub symbols: Vec<String>,
     /// Whether the path points to a CLAUDE.md file (new format)
     pub is_claude_md_ref: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<LineRange>,
 }
 
 /// Dependencies specification
@@ -131,6 +235,9 @@ pub struct InternalDepSpec {
 pub struct DependenciesSpec {
     pub external: Vec<String>,
     pub internal: Vec<InternalDepSpec>,
+    /// Lines of each `external` entry, index-aligned with `external`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub external_locations: Vec<LineRange>,
 }
 
 /// Behavioral scenario
@@ -139,6 +246,8 @@ pub struct BehaviorSpec {
     pub input: String,
     pub output: String,
     pub category: BehaviorCategory,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<LineRange>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
@@ -157,6 +266,9 @@ pub struct ContractSpec {
     pub postconditions: Vec<String>,
     pub throws: Vec<String>,
     pub invariants: Vec<String>,
+    /// Lines from the function heading to its last non-blank line
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<LineRange>,
 }
 
 /// Domain Context specification
@@ -181,6 +293,8 @@ pub struct TransitionSpec {
     pub from: String,
     pub trigger: String,
     pub to: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<LineRange>,
 }
 
 /// Lifecycle method
@@ -189,6 +303,8 @@ pub struct LifecycleMethod {
     pub order: u32,
     pub method: String,
     pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<LineRange>,
 }
 
 /// Directory structure
@@ -203,6 +319,8 @@ pub struct StructureSpec {
 pub struct StructureEntry {
     pub name: String,
     pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<LineRange>,
 }
 
 /// Deduplicate items by name, keeping the first occurrence.
@@ -214,9 +332,42 @@ where
     items.retain(|item| seen.insert(name_fn(item).clone()));
 }
 
+/// Text of an unindented list item outside code blocks, unless it is a "None" marker.
+/// Indented items and prose are descriptions, not entries.
+fn list_item(section: &MarkdownSection, index: usize) -> Option<&str> {
+    let line = section.content[index].as_str();
+    if section.is_code(index) {
+        return None;
+    }
+    let item = line.strip_prefix("- ").or_else(|| line.strip_prefix("* "))?.trim();
+    let is_none = item.eq_ignore_ascii_case("none") || item.eq_ignore_ascii_case("n/a");
+    (!item.is_empty() && !is_none).then_some(item)
+}
+
+fn report_unparseable_export(section: &MarkdownSection, index: usize, diagnostics: &mut Vec<ParseDiagnostic>) {
+    if let Some(item) = list_item(section, index) {
+        diagnostics.push(ParseDiagnostic::new(
+            DiagnosticKind::UnparseableExport,
+            format!("Export does not match any known signature form: {}", item),
+            &section.name,
+            Some(section.content_line(index)),
+        ));
+    }
+}
+
+fn report_malformed_behavior(section: &MarkdownSection, index: usize, diagnostics: &mut Vec<ParseDiagnostic>) {
+    if let Some(item) = list_item(section, index) {
+        diagnostics.push(ParseDiagnostic::new(
+            DiagnosticKind::MalformedBehavior,
+            format!("Behavior is not in 'input → output' form: {}", item),
+            &section.name,
+            Some(section.content_line(index)),
+        ));
+    }
+}
+
 /// CLAUDE.md Parser
 pub struct ClaudeMdParser {
-    section_pattern: Regex,
     behavior_pattern: Regex,
     function_pattern: Regex,
     type_pattern: Regex,
@@ -231,8 +382,6 @@ pub struct ClaudeMdParser {
 impl ClaudeMdParser {
     pub fn new() -> Self {
         Self {
-            // Match markdown headers: ## Purpose, ### Functions
-            section_pattern: Regex::new(r"^(#{1,4})\s+(.+)$").unwrap_or_else(|_| Regex::new(r".^").unwrap()),
             // Match behavior: input → output or input -> output
             behavior_pattern: Regex::new(r"^[-*]?\s*(.+?)\s*(?:→|->)+\s*(.+)$").unwrap_or_else(|_| Regex::new(r".^").unwrap()),
             // Match function signature: `funcName(params): ReturnType` or Name(params): Type
@@ -263,12 +412,35 @@ impl ClaudeMdParser {
         self.parse_content(&content)
     }
 
+    /// Parse a CLAUDE.md file in lenient mode
+    pub fn parse_lenient(&self, file: &Path) -> Result<LenientParseResult, ParseError> {
+        let content = std::fs::read_to_string(file).map_err(|e| ParseError::FileReadError {
+            path: file.to_string_lossy().to_string(),
+            source: e,
+        })?;
+
+        Ok(self.parse_content_lenient(&content))
+    }
+
     /// Parse CLAUDE.md content directly
-    /// Returns Err immediately if any required section is missing.
+    /// Returns Err if any required section is missing, reporting the first one.
     /// Required sections are defined in schema-rules.yaml (SSOT).
     pub fn parse_content(&self, content: &str) -> Result<ClaudeMdSpec, ParseError> {
+        let LenientParseResult { spec, diagnostics } = self.parse_content_lenient(content);
+
+        match diagnostics.into_iter().find(|d| d.severity == DiagnosticSeverity::Error) {
+            Some(diagnostic) => Err(diagnostic.into_parse_error()),
+            None => Ok(spec),
+        }
+    }
+
+    /// Parse CLAUDE.md content without failing: always returns a best-effort spec,
+    /// together with diagnostics for missing sections, unparseable export lines and
+    /// malformed behaviors (in document order per check).
+    pub fn parse_content_lenient(&self, content: &str) -> LenientParseResult {
         let mut spec = ClaudeMdSpec::default();
-        let sections = self.extract_sections(content);
+        let mut diagnostics = Vec::new();
+        let sections = parse_sections(content);
 
         // Extract module name from first H1 header
         for section in &sections {
@@ -278,15 +450,18 @@ impl ClaudeMdParser {
             }
         }
 
-        // Check all required sections exist (from SSOT) - FAIL FAST
+        // Check all required sections exist (from SSOT)
         for required in REQUIRED_SECTIONS {
             let section_found = sections.iter().find(|s| s.name.eq_ignore_ascii_case(required));
 
             match section_found {
                 None => {
-                    return Err(ParseError::MissingRequiredSection {
-                        section: required.to_string(),
-                    });
+                    diagnostics.push(ParseDiagnostic::new(
+                        DiagnosticKind::MissingSection,
+                        format!("Missing required section: {}", required),
+                        required,
+                        None,
+                    ));
                 }
                 Some(section) => {
                     // For sections that allow "None", check if it's a valid None marker
@@ -297,10 +472,12 @@ impl ClaudeMdParser {
 
                     // If section doesn't allow None but has None marker, that's an error
                     if !allows_none && is_none_marker {
-                        return Err(ParseError::InvalidSectionFormat {
-                            section: required.to_string(),
-                            details: format!("Section '{}' does not allow 'None' as value", required),
-                        });
+                        diagnostics.push(ParseDiagnostic::new(
+                            DiagnosticKind::NoneNotAllowed,
+                            format!("Section '{}' does not allow 'None' as value", required),
+                            required,
+                            Some(section.line),
+                        ));
                     }
                 }
             }
@@ -312,15 +489,15 @@ impl ClaudeMdParser {
         }
 
         // Parse Exports section
-        self.parse_exports(&sections, &mut spec);
+        self.parse_exports(&sections, &mut spec, &mut diagnostics);
 
         // Parse Dependencies section (optional - not in REQUIRED_SECTIONS)
         if let Some(deps_section) = sections.iter().find(|s| s.name.eq_ignore_ascii_case("Dependencies")) {
-            spec.dependencies = self.parse_dependencies(&deps_section.content);
+            spec.dependencies = self.parse_dependencies(deps_section);
         }
 
         // Parse Behavior section
-        self.parse_behaviors(&sections, &mut spec);
+        self.parse_behaviors(&sections, &mut spec, &mut diagnostics);
 
         // Parse Contract section
         self.parse_contracts(&sections, &mut spec);
@@ -333,50 +510,19 @@ impl ClaudeMdParser {
 
         // Parse Structure section (optional - not in REQUIRED_SECTIONS)
         if let Some(structure_section) = sections.iter().find(|s| s.name.eq_ignore_ascii_case("Structure")) {
-            spec.structure = Some(self.parse_structure(&structure_section.content));
+            spec.structure = Some(self.parse_structure(structure_section));
         }
 
-        Ok(spec)
+        LenientParseResult { spec, diagnostics }
     }
 
     /// Check if a section contains only a "None" marker (None, N/A, etc.)
-    fn is_none_marker(&self, section: &ParserSection) -> bool {
+    fn is_none_marker(&self, section: &MarkdownSection) -> bool {
         let lines: Vec<&str> = section.content.iter().map(|s| s.as_str()).collect();
         crate::is_none_marker_content(&lines)
     }
 
-    fn extract_sections(&self, content: &str) -> Vec<ParserSection> {
-        let mut sections = Vec::new();
-        let mut current_section: Option<ParserSection> = None;
-
-        for line in content.lines() {
-            if let Some(caps) = self.section_pattern.captures(line) {
-                // Save previous section
-                if let Some(section) = current_section.take() {
-                    sections.push(section);
-                }
-
-                let level = caps.get(1).map(|m| m.as_str().len()).unwrap_or(1);
-                let name = caps.get(2).map(|m| m.as_str().to_string()).unwrap_or_default();
-
-                current_section = Some(ParserSection {
-                    name,
-                    level,
-                    content: Vec::new(),
-                });
-            } else if let Some(ref mut section) = current_section {
-                section.content.push(line.to_string());
-            }
-        }
-
-        if let Some(section) = current_section {
-            sections.push(section);
-        }
-
-        sections
-    }
-
-    fn parse_exports(&self, sections: &[ParserSection], spec: &mut ClaudeMdSpec) {
+    fn parse_exports(&self, sections: &[MarkdownSection], spec: &mut ClaudeMdSpec, diagnostics: &mut Vec<ParseDiagnostic>) {
         // Note: existence of Exports section is checked in parse_content (fail-fast)
         let exports_section = sections.iter().find(|s| s.name.eq_ignore_ascii_case("Exports"));
 
@@ -413,21 +559,13 @@ impl ClaudeMdParser {
                 in_variables = false;
                 // Parse direct content of ## Exports (flat exports before any subsection)
                 if in_exports_scope {
-                    for line in &section.content {
+                    for (i, line) in section.content.iter().enumerate() {
                         let trimmed = line.trim();
                         if trimmed.is_empty() || trimmed.eq_ignore_ascii_case("none") {
                             continue;
                         }
-                        if let Some(func) = self.parse_function_line(trimmed) {
-                            spec.exports.functions.push(func);
-                        } else if let Some(type_export) = self.parse_type_line(trimmed, false, false) {
-                            spec.exports.types.push(type_export);
-                        } else if let Some(class) = self.parse_class_line(trimmed) {
-                            spec.exports.classes.push(class);
-                        } else if let Some(enum_export) = self.parse_enum_line(trimmed) {
-                            spec.exports.enums.push(enum_export);
-                        } else if let Some(var) = self.parse_variable_line(trimmed) {
-                            spec.exports.variables.push(var);
+                        if !self.parse_untyped_export_line(trimmed, section.content_line(i), &mut spec.exports) {
+                            report_unparseable_export(section, i, diagnostics);
                         }
                     }
                 }
@@ -492,32 +630,34 @@ impl ClaudeMdParser {
             }
 
             // Parse content based on context
-            for line in &section.content {
+            for (i, line) in section.content.iter().enumerate() {
                 let trimmed = line.trim();
                 if trimmed.is_empty() || trimmed.eq_ignore_ascii_case("none") {
                     continue;
                 }
+                let location = Some(LineRange::line(section.content_line(i)));
 
-                if in_functions || in_methods {
-                    if let Some(func) = self.parse_function_line(trimmed) {
-                        spec.exports.functions.push(func);
-                    }
+                let parsed = if in_functions || in_methods {
+                    self.parse_function_line(trimmed)
+                        .map(|func| spec.exports.functions.push(FunctionExport { location, ..func }))
                 } else if in_types || in_structs || in_data_classes {
-                    if let Some(type_export) = self.parse_type_line(trimmed, in_structs, in_data_classes) {
-                        spec.exports.types.push(type_export);
-                    }
+                    self.parse_type_line(trimmed, in_structs, in_data_classes)
+                        .map(|type_export| spec.exports.types.push(TypeExport { location, ..type_export }))
                 } else if in_classes {
-                    if let Some(class) = self.parse_class_line(trimmed) {
-                        spec.exports.classes.push(class);
-                    }
+                    self.parse_class_line(trimmed)
+                        .map(|class| spec.exports.classes.push(ClassExport { location, ..class }))
                 } else if in_enums {
-                    if let Some(enum_export) = self.parse_enum_line(trimmed) {
-                        spec.exports.enums.push(enum_export);
-                    }
+                    self.parse_enum_line(trimmed)
+                        .map(|enum_export| spec.exports.enums.push(EnumExport { location, ..enum_export }))
                 } else if in_variables {
-                    if let Some(var) = self.parse_variable_line(trimmed) {
-                        spec.exports.variables.push(var);
-                    }
+                    self.parse_variable_line(trimmed)
+                        .map(|var| spec.exports.variables.push(VariableExport { location, ..var }))
+                } else {
+                    // Unknown subsection (e.g. ### Notes): free-form content
+                    Some(())
+                };
+                if parsed.is_none() {
+                    report_unparseable_export(section, i, diagnostics);
                 }
             }
         }
@@ -537,24 +677,13 @@ impl ClaudeMdParser {
             && spec.exports.variables.is_empty()
         {
             if let Some(exports) = exports_section {
-                for line in &exports.content {
+                for (i, line) in exports.content.iter().enumerate() {
                     let trimmed = line.trim();
                     if trimmed.is_empty() || trimmed.eq_ignore_ascii_case("none") {
                         continue;
                     }
-
-                    // Try parsing as function first
-                    if let Some(func) = self.parse_function_line(trimmed) {
-                        spec.exports.functions.push(func);
-                    } else if let Some(type_export) = self.parse_type_line(trimmed, false, false) {
-                        spec.exports.types.push(type_export);
-                    } else if let Some(class) = self.parse_class_line(trimmed) {
-                        spec.exports.classes.push(class);
-                    } else if let Some(enum_export) = self.parse_enum_line(trimmed) {
-                        spec.exports.enums.push(enum_export);
-                    } else if let Some(var) = self.parse_variable_line(trimmed) {
-                        spec.exports.variables.push(var);
-                    }
+                    // Already reported by the loop above when Exports is an H2
+                    self.parse_untyped_export_line(trimmed, exports.content_line(i), &mut spec.exports);
                 }
             }
         }
@@ -578,7 +707,47 @@ impl ClaudeMdParser {
         }
     }
 
+    /// Parse an export line outside any kind subsection, trying function first.
+    /// Returns false if the line matches no export form.
+    fn parse_untyped_export_line(&self, trimmed: &str, line_number: usize, exports: &mut ExportsSpec) -> bool {
+        let location = Some(LineRange::line(line_number));
+        if let Some(func) = self.parse_function_line(trimmed) {
+            exports.functions.push(FunctionExport { location, ..func });
+        } else if let Some(type_export) = self.parse_type_line(trimmed, false, false) {
+            exports.types.push(TypeExport { location, ..type_export });
+        } else if let Some(class) = self.parse_class_line(trimmed) {
+            exports.classes.push(ClassExport { location, ..class });
+        } else if let Some(enum_export) = self.parse_enum_line(trimmed) {
+            exports.enums.push(EnumExport { location, ..enum_export });
+        } else if let Some(var) = self.parse_variable_line(trimmed) {
+            exports.variables.push(VariableExport { location, ..var });
+        } else {
+            return false;
+        }
+        true
+    }
+
+    /// Name of the symbol an export line declares, trying the same forms as a flat Exports line.
+    pub(crate) fn export_name(&self, line: &str) -> Option<String> {
+        let trimmed = line.trim();
+        self.parse_function_line(trimmed)
+            .map(|f| f.name)
+            .or_else(|| self.parse_type_line(trimmed, false, false).map(|t| t.name))
+            .or_else(|| self.parse_class_line(trimmed).map(|c| c.name))
+            .or_else(|| self.parse_enum_line(trimmed).map(|e| e.name))
+            .or_else(|| self.parse_variable_line(trimmed).map(|v| v.name))
+    }
+
     fn parse_function_line(&self, line: &str) -> Option<FunctionExport> {
+        let func = self.parse_function_forms(line)?;
+        // Spec signatures may be written in any language's syntax: parameters are parsed
+        // by inference, and the language is tagged when the declaration keyword tells it
+        let parsed_signature = Signature::parse(&func.signature, &func.name, Language::Unknown)
+            .map(|sig| Signature { language: spec_signature_language(line), ..sig });
+        Some(FunctionExport { parsed_signature, ..func })
+    }
+
+    fn parse_function_forms(&self, line: &str) -> Option<FunctionExport> {
         // Handle various function signature formats
         let cleaned = line.trim_start_matches('-').trim_start_matches('*').trim();
         let cleaned = cleaned.trim_start_matches('`').trim_end_matches('`');
@@ -607,6 +776,8 @@ impl ClaudeMdParser {
                 name,
                 signature,
                 is_async,
+                location: None,
+                parsed_signature: None,
             });
         }
 
@@ -690,6 +861,8 @@ impl ClaudeMdParser {
             name,
             signature,
             is_async,
+            location: None,
+            parsed_signature: None,
         })
     }
 
@@ -728,6 +901,8 @@ impl ClaudeMdParser {
             name,
             signature,
             is_async: return_type.contains("CompletableFuture"),
+            location: None,
+            parsed_signature: None,
         })
     }
 
@@ -761,6 +936,8 @@ impl ClaudeMdParser {
             name,
             signature,
             is_async: is_suspend,
+            location: None,
+            parsed_signature: None,
         })
     }
 
@@ -795,6 +972,8 @@ impl ClaudeMdParser {
                 name,
                 signature,
                 is_async: false,
+                location: None,
+                parsed_signature: None,
             });
         }
 
@@ -803,6 +982,8 @@ impl ClaudeMdParser {
             name,
             signature,
             is_async: false,
+            location: None,
+            parsed_signature: None,
         })
     }
 
@@ -833,6 +1014,8 @@ impl ClaudeMdParser {
             name,
             signature,
             is_async,
+            location: None,
+            parsed_signature: None,
         })
     }
 
@@ -842,7 +1025,7 @@ impl ClaudeMdParser {
 
         if let Some(caps) = self.type_pattern.captures(cleaned) {
             let name = caps.get(1)?.as_str().to_string();
-            let fields = caps.get(2)?.as_str();
+            let fields = caps.get(2)?.as_str().trim();
 
             let definition = format!("{} {{ {} }}", name, fields);
             let kind = if is_struct {
@@ -857,6 +1040,7 @@ impl ClaudeMdParser {
                 name,
                 definition,
                 kind,
+                location: None,
             });
         }
 
@@ -869,6 +1053,7 @@ impl ClaudeMdParser {
                 name: name.clone(),
                 definition: format!("data class {}({})", name, fields),
                 kind: TypeKind::DataClass,
+                location: None,
             });
         }
 
@@ -887,6 +1072,7 @@ impl ClaudeMdParser {
             return Some(ClassExport {
                 name,
                 constructor_signature,
+                location: None,
             });
         }
 
@@ -918,7 +1104,7 @@ impl ClaudeMdParser {
             return None;
         }
 
-        Some(EnumExport { name, variants })
+        Some(EnumExport { name, variants, location: None })
     }
 
     fn parse_variable_line(&self, line: &str) -> Option<VariableExport> {
@@ -938,6 +1124,7 @@ impl ClaudeMdParser {
             return Some(VariableExport {
                 name,
                 value: if value.is_empty() { None } else { Some(value) },
+                location: None,
             });
         }
 
@@ -951,18 +1138,20 @@ impl ClaudeMdParser {
             return Some(VariableExport {
                 name,
                 value: if type_str.is_empty() { None } else { Some(type_str) },
+                location: None,
             });
         }
 
         None
     }
 
-    fn parse_dependencies(&self, content: &[String]) -> DependenciesSpec {
+    fn parse_dependencies(&self, section: &MarkdownSection) -> DependenciesSpec {
         let mut deps = DependenciesSpec::default();
         let mut current_dep_type: Option<String> = None;
 
-        for line in content {
+        for (i, line) in section.content.iter().enumerate() {
             let trimmed = line.trim();
+            let location = LineRange::line(section.content_line(i));
 
             // Check for top-level dep type markers: "- external:" or "- internal:"
             if let Some(caps) = self.dependency_pattern.captures(trimmed) {
@@ -975,12 +1164,16 @@ impl ClaudeMdParser {
                 } else {
                     // Inline-style: "- internal: ./types"
                     match dep_type {
-                        "external" => deps.external.push(dep_value),
+                        "external" => {
+                            deps.external.push(dep_value);
+                            deps.external_locations.push(location);
+                        }
                         "internal" => {
                             deps.internal.push(InternalDepSpec {
                                 path: dep_value,
                                 symbols: Vec::new(),
                                 is_claude_md_ref: false,
+                                location: Some(location),
                             });
                         }
                         _ => {}
@@ -998,14 +1191,14 @@ impl ClaudeMdParser {
                 }
 
                 // Parse: `path`: symbols  or  `path`
-                if sub_trimmed.starts_with('`') {
-                    if let Some(backtick_end) = sub_trimmed[1..].find('`') {
-                        let path = sub_trimmed[1..=backtick_end].to_string();
-                        let rest = sub_trimmed[backtick_end + 2..].trim();
+                if let Some(quoted) = sub_trimmed.strip_prefix('`') {
+                    if let Some(backtick_end) = quoted.find('`') {
+                        let path = quoted[..backtick_end].to_string();
+                        let rest = quoted[backtick_end + 1..].trim();
 
                         // Extract symbols after colon
-                        let symbols = if rest.starts_with(':') {
-                            rest[1..]
+                        let symbols = if let Some(symbol_list) = rest.strip_prefix(':') {
+                            symbol_list
                                 .split(',')
                                 .map(|s| s.trim().to_string())
                                 .filter(|s| !s.is_empty())
@@ -1015,19 +1208,23 @@ impl ClaudeMdParser {
                         };
 
                         match dep_type.as_str() {
-                            "external" => deps.external.push(
-                                if symbols.is_empty() {
-                                    path
-                                } else {
-                                    format!("{}: {}", path, symbols.join(", "))
-                                }
-                            ),
+                            "external" => {
+                                deps.external.push(
+                                    if symbols.is_empty() {
+                                        path
+                                    } else {
+                                        format!("{}: {}", path, symbols.join(", "))
+                                    }
+                                );
+                                deps.external_locations.push(location);
+                            }
                             "internal" => {
                                 let is_claude_md = path.ends_with("/CLAUDE.md");
                                 deps.internal.push(InternalDepSpec {
                                     path,
                                     symbols,
                                     is_claude_md_ref: is_claude_md,
+                                    location: Some(location),
                                 });
                             }
                             _ => {}
@@ -1036,12 +1233,16 @@ impl ClaudeMdParser {
                 } else {
                     // Plain text sub-item (legacy format or simple text)
                     match dep_type.as_str() {
-                        "external" => deps.external.push(sub_trimmed.to_string()),
+                        "external" => {
+                            deps.external.push(sub_trimmed.to_string());
+                            deps.external_locations.push(location);
+                        }
                         "internal" => {
                             deps.internal.push(InternalDepSpec {
                                 path: sub_trimmed.to_string(),
                                 symbols: Vec::new(),
                                 is_claude_md_ref: false,
+                                location: Some(location),
                             });
                         }
                         _ => {}
@@ -1053,7 +1254,7 @@ impl ClaudeMdParser {
         deps
     }
 
-    fn parse_behaviors(&self, sections: &[ParserSection], spec: &mut ClaudeMdSpec) {
+    fn parse_behaviors(&self, sections: &[MarkdownSection], spec: &mut ClaudeMdSpec, diagnostics: &mut Vec<ParseDiagnostic>) {
         // Note: existence of Behavior section is checked in parse_content (fail-fast)
         // Find the index of the Behavior section
         let behavior_idx = match sections.iter().position(|s| s.name.eq_ignore_ascii_case("Behavior")) {
@@ -1065,7 +1266,7 @@ impl ClaudeMdParser {
         let mut current_category = BehaviorCategory::Success;
 
         // First parse the main Behavior section content
-        for line in &behavior_section.content {
+        for (i, line) in behavior_section.content.iter().enumerate() {
             let trimmed = line.trim();
             if trimmed.is_empty() || trimmed.eq_ignore_ascii_case("none") {
                 continue;
@@ -1085,7 +1286,10 @@ impl ClaudeMdParser {
                     input,
                     output,
                     category,
+                    location: Some(LineRange::line(behavior_section.content_line(i))),
                 });
+            } else {
+                report_malformed_behavior(behavior_section, i, diagnostics);
             }
         }
 
@@ -1106,7 +1310,7 @@ impl ClaudeMdParser {
             }
 
             // Parse behavior lines in subsection
-            for line in &section.content {
+            for (i, line) in section.content.iter().enumerate() {
                 let trimmed = line.trim();
                 if trimmed.is_empty() || trimmed.eq_ignore_ascii_case("none") {
                     continue;
@@ -1127,7 +1331,10 @@ impl ClaudeMdParser {
                         input,
                         output,
                         category,
+                        location: Some(LineRange::line(section.content_line(i))),
                     });
+                } else {
+                    report_malformed_behavior(section, i, diagnostics);
                 }
             }
         }
@@ -1143,7 +1350,7 @@ impl ClaudeMdParser {
         }
     }
 
-    fn parse_contracts(&self, sections: &[ParserSection], spec: &mut ClaudeMdSpec) {
+    fn parse_contracts(&self, sections: &[MarkdownSection], spec: &mut ClaudeMdSpec) {
         let contract_idx = sections.iter().position(|s| s.name.eq_ignore_ascii_case("Contract"));
         if contract_idx.is_none() {
             return;
@@ -1152,7 +1359,7 @@ impl ClaudeMdParser {
         let contract_level = sections[contract_start].level;
 
         // Collect Contract section and its subsections (until next same-or-higher level section)
-        let contract_sections: Vec<&ParserSection> = std::iter::once(&sections[contract_start])
+        let contract_sections: Vec<&MarkdownSection> = std::iter::once(&sections[contract_start])
             .chain(
                 sections[contract_start + 1..]
                     .iter()
@@ -1167,6 +1374,7 @@ impl ClaudeMdParser {
             postconditions: Vec::new(),
             throws: Vec::new(),
             invariants: Vec::new(),
+            location: None,
         };
 
         for section in &contract_sections {
@@ -1187,15 +1395,19 @@ impl ClaudeMdParser {
                     postconditions: Vec::new(),
                     throws: Vec::new(),
                     invariants: Vec::new(),
+                    location: Some(LineRange::line(section.line)),
                 };
             }
 
             // Parse contract content
-            for line in &section.content {
+            for (i, line) in section.content.iter().enumerate() {
                 let trimmed = line.trim();
                 if trimmed.is_empty() {
                     continue;
                 }
+                if let Some(location) = current_contract.location.as_mut() {
+                    location.end_line = section.content_line(i);
+                }
 
                 if trimmed.contains("**Preconditions**") || trimmed.contains("**Precondition**") {
                     let value = trimmed.split(':').nth(1).unwrap_or("").trim();
@@ -1230,7 +1442,7 @@ impl ClaudeMdParser {
         }
     }
 
-    fn parse_protocol(&self, sections: &[ParserSection], spec: &mut ClaudeMdSpec) {
+    fn parse_protocol(&self, sections: &[MarkdownSection], spec: &mut ClaudeMdSpec) {
         let protocol_idx = sections.iter().position(|s| s.name.eq_ignore_ascii_case("Protocol"));
         if protocol_idx.is_none() {
             return;
@@ -1239,7 +1451,7 @@ impl ClaudeMdParser {
         let protocol_level = sections[protocol_start].level;
 
         // Collect Protocol section and its subsections (until next same-or-higher level section)
-        let protocol_sections: Vec<&ParserSection> = std::iter::once(&sections[protocol_start])
+        let protocol_sections: Vec<&MarkdownSection> = std::iter::once(&sections[protocol_start])
             .chain(
                 sections[protocol_start + 1..]
                     .iter()
@@ -1262,11 +1474,12 @@ impl ClaudeMdParser {
                 in_lifecycle = true;
             }
 
-            for line in &section.content {
+            for (i, line) in section.content.iter().enumerate() {
                 let trimmed = line.trim();
                 if trimmed.is_empty() {
                     continue;
                 }
+                let location = Some(LineRange::line(section.content_line(i)));
 
                 // Parse states line: States: `A` | `B` | `C`
                 if trimmed.starts_with("States:") {
@@ -1285,7 +1498,7 @@ impl ClaudeMdParser {
                         let trigger = caps.get(2).map(|m| m.as_str().trim().to_string()).unwrap_or_default();
                         let to = caps.get(3).map(|m| m.as_str().trim().to_string()).unwrap_or_default();
 
-                        protocol.transitions.push(TransitionSpec { from, trigger, to });
+                        protocol.transitions.push(TransitionSpec { from, trigger, to, location });
                     }
                 }
                 // Parse lifecycle methods
@@ -1299,6 +1512,7 @@ impl ClaudeMdParser {
                             order,
                             method,
                             description,
+                            location,
                         });
                     }
                 }
@@ -1310,7 +1524,7 @@ impl ClaudeMdParser {
         }
     }
 
-    fn parse_domain_context(&self, sections: &[ParserSection], spec: &mut ClaudeMdSpec) {
+    fn parse_domain_context(&self, sections: &[MarkdownSection], spec: &mut ClaudeMdSpec) {
         let dc_idx = sections.iter().position(|s| s.name.eq_ignore_ascii_case("Domain Context"));
         if dc_idx.is_none() {
             return;
@@ -1326,7 +1540,7 @@ impl ClaudeMdParser {
         let dc_level = dc_section.level;
 
         // Collect Domain Context section and its subsections
-        let dc_sections: Vec<&ParserSection> = std::iter::once(&sections[dc_start])
+        let dc_sections: Vec<&MarkdownSection> = std::iter::once(&sections[dc_start])
             .chain(
                 sections[dc_start + 1..]
                     .iter()
@@ -1382,10 +1596,10 @@ impl ClaudeMdParser {
         }
     }
 
-    fn parse_structure(&self, content: &[String]) -> StructureSpec {
+    fn parse_structure(&self, section: &MarkdownSection) -> StructureSpec {
         let mut structure = StructureSpec::default();
 
-        for line in content {
+        for (i, line) in section.content.iter().enumerate() {
             let trimmed = line.trim();
             if trimmed.is_empty() {
                 continue;
@@ -1398,6 +1612,7 @@ impl ClaudeMdParser {
                 let entry = StructureEntry {
                     name: name.trim_end_matches('/').to_string(),
                     description,
+                    location: Some(LineRange::line(section.content_line(i))),
                 };
 
                 if name.ends_with('/') {
@@ -1418,14 +1633,40 @@ impl Default for ClaudeMdParser {
     }
 }
 
-/// Section representation for CLAUDE.md parsing, storing heading level and raw content lines.
-struct ParserSection {
-    name: String,
-    level: usize,
-    content: Vec<String>,
+/// Language of a spec signature line, from its declaration keyword: `fn` (Rust), `fun`
+/// (Kotlin), `func` (Go, or Swift with `name: Type` parameters or `->`), `def` (Python
+/// with `->` or annotated parameters, Scala with `): Type`) and `function` with `$`
+/// parameters (PHP). Other lines are `Unknown`.
+fn spec_signature_language(line: &str) -> Language {
+    const MODIFIERS: &[&str] = &[
+        "pub", "public", "private", "protected", "internal", "static", "async", "suspend",
+        "override", "open", "final", "abstract", "export", "inline", "unsafe", "const", "extern",
+    ];
+    let cleaned = line.trim_start_matches('-').trim_start_matches('*').trim();
+    let cleaned = cleaned.trim_start_matches('`').trim_end_matches('`');
+    let keyword = cleaned
+        .split_whitespace()
+        .find(|w| !MODIFIERS.contains(w) && !w.starts_with("pub(") && !w.starts_with('@'))
+        .unwrap_or("");
+    let params = cleaned
+        .find('(')
+        .and_then(|open| find_matching_bracket(cleaned, open, '(', ')').map(|close| &cleaned[open + 1..close]))
+        .unwrap_or("");
+    let after_params = cleaned.rsplit_once(')').map_or("", |(_, rest)| rest.trim_start());
+
+    match keyword {
+        "fn" => Language::Rust,
+        "fun" => Language::Kotlin,
+        "func" if params.contains(':') || cleaned.contains("->") => Language::Swift,
+        "func" => Language::Go,
+        "def" if cleaned.contains("->") => Language::Python,
+        "def" if after_params.starts_with(':') || !cleaned.contains('(') => Language::Scala,
+        "def" if params.contains(':') => Language::Python,
+        "function" if params.contains('$') => Language::Php,
+        _ => Language::Unknown,
+    }
 }
 
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1445,6 +1686,25 @@ mod tests {
         content
     }
 
+    #[test]
+    fn test_spec_signature_language_from_keyword() {
+        let cases = [
+            ("- `def issue(user_id: str) -> Token`", Language::Python),
+            ("- `def issue(user_id: str)`", Language::Python),
+            ("- `def issue(userId: String): Token`", Language::Scala),
+            ("- `suspend fun issue(userId: String): Token`", Language::Kotlin),
+            ("- `func Issue(userID string) (*Token, error)`", Language::Go),
+            ("- `func (s *Service) Issue(userID string) error`", Language::Go),
+            ("- `func issue(for userId: String) -> Token`", Language::Swift),
+            ("- `pub fn issue(user_id: &str) -> Token`", Language::Rust),
+            ("- `function issue(string $userId): Token`", Language::Php),
+            ("- `issue(userId: string): Token`", Language::Unknown),
+        ];
+        for (line, language) in cases {
+            assert_eq!(spec_signature_language(line), language, "{}", line);
+        }
+    }
+
     #[test]
     fn test_parse_purpose() {
         let parser = ClaudeMdParser::new();
@@ -1515,6 +1775,11 @@ Test module.
         assert_eq!(spec.dependencies.external[0], "jsonwebtoken@9.0.0");
         assert_eq!(spec.dependencies.internal[0].path, "./types");
         assert!(!spec.dependencies.internal[0].is_claude_md_ref);
+
+        // `external` stays a list of strings in the JSON output; lines are a side list
+        let json = serde_json::to_value(&spec.dependencies).unwrap();
+        assert_eq!(json["external"], serde_json::json!(["jsonwebtoken@9.0.0"]));
+        assert_eq!(json["external_locations"].as_array().map(Vec::len), Some(1));
     }
 
     #[test]
@@ -1591,6 +1856,90 @@ Test module.
         assert_eq!(spec.behaviors[1].category, BehaviorCategory::Error);
     }
 
+    #[test]
+    fn test_parsed_entries_carry_line_ranges() {
+        let parser = ClaudeMdParser::new();
+        let content = r#"# test
+
+## Purpose
+Test module.
+
+## Exports
+
+### Functions
+- `validate(token: string): Claims`
+
+### Types
+- `Claims { userId: string }`
+
+## Dependencies
+- external: jsonwebtoken
+- internal:
+  - `../types/CLAUDE.md`: Claims
+
+## Behavior
+- valid token → Claims
+- expired token → TokenExpiredError
+
+## Contract
+
+### validate
+- **Preconditions**: token must be non-empty
+
+- **Throws**: TokenExpiredError
+
+## Protocol
+
+### State Machine
+States: `Idle` | `Done`
+- `Idle` + `validate()` → `Done`
+
+## Domain Context
+None
+
+## Structure
+- jwt/: JWT helpers
+"#;
+        let spec = parser.parse_content(content).unwrap();
+        let line = |n| Some(LineRange::line(n));
+
+        assert_eq!(spec.exports.functions[0].location, line(9));
+        assert_eq!(spec.exports.types[0].location, line(12));
+        assert_eq!(spec.dependencies.external_locations, vec![LineRange::line(15)]);
+        assert_eq!(spec.dependencies.internal[0].location, line(17));
+        assert_eq!(spec.behaviors[0].location, line(20));
+        assert_eq!(spec.behaviors[1].location, line(21));
+        assert_eq!(spec.contracts[0].location, Some(LineRange::new(25, 28)));
+        assert_eq!(spec.protocol.unwrap().transitions[0].location, line(34));
+        assert_eq!(spec.structure.unwrap().subdirs[0].location, line(40));
+    }
+
+    #[test]
+    fn test_code_fence_comments_do_not_split_sections() {
+        let parser = ClaudeMdParser::new();
+        let content = with_required_sections(
+            r#"# test
+
+## Purpose
+Test module.
+
+## Exports
+- `validate(token: string): Claims`
+
+## Behavior
+```python
+# Exports
+validate("abc")
+```
+- valid token → Claims
+"#,
+        );
+        let spec = parser.parse_content(&content).unwrap();
+        assert_eq!(spec.exports.functions.len(), 1);
+        assert_eq!(spec.behaviors.len(), 1);
+        assert_eq!(spec.behaviors[0].location, Some(LineRange::line(14)));
+    }
+
     #[test]
     fn test_fail_fast_missing_purpose() {
         let parser = ClaudeMdParser::new();
@@ -1950,4 +2299,75 @@ Test module.
         );
         assert_eq!(spec.exports.functions[0].name, "validateToken");
     }
+
+    #[test]
+    fn test_lenient_reports_every_missing_section() {
+        let parser = ClaudeMdParser::new();
+        let content = r#"# test
+
+## Exports
+- `validate(): void`
+"#;
+        let result = parser.parse_content_lenient(content);
+        let missing: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.kind == DiagnosticKind::MissingSection)
+            .map(|d| d.section.as_deref().unwrap())
+            .collect();
+        assert!(missing.contains(&"Purpose"));
+        assert!(missing.contains(&"Behavior"));
+        assert!(missing.contains(&"Contract"));
+        assert!(result.diagnostics.iter().all(|d| d.severity == DiagnosticSeverity::Error));
+        // Best-effort spec is still populated
+        assert_eq!(result.spec.exports.functions.len(), 1);
+
+        // Strict mode reports only the first one, in REQUIRED_SECTIONS order
+        let err = parser.parse_content(content).unwrap_err();
+        assert!(matches!(err, ParseError::MissingRequiredSection { section } if section == missing[0]));
+    }
+
+    #[test]
+    fn test_lenient_reports_unparseable_exports_and_malformed_behaviors() {
+        let parser = ClaudeMdParser::new();
+        let content = with_required_sections(
+            r#"# test
+
+## Purpose
+Test module.
+
+## Exports
+- `validate(): void`
+- validate tokens somehow
+
+### Functions
+- `refresh(token: string): Token`
+- refresh
+
+## Behavior
+- valid token → Claims
+- rejects expired tokens
+  - indented notes are descriptions
+"#,
+        );
+        let result = parser.parse_content_lenient(&content);
+        let summary: Vec<_> = result
+            .diagnostics
+            .iter()
+            .map(|d| (d.kind, d.severity, d.section.as_deref().unwrap(), d.line.unwrap()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (DiagnosticKind::UnparseableExport, DiagnosticSeverity::Warning, "Exports", 8),
+                (DiagnosticKind::UnparseableExport, DiagnosticSeverity::Warning, "Functions", 12),
+                (DiagnosticKind::MalformedBehavior, DiagnosticSeverity::Warning, "Behavior", 16),
+            ]
+        );
+        assert_eq!(result.spec.exports.functions.len(), 2);
+        assert_eq!(result.spec.behaviors.len(), 1);
+
+        // Warnings alone do not fail a strict parse
+        assert!(parser.parse_content(&content).is_ok());
+    }
 }
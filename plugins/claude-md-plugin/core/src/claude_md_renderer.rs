@@ -0,0 +1,411 @@
+//! Renderer from `ClaudeMdSpec` back to canonical CLAUDE.md markdown.
+//!
+//! The output is schema-conformant and chosen so that `ClaudeMdParser` reads it back
+//! into the same spec: parse → render → parse yields an identical spec for anything the
+//! parser produced, except `location` fields and `warnings` about the original text.
+//!
+//! Layout rules:
+//! - Section order: Purpose → Structure → Exports → Dependencies → Behavior → Contract → Protocol → Domain Context
+//! - Structure is rendered only when present, Dependencies only when non-empty
+//! - Empty Exports / Behavior / Contract / Protocol / Domain Context → `None`
+//! - Exports always use kind subsections, since a flat line is classified function-first
+//! - Entry order is preserved; consecutive entries of the same kind or category share a subsection
+
+use crate::claude_md_parser::{
+    BehaviorCategory, BehaviorSpec, ClaudeMdSpec, ContractSpec, DependenciesSpec, DomainContextSpec,
+    ExportsSpec, FunctionExport, ProtocolSpec, StructureSpec, TypeExport, TypeKind,
+};
+
+/// Renders a spec as a complete CLAUDE.md document (ends with a newline).
+pub fn render_claude_md(spec: &ClaudeMdSpec) -> String {
+    let mut lines: Vec<String> = Vec::new();
+
+    if !spec.name.is_empty() {
+        lines.push(format!("# {}", spec.name));
+        lines.push(String::new());
+    }
+
+    push_section(&mut lines, "Purpose", spec.purpose.lines().map(str::to_string).collect());
+    if let Some(structure) = &spec.structure {
+        push_section(&mut lines, "Structure", render_structure(structure));
+    }
+    push_section(&mut lines, "Exports", render_exports(&spec.exports));
+    if !spec.dependencies.external.is_empty() || !spec.dependencies.internal.is_empty() {
+        push_section(&mut lines, "Dependencies", render_dependencies(&spec.dependencies));
+    }
+    push_section(&mut lines, "Behavior", render_behaviors(&spec.behaviors));
+    push_section(&mut lines, "Contract", render_contracts(&spec.contracts));
+    push_section(&mut lines, "Protocol", render_protocol(spec.protocol.as_ref()));
+    push_section(&mut lines, "Domain Context", render_domain_context(spec.domain_context.as_ref()));
+
+    // Drop the trailing blank separator
+    lines.pop();
+    let mut out = lines.join("\n");
+    out.push('\n');
+    out
+}
+
+/// Appends `## name`, its body and a blank separator line.
+fn push_section(lines: &mut Vec<String>, name: &str, body: Vec<String>) {
+    lines.push(format!("## {name}"));
+    lines.push(String::new());
+    if !body.is_empty() {
+        lines.extend(body);
+        lines.push(String::new());
+    }
+}
+
+/// Body lines of `### name` subsections, separated by blank lines.
+fn subsections<'a>(groups: impl IntoIterator<Item = (&'a str, Vec<String>)>) -> Vec<String> {
+    let mut lines = Vec::new();
+    for (name, items) in groups {
+        if items.is_empty() {
+            continue;
+        }
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.push(format!("### {name}"));
+        lines.extend(items);
+    }
+    lines
+}
+
+/// Splits `items` into maximal runs sharing the same key, preserving order.
+fn runs<T, K: PartialEq>(items: &[T], key: impl Fn(&T) -> K) -> Vec<(K, &[T])> {
+    let mut result: Vec<(K, &[T])> = Vec::new();
+    let mut start = 0;
+    for i in 1..=items.len() {
+        if i == items.len() || key(&items[i]) != key(&items[start]) {
+            result.push((key(&items[start]), &items[start..i]));
+            start = i;
+        }
+    }
+    result
+}
+
+fn none_if_empty(lines: Vec<String>) -> Vec<String> {
+    if lines.is_empty() {
+        vec!["None".to_string()]
+    } else {
+        lines
+    }
+}
+
+fn render_structure(structure: &StructureSpec) -> Vec<String> {
+    let subdirs = structure.subdirs.iter().map(|e| format!("- {}/: {}", e.name, e.description));
+    let files = structure.files.iter().map(|e| format!("- {}: {}", e.name, e.description));
+    subdirs.chain(files).collect()
+}
+
+fn render_exports(exports: &ExportsSpec) -> Vec<String> {
+    let mut groups = vec![("Functions", exports.functions.iter().map(render_function).collect())];
+    for (heading, types) in runs(&exports.types, |t| type_heading(&t.kind)) {
+        groups.push((heading, types.iter().map(render_type).collect()));
+    }
+    groups.push(("Classes", exports.classes.iter().map(|c| format!("- `{}`", c.constructor_signature)).collect()));
+    groups.push(("Enums", exports.enums.iter().map(|e| format!("- `{}: {}`", e.name, e.variants.join(" | "))).collect()));
+    groups.push((
+        "Variables",
+        exports
+            .variables
+            .iter()
+            .map(|v| match v.value.as_deref() {
+                // The `:` form is read as a type and rejects parentheses; `=` keeps everything after it
+                Some(value) if value.contains(['=', '(']) => format!("- `{} = {}`", v.name, value),
+                Some(value) => format!("- `{}: {}`", v.name, value),
+                None => format!("- `{}:`", v.name),
+            })
+            .collect(),
+    ));
+    none_if_empty(subsections(groups))
+}
+
+fn render_function(func: &FunctionExport) -> String {
+    // The parser strips a leading `async ` from the signature but keeps it in `is_async`
+    let implied = ["Promise<", "async ", "suspend ", "CompletableFuture"]
+        .iter()
+        .any(|marker| func.signature.contains(marker));
+    if func.is_async && !implied {
+        format!("- `async {}`", func.signature)
+    } else {
+        format!("- `{}`", func.signature)
+    }
+}
+
+/// Subsection that makes the parser assign `kind` back.
+/// Type aliases and records have no dedicated subsection and read back as interfaces.
+fn type_heading(kind: &TypeKind) -> &'static str {
+    match kind {
+        TypeKind::Struct => "Structs",
+        TypeKind::DataClass => "Data Classes",
+        TypeKind::Interface | TypeKind::TypeAlias | TypeKind::Record => "Types",
+    }
+}
+
+fn render_type(type_export: &TypeExport) -> String {
+    format!("- `{}`", type_export.definition)
+}
+
+fn render_dependencies(deps: &DependenciesSpec) -> Vec<String> {
+    let mut lines = Vec::new();
+    if !deps.external.is_empty() {
+        lines.push("- external:".to_string());
+        lines.extend(deps.external.iter().map(|pkg| format!("  - `{pkg}`")));
+    }
+    if !deps.internal.is_empty() {
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.push("- internal:".to_string());
+        for dep in &deps.internal {
+            // A backticked `.../CLAUDE.md` path is read back as a CLAUDE.md reference
+            let line = if !dep.is_claude_md_ref && dep.path.ends_with("/CLAUDE.md") {
+                format!("  - {}", dep.path)
+            } else if dep.symbols.is_empty() {
+                format!("  - `{}`", dep.path)
+            } else {
+                format!("  - `{}`: {}", dep.path, dep.symbols.join(", "))
+            };
+            lines.push(line);
+        }
+    }
+    lines
+}
+
+fn render_behaviors(behaviors: &[BehaviorSpec]) -> Vec<String> {
+    let groups = runs(behaviors, |b| b.category.clone()).into_iter().map(|(category, items)| {
+        let heading = match category {
+            BehaviorCategory::Success => "Success Cases",
+            BehaviorCategory::Error => "Error Cases",
+        };
+        (heading, items.iter().map(|b| format!("- {} → {}", b.input, b.output)).collect())
+    });
+    none_if_empty(subsections(groups))
+}
+
+fn render_contracts(contracts: &[ContractSpec]) -> Vec<String> {
+    let groups = contracts.iter().map(|contract| {
+        let labeled = [
+            ("Preconditions", &contract.preconditions),
+            ("Postconditions", &contract.postconditions),
+            ("Throws", &contract.throws),
+            ("Invariants", &contract.invariants),
+        ];
+        let items = labeled
+            .iter()
+            .flat_map(|(label, values)| values.iter().map(move |v| format!("- **{label}**: {v}")))
+            .collect();
+        (contract.function_name.as_str(), items)
+    });
+    none_if_empty(subsections(groups))
+}
+
+fn render_protocol(protocol: Option<&ProtocolSpec>) -> Vec<String> {
+    let Some(protocol) = protocol else {
+        return none_if_empty(Vec::new());
+    };
+
+    let mut state_machine = Vec::new();
+    if !protocol.states.is_empty() {
+        let states: Vec<String> = protocol.states.iter().map(|s| format!("`{s}`")).collect();
+        state_machine.push(format!("States: {}", states.join(" | ")));
+    }
+    if !protocol.transitions.is_empty() {
+        if !state_machine.is_empty() {
+            state_machine.push(String::new());
+        }
+        state_machine.push("Transitions:".to_string());
+        state_machine.extend(
+            protocol
+                .transitions
+                .iter()
+                .map(|t| format!("- `{}` + `{}` → `{}`", t.from, t.trigger, t.to)),
+        );
+    }
+    let lifecycle = protocol
+        .lifecycle
+        .iter()
+        .map(|m| format!("{}. `{}()` - {}", m.order, m.method, m.description))
+        .collect();
+
+    none_if_empty(subsections([("State Machine", state_machine), ("Lifecycle", lifecycle)]))
+}
+
+fn render_domain_context(domain_context: Option<&DomainContextSpec>) -> Vec<String> {
+    let Some(dc) = domain_context else {
+        return none_if_empty(Vec::new());
+    };
+    let bullets = |values: &[String]| values.iter().map(|v| format!("- {v}")).collect();
+    none_if_empty(subsections([
+        ("Decision Rationale", bullets(&dc.decision_rationale)),
+        ("Constraints", bullets(&dc.constraints)),
+        ("Compatibility", bullets(&dc.compatibility)),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::claude_md_parser::{ClaudeMdParser, FunctionExport};
+    use crate::signature::{Language, Signature};
+
+    /// Spec as JSON without `location` data, which depends on the source layout.
+    fn without_locations(spec: &ClaudeMdSpec) -> serde_json::Value {
+        fn strip(value: &mut serde_json::Value) {
+            match value {
+                serde_json::Value::Object(map) => {
+                    map.remove("location");
+                    map.remove("external_locations");
+                    map.values_mut().for_each(strip);
+                }
+                serde_json::Value::Array(items) => items.iter_mut().for_each(strip),
+                _ => {}
+            }
+        }
+        let mut value = serde_json::to_value(spec).unwrap();
+        strip(&mut value);
+        value
+    }
+
+    const FULL: &str = r#"# auth
+
+## Purpose
+Handles authentication.
+Issues and validates tokens.
+
+## Structure
+- jwt/: JWT helpers
+- types.ts: shared types
+
+## Exports
+
+### Functions
+- `async fetchKeys(url: string): Keys`
+- `validateToken(token: string): Promise<Claims>`
+- `Reset()`
+
+### Types
+- `Claims {userId: string}`
+
+### Structs
+- `Config { secret: String }`
+
+### Data Classes
+- `data class Session(val id: String)`
+
+### Types
+- `Token { value: string }`
+
+### Classes
+- `TokenManager(secret: string)`
+
+### Enums
+- `Role: Admin | User`
+
+### Variables
+- `MAX_RETRY = 3`
+- `DEFAULT_TTL: Duration`
+- `FACTORY = build(1)`
+
+## Dependencies
+
+- external:
+  - `jsonwebtoken@9.0.0`: sign, verify
+
+- internal:
+  - `core/types/CLAUDE.md`: Claims
+  - internal: legacy/CLAUDE.md
+
+## Behavior
+- valid token → Claims
+- expired token → TokenExpiredError
+- empty input → null
+
+## Contract
+
+### validateToken
+- **Preconditions**: token must be non-empty
+- **Postconditions**: returns Claims
+- **Throws**: InvalidTokenError
+- **Invariants**: key cache is never empty
+
+## Protocol
+
+### State Machine
+States: `Idle` | `Ready`
+
+Transitions:
+- `Idle` + `init()` → `Ready`
+
+### Lifecycle
+1. `init()` - load keys
+2. `close` - release keys
+
+## Domain Context
+
+### Decision Rationale
+- TOKEN_EXPIRY: 7 days (PCI-DSS)
+
+### Compatibility
+- Node.js 18+
+"#;
+
+    #[test]
+    fn test_round_trip_preserves_spec() {
+        let parser = ClaudeMdParser::new();
+        let spec = parser.parse_content(FULL).unwrap();
+        assert!(spec.warnings.is_empty());
+
+        let rendered = render_claude_md(&spec);
+        let reparsed = parser.parse_content(&rendered).unwrap();
+        assert_eq!(without_locations(&reparsed), without_locations(&spec));
+
+        // Canonical output is a fixed point
+        assert_eq!(render_claude_md(&reparsed), rendered);
+    }
+
+    #[test]
+    fn test_round_trip_keeps_kinds_and_order() {
+        let spec = ClaudeMdParser::new().parse_content(FULL).unwrap();
+        let rendered = render_claude_md(&spec);
+
+        assert!(rendered.contains("- `async fetchKeys(url: string): Keys`"));
+        assert!(rendered.contains("### Types\n- `Claims { userId: string }`\n\n### Structs"));
+        // Interleaved categories keep their order through repeated subsections
+        assert!(rendered.contains(
+            "### Success Cases\n- valid token → Claims\n\n### Error Cases\n- expired token → TokenExpiredError\n\n### Success Cases\n- empty input → null"
+        ));
+        // Plain inline CLAUDE.md path is not a reference and must not become one
+        assert!(rendered.contains("  - legacy/CLAUDE.md\n"));
+    }
+
+    #[test]
+    fn test_empty_spec_renders_none_sections() {
+        let rendered = render_claude_md(&ClaudeMdSpec { name: "empty".to_string(), purpose: "Nothing.".to_string(), ..Default::default() });
+        assert_eq!(
+            rendered,
+            "# empty\n\n## Purpose\n\nNothing.\n\n## Exports\n\nNone\n\n## Behavior\n\nNone\n\n## Contract\n\nNone\n\n## Protocol\n\nNone\n\n## Domain Context\n\nNone\n"
+        );
+        let reparsed = ClaudeMdParser::new().parse_content(&rendered).unwrap();
+        assert!(reparsed.warnings.is_empty());
+        assert!(reparsed.structure.is_none() && reparsed.protocol.is_none() && reparsed.domain_context.is_none());
+    }
+
+    #[test]
+    fn test_structural_edit_is_rendered() {
+        let parser = ClaudeMdParser::new();
+        let mut spec = parser.parse_content(FULL).unwrap();
+        spec.exports.functions.push(FunctionExport {
+            name: "revoke".to_string(),
+            signature: "revoke(token: string): void".to_string(),
+            is_async: false,
+            location: None,
+            parsed_signature: Signature::parse("revoke(token: string): void", "revoke", Language::Unknown),
+        });
+
+        let reparsed = parser.parse_content(&render_claude_md(&spec)).unwrap();
+        assert_eq!(without_locations(&reparsed), without_locations(&spec));
+        assert_eq!(reparsed.exports.functions.last().unwrap().name, "revoke");
+    }
+}
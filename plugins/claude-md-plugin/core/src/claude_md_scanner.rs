@@ -4,6 +4,8 @@ use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
+use crate::markdown::{find_section, parse_sections, with_subsections};
+
 /// Result of scanning existing CLAUDE.md files
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ScanResult {
@@ -117,16 +119,11 @@ impl Default for ClaudeMdScanner {
 
 /// Extract the first paragraph after `## Purpose`, truncated to 200 chars
 fn extract_purpose(content: &str) -> String {
-    let lines: Vec<&str> = content.lines().collect();
-    let mut in_purpose = false;
+    let sections = parse_sections(content);
     let mut paragraph = String::new();
 
-    for line in &lines {
-        if in_purpose {
-            // Stop at next heading
-            if line.starts_with("## ") {
-                break;
-            }
+    if let Some(index) = find_section(&sections, 2, "Purpose") {
+        for line in &sections[index].content {
             let trimmed = line.trim();
             if trimmed.is_empty() {
                 // If we already have content, the paragraph is done
@@ -139,8 +136,6 @@ fn extract_purpose(content: &str) -> String {
                 paragraph.push(' ');
             }
             paragraph.push_str(trimmed);
-        } else if line.starts_with("## Purpose") {
-            in_purpose = true;
         }
     }
 
@@ -159,8 +154,7 @@ fn extract_export_names(content: &str) -> Vec<String> {
     use std::sync::OnceLock;
     static RE: OnceLock<Regex> = OnceLock::new();
 
-    let lines: Vec<&str> = content.lines().collect();
-    let mut in_exports = false;
+    let sections = parse_sections(content);
     let mut names = Vec::new();
 
     // INTENTIONAL: Backticks required to distinguish export identifiers from prose.
@@ -173,18 +167,12 @@ fn extract_export_names(content: &str) -> Vec<String> {
     // e.g., `Role = "admin" | "user"` → "Role"
     let re = RE.get_or_init(|| Regex::new(r"^-?\s*`([A-Za-z_][A-Za-z0-9_]*)").unwrap());
 
-    for line in &lines {
-        if in_exports {
-            if line.starts_with("## ") {
-                break;
-            }
-            if let Some(caps) = re.captures(line.trim()) {
-                if let Some(name) = caps.get(1) {
-                    names.push(name.as_str().to_string());
-                }
+    let Some(index) = find_section(&sections, 2, "Exports") else { return names };
+    for line in with_subsections(&sections, index).iter().flat_map(|s| &s.content) {
+        if let Some(caps) = re.captures(line.trim()) {
+            if let Some(name) = caps.get(1) {
+                names.push(name.as_str().to_string());
             }
-        } else if line.starts_with("## Exports") {
-            in_exports = true;
         }
     }
 
@@ -1,6 +1,9 @@
 //! Code analyzer module for extracting exports, dependencies, and behaviors from source files.
 //!
-//! Supports multiple languages: TypeScript, Python, Go, Rust, Java, Kotlin
+//! Supports multiple languages: TypeScript, Python, Go, Rust, Java, Kotlin, Scala, C#, Swift, C/C++, Ruby, PHP.
+//! The languages in [`TREE_SITTER_LANGUAGES`] (Rust, TypeScript/JavaScript, Go, Python, Java)
+//! can alternatively use the tree-sitter backend (`tree-sitter` feature), selected per
+//! language with [`CodeAnalyzer::with_backend`].
 
 mod typescript;
 mod python;
@@ -8,17 +11,44 @@ mod go;
 mod rust_lang;
 mod java;
 mod kotlin;
-
-use std::path::Path;
+mod csharp;
+mod swift;
+mod cpp;
+mod ruby;
+mod php;
+mod scala;
+mod source_locator;
+#[cfg(feature = "tree-sitter")]
+mod syntax_tree;
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::signature::{Language, Signature};
+
 pub use typescript::TypeScriptAnalyzer;
 pub use python::PythonAnalyzer;
 pub use go::GoAnalyzer;
 pub use rust_lang::RustAnalyzer;
 pub use java::JavaAnalyzer;
 pub use kotlin::KotlinAnalyzer;
+pub use csharp::CSharpAnalyzer;
+pub use swift::SwiftAnalyzer;
+pub use cpp::CppAnalyzer;
+pub use ruby::RubyAnalyzer;
+pub use php::PhpAnalyzer;
+pub use scala::ScalaAnalyzer;
+#[cfg(feature = "tree-sitter")]
+pub use syntax_tree::{
+    TreeSitterGoAnalyzer, TreeSitterJavaAnalyzer, TreeSitterPythonAnalyzer, TreeSitterRustAnalyzer,
+    TreeSitterTypeScriptAnalyzer,
+};
+
+/// Languages that have a tree-sitter backend (no Kotlin, C#, Swift, C/C++, Ruby, PHP or Scala grammar yet)
+pub const TREE_SITTER_LANGUAGES: &[&str] = &["rust", "typescript", "go", "python", "java"];
 
 /// Errors that can occur during code analysis.
 #[derive(Debug, Error)]
@@ -29,6 +59,46 @@ pub enum AnalyzerError {
     #[error("Unsupported language for file: {0}")]
     UnsupportedLanguage(String),
 
+    #[error("Backend '{backend}' is not available for language '{language}'")]
+    UnsupportedBackend { language: String, backend: String },
+
+    #[error("{0}")]
+    UnknownBackend(String),
+
+    #[error("Failed to parse syntax tree: {0}")]
+    ParseError(String),
+}
+
+/// Implementation used to analyze a language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AnalyzerBackend {
+    /// Line-oriented regex patterns (default for every language)
+    #[default]
+    Regex,
+    /// tree-sitter syntax trees
+    TreeSitter,
+}
+
+impl AnalyzerBackend {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AnalyzerBackend::Regex => "regex",
+            AnalyzerBackend::TreeSitter => "tree-sitter",
+        }
+    }
+}
+
+impl FromStr for AnalyzerBackend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "regex" => Ok(AnalyzerBackend::Regex),
+            "tree-sitter" => Ok(AnalyzerBackend::TreeSitter),
+            other => Err(format!("Unknown analyzer backend '{}' (expected regex or tree-sitter)", other)),
+        }
+    }
 }
 
 /// Result of code analysis.
@@ -75,6 +145,17 @@ pub struct ReExport {
     pub source: String,
 }
 
+/// Source span of a symbol: file name and 1-based, inclusive line range.
+///
+/// `file` is the file name as listed in `AnalysisResult::analyzed_files`; directory
+/// analysis is non-recursive, so it is unambiguous within one analyzed directory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceLocation {
+    pub file: String,
+    pub start_line: usize,
+    pub end_line: usize,
+}
+
 /// An exported function.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExportedFunction {
@@ -82,6 +163,12 @@ pub struct ExportedFunction {
     pub signature: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Where the symbol is defined
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<SourceLocation>,
+    /// `signature` broken into parameters, return type and generics
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parsed_signature: Option<Signature>,
 }
 
 /// An exported type (interface, type alias, struct, etc.).
@@ -93,6 +180,9 @@ pub struct ExportedType {
     pub definition: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Where the symbol is defined
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<SourceLocation>,
 }
 
 /// Kind of exported type.
@@ -116,6 +206,9 @@ pub struct ExportedClass {
     pub signature: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Where the symbol is defined
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<SourceLocation>,
 }
 
 /// An exported enum.
@@ -124,6 +217,9 @@ pub struct ExportedEnum {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub variants: Option<Vec<String>>,
+    /// Where the symbol is defined
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<SourceLocation>,
 }
 
 /// An exported variable or constant.
@@ -132,6 +228,9 @@ pub struct ExportedVariable {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub var_type: Option<String>,
+    /// Where the symbol is defined
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<SourceLocation>,
 }
 
 /// A resolved internal dependency pointing to a specific CLAUDE.md
@@ -185,6 +284,9 @@ pub struct Behavior {
     pub input: String,
     pub output: String,
     pub category: BehaviorCategory,
+    /// Where the behavior originates (e.g. the throw site)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<SourceLocation>,
 }
 
 /// Category of behavior.
@@ -213,6 +315,9 @@ pub struct Contract {
 pub struct FunctionContract {
     pub function_name: String,
     pub contract: Contract,
+    /// Where the function carrying the contract is defined
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<SourceLocation>,
 }
 
 /// Protocol information (state machines, lifecycle).
@@ -268,6 +373,24 @@ pub struct CodeAnalyzer {
     rust: RustAnalyzer,
     java: JavaAnalyzer,
     kotlin: KotlinAnalyzer,
+    csharp: CSharpAnalyzer,
+    swift: SwiftAnalyzer,
+    cpp: CppAnalyzer,
+    ruby: RubyAnalyzer,
+    php: PhpAnalyzer,
+    scala: ScalaAnalyzer,
+    #[cfg(feature = "tree-sitter")]
+    tree_sitter_rust: TreeSitterRustAnalyzer,
+    #[cfg(feature = "tree-sitter")]
+    tree_sitter_typescript: TreeSitterTypeScriptAnalyzer,
+    #[cfg(feature = "tree-sitter")]
+    tree_sitter_go: TreeSitterGoAnalyzer,
+    #[cfg(feature = "tree-sitter")]
+    tree_sitter_python: TreeSitterPythonAnalyzer,
+    #[cfg(feature = "tree-sitter")]
+    tree_sitter_java: TreeSitterJavaAnalyzer,
+    /// Per-language backend overrides (languages not listed use regex)
+    backends: HashMap<&'static str, AnalyzerBackend>,
 }
 
 impl CodeAnalyzer {
@@ -280,27 +403,120 @@ impl CodeAnalyzer {
             rust: RustAnalyzer::new(),
             java: JavaAnalyzer::new(),
             kotlin: KotlinAnalyzer::new(),
+            csharp: CSharpAnalyzer::new(),
+            swift: SwiftAnalyzer::new(),
+            cpp: CppAnalyzer::new(),
+            ruby: RubyAnalyzer::new(),
+            php: PhpAnalyzer::new(),
+            scala: ScalaAnalyzer::new(),
+            #[cfg(feature = "tree-sitter")]
+            tree_sitter_rust: TreeSitterRustAnalyzer::new(),
+            #[cfg(feature = "tree-sitter")]
+            tree_sitter_typescript: TreeSitterTypeScriptAnalyzer::new(),
+            #[cfg(feature = "tree-sitter")]
+            tree_sitter_go: TreeSitterGoAnalyzer::new(),
+            #[cfg(feature = "tree-sitter")]
+            tree_sitter_python: TreeSitterPythonAnalyzer::new(),
+            #[cfg(feature = "tree-sitter")]
+            tree_sitter_java: TreeSitterJavaAnalyzer::new(),
+            backends: HashMap::new(),
         }
     }
 
+    /// Create a CodeAnalyzer from `--backend` entries (`lang=backend`, or a bare backend
+    /// name for every language that supports it).
+    pub fn with_backend_specs(entries: &[String]) -> Result<Self, AnalyzerError> {
+        let mut analyzer = Self::new();
+        for entry in entries {
+            let (languages, backend) = match entry.split_once('=') {
+                Some((language, backend)) => (vec![language.trim()], backend.trim()),
+                None => (TREE_SITTER_LANGUAGES.to_vec(), entry.trim()),
+            };
+            let backend: AnalyzerBackend = backend.parse().map_err(AnalyzerError::UnknownBackend)?;
+            for language in languages {
+                analyzer = analyzer.with_backend(language, backend)?;
+            }
+        }
+        Ok(analyzer)
+    }
+
+    /// Select the backend for a language (`javascript` is an alias of `typescript`).
+    /// Fails if the backend is not available for that language or was compiled out.
+    pub fn with_backend(mut self, language: &str, backend: AnalyzerBackend) -> Result<Self, AnalyzerError> {
+        let language = match language {
+            "javascript" => "typescript",
+            other => other,
+        };
+        let key = ["typescript", "python", "go", "rust", "java", "kotlin", "csharp", "swift", "c", "cpp", "ruby", "php", "scala"]
+            .into_iter()
+            .find(|l| *l == language)
+            .ok_or_else(|| AnalyzerError::UnsupportedBackend {
+                language: language.to_string(),
+                backend: backend.as_str().to_string(),
+            })?;
+
+        let available = backend == AnalyzerBackend::Regex
+            || (cfg!(feature = "tree-sitter") && TREE_SITTER_LANGUAGES.contains(&key));
+        if !available {
+            return Err(AnalyzerError::UnsupportedBackend {
+                language: key.to_string(),
+                backend: backend.as_str().to_string(),
+            });
+        }
+
+        self.backends.insert(key, backend);
+        Ok(self)
+    }
+
+    /// Backend used for a language.
+    pub fn backend_for(&self, language: &str) -> AnalyzerBackend {
+        let language = if language == "javascript" { "typescript" } else { language };
+        self.backends.get(language).copied().unwrap_or_default()
+    }
+
     /// Analyze a single file.
     pub fn analyze_file(&self, path: &Path) -> Result<AnalysisResult, AnalyzerError> {
         let content = std::fs::read_to_string(path)?;
         let language = self.detect_language(path)?;
 
-        let partial = match language {
-            "typescript" | "javascript" => self.typescript.analyze_file(path, &content)?,
-            "python" => self.python.analyze_file(path, &content)?,
-            "go" => self.go.analyze_file(path, &content)?,
-            "rust" => self.rust.analyze_file(path, &content)?,
-            "java" => self.java.analyze_file(path, &content)?,
-            "kotlin" => self.kotlin.analyze_file(path, &content)?,
+        let mut partial = match (language, self.backend_for(language)) {
+            #[cfg(feature = "tree-sitter")]
+            ("typescript" | "javascript", AnalyzerBackend::TreeSitter) => {
+                self.tree_sitter_typescript.analyze_file(path, &content)?
+            }
+            #[cfg(feature = "tree-sitter")]
+            ("rust", AnalyzerBackend::TreeSitter) => self.tree_sitter_rust.analyze_file(path, &content)?,
+            #[cfg(feature = "tree-sitter")]
+            ("go", AnalyzerBackend::TreeSitter) => self.tree_sitter_go.analyze_file(path, &content)?,
+            #[cfg(feature = "tree-sitter")]
+            ("python", AnalyzerBackend::TreeSitter) => self.tree_sitter_python.analyze_file(path, &content)?,
+            #[cfg(feature = "tree-sitter")]
+            ("java", AnalyzerBackend::TreeSitter) => self.tree_sitter_java.analyze_file(path, &content)?,
+            ("typescript" | "javascript", _) => self.typescript.analyze_file(path, &content)?,
+            ("python", _) => self.python.analyze_file(path, &content)?,
+            ("go", _) => self.go.analyze_file(path, &content)?,
+            ("rust", _) => self.rust.analyze_file(path, &content)?,
+            ("java", _) => self.java.analyze_file(path, &content)?,
+            ("kotlin", _) => self.kotlin.analyze_file(path, &content)?,
+            ("csharp", _) => self.csharp.analyze_file(path, &content)?,
+            ("swift", _) => self.swift.analyze_file(path, &content)?,
+            ("c" | "cpp", _) => self.cpp.analyze_file(path, &content)?,
+            ("ruby", _) => self.ruby.analyze_file(path, &content)?,
+            ("php", _) => self.php.analyze_file(path, &content)?,
+            ("scala", _) => self.scala.analyze_file(path, &content)?,
             _ => return Err(AnalyzerError::UnsupportedLanguage(path.display().to_string())),
         };
 
         let file_name = path.file_name()
             .map(|s| s.to_string_lossy().to_string())
             .unwrap_or_default();
+        // Only the tree-sitter analyzers report full export spans
+        let complete_spans = cfg!(feature = "tree-sitter")
+            && matches!(self.backend_for(language), AnalyzerBackend::TreeSitter);
+        source_locator::locate(&mut partial, &file_name, language, &content, complete_spans);
+        for function in &mut partial.functions {
+            function.parsed_signature = Signature::parse(&function.signature, &function.name, Language::from_name(language));
+        }
 
         Ok(AnalysisResult {
             path: path.display().to_string(),
@@ -367,6 +583,13 @@ impl CodeAnalyzer {
             "rs" => Ok("rust"),
             "java" => Ok("java"),
             "kt" | "kts" => Ok("kotlin"),
+            "scala" | "sc" => Ok("scala"),
+            "cs" => Ok("csharp"),
+            "swift" => Ok("swift"),
+            "c" | "h" => Ok("c"),
+            "cpp" | "cc" | "cxx" | "hpp" => Ok("cpp"),
+            "rb" => Ok("ruby"),
+            "php" => Ok("php"),
             _ => Err(AnalyzerError::UnsupportedLanguage(path.display().to_string())),
         }
     }
@@ -386,10 +609,8 @@ impl CodeAnalyzer {
             let entry = entry?;
             let entry_path = entry.path();
 
-            if entry_path.is_file() {
-                if self.detect_language(&entry_path).is_ok() {
-                    files.push(entry_path);
-                }
+            if entry_path.is_file() && self.detect_language(&entry_path).is_ok() {
+                files.push(entry_path);
             }
         }
 
@@ -440,3 +661,79 @@ impl Default for CodeAnalyzer {
         Self::new()
     }
 }
+
+/// Find the nearest directory at or above `start` that contains a file named `marker`
+/// (e.g. `go.mod`, `Cargo.toml`).
+pub(crate) fn find_ancestor_containing(start: &Path, marker: &str) -> Option<PathBuf> {
+    let start = start.canonicalize().unwrap_or_else(|_| start.to_path_buf());
+    start.ancestors()
+        .find(|dir| dir.join(marker).is_file())
+        .map(|dir| dir.to_path_buf())
+}
+
+/// Express `target_dir` as a `./` or `../` path relative to `from_dir`.
+/// Both paths should be absolute (canonicalized). Returns "." when they are the same directory.
+/// The result is what `DependencyResolver` expects in `internal_raw`.
+pub(crate) fn relative_to_dir(from_dir: &Path, target_dir: &Path) -> String {
+    let from: Vec<_> = from_dir.components().collect();
+    let to: Vec<_> = target_dir.components().collect();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+
+    let ups = from.len() - common;
+    let downs = to[common..]
+        .iter()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect::<Vec<_>>()
+        .join("/");
+
+    match (ups, downs.is_empty()) {
+        (0, true) => ".".to_string(),
+        (0, false) => format!("./{}", downs),
+        (_, true) => "../".repeat(ups).trim_end_matches('/').to_string(),
+        (_, false) => format!("{}{}", "../".repeat(ups), downs),
+    }
+}
+
+/// Express the package `import_pkg` as a path relative to the directory of `current_pkg`,
+/// assuming directories mirror packages (Java/Kotlin convention).
+/// Returns None when both are the same package.
+pub(crate) fn package_relative_path(current_pkg: &str, import_pkg: &str) -> Option<String> {
+    let current: Vec<&str> = current_pkg.split('.').collect();
+    let import: Vec<&str> = import_pkg.split('.').collect();
+    let common = current.iter().zip(&import).take_while(|(a, b)| a == b).count();
+
+    let ups = current.len() - common;
+    let downs = import[common..].join("/");
+    if ups == 0 && downs.is_empty() {
+        return None;
+    }
+
+    let relative = if ups == 0 {
+        format!("./{}", downs)
+    } else {
+        format!("{}{}", "../".repeat(ups), downs)
+    };
+    Some(relative.trim_end_matches('/').to_string())
+}
+
+/// Directory of package `import_pkg` relative to `file_dir`, the directory of a file in
+/// package `current_pkg`, when that directory exists in the project (directories mirror
+/// packages). A package that only shares the organization prefix, such as a separate
+/// `com.example.sharedlib` library, has no directory here and is not internal.
+pub(crate) fn project_package_dir(file_dir: &Path, current_pkg: &str, import_pkg: &str) -> Option<String> {
+    if current_pkg.is_empty() {
+        return None;
+    }
+    let relative = package_relative_path(current_pkg, import_pkg)?;
+    file_dir.join(&relative).is_dir().then_some(relative)
+}
+
+/// Strip trailing class/member segments from a qualified import
+/// (`com.example.auth.JwtDecoder.Builder` → `com.example.auth`).
+pub(crate) fn package_of_import(import_path: &str) -> String {
+    import_path
+        .split('.')
+        .take_while(|seg| seg.chars().next().map(|c| c.is_lowercase()).unwrap_or(false))
+        .collect::<Vec<_>>()
+        .join(".")
+}
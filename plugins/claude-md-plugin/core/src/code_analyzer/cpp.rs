@@ -0,0 +1,622 @@
+//! C and C++ code analyzer.
+//!
+//! Headers are the export surface: declarations in `.h`/`.hpp` files are exported,
+//! while `.c`/`.cpp` files only contribute dependencies.
+
+use std::path::{Path, PathBuf};
+use regex::Regex;
+
+use super::{
+    AnalyzerError, ExportedClass, ExportedEnum, ExportedFunction, ExportedType, ExportedVariable,
+    LanguageAnalyzer, PartialAnalysis, TypeKind, relative_to_dir,
+};
+use super::source_locator::{line_at, line_number};
+
+/// A statement of a scope body: collapsed header, optional `{ ... }` body and the
+/// declarator after it (`typedef struct { ... } Name;`), with the lines they start on.
+struct Statement<'a> {
+    header: String,
+    body: Option<&'a str>,
+    body_line: usize,
+    trailing: String,
+    line: usize,
+}
+
+/// Header file extensions whose declarations are exported.
+const HEADER_EXTENSIONS: &[&str] = &["h", "hpp"];
+
+/// C standard library and common POSIX headers (`<stdio.h>`, `<unistd.h>`, ...).
+const C_SYSTEM_HEADERS: &[&str] = &[
+    "assert", "complex", "ctype", "errno", "fenv", "float", "inttypes", "iso646", "limits",
+    "locale", "math", "setjmp", "signal", "stdalign", "stdarg", "stdatomic", "stdbool",
+    "stddef", "stdint", "stdio", "stdlib", "stdnoreturn", "string", "tgmath", "threads",
+    "time", "uchar", "wchar", "wctype", "unistd", "fcntl", "pthread", "dirent", "dlfcn",
+    "netdb", "poll", "sched", "semaphore", "strings", "syslog", "termios",
+];
+
+/// Namespaces that conventionally hold implementation details.
+const PRIVATE_NAMESPACES: &[&str] = &["detail", "details", "internal", "impl"];
+
+/// Keywords that start statements which are never function declarations.
+const NON_DECLARATION_KEYWORDS: &[&str] = &[
+    "return", "static_assert", "friend", "using", "typedef", "template", "namespace",
+];
+
+/// Scope being scanned for declarations.
+#[derive(Debug, Clone, Copy)]
+enum Scope<'a> {
+    /// File or namespace scope
+    Namespace,
+    /// Body of a class or struct, with whether the current access is public
+    Record { name: &'a str, public: bool },
+}
+
+/// Analyzer for C and C++ files.
+#[derive(Debug)]
+pub struct CppAnalyzer {
+    // Regex patterns for preprocessor directives
+    include_re: Regex,
+    define_re: Regex,
+    // Declaration patterns (applied to single statements)
+    function_re: Regex,
+    record_re: Regex,
+    enum_re: Regex,
+    namespace_re: Regex,
+    access_re: Regex,
+    typedef_fn_ptr_re: Regex,
+    using_alias_re: Regex,
+    constant_re: Regex,
+    export_macro_re: Regex,
+}
+
+impl CppAnalyzer {
+    pub fn new() -> Self {
+        Self {
+            // #include "local.h" / #include <system.h>
+            include_re: Regex::new(
+                r#"(?m)^\s*#\s*include\s*([<"])([^>"]+)[>"]"#
+            ).unwrap(),
+
+            // #define NAME value (object-like macros only)
+            define_re: Regex::new(
+                r"(?m)^\s*#\s*define\s+([A-Za-z_]\w*)[ \t]+(\S[^\n]*)$"
+            ).unwrap(),
+
+            // [specifiers] ReturnType name(params) [const] [noexcept] [override] [= 0]
+            function_re: Regex::new(
+                r"^(?:(?:static|inline|extern|virtual|explicit|constexpr|consteval|\[\[[^\]]*\]\])\s+)*(.*?[\w>*&])\s*\b(operator\s*[^\s(]+|~?\w+)\s*\((.*)\)\s*((?:const|noexcept|override|final|&|\s)*)(=\s*(?:0|default|delete))?$"
+            ).unwrap(),
+
+            // [typedef] struct|class|union Name [final] [: public Base]
+            record_re: Regex::new(
+                r"^(typedef\s+)?(struct|class|union)\s*(?:\[\[[^\]]*\]\]\s*)?(\w+)?(?:\s+final)?\s*(?::\s*(.+))?$"
+            ).unwrap(),
+
+            // [typedef] enum [class|struct] Name [: type]
+            enum_re: Regex::new(
+                r"^(typedef\s+)?enum(?:\s+(?:class|struct))?\s*(\w+)?\s*(?::\s*[\w:\s]+)?$"
+            ).unwrap(),
+
+            // namespace name [::name]
+            namespace_re: Regex::new(
+                r"^(?:inline\s+)?namespace\s*([\w:]*)$"
+            ).unwrap(),
+
+            // public: / private: / protected:
+            access_re: Regex::new(
+                r"^(public|private|protected)\s*:"
+            ).unwrap(),
+
+            // typedef Ret (*Name)(params);
+            typedef_fn_ptr_re: Regex::new(
+                r"\(\s*\*\s*(\w+)\s*\)\s*\("
+            ).unwrap(),
+
+            // using Name = Type;
+            using_alias_re: Regex::new(
+                r"^(?:template\s*<.*>\s*)?using\s+(\w+)\s*=\s*(.+)$"
+            ).unwrap(),
+
+            // [inline] [static] constexpr Type NAME = value; / extern const Type NAME;
+            constant_re: Regex::new(
+                r"^(?:(?:inline|static)\s+)*(?:constexpr|extern\s+const)\s+(.+?)\s+(\w+)\s*(?:=.*|\{.*\})?$"
+            ).unwrap(),
+
+            // SDK_API / MYLIB_EXPORT visibility macros
+            export_macro_re: Regex::new(
+                r"\b[A-Z][A-Z0-9_]*_(?:API|EXPORT|EXPORTS|PUBLIC)\b\s*"
+            ).unwrap(),
+        }
+    }
+
+    /// Remove comments, string contents and preprocessor lines, leaving declarations.
+    fn strip_non_code(content: &str) -> String {
+        let mut out = String::with_capacity(content.len());
+        let mut chars = content.chars().peekable();
+        let mut at_line_start = true;
+
+        while let Some(c) = chars.next() {
+            match c {
+                '/' if chars.peek() == Some(&'/') => {
+                    while let Some(&n) = chars.peek() {
+                        if n == '\n' { break; }
+                        chars.next();
+                    }
+                }
+                '/' if chars.peek() == Some(&'*') => {
+                    chars.next();
+                    let mut prev = ' ';
+                    for n in chars.by_ref() {
+                        if n == '\n' { out.push('\n'); }
+                        if prev == '*' && n == '/' { break; }
+                        prev = n;
+                    }
+                    out.push(' ');
+                }
+                '"' | '\'' => {
+                    out.push(c);
+                    let mut escaped = false;
+                    for n in chars.by_ref() {
+                        if n == '\n' { out.push('\n'); break; }
+                        if !escaped && n == c { break; }
+                        escaped = !escaped && n == '\\';
+                    }
+                    out.push(c);
+                }
+                '#' if at_line_start => {
+                    // Skip the directive, including backslash continuations
+                    let mut prev = ' ';
+                    for n in chars.by_ref() {
+                        if n == '\n' && prev != '\\' { break; }
+                        prev = n;
+                    }
+                    out.push('\n');
+                    at_line_start = true;
+                    continue;
+                }
+                _ => out.push(c),
+            }
+            if c == '\n' {
+                at_line_start = true;
+            } else if !c.is_whitespace() {
+                at_line_start = false;
+            }
+        }
+
+        out
+    }
+
+    /// Split a scope body starting at line `first_line` into statements.
+    /// A statement ends at `;` or at a `{ ... }` block (plus an optional `Name;` after it).
+    fn statements<'a>(&self, code: &'a str, first_line: usize) -> Vec<Statement<'a>> {
+        let bytes = code.as_bytes();
+        let mut result = Vec::new();
+        let mut start = 0;
+        let mut parens = 0i32;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            match bytes[i] {
+                b'(' => parens += 1,
+                b')' => parens -= 1,
+                b';' if parens <= 0 => {
+                    let header = collapse(&code[start..i]);
+                    if !header.is_empty() {
+                        let line = self.declaration_line(code, start, i, first_line);
+                        result.push(Statement { header, body: None, body_line: 0, trailing: String::new(), line });
+                    }
+                    start = i + 1;
+                    parens = 0;
+                }
+                b'{' if parens <= 0 => {
+                    let header = collapse(&code[start..i]);
+                    let mut depth = 0;
+                    let mut end = bytes.len();
+                    for (j, &b) in bytes.iter().enumerate().skip(i) {
+                        if b == b'{' { depth += 1; }
+                        if b == b'}' {
+                            depth -= 1;
+                            if depth == 0 { end = j; break; }
+                        }
+                    }
+                    let body = &code[(i + 1).min(end)..end];
+
+                    // Type definitions may be followed by a declarator up to `;`
+                    // (typedef struct { ... } Name;), function bodies are not
+                    let is_type = !header.contains('(')
+                        && header.split_whitespace().any(|w| matches!(w, "struct" | "class" | "union" | "enum"));
+                    let rest = &code[(end + 1).min(code.len())..];
+                    let trailing_len = rest.find([';', '{', '}']);
+                    let (trailing, consumed) = match trailing_len {
+                        Some(n) if is_type && rest.as_bytes()[n] == b';' => (collapse(&rest[..n]), n + 1),
+                        _ => (String::new(), 0),
+                    };
+
+                    let line = self.declaration_line(code, start, i, first_line);
+                    let body_line = first_line + code[..=i].matches('\n').count();
+                    result.push(Statement { header, body: Some(body), body_line, trailing, line });
+                    i = (end + 1 + consumed).min(bytes.len());
+                    start = i;
+                    parens = 0;
+                    continue;
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+
+        result
+    }
+
+    /// Line of the statement in `code[start..end]`, skipping blank lines and access labels.
+    fn declaration_line(&self, code: &str, start: usize, end: usize, first_line: usize) -> usize {
+        let before = first_line + code[..start].matches('\n').count();
+        code[start..end].lines()
+            .position(|l| {
+                let l = l.trim();
+                let is_label = self.access_re.find(l).is_some_and(|m| m.end() == l.len());
+                !l.is_empty() && !is_label
+            })
+            .map_or(before, |offset| before + offset)
+    }
+
+    /// Scan a scope body starting at line `first_line`, adding exported declarations to `analysis`.
+    fn scan_scope(&self, code: &str, first_line: usize, scope: Scope, analysis: &mut PartialAnalysis) {
+        let mut scope = scope;
+
+        for statement in self.statements(code, first_line) {
+            let mut header = self.export_macro_re.replace_all(&statement.header, "").trim().to_string();
+
+            // Access labels precede the statement they apply to
+            while let Some(cap) = self.access_re.captures(&header) {
+                if let Scope::Record { name, .. } = scope {
+                    scope = Scope::Record { name, public: &cap[1] == "public" };
+                }
+                header = header[cap.get(0).map(|m| m.end()).unwrap_or(0)..].trim().to_string();
+            }
+            if header.is_empty() {
+                continue;
+            }
+            if let Scope::Record { public: false, .. } = scope {
+                continue;
+            }
+
+            // template <...> prefixes do not change what is declared
+            let header = strip_template_prefix(&header);
+
+            match statement.body {
+                Some(body) => self.scan_block(header, body, &statement, scope, analysis),
+                None => self.scan_declaration(header, statement.line, scope, analysis),
+            }
+        }
+    }
+
+    /// Handle a statement with a `{ ... }` block: namespace, linkage, record, enum or function.
+    fn scan_block(&self, header: &str, body: &str, statement: &Statement, scope: Scope, analysis: &mut PartialAnalysis) {
+        let trailing = statement.trailing.as_str();
+        let location = line_number(statement.line);
+        // extern "C" { ... } (string contents are stripped, leaving extern "")
+        if header.starts_with("extern \"") {
+            self.scan_scope(body, statement.body_line, scope, analysis);
+            return;
+        }
+
+        if let Some(cap) = self.namespace_re.captures(header) {
+            let name = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let is_private = name.is_empty()
+                || name.split("::").any(|seg| PRIVATE_NAMESPACES.contains(&seg));
+            if !is_private {
+                self.scan_scope(body, statement.body_line, Scope::Namespace, analysis);
+            }
+            return;
+        }
+
+        if let Some(cap) = self.enum_re.captures(header) {
+            let name = cap.get(2).map(|m| m.as_str())
+                .or_else(|| cap.get(1).and(declarator_name(trailing)))
+                .unwrap_or("");
+            if !name.is_empty() {
+                let variants: Vec<String> = body
+                    .split(',')
+                    .map(|v| v.split('=').next().unwrap_or("").trim().to_string())
+                    .filter(|v| !v.is_empty())
+                    .collect();
+                analysis.enums.push(ExportedEnum {
+                    name: qualified(scope, name),
+                    variants: if variants.is_empty() { None } else { Some(variants) },
+                    location: location.clone(),
+                });
+            }
+            return;
+        }
+
+        if let Some(cap) = self.record_re.captures(header) {
+            let keyword = cap.get(2).map(|m| m.as_str()).unwrap_or("struct");
+            let name = cap.get(3).map(|m| m.as_str())
+                .or_else(|| cap.get(1).and(declarator_name(trailing)))
+                .unwrap_or("");
+            if name.is_empty() {
+                return;
+            }
+            let bases = cap.get(4).map(|m| m.as_str().trim());
+
+            if keyword == "class" {
+                analysis.classes.push(ExportedClass {
+                    name: qualified(scope, name),
+                    signature: Some(match bases {
+                        Some(bases) => format!("class {} : {}", name, bases),
+                        None => format!("class {}", name),
+                    }),
+                    description: None,
+                    location: location.clone(),
+                });
+            } else {
+                analysis.types.push(ExportedType {
+                    name: qualified(scope, name),
+                    kind: TypeKind::Struct,
+                    definition: None,
+                    description: None,
+                    location: location.clone(),
+                });
+            }
+
+            // typedef struct Tag { ... } Alias;
+            if let Some(alias) = cap.get(1).and(declarator_name(trailing)).filter(|a| *a != name) {
+                analysis.types.push(ExportedType {
+                    name: qualified(scope, alias),
+                    kind: TypeKind::Type,
+                    definition: Some(format!("{} {}", keyword, name)),
+                    description: None,
+                    location: location.clone(),
+                });
+            }
+
+            // Members are public by default in structs, private in classes
+            let members = Scope::Record { name, public: keyword != "class" };
+            self.scan_scope(body, statement.body_line, members, analysis);
+            return;
+        }
+
+        // Inline function definition
+        self.scan_declaration(header, statement.line, scope, analysis);
+    }
+
+    /// Handle a statement without a block: function declaration, alias or constant.
+    fn scan_declaration(&self, header: &str, line: usize, scope: Scope, analysis: &mut PartialAnalysis) {
+        let location = line_number(line);
+        if let Some(alias) = header.strip_prefix("typedef ") {
+            let name = self.typedef_fn_ptr_re.captures(alias)
+                .and_then(|c| c.get(1))
+                .map(|m| m.as_str())
+                .or_else(|| declarator_name(alias));
+            if let Some(name) = name {
+                analysis.types.push(ExportedType {
+                    name: qualified(scope, name),
+                    kind: TypeKind::Type,
+                    definition: Some(alias.to_string()),
+                    description: None,
+                    location: location.clone(),
+                });
+            }
+            return;
+        }
+
+        if let Some(cap) = self.using_alias_re.captures(header) {
+            analysis.types.push(ExportedType {
+                name: qualified(scope, &cap[1]),
+                kind: TypeKind::Type,
+                definition: Some(cap[2].trim().to_string()),
+                description: None,
+                location: location.clone(),
+            });
+            return;
+        }
+
+        if let Some(cap) = self.constant_re.captures(header) {
+            if !header.contains('(') || header.contains('=') {
+                analysis.variables.push(ExportedVariable {
+                    name: qualified(scope, &cap[2]),
+                    var_type: Some(cap[1].trim().to_string()),
+                    location: location.clone(),
+                });
+                return;
+            }
+        }
+
+        self.scan_function(header, line, scope, analysis);
+    }
+
+    /// Add a function or member function declaration.
+    fn scan_function(&self, header: &str, line: usize, scope: Scope, analysis: &mut PartialAnalysis) {
+        let first_word = header.split(|c: char| !c.is_alphanumeric() && c != '_').next().unwrap_or("");
+        if NON_DECLARATION_KEYWORDS.contains(&first_word) {
+            return;
+        }
+        // Constructors with member initializer lists
+        if header.contains(") :") {
+            return;
+        }
+
+        let Some(cap) = self.function_re.captures(header) else { return };
+        let return_type = cap.get(1).map(|m| m.as_str().trim()).unwrap_or("");
+        let name = cap.get(2).map(|m| m.as_str()).unwrap_or("");
+        let params = cap.get(3).map(|m| m.as_str().trim()).unwrap_or("");
+        let qualifiers = cap.get(4).map(|m| m.as_str().trim()).unwrap_or("");
+        let deleted = cap.get(5).map(|m| m.as_str().contains("delete")).unwrap_or(false);
+
+        if deleted || return_type.is_empty() || return_type.ends_with("::") {
+            return;
+        }
+        // Plain `static` functions at file scope have internal linkage
+        if matches!(scope, Scope::Namespace) && header.starts_with("static ") && !header.contains("inline") {
+            return;
+        }
+        if let Scope::Record { name: record, .. } = scope {
+            // Constructors and destructors
+            if name == record || name.trim_start_matches('~') == record {
+                return;
+            }
+        }
+
+        let name = qualified(scope, name);
+        if analysis.functions.iter().any(|f| f.name == name) {
+            return;
+        }
+
+        let mut signature = format!("{} {}({})", return_type, name, params);
+        if !qualifiers.is_empty() {
+            signature.push(' ');
+            signature.push_str(qualifiers);
+        }
+
+        analysis.functions.push(ExportedFunction {
+            name,
+            signature,
+            description: None,
+            location: line_number(line),
+            parsed_signature: None,
+        });
+    }
+
+    /// Resolve a quoted include to the directory holding it.
+    /// Tries the including file's directory first, then each ancestor
+    /// and its `include/` directory as an include root.
+    fn local_include_dir(file_dir: &Path, include: &str) -> Option<PathBuf> {
+        file_dir.ancestors()
+            .flat_map(|dir| [dir.join(include), dir.join("include").join(include)])
+            .find(|candidate| candidate.is_file())
+            .and_then(|found| found.canonicalize().ok())
+            .and_then(|found| found.parent().map(|p| p.to_path_buf()))
+    }
+
+    /// Whether `<header>` belongs to the C/C++ standard library or POSIX.
+    fn is_system_header(header: &str) -> bool {
+        // C++ standard headers have no extension: <vector>, <memory>, <cstdint>
+        if !header.contains('.') && !header.contains('/') {
+            return true;
+        }
+        if header.starts_with("sys/") || header.starts_with("arpa/") || header.starts_with("netinet/") {
+            return true;
+        }
+        header.strip_suffix(".h").map(|h| C_SYSTEM_HEADERS.contains(&h)).unwrap_or(false)
+    }
+}
+
+/// Collapse runs of whitespace into single spaces.
+fn collapse(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Drop a leading `template <...>` clause.
+fn strip_template_prefix(header: &str) -> &str {
+    let Some(rest) = header.strip_prefix("template") else { return header };
+    let rest = rest.trim_start();
+    if !rest.starts_with('<') {
+        return header;
+    }
+    let mut depth = 0;
+    for (i, c) in rest.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => {
+                depth -= 1;
+                if depth == 0 {
+                    return rest[i + 1..].trim_start();
+                }
+            }
+            _ => {}
+        }
+    }
+    header
+}
+
+/// Last identifier of a declarator (`const char *Name` → `Name`, `Foo, *PFoo` → `Foo`).
+fn declarator_name(declarator: &str) -> Option<&str> {
+    let first = declarator.split(',').next().unwrap_or("").trim();
+    let first = first.split('[').next().unwrap_or("").trim_end();
+    let start = first.rfind(|c: char| !c.is_alphanumeric() && c != '_').map(|i| i + 1).unwrap_or(0);
+    let name = &first[start..];
+    if name.is_empty() || name.chars().next().map(|c| c.is_numeric()).unwrap_or(true) {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+/// Qualify a member name with its enclosing record (`TokenStore::get`).
+fn qualified(scope: Scope, name: &str) -> String {
+    match scope {
+        Scope::Record { name: record, .. } => format!("{}::{}", record, name),
+        Scope::Namespace => name.to_string(),
+    }
+}
+
+impl LanguageAnalyzer for CppAnalyzer {
+    fn analyze_file(&self, path: &Path, content: &str) -> Result<PartialAnalysis, AnalyzerError> {
+        let mut analysis = PartialAnalysis::default();
+
+        let is_header = path.extension()
+            .and_then(|e| e.to_str())
+            .map(|e| HEADER_EXTENSIONS.contains(&e))
+            .unwrap_or(false);
+
+        if is_header {
+            // Extract #define constants (include guards have no value)
+            for cap in self.define_re.captures_iter(content) {
+                let name = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+                analysis.variables.push(ExportedVariable {
+                    name: name.to_string(),
+                    var_type: None,
+                    location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+                });
+            }
+
+            // Extract declarations from the header body
+            let code = Self::strip_non_code(content);
+            self.scan_scope(&code, 1, Scope::Namespace, &mut analysis);
+        }
+
+        // Extract dependencies from #include directives
+        let file_dir = path.parent().unwrap_or(Path::new("."));
+        let file_dir = file_dir.canonicalize().unwrap_or_else(|_| file_dir.to_path_buf());
+
+        for cap in self.include_re.captures_iter(content) {
+            let delimiter = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let include = cap.get(2).map(|m| m.as_str().trim()).unwrap_or("");
+
+            // #include "..." is part of this project
+            if delimiter == "\"" {
+                let relative = match Self::local_include_dir(&file_dir, include) {
+                    Some(dir) => relative_to_dir(&file_dir, &dir),
+                    None => match include.rsplit_once('/') {
+                        Some((dir, _)) if dir.starts_with('.') => dir.to_string(),
+                        Some((dir, _)) => format!("./{}", dir),
+                        None => ".".to_string(),
+                    },
+                };
+                if relative != "." && !analysis.internal_deps.contains(&relative) {
+                    analysis.internal_deps.push(relative);
+                }
+                continue;
+            }
+
+            // #include <...> is external unless it is a standard header
+            if Self::is_system_header(include) {
+                continue;
+            }
+            let library = include.split('/').next().unwrap_or(include);
+            let library = library.split('.').next().unwrap_or(library).to_string();
+            if !analysis.external_deps.contains(&library) {
+                analysis.external_deps.push(library);
+            }
+        }
+
+        Ok(analysis)
+    }
+}
+
+impl Default for CppAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
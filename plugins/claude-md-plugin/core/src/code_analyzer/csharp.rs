@@ -0,0 +1,490 @@
+//! C# code analyzer.
+
+use std::path::Path;
+use regex::Regex;
+
+use super::{
+    AnalyzerError, Behavior, BehaviorCategory, Contract, ExportedClass, ExportedFunction,
+    ExportedType, ExportedVariable, FunctionContract, LanguageAnalyzer, PartialAnalysis, Protocol,
+    TypeKind, ExportedEnum, project_package_dir,
+};
+use super::source_locator::line_at;
+
+/// Declaration keywords that the method pattern can mistake for a return type.
+const DECLARATION_KEYWORDS: &[&str] = &[
+    "class", "record", "struct", "interface", "enum", "delegate", "event", "const", "readonly",
+];
+
+/// Analyzer for C# files.
+#[derive(Debug)]
+pub struct CSharpAnalyzer {
+    // Regex patterns for C# analysis
+    public_method_re: Regex,
+    public_class_re: Regex,
+    public_interface_re: Regex,
+    public_struct_re: Regex,
+    public_enum_re: Regex,
+    using_re: Regex,
+    namespace_re: Regex,
+    throw_new_re: Regex,
+    // Contract extraction patterns
+    xml_doc_method_re: Regex,
+    param_tag_re: Regex,
+    returns_tag_re: Regex,
+    exception_tag_re: Regex,
+    // Protocol patterns
+    enum_body_re: Regex,
+    lifecycle_re: Regex,
+    // Export candidates patterns
+    public_constant_re: Regex,
+    public_record_re: Regex,
+}
+
+impl CSharpAnalyzer {
+    pub fn new() -> Self {
+        Self {
+            // public [static|async|virtual|override ...] ReturnType MethodName<T>(params)
+            public_method_re: Regex::new(
+                r"public\s+(?:(?:static|virtual|override|abstract|async|sealed|new|extern|unsafe)\s+)*([\w.]+(?:<[^>]+>)?(?:\[\])?\??)\s+(\w+)\s*(<[^>]+>)?\s*\(([^)]*)\)"
+            ).unwrap(),
+
+            // public [static|abstract|sealed|partial] class ClassName<T> : BaseType
+            public_class_re: Regex::new(
+                r"public\s+(?:(?:static|abstract|sealed|partial)\s+)*class\s+(\w+)(?:<[^>]+>)?(?:\s*\([^)]*\))?(?:\s*:\s*([\w.]+))?"
+            ).unwrap(),
+
+            // public [partial] interface IName
+            public_interface_re: Regex::new(
+                r"public\s+(?:partial\s+)?interface\s+(\w+)"
+            ).unwrap(),
+
+            // public [readonly|ref|partial] struct StructName
+            public_struct_re: Regex::new(
+                r"public\s+(?:(?:readonly|ref|partial)\s+)*struct\s+(\w+)"
+            ).unwrap(),
+
+            // public enum EnumName
+            public_enum_re: Regex::new(
+                r"public\s+enum\s+(\w+)"
+            ).unwrap(),
+
+            // [global] using [static] [Alias =] Namespace.Name;
+            using_re: Regex::new(
+                r"(?m)^\s*(?:global\s+)?using\s+(static\s+)?(?:\w+\s*=\s*)?([\w.]+)\s*;"
+            ).unwrap(),
+
+            // namespace Company.Product.Module; or namespace Company.Product.Module {
+            namespace_re: Regex::new(
+                r"(?m)^\s*namespace\s+([\w.]+)"
+            ).unwrap(),
+
+            // throw new ExceptionType(
+            throw_new_re: Regex::new(
+                r"throw\s+new\s+(\w+)\s*\("
+            ).unwrap(),
+
+            // Match an XML doc block (/// lines), optional attributes, then a public method
+            xml_doc_method_re: Regex::new(
+                r"((?:[ \t]*///[^\n]*\n)+)(?:[ \t]*\[[^\n]*\][ \t]*\n)*[ \t]*public\s+(?:(?:static|virtual|override|abstract|async|sealed|new)\s+)*[\w.]+(?:<[^>]+>)?(?:\[\])?\??\s+(\w+)\s*(?:<[^>]+>)?\s*\("
+            ).unwrap(),
+
+            // <param name="token">description</param>
+            param_tag_re: Regex::new(
+                r#"(?s)<param\s+name="(\w+)"\s*>(.*?)</param>"#
+            ).unwrap(),
+
+            // <returns>description</returns>
+            returns_tag_re: Regex::new(
+                r"(?s)<returns>(.*?)</returns>"
+            ).unwrap(),
+
+            // <exception cref="[T:]Namespace.ExceptionName">
+            exception_tag_re: Regex::new(
+                r#"<exception\s+cref="(?:\w:)?(?:[\w.]+\.)?(\w+)""#
+            ).unwrap(),
+
+            // public enum EnumName [: int] { Constant1, Constant2, ... }
+            enum_body_re: Regex::new(
+                r"(?s)public\s+enum\s+(\w+)(?:\s*:\s*\w+)?\s*\{([^}]*)\}"
+            ).unwrap(),
+
+            // <lifecycle>N</lifecycle> in XML doc
+            lifecycle_re: Regex::new(
+                r"<lifecycle>\s*(\d+)\s*</lifecycle>"
+            ).unwrap(),
+
+            // public const Type Name = value / public static readonly Type Name = value
+            public_constant_re: Regex::new(
+                r"public\s+(?:const|static\s+readonly)\s+([\w.]+(?:<[^>]+>)?(?:\[\])?\??)\s+(\w+)\s*="
+            ).unwrap(),
+
+            // public [sealed] record [class|struct] Name(fields) or record Name { ... }
+            public_record_re: Regex::new(
+                r"public\s+(?:(?:sealed|abstract|partial|readonly)\s+)*record\s+(?:class\s+|struct\s+)?(\w+)(?:<[^>]+>)?(?:\s*\(([^)]*)\))?"
+            ).unwrap(),
+        }
+    }
+
+    /// Strip the `///` prefixes of an XML doc block, leaving the XML content.
+    fn doc_text(doc_block: &str) -> String {
+        doc_block
+            .lines()
+            .map(|l| l.trim().trim_start_matches("///").trim())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Enum member names from an enum body (`Idle, Loading = 1, ...`).
+    fn enum_members(body: &str) -> Vec<String> {
+        let without_comments: String = body
+            .lines()
+            .map(|l| l.split("//").next().unwrap_or(""))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        without_comments
+            .split(',')
+            .filter_map(|member| {
+                let member = member.trim();
+                // Drop attributes such as [Description("...")]
+                let member = match member.rfind(']') {
+                    Some(end) if member.starts_with('[') => member[end + 1..].trim(),
+                    _ => member,
+                };
+                let name: String = member
+                    .chars()
+                    .take_while(|c| c.is_alphanumeric() || *c == '_')
+                    .collect();
+                if name.is_empty() { None } else { Some(name) }
+            })
+            .collect()
+    }
+
+    /// Extract contracts from XML doc comments.
+    fn extract_contracts(&self, content: &str) -> Vec<FunctionContract> {
+        let mut contracts = Vec::new();
+
+        for cap in self.xml_doc_method_re.captures_iter(content) {
+            let doc = Self::doc_text(cap.get(1).map(|m| m.as_str()).unwrap_or(""));
+            let method_name = cap.get(2).map(|m| m.as_str()).unwrap_or("");
+
+            let mut contract = Contract::default();
+
+            // Extract preconditions from <param> tags
+            // Look for patterns like <param name="token">JWT token (must be non-empty)</param>
+            for param_cap in self.param_tag_re.captures_iter(&doc) {
+                let param_name = param_cap.get(1).map(|m| m.as_str()).unwrap_or("");
+                let desc = param_cap.get(2).map(|m| m.as_str()).unwrap_or("");
+                // Look for constraint patterns in parentheses
+                if let Some(start) = desc.find('(') {
+                    if let Some(end) = desc.find(')') {
+                        let constraint = &desc[start + 1..end];
+                        let constraint_lower = constraint.to_lowercase();
+                        if constraint_lower.contains("must be") || constraint_lower.contains("required") || constraint_lower.contains("non-empty") {
+                            contract.preconditions.push(format!("{} {}", param_name, constraint.trim()));
+                        }
+                    }
+                }
+            }
+
+            // Extract postconditions from <returns> tag
+            if let Some(returns_cap) = self.returns_tag_re.captures(&doc) {
+                let clean_desc = returns_cap.get(1)
+                    .map(|m| m.as_str().split_whitespace().collect::<Vec<_>>().join(" "))
+                    .unwrap_or_default();
+                if !clean_desc.is_empty() {
+                    contract.postconditions.push(clean_desc);
+                }
+            }
+
+            // Extract throws from <exception cref="..."> tags
+            for exception_cap in self.exception_tag_re.captures_iter(&doc) {
+                if let Some(exc_name) = exception_cap.get(1) {
+                    contract.throws.push(exc_name.as_str().to_string());
+                }
+            }
+
+            // Only add if contract has any content
+            if !contract.preconditions.is_empty()
+                || !contract.postconditions.is_empty()
+                || !contract.throws.is_empty()
+            {
+                contracts.push(FunctionContract {
+                    function_name: method_name.to_string(),
+                    contract,
+                    location: None,
+                });
+            }
+        }
+
+        contracts
+    }
+
+    /// Extract protocol information (states from enum, lifecycle).
+    fn extract_protocol(&self, content: &str) -> Option<Protocol> {
+        let mut protocol = Protocol::default();
+
+        // Extract states from public enum State { ... }
+        for cap in self.enum_body_re.captures_iter(content) {
+            let enum_name = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let enum_body = cap.get(2).map(|m| m.as_str()).unwrap_or("");
+
+            // Check if this looks like a State enum
+            if enum_name.to_lowercase().contains("state") ||
+               enum_body.to_lowercase().contains("idle") ||
+               enum_body.to_lowercase().contains("loading") {
+                for member in Self::enum_members(enum_body) {
+                    if !protocol.states.contains(&member) {
+                        protocol.states.push(member);
+                    }
+                }
+            }
+        }
+
+        // Extract lifecycle methods from <lifecycle> XML doc tags
+        let mut lifecycle_methods: Vec<(u32, String)> = Vec::new();
+        for cap in self.xml_doc_method_re.captures_iter(content) {
+            let doc = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let method_name = cap.get(2).map(|m| m.as_str()).unwrap_or("");
+
+            if let Some(lifecycle_cap) = self.lifecycle_re.captures(doc) {
+                if let Some(order) = lifecycle_cap.get(1) {
+                    if let Ok(order_num) = order.as_str().parse::<u32>() {
+                        lifecycle_methods.push((order_num, method_name.to_string()));
+                    }
+                }
+            }
+        }
+
+        // Sort by order and extract names
+        lifecycle_methods.sort_by_key(|(order, _)| *order);
+        protocol.lifecycle = lifecycle_methods.into_iter().map(|(_, name)| name).collect();
+
+        // Only return protocol if it has content
+        if !protocol.states.is_empty() || !protocol.lifecycle.is_empty() {
+            Some(protocol)
+        } else {
+            None
+        }
+    }
+}
+
+impl LanguageAnalyzer for CSharpAnalyzer {
+    fn analyze_file(&self, path: &Path, content: &str) -> Result<PartialAnalysis, AnalyzerError> {
+        let mut analysis = PartialAnalysis::default();
+
+        // Extract public methods
+        for cap in self.public_method_re.captures_iter(content) {
+            let return_type = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let name = cap.get(2).map(|m| m.as_str()).unwrap_or("");
+            let type_params = cap.get(3).map(|m| m.as_str()).unwrap_or("");
+            let params = cap.get(4).map(|m| m.as_str()).unwrap_or("");
+
+            // Skip type declarations with primary constructors (class Foo(...), record Bar(...))
+            if DECLARATION_KEYWORDS.contains(&return_type) {
+                continue;
+            }
+
+            if analysis.functions.iter().any(|f| f.name == name) {
+                continue;
+            }
+
+            analysis.functions.push(ExportedFunction {
+                name: name.to_string(),
+                signature: format!("{} {}{}({})", return_type, name, type_params, params),
+                description: None,
+                location: line_at(content, cap.get(2).map_or(0, |m| m.start())),
+                parsed_signature: None,
+            });
+        }
+
+        // Extract public classes
+        for cap in self.public_class_re.captures_iter(content) {
+            let name = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let base = cap.get(2).map(|m| m.as_str());
+
+            analysis.classes.push(ExportedClass {
+                name: name.to_string(),
+                signature: if let Some(base) = base {
+                    Some(format!("class {} : {}", name, base))
+                } else {
+                    Some(format!("class {}", name))
+                },
+                description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+            });
+        }
+
+        // Extract public records as classes
+        for cap in self.public_record_re.captures_iter(content) {
+            let name = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let signature = match cap.get(2) {
+                Some(fields) => format!("record {}({})", name, fields.as_str()),
+                None => format!("record {}", name),
+            };
+
+            analysis.classes.push(ExportedClass {
+                name: name.to_string(),
+                signature: Some(signature),
+                description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+            });
+        }
+
+        // Extract public interfaces
+        for cap in self.public_interface_re.captures_iter(content) {
+            let name = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+
+            analysis.types.push(ExportedType {
+                name: name.to_string(),
+                kind: TypeKind::Interface,
+                definition: None,
+                description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+            });
+        }
+
+        // Extract public structs
+        for cap in self.public_struct_re.captures_iter(content) {
+            let name = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+
+            analysis.types.push(ExportedType {
+                name: name.to_string(),
+                kind: TypeKind::Struct,
+                definition: None,
+                description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+            });
+        }
+
+        // Extract public enums with their members
+        for cap in self.enum_body_re.captures_iter(content) {
+            let name = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let members = Self::enum_members(cap.get(2).map(|m| m.as_str()).unwrap_or(""));
+
+            analysis.enums.push(ExportedEnum {
+                name: name.to_string(),
+                variants: if members.is_empty() { None } else { Some(members) },
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+            });
+        }
+        for cap in self.public_enum_re.captures_iter(content) {
+            let name = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            if !analysis.enums.iter().any(|e| e.name == name) {
+                analysis.enums.push(ExportedEnum {
+                    name: name.to_string(),
+                    variants: None,
+                    location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+                });
+            }
+        }
+
+        // Extract public const and static readonly constants
+        for cap in self.public_constant_re.captures_iter(content) {
+            let const_type = cap.get(1).map(|m| m.as_str().trim().to_string());
+            let name = cap.get(2).map(|m| m.as_str()).unwrap_or("");
+
+            analysis.variables.push(ExportedVariable {
+                name: name.to_string(),
+                var_type: const_type,
+                location: line_at(content, cap.get(2).map_or(0, |m| m.start())),
+            });
+        }
+
+        // Extract dependencies from using directives
+        let current_namespace = self.namespace_re.captures(content)
+            .and_then(|cap| cap.get(1))
+            .map(|m| m.as_str())
+            .unwrap_or("");
+
+        let file_dir = path.parent().unwrap_or(Path::new("."));
+        for cap in self.using_re.captures_iter(content) {
+            let is_static = cap.get(1).is_some();
+            let using_path = cap.get(2).map(|m| m.as_str()).unwrap_or("");
+
+            // `using static Namespace.Type;` imports a type; its namespace is the dependency
+            let namespace = if is_static {
+                using_path.rsplit_once('.').map(|(ns, _)| ns).unwrap_or(using_path)
+            } else {
+                using_path
+            };
+
+            // The file's own namespace needs no dependency
+            if namespace == current_namespace {
+                continue;
+            }
+
+            // Namespaces with a directory in this project are internal,
+            // expressed as a directory path relative to the file's namespace
+            if let Some(relative) = project_package_dir(file_dir, current_namespace, namespace) {
+                if !analysis.internal_deps.contains(&relative) {
+                    analysis.internal_deps.push(relative);
+                }
+                continue;
+            }
+
+            // Skip System.* (base class library)
+            if namespace == "System" || namespace.starts_with("System.") {
+                continue;
+            }
+
+            // Extract package name (first two segments, e.g. Newtonsoft.Json)
+            let parts: Vec<&str> = namespace.split('.').collect();
+            let pkg_name = if parts.len() >= 2 {
+                format!("{}.{}", parts[0], parts[1])
+            } else {
+                parts[0].to_string()
+            };
+
+            if !analysis.external_deps.contains(&pkg_name) {
+                analysis.external_deps.push(pkg_name);
+            }
+        }
+
+        // Extract contracts from XML doc comments
+        analysis.contracts = self.extract_contracts(content);
+
+        // Extract protocol information (states, lifecycle)
+        analysis.protocol = self.extract_protocol(content);
+
+        // Infer behaviors from throw new statements
+        for cap in self.throw_new_re.captures_iter(content) {
+            let exc_name = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let input = if exc_name.contains("Expired") {
+                "Expired token"
+            } else if exc_name.contains("Invalid") {
+                "Invalid token"
+            } else {
+                continue;
+            };
+
+            if !analysis.behaviors.iter().any(|b| b.input == input) {
+                analysis.behaviors.push(Behavior {
+                    input: input.to_string(),
+                    output: exc_name.to_string(),
+                    category: BehaviorCategory::Error,
+                    location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+                });
+            }
+        }
+
+        // Add success behavior if we have validation methods
+        let validate = analysis.functions.iter().find(|f| f.name.contains("Validate")).map(|f| f.location.clone());
+        if let Some(location) = validate.filter(|_| !analysis.behaviors.iter().any(|b| b.category == BehaviorCategory::Success)) {
+            analysis.behaviors.insert(0, Behavior {
+                input: "Valid JWT token".to_string(),
+                output: "TokenClaims object".to_string(),
+                category: BehaviorCategory::Success,
+                location,
+            });
+        }
+
+        Ok(analysis)
+    }
+}
+
+impl Default for CSharpAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
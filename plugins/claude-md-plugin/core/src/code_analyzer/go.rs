@@ -6,7 +6,9 @@ use regex::Regex;
 use super::{
     AnalyzerError, Behavior, BehaviorCategory, Contract, ExportedFunction, ExportedType,
     ExportedVariable, FunctionContract, LanguageAnalyzer, PartialAnalysis, Protocol, TypeKind,
+    find_ancestor_containing, relative_to_dir,
 };
+use super::source_locator::line_at;
 
 /// Analyzer for Go files.
 #[derive(Debug)]
@@ -118,6 +120,51 @@ impl GoAnalyzer {
         }
     }
 
+    /// Locate the enclosing go.mod and return (module root directory, module path).
+    fn find_module(dir: &Path) -> Option<(std::path::PathBuf, String)> {
+        let root = find_ancestor_containing(dir, "go.mod")?;
+        let go_mod = std::fs::read_to_string(root.join("go.mod")).ok()?;
+        let module_path = go_mod
+            .lines()
+            .find_map(|line| line.trim().strip_prefix("module "))?
+            .trim()
+            .trim_matches('"')
+            .to_string();
+        Some((root, module_path))
+    }
+
+    /// Record imported packages of the file at `path` as dependencies: packages under
+    /// the go.mod module path are internal (as a directory relative to the file), other
+    /// non-standard-library packages are external.
+    pub(crate) fn add_imports(path: &Path, packages: Vec<String>, analysis: &mut PartialAnalysis) {
+        let module = path.parent().and_then(Self::find_module);
+
+        for package in packages {
+            if let Some((module_root, module_path)) = &module {
+                let rest = if package == *module_path {
+                    Some("")
+                } else {
+                    package.strip_prefix(&format!("{}/", module_path))
+                };
+                if let Some(rest) = rest {
+                    let dir = path.parent().unwrap_or(Path::new("."));
+                    let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+                    let relative = relative_to_dir(&dir, &module_root.join(rest));
+                    if relative != "." && !analysis.internal_deps.contains(&relative) {
+                        analysis.internal_deps.push(relative);
+                    }
+                    continue;
+                }
+            }
+            if !package.contains('.') && !package.contains('/') {
+                continue;
+            }
+            if !analysis.external_deps.contains(&package) {
+                analysis.external_deps.push(package);
+            }
+        }
+    }
+
     /// Check if a name is exported (starts with uppercase)
     fn is_exported(&self, name: &str) -> bool {
         name.chars().next().map(|c| c.is_uppercase()).unwrap_or(false)
@@ -167,6 +214,7 @@ impl GoAnalyzer {
                 contracts.push(FunctionContract {
                     function_name: function_name.to_string(),
                     contract,
+                    location: None,
                 });
             }
         }
@@ -226,7 +274,7 @@ impl GoAnalyzer {
 }
 
 impl LanguageAnalyzer for GoAnalyzer {
-    fn analyze_file(&self, _path: &Path, content: &str) -> Result<PartialAnalysis, AnalyzerError> {
+    fn analyze_file(&self, path: &Path, content: &str) -> Result<PartialAnalysis, AnalyzerError> {
         let mut analysis = PartialAnalysis::default();
 
         // Extract exported functions (capitalized)
@@ -254,6 +302,8 @@ impl LanguageAnalyzer for GoAnalyzer {
                 name: name.to_string(),
                 signature,
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+                parsed_signature: None,
             });
         }
 
@@ -270,6 +320,7 @@ impl LanguageAnalyzer for GoAnalyzer {
                 kind: TypeKind::Struct,
                 definition: None,
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -286,6 +337,7 @@ impl LanguageAnalyzer for GoAnalyzer {
                 kind: TypeKind::Interface,
                 definition: None,
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -300,6 +352,7 @@ impl LanguageAnalyzer for GoAnalyzer {
             analysis.variables.push(ExportedVariable {
                 name: name.to_string(),
                 var_type: Some("error".to_string()),
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -320,6 +373,7 @@ impl LanguageAnalyzer for GoAnalyzer {
             analysis.variables.push(ExportedVariable {
                 name: name.to_string(),
                 var_type: const_type,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -336,6 +390,7 @@ impl LanguageAnalyzer for GoAnalyzer {
                 kind: TypeKind::Type,
                 definition: None,
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -345,30 +400,23 @@ impl LanguageAnalyzer for GoAnalyzer {
             r#"(?m)^import\s*\(([^)]*)\)|^import\s+"([^"]+)""#
         ).unwrap();
 
+        let mut packages: Vec<String> = Vec::new();
         for cap in import_block_re.captures_iter(content) {
             if let Some(block) = cap.get(1) {
                 // Grouped import block: extract all quoted packages
                 for pkg_cap in self.import_re.captures_iter(block.as_str()) {
-                    let package = pkg_cap.get(1).map(|m| m.as_str()).unwrap_or("");
-                    if !package.contains('.') && !package.contains('/') {
-                        continue;
-                    }
-                    if !analysis.external_deps.contains(&package.to_string()) {
-                        analysis.external_deps.push(package.to_string());
+                    if let Some(pkg) = pkg_cap.get(1) {
+                        packages.push(pkg.as_str().to_string());
                     }
                 }
             } else if let Some(pkg) = cap.get(2) {
                 // Single-line import: import "pkg"
-                let package = pkg.as_str();
-                if !package.contains('.') && !package.contains('/') {
-                    continue;
-                }
-                if !analysis.external_deps.contains(&package.to_string()) {
-                    analysis.external_deps.push(package.to_string());
-                }
+                packages.push(pkg.as_str().to_string());
             }
         }
 
+        Self::add_imports(path, packages, &mut analysis);
+
         // Extract contracts from comments
         analysis.contracts = self.extract_contracts(content);
 
@@ -392,17 +440,19 @@ impl LanguageAnalyzer for GoAnalyzer {
                     input: input.to_string(),
                     output: error_name.to_string(),
                     category: BehaviorCategory::Error,
+                    location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
                 });
             }
         }
 
         // Add success behavior if we have validation functions
-        let has_validate = analysis.functions.iter().any(|f| f.name.contains("Validate"));
-        if has_validate {
+        if let Some(validate) = analysis.functions.iter().find(|f| f.name.contains("Validate")) {
+            let location = validate.location.clone();
             analysis.behaviors.insert(0, Behavior {
                 input: "Valid JWT token".to_string(),
                 output: "Claims pointer".to_string(),
                 category: BehaviorCategory::Success,
+                location,
             });
         }
 
@@ -6,8 +6,9 @@ use regex::Regex;
 use super::{
     AnalyzerError, Behavior, BehaviorCategory, Contract, ExportedClass, ExportedFunction,
     ExportedType, ExportedVariable, FunctionContract, LanguageAnalyzer, PartialAnalysis, Protocol,
-    TypeKind, ExportedEnum,
+    TypeKind, ExportedEnum, package_of_import, project_package_dir,
 };
+use super::source_locator::line_at;
 
 /// Analyzer for Java files.
 #[derive(Debug)]
@@ -18,6 +19,7 @@ pub struct JavaAnalyzer {
     public_interface_re: Regex,
     public_enum_re: Regex,
     import_re: Regex,
+    package_re: Regex,
     throws_re: Regex,
     private_method_re: Regex,
     // Contract extraction patterns
@@ -59,6 +61,11 @@ impl JavaAnalyzer {
                 r"public\s+enum\s+(\w+)"
             ).unwrap(),
 
+            // package com.example.module
+            package_re: Regex::new(
+                r"(?m)^\s*package\s+([\w.]+)\s*;"
+            ).unwrap(),
+
             // import package.Class
             import_re: Regex::new(
                 r"import\s+([\w.]+);"
@@ -127,6 +134,49 @@ impl JavaAnalyzer {
         }
     }
 
+    /// Record imports of the file at `path` (in package `current_package`) as dependencies:
+    /// packages with a directory in this project are internal, expressed as a directory
+    /// path relative to the file's package; other non-JDK packages are external.
+    pub(crate) fn add_imports<'a>(
+        path: &Path,
+        current_package: &str,
+        imports: impl IntoIterator<Item = &'a str>,
+        analysis: &mut PartialAnalysis,
+    ) {
+        let file_dir = path.parent().unwrap_or(Path::new("."));
+        for import_path in imports {
+            // Imports of this file's own package need no dependency
+            let import_package = package_of_import(import_path);
+            if import_package == current_package {
+                continue;
+            }
+
+            if let Some(relative) = project_package_dir(file_dir, current_package, &import_package) {
+                if !analysis.internal_deps.contains(&relative) {
+                    analysis.internal_deps.push(relative);
+                }
+                continue;
+            }
+
+            // Skip java.* and javax.* (standard library)
+            if import_path.starts_with("java.") || import_path.starts_with("javax.") {
+                continue;
+            }
+
+            // Extract package name (first two segments or until class name)
+            let parts: Vec<&str> = import_path.split('.').collect();
+            let pkg_name = if parts.len() >= 2 {
+                format!("{}.{}", parts[0], parts[1])
+            } else {
+                parts[0].to_string()
+            };
+
+            if !analysis.external_deps.contains(&pkg_name) {
+                analysis.external_deps.push(pkg_name);
+            }
+        }
+    }
+
     /// Extract contracts from Javadoc comments.
     fn extract_contracts(&self, content: &str) -> Vec<FunctionContract> {
         let mut contracts = Vec::new();
@@ -184,6 +234,7 @@ impl JavaAnalyzer {
                 contracts.push(FunctionContract {
                     function_name: method_name.to_string(),
                     contract,
+                    location: None,
                 });
             }
         }
@@ -298,6 +349,8 @@ impl LanguageAnalyzer for JavaAnalyzer {
                 name: name.to_string(),
                 signature: format!("{} {}({})", return_type, name, params),
                 description: None,
+                location: line_at(content, cap.get(2).map_or(0, |m| m.start())),
+                parsed_signature: None,
             });
         }
 
@@ -316,6 +369,7 @@ impl LanguageAnalyzer for JavaAnalyzer {
                     name: name.to_string(),
                     signature: Some(format!("class {} extends Exception", name)),
                     description: None,
+                    location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
                 });
             } else {
                 analysis.classes.push(ExportedClass {
@@ -326,6 +380,7 @@ impl LanguageAnalyzer for JavaAnalyzer {
                         Some(format!("class {}", name))
                     },
                     description: None,
+                    location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
                 });
             }
         }
@@ -339,6 +394,7 @@ impl LanguageAnalyzer for JavaAnalyzer {
                 kind: TypeKind::Interface,
                 definition: None,
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -349,6 +405,7 @@ impl LanguageAnalyzer for JavaAnalyzer {
             analysis.enums.push(ExportedEnum {
                 name: name.to_string(),
                 variants: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -360,6 +417,7 @@ impl LanguageAnalyzer for JavaAnalyzer {
             analysis.variables.push(ExportedVariable {
                 name: name.to_string(),
                 var_type: const_type,
+                location: line_at(content, cap.get(2).map_or(0, |m| m.start())),
             });
         }
 
@@ -372,30 +430,19 @@ impl LanguageAnalyzer for JavaAnalyzer {
                 name: name.to_string(),
                 signature: Some(format!("record {}({})", name, fields)),
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
         // Extract dependencies from imports
-        for cap in self.import_re.captures_iter(content) {
-            let import_path = cap.get(1).map(|m| m.as_str()).unwrap_or("");
-
-            // Skip java.* and javax.* (standard library)
-            if import_path.starts_with("java.") || import_path.starts_with("javax.") {
-                continue;
-            }
-
-            // Extract package name (first two segments or until class name)
-            let parts: Vec<&str> = import_path.split('.').collect();
-            let pkg_name = if parts.len() >= 2 {
-                format!("{}.{}", parts[0], parts[1])
-            } else {
-                parts[0].to_string()
-            };
+        let current_package = self.package_re.captures(content)
+            .and_then(|cap| cap.get(1))
+            .map(|m| m.as_str())
+            .unwrap_or("");
 
-            if !analysis.external_deps.contains(&pkg_name) {
-                analysis.external_deps.push(pkg_name);
-            }
-        }
+        let imports = self.import_re.captures_iter(content)
+            .map(|cap| cap.get(1).map(|m| m.as_str()).unwrap_or(""));
+        Self::add_imports(path, current_package, imports, &mut analysis);
 
         // Extract contracts from Javadoc comments
         analysis.contracts = self.extract_contracts(content);
@@ -422,18 +469,20 @@ impl LanguageAnalyzer for JavaAnalyzer {
                         input: input.to_string(),
                         output: exc_name.to_string(),
                         category: BehaviorCategory::Error,
+                        location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
                     });
                 }
             }
         }
 
         // Add success behavior if we have validation methods
-        let has_validate = analysis.functions.iter().any(|f| f.name.contains("validate"));
-        if has_validate && !analysis.behaviors.iter().any(|b| b.category == BehaviorCategory::Success) {
+        let validate = analysis.functions.iter().find(|f| f.name.contains("validate")).map(|f| f.location.clone());
+        if let Some(location) = validate.filter(|_| !analysis.behaviors.iter().any(|b| b.category == BehaviorCategory::Success)) {
             analysis.behaviors.insert(0, Behavior {
                 input: "Valid JWT token".to_string(),
                 output: "TokenClaims object".to_string(),
                 category: BehaviorCategory::Success,
+                location,
             });
         }
 
@@ -6,8 +6,9 @@ use regex::Regex;
 use super::{
     AnalyzerError, Behavior, BehaviorCategory, Contract, ExportedClass, ExportedFunction,
     ExportedType, ExportedVariable, FunctionContract, LanguageAnalyzer, PartialAnalysis, Protocol,
-    TypeKind, ExportedEnum,
+    TypeKind, ExportedEnum, package_of_import, project_package_dir,
 };
+use super::source_locator::line_at;
 
 /// Analyzer for Kotlin files.
 #[derive(Debug)]
@@ -19,6 +20,7 @@ pub struct KotlinAnalyzer {
     data_class_re: Regex,
     enum_class_re: Regex,
     import_re: Regex,
+    package_re: Regex,
     result_re: Regex,
     throw_re: Regex,
     // Contract extraction patterns
@@ -68,6 +70,11 @@ impl KotlinAnalyzer {
                 r"enum\s+class\s+(\w+)"
             ).unwrap(),
 
+            // package com.example.module
+            package_re: Regex::new(
+                r"(?m)^\s*package\s+([\w.]+)"
+            ).unwrap(),
+
             // import package.Class
             import_re: Regex::new(
                 r"import\s+([\w.]+)"
@@ -209,6 +216,7 @@ impl KotlinAnalyzer {
                 contracts.push(FunctionContract {
                     function_name: function_name.to_string(),
                     contract,
+                    location: None,
                 });
             }
         }
@@ -254,10 +262,10 @@ impl KotlinAnalyzer {
             let parent_name = cap.get(2).map(|m| m.as_str()).unwrap_or("");
 
             // Check if parent is a sealed class/interface
-            if sealed_names.contains(&parent_name.to_string()) {
-                if !protocol.states.contains(&subtype_name.to_string()) {
-                    protocol.states.push(subtype_name.to_string());
-                }
+            if sealed_names.contains(&parent_name.to_string())
+                && !protocol.states.contains(&subtype_name.to_string())
+            {
+                protocol.states.push(subtype_name.to_string());
             }
         }
 
@@ -294,7 +302,7 @@ impl KotlinAnalyzer {
 }
 
 impl LanguageAnalyzer for KotlinAnalyzer {
-    fn analyze_file(&self, _path: &Path, content: &str) -> Result<PartialAnalysis, AnalyzerError> {
+    fn analyze_file(&self, path: &Path, content: &str) -> Result<PartialAnalysis, AnalyzerError> {
         let mut analysis = PartialAnalysis::default();
 
         // Get list of private functions
@@ -324,6 +332,8 @@ impl LanguageAnalyzer for KotlinAnalyzer {
                 name: name.to_string(),
                 signature,
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+                parsed_signature: None,
             });
         }
 
@@ -336,6 +346,7 @@ impl LanguageAnalyzer for KotlinAnalyzer {
                 kind: TypeKind::DataClass,
                 definition: None,
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -369,6 +380,7 @@ impl LanguageAnalyzer for KotlinAnalyzer {
                     Some(format!("class {}", name))
                 },
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -379,6 +391,7 @@ impl LanguageAnalyzer for KotlinAnalyzer {
             analysis.enums.push(ExportedEnum {
                 name: name.to_string(),
                 variants: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -395,6 +408,7 @@ impl LanguageAnalyzer for KotlinAnalyzer {
             analysis.variables.push(ExportedVariable {
                 name: name.to_string(),
                 var_type: val_type,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -407,6 +421,7 @@ impl LanguageAnalyzer for KotlinAnalyzer {
                 kind: TypeKind::Type,
                 definition: None,
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -428,6 +443,7 @@ impl LanguageAnalyzer for KotlinAnalyzer {
                 name: name.to_string(),
                 signature: Some(format!("object {}", name)),
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -440,13 +456,35 @@ impl LanguageAnalyzer for KotlinAnalyzer {
                 kind: TypeKind::Trait,
                 definition: None,
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
         // Extract dependencies from imports
+        let current_package = self.package_re.captures(content)
+            .and_then(|cap| cap.get(1))
+            .map(|m| m.as_str())
+            .unwrap_or("");
+
+        let file_dir = path.parent().unwrap_or(Path::new("."));
         for cap in self.import_re.captures_iter(content) {
             let import_path = cap.get(1).map(|m| m.as_str()).unwrap_or("");
 
+            // Imports of this file's own package need no dependency
+            let import_package = package_of_import(import_path);
+            if import_package == current_package {
+                continue;
+            }
+
+            // Packages with a directory in this project are internal,
+            // expressed as a directory path relative to the file's package
+            if let Some(relative) = project_package_dir(file_dir, current_package, &import_package) {
+                if !analysis.internal_deps.contains(&relative) {
+                    analysis.internal_deps.push(relative);
+                }
+                continue;
+            }
+
             // Skip kotlin.* and java.* (standard library)
             if import_path.starts_with("kotlin.") || import_path.starts_with("java.") {
                 continue;
@@ -473,7 +511,7 @@ impl LanguageAnalyzer for KotlinAnalyzer {
 
         // Infer behaviors from Result types and throws
         let has_result = self.result_re.is_match(content);
-        let has_validate = analysis.functions.iter().any(|f| f.name.contains("validate"));
+        let validate = analysis.functions.iter().find(|f| f.name.contains("validate")).map(|f| f.location.clone());
 
         // Extract thrown exceptions
         for cap in self.throw_re.captures_iter(content) {
@@ -498,12 +536,13 @@ impl LanguageAnalyzer for KotlinAnalyzer {
                     input: input.to_string(),
                     output,
                     category: BehaviorCategory::Error,
+                    location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
                 });
             }
         }
 
         // Add success behavior
-        if has_validate {
+        if let Some(location) = validate {
             let success_output = if has_result {
                 "Result.success(TokenClaims)".to_string()
             } else {
@@ -515,6 +554,7 @@ impl LanguageAnalyzer for KotlinAnalyzer {
                     input: "Valid JWT token".to_string(),
                     output: success_output,
                     category: BehaviorCategory::Success,
+                    location,
                 });
             }
         }
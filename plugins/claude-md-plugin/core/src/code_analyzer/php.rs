@@ -0,0 +1,435 @@
+//! PHP code analyzer.
+
+use std::path::Path;
+use regex::Regex;
+
+use super::{
+    AnalyzerError, Behavior, BehaviorCategory, Contract, ExportedClass, ExportedEnum,
+    ExportedFunction, ExportedType, ExportedVariable, FunctionContract, LanguageAnalyzer,
+    PartialAnalysis, Protocol, TypeKind, package_relative_path,
+};
+use super::source_locator::line_at;
+
+/// Analyzer for PHP files.
+#[derive(Debug)]
+pub struct PhpAnalyzer {
+    // Regex patterns for PHP analysis
+    namespace_re: Regex,
+    use_re: Regex,
+    require_re: Regex,
+    class_re: Regex,
+    interface_re: Regex,
+    trait_re: Regex,
+    enum_re: Regex,
+    enum_case_re: Regex,
+    function_re: Regex,
+    const_re: Regex,
+    define_re: Regex,
+    throw_re: Regex,
+    // Contract extraction patterns (PHPDoc)
+    phpdoc_function_re: Regex,
+    param_tag_re: Regex,
+    return_tag_re: Regex,
+    throws_tag_re: Regex,
+}
+
+impl PhpAnalyzer {
+    pub fn new() -> Self {
+        Self {
+            // namespace App\Auth;
+            namespace_re: Regex::new(
+                r"(?m)^\s*namespace\s+([\w\\]+)\s*;"
+            ).unwrap(),
+
+            // use App\Token\TokenService; / use function App\x; / use App\Models\{User, Post};
+            // Only column-0 statements: indented `use` inside a class imports a trait.
+            use_re: Regex::new(
+                r"(?m)^use\s+(?:function\s+|const\s+)?\\?([\w\\]+?)\\?(?:\{([^}]*)\})?(?:\s+as\s+\w+)?\s*;"
+            ).unwrap(),
+
+            // require_once __DIR__ . '/../lib/helpers.php'; / include 'config.php';
+            require_re: Regex::new(
+                r#"(?m)^\s*(?:require|require_once|include|include_once)\s*\(?\s*(?:__DIR__\s*\.\s*)?['"]([^'"]+)['"]"#
+            ).unwrap(),
+
+            // [abstract|final|readonly] class Name [extends Base] [implements I, J]
+            class_re: Regex::new(
+                r"(?m)^\s*(?:(?:abstract|final|readonly)\s+)*class\s+(\w+)(?:\s+extends\s+([\w\\]+))?(?:\s+implements\s+([\w\\,\s]+?))?\s*\{"
+            ).unwrap(),
+
+            // interface Name [extends Other]
+            interface_re: Regex::new(
+                r"(?m)^\s*interface\s+(\w+)"
+            ).unwrap(),
+
+            // trait Name
+            trait_re: Regex::new(
+                r"(?m)^\s*trait\s+(\w+)"
+            ).unwrap(),
+
+            // enum Name[: string] [implements I] {
+            enum_re: Regex::new(
+                r"(?m)^\s*enum\s+(\w+)[^{]*\{"
+            ).unwrap(),
+
+            // case Name [= 'value'];
+            enum_case_re: Regex::new(
+                r"(?m)^\s*case\s+(\w+)\s*(?:=[^;]*)?;"
+            ).unwrap(),
+
+            // [visibility] [static|abstract|final] function name(params)[: Type]
+            function_re: Regex::new(
+                r"(?m)^(\s*)((?:(?:public|protected|private|static|abstract|final)\s+)*)function\s+&?(\w+)\s*\(([^)]*)\)(?:\s*:\s*(\??[\w\\|]+))?"
+            ).unwrap(),
+
+            // [public] const NAME = value;
+            const_re: Regex::new(
+                r"(?m)^\s*((?:(?:public|protected|private|final)\s+)*)const\s+(?:\w+\s+)?([A-Z][A-Z0-9_]*)\s*="
+            ).unwrap(),
+
+            // define('NAME', value);
+            define_re: Regex::new(
+                r#"define\s*\(\s*['"](\w+)['"]"#
+            ).unwrap(),
+
+            // throw new ExceptionClass(
+            throw_re: Regex::new(
+                r"throw\s+new\s+\\?([\w\\]+)\s*\("
+            ).unwrap(),
+
+            // Match a PHPDoc block followed by a function declaration
+            phpdoc_function_re: Regex::new(
+                r"/\*\*((?:[^*]|\*[^/])*)\*/\s*(?:(?:public|protected|private|static|abstract|final)\s+)*function\s+&?(\w+)"
+            ).unwrap(),
+
+            // @param string $token description
+            param_tag_re: Regex::new(
+                r"@param\s+(?:[^\s$]+\s+)?\$(\w+)[ \t]*([^\n]*)"
+            ).unwrap(),
+
+            // @return Type description
+            return_tag_re: Regex::new(
+                r"@return\s+([^\s*]+)[ \t]*([^\n]*)"
+            ).unwrap(),
+
+            // @throws ExceptionClass
+            throws_tag_re: Regex::new(
+                r"@throws\s+\\?([\w\\]+)"
+            ).unwrap(),
+        }
+    }
+
+    /// Find the brace-delimited body starting at the `{` at `open`.
+    fn block_body(content: &str, open: usize) -> &str {
+        let mut depth = 0;
+        for (i, c) in content[open..].char_indices() {
+            match c {
+                '{' => depth += 1,
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return &content[open + 1..open + i];
+                    }
+                }
+                _ => {}
+            }
+        }
+        &content[open + 1..]
+    }
+
+    /// Classify a namespace import as internal (relative path) or external (vendor package).
+    fn classify_import(current_ns: &str, import: &str, analysis: &mut PartialAnalysis) {
+        let segments: Vec<&str> = import.split('\\').filter(|s| !s.is_empty()).collect();
+        if segments.len() < 2 {
+            return;
+        }
+
+        // Same root namespace as this file → a directory of this project (PSR-4)
+        let current_root = current_ns.split('\\').next().unwrap_or("");
+        if !current_root.is_empty() && segments[0] == current_root {
+            let import_ns = segments[..segments.len() - 1].join(".");
+            let current_pkg = current_ns.replace('\\', ".");
+            if let Some(relative) = package_relative_path(&current_pkg, &import_ns) {
+                if !analysis.internal_deps.contains(&relative) {
+                    analysis.internal_deps.push(relative);
+                }
+            }
+            return;
+        }
+
+        // Vendor package: Vendor\Package
+        let package = segments[..2].join("\\");
+        if !analysis.external_deps.contains(&package) {
+            analysis.external_deps.push(package);
+        }
+    }
+
+    /// Extract contracts from PHPDoc comments.
+    fn extract_contracts(&self, content: &str) -> Vec<FunctionContract> {
+        let mut contracts = Vec::new();
+
+        for cap in self.phpdoc_function_re.captures_iter(content) {
+            let doc = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let function_name = cap.get(2).map(|m| m.as_str()).unwrap_or("");
+
+            let mut contract = Contract::default();
+
+            // Extract preconditions from @param tags
+            // Look for patterns like "@param string $token JWT token (must be non-empty)"
+            for param_cap in self.param_tag_re.captures_iter(doc) {
+                let param_name = param_cap.get(1).map(|m| m.as_str()).unwrap_or("");
+                let desc = param_cap.get(2).map(|m| m.as_str()).unwrap_or("");
+                if let Some(start) = desc.find('(') {
+                    if let Some(end) = desc.find(')') {
+                        let constraint = &desc[start + 1..end];
+                        let constraint_lower = constraint.to_lowercase();
+                        if constraint_lower.contains("must be") || constraint_lower.contains("required") || constraint_lower.contains("non-empty") {
+                            contract.preconditions.push(format!("{} {}", param_name, constraint.trim()));
+                        }
+                    }
+                }
+            }
+
+            // Extract postconditions from @return tag
+            if let Some(return_cap) = self.return_tag_re.captures(doc) {
+                let return_type = return_cap.get(1).map(|m| m.as_str().trim()).unwrap_or("");
+                let desc = return_cap.get(2).map(|m| m.as_str().trim()).unwrap_or("");
+                let postcondition = if desc.is_empty() { return_type } else { desc };
+                if !postcondition.is_empty() && postcondition != "void" {
+                    contract.postconditions.push(postcondition.to_string());
+                }
+            }
+
+            // Extract throws from @throws tags
+            for throws_cap in self.throws_tag_re.captures_iter(doc) {
+                if let Some(error) = throws_cap.get(1) {
+                    let name = error.as_str().rsplit('\\').next().unwrap_or("");
+                    contract.throws.push(name.to_string());
+                }
+            }
+
+            if !contract.preconditions.is_empty()
+                || !contract.postconditions.is_empty()
+                || !contract.throws.is_empty()
+            {
+                contracts.push(FunctionContract {
+                    function_name: function_name.to_string(),
+                    contract,
+                    location: None,
+                });
+            }
+        }
+
+        contracts
+    }
+}
+
+impl LanguageAnalyzer for PhpAnalyzer {
+    fn analyze_file(&self, _path: &Path, content: &str) -> Result<PartialAnalysis, AnalyzerError> {
+        let mut analysis = PartialAnalysis::default();
+
+        // Extract classes
+        for cap in self.class_re.captures_iter(content) {
+            let name = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let mut signature = format!("class {}", name);
+            if let Some(base) = cap.get(2) {
+                signature.push_str(&format!(" extends {}", base.as_str()));
+            }
+            if let Some(interfaces) = cap.get(3) {
+                let interfaces = interfaces.as_str().split_whitespace().collect::<Vec<_>>().join(" ");
+                signature.push_str(&format!(" implements {}", interfaces));
+            }
+            analysis.classes.push(ExportedClass {
+                name: name.to_string(),
+                signature: Some(signature),
+                description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+            });
+        }
+
+        // Extract interfaces
+        for cap in self.interface_re.captures_iter(content) {
+            let name = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            analysis.types.push(ExportedType {
+                name: name.to_string(),
+                kind: TypeKind::Interface,
+                definition: None,
+                description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+            });
+        }
+
+        // Extract traits
+        for cap in self.trait_re.captures_iter(content) {
+            let name = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            analysis.types.push(ExportedType {
+                name: name.to_string(),
+                kind: TypeKind::Trait,
+                definition: None,
+                description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+            });
+        }
+
+        // Extract enums with their cases; state enums also describe the protocol
+        let mut protocol = Protocol::default();
+        for cap in self.enum_re.captures_iter(content) {
+            let name = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let whole = cap.get(0).unwrap();
+            let body = Self::block_body(content, whole.end() - 1);
+            let variants: Vec<String> = self.enum_case_re.captures_iter(body)
+                .filter_map(|c| c.get(1).map(|m| m.as_str().to_string()))
+                .collect();
+
+            let lower = variants.iter().map(|v| v.to_lowercase()).collect::<Vec<_>>();
+            if name.to_lowercase().contains("state") || lower.iter().any(|v| v == "idle" || v == "loading") {
+                protocol.states.extend(variants.iter().cloned());
+            }
+
+            analysis.enums.push(ExportedEnum {
+                name: name.to_string(),
+                variants: if variants.is_empty() { None } else { Some(variants) },
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+            });
+        }
+        if !protocol.states.is_empty() {
+            analysis.protocol = Some(protocol);
+        }
+
+        // Extract public functions and methods (no visibility modifier means public)
+        for cap in self.function_re.captures_iter(content) {
+            let modifiers = cap.get(2).map(|m| m.as_str()).unwrap_or("");
+            let name = cap.get(3).map(|m| m.as_str()).unwrap_or("");
+            let params = cap.get(4).map(|m| m.as_str()).unwrap_or("");
+            let return_type = cap.get(5).map(|m| m.as_str());
+
+            if modifiers.contains("private") || modifiers.contains("protected") {
+                continue;
+            }
+            // Constructors and magic methods are not part of the API surface
+            if name.starts_with("__") || analysis.functions.iter().any(|f| f.name == name) {
+                continue;
+            }
+
+            let params = params.split_whitespace().collect::<Vec<_>>().join(" ");
+            let prefix = if modifiers.contains("static") { "static " } else { "" };
+            let signature = match return_type {
+                Some(ret) => format!("{}function {}({}): {}", prefix, name, params, ret),
+                None => format!("{}function {}({})", prefix, name, params),
+            };
+
+            analysis.functions.push(ExportedFunction {
+                name: name.to_string(),
+                signature,
+                description: None,
+                location: line_at(content, cap.get(3).map_or(0, |m| m.start())),
+                parsed_signature: None,
+            });
+        }
+
+        // Extract constants
+        for cap in self.const_re.captures_iter(content) {
+            let modifiers = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let name = cap.get(2).map(|m| m.as_str()).unwrap_or("");
+            if modifiers.contains("private") || modifiers.contains("protected") {
+                continue;
+            }
+            if !analysis.variables.iter().any(|v| v.name == name) {
+                analysis.variables.push(ExportedVariable {
+                    name: name.to_string(),
+                    var_type: None,
+                    location: line_at(content, cap.get(2).map_or(0, |m| m.start())),
+                });
+            }
+        }
+        for cap in self.define_re.captures_iter(content) {
+            let name = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            if !analysis.variables.iter().any(|v| v.name == name) {
+                analysis.variables.push(ExportedVariable {
+                    name: name.to_string(),
+                    var_type: None,
+                    location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+                });
+            }
+        }
+
+        // Extract dependencies from use statements
+        let current_ns = self.namespace_re.captures(content)
+            .and_then(|c| c.get(1))
+            .map(|m| m.as_str())
+            .unwrap_or("");
+        for cap in self.use_re.captures_iter(content) {
+            let base = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            match cap.get(2) {
+                // Group import: use App\Models\{User, Post as P};
+                Some(group) => {
+                    for member in group.as_str().split(',') {
+                        let member = member.split_whitespace().next().unwrap_or("");
+                        if !member.is_empty() {
+                            Self::classify_import(current_ns, &format!("{}\\{}", base, member), &mut analysis);
+                        }
+                    }
+                }
+                None => Self::classify_import(current_ns, base, &mut analysis),
+            }
+        }
+
+        // require/include of project files are internal, expressed as the included file's directory
+        for cap in self.require_re.captures_iter(content) {
+            let required = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let required = required.trim_start_matches('/');
+            let relative = match required.rsplit_once('/') {
+                Some((dir, _)) if dir.starts_with('.') => dir.to_string(),
+                Some((dir, _)) => format!("./{}", dir),
+                None => continue,
+            };
+            if !analysis.internal_deps.contains(&relative) {
+                analysis.internal_deps.push(relative);
+            }
+        }
+
+        // Extract contracts from PHPDoc comments
+        analysis.contracts = self.extract_contracts(content);
+
+        // Infer behaviors from throw statements
+        for cap in self.throw_re.captures_iter(content) {
+            let error = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let error = error.rsplit('\\').next().unwrap_or(error);
+            let input = if error.contains("Expired") || error.contains("expired") {
+                "Expired token"
+            } else if error.contains("Invalid") || error.contains("invalid") {
+                "Invalid token"
+            } else {
+                continue;
+            };
+
+            if !analysis.behaviors.iter().any(|b| b.input == input) {
+                analysis.behaviors.push(Behavior {
+                    input: input.to_string(),
+                    output: error.to_string(),
+                    category: BehaviorCategory::Error,
+                    location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+                });
+            }
+        }
+
+        // Add success behavior if we have validation functions
+        let validate = analysis.functions.iter().find(|f| f.name.contains("validate") || f.name.contains("Validate")).map(|f| f.location.clone());
+        if let Some(location) = validate.filter(|_| !analysis.behaviors.iter().any(|b| b.category == BehaviorCategory::Success)) {
+            analysis.behaviors.insert(0, Behavior {
+                input: "Valid JWT token".to_string(),
+                output: "TokenClaims object".to_string(),
+                category: BehaviorCategory::Success,
+                location,
+            });
+        }
+
+        Ok(analysis)
+    }
+}
+
+impl Default for PhpAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
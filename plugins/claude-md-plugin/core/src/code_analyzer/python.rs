@@ -8,6 +8,7 @@ use super::{
     ExportedType, ExportedVariable, FunctionContract, LanguageAnalyzer, PartialAnalysis, Protocol,
     TypeKind,
 };
+use super::source_locator::line_at;
 
 /// Analyzer for Python files.
 #[derive(Debug)]
@@ -125,6 +126,21 @@ impl PythonAnalyzer {
         }
     }
 
+    /// Record an imported module as a dependency: relative imports (`.models`) are
+    /// internal, anything else is external by its top-level package.
+    pub(crate) fn add_import(module: &str, analysis: &mut PartialAnalysis) {
+        if module.starts_with('.') {
+            if !analysis.internal_deps.iter().any(|d| d == module) {
+                analysis.internal_deps.push(module.to_string());
+            }
+        } else {
+            let pkg_name = module.split('.').next().unwrap_or(module);
+            if !analysis.external_deps.iter().any(|d| d == pkg_name) {
+                analysis.external_deps.push(pkg_name.to_string());
+            }
+        }
+    }
+
     /// Check if a function name is public (doesn't start with _)
     fn is_public(&self, name: &str) -> bool {
         !name.starts_with('_')
@@ -133,6 +149,8 @@ impl PythonAnalyzer {
     /// Extract contracts from Python docstrings.
     fn extract_contracts(&self, content: &str) -> Vec<FunctionContract> {
         let mut contracts = Vec::new();
+        let arg_re = Regex::new(r"(\w+):\s*(.+?)(?:\n|$)").unwrap();
+        let raise_re = Regex::new(r"(\w+):").unwrap();
 
         for cap in self.docstring_func_re.captures_iter(content) {
             let function_name = cap.get(1).map(|m| m.as_str()).unwrap_or("");
@@ -150,7 +168,6 @@ impl PythonAnalyzer {
             if let Some(args_cap) = self.args_section_re.captures(docstring) {
                 let args_content = args_cap.get(1).map(|m| m.as_str()).unwrap_or("");
                 // Parse each argument line
-                let arg_re = Regex::new(r"(\w+):\s*(.+?)(?:\n|$)").unwrap();
                 for arg_cap in arg_re.captures_iter(args_content) {
                     let param_name = arg_cap.get(1).map(|m| m.as_str()).unwrap_or("");
                     let desc = arg_cap.get(2).map(|m| m.as_str()).unwrap_or("");
@@ -179,7 +196,6 @@ impl PythonAnalyzer {
             if let Some(raises_cap) = self.raises_section_re.captures(docstring) {
                 let raises_content = raises_cap.get(1).map(|m| m.as_str()).unwrap_or("");
                 // Parse each exception line: "ExceptionName: description"
-                let raise_re = Regex::new(r"(\w+):").unwrap();
                 for raise_cap in raise_re.captures_iter(raises_content) {
                     if let Some(exc_name) = raise_cap.get(1) {
                         contract.throws.push(exc_name.as_str().to_string());
@@ -195,6 +211,7 @@ impl PythonAnalyzer {
                 contracts.push(FunctionContract {
                     function_name: function_name.to_string(),
                     contract,
+                    location: None,
                 });
             }
         }
@@ -328,6 +345,8 @@ impl LanguageAnalyzer for PythonAnalyzer {
                 name: name.to_string(),
                 signature,
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+                parsed_signature: None,
             });
         }
 
@@ -349,6 +368,7 @@ impl LanguageAnalyzer for PythonAnalyzer {
                 name: name.to_string(),
                 signature: Some(format!("class {}", name)),
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -374,6 +394,7 @@ impl LanguageAnalyzer for PythonAnalyzer {
             analysis.variables.push(ExportedVariable {
                 name: name.to_string(),
                 var_type: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -396,29 +417,17 @@ impl LanguageAnalyzer for PythonAnalyzer {
                 kind: TypeKind::Type,
                 definition: None,
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
         // Extract dependencies
         for cap in self.import_re.captures_iter(content) {
-            let package = cap.get(1).map(|m| m.as_str()).unwrap_or("");
-            if !analysis.external_deps.contains(&package.to_string()) {
-                analysis.external_deps.push(package.to_string());
-            }
+            Self::add_import(cap.get(1).map(|m| m.as_str()).unwrap_or(""), &mut analysis);
         }
 
         for cap in self.from_import_re.captures_iter(content) {
-            let package = cap.get(1).map(|m| m.as_str()).unwrap_or("");
-            if package.starts_with('.') {
-                if !analysis.internal_deps.contains(&package.to_string()) {
-                    analysis.internal_deps.push(package.to_string());
-                }
-            } else {
-                let pkg_name = package.split('.').next().unwrap_or(package);
-                if !analysis.external_deps.contains(&pkg_name.to_string()) {
-                    analysis.external_deps.push(pkg_name.to_string());
-                }
-            }
+            Self::add_import(cap.get(1).map(|m| m.as_str()).unwrap_or(""), &mut analysis);
         }
 
         // Extract contracts from docstrings
@@ -452,6 +461,7 @@ impl LanguageAnalyzer for PythonAnalyzer {
                     input: input.to_string(),
                     output: error_name,
                     category: BehaviorCategory::Error,
+                    location: line_at(content, cap.get(2).map_or(0, |m| m.start())),
                 });
             }
         }
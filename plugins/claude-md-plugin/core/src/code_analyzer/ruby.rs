@@ -0,0 +1,432 @@
+//! Ruby code analyzer.
+
+use std::path::Path;
+use regex::Regex;
+
+use super::{
+    AnalyzerError, Behavior, BehaviorCategory, Contract, ExportedClass, ExportedFunction,
+    ExportedVariable, FunctionContract, LanguageAnalyzer, PartialAnalysis, Protocol,
+    relative_to_dir,
+};
+use super::source_locator::{line_at, line_number};
+
+/// Ruby standard library features (`require 'json'`), not gem dependencies.
+const STDLIB_FEATURES: &[&str] = &[
+    "base64", "benchmark", "bigdecimal", "cgi", "csv", "date", "delegate", "digest", "English",
+    "erb", "etc", "fileutils", "find", "forwardable", "io", "ipaddr", "json", "logger",
+    "monitor", "net", "observer", "open-uri", "open3", "openssl", "optparse", "ostruct",
+    "pathname", "pp", "prettyprint", "pstore", "psych", "rbconfig", "ripper", "securerandom",
+    "set", "shellwords", "singleton", "socket", "stringio", "strscan", "tempfile", "time",
+    "timeout", "tmpdir", "tsort", "uri", "weakref", "yaml", "zlib",
+];
+
+/// A `class`/`module` being scanned, with the visibility of its next `def`.
+#[derive(Debug)]
+struct Scope {
+    indent: usize,
+    public: bool,
+}
+
+/// Analyzer for Ruby files.
+#[derive(Debug)]
+pub struct RubyAnalyzer {
+    // Regex patterns for Ruby analysis
+    class_re: Regex,
+    module_re: Regex,
+    def_re: Regex,
+    end_re: Regex,
+    visibility_re: Regex,
+    private_symbols_re: Regex,
+    constant_re: Regex,
+    require_re: Regex,
+    require_relative_re: Regex,
+    raise_re: Regex,
+    // Contract extraction patterns (YARD tags)
+    yard_def_re: Regex,
+    param_tag_re: Regex,
+    return_tag_re: Regex,
+    raise_tag_re: Regex,
+    // Protocol patterns
+    aasm_state_re: Regex,
+    states_constant_re: Regex,
+}
+
+impl RubyAnalyzer {
+    pub fn new() -> Self {
+        Self {
+            // class Name < Base
+            class_re: Regex::new(
+                r"^(\s*)class\s+([A-Z][\w:]*)(?:\s*<\s*([\w:]+))?"
+            ).unwrap(),
+
+            // module Name
+            module_re: Regex::new(
+                r"^(\s*)module\s+([A-Z][\w:]*)"
+            ).unwrap(),
+
+            // def [self.]name(params) / def name params
+            def_re: Regex::new(
+                r"^(\s*)(?:(private|protected|public)\s+)?def\s+(self\.)?(\w+[?!=]?)\s*(?:\(([^)]*)\)|([^#\n=;]*))?"
+            ).unwrap(),
+
+            // end closing a class/module at the same indentation
+            end_re: Regex::new(
+                r"^(\s*)end\b"
+            ).unwrap(),
+
+            // private / protected / public on their own line
+            visibility_re: Regex::new(
+                r"^\s*(private|protected|public)\s*$"
+            ).unwrap(),
+
+            // private :name, :other / private_class_method :name
+            private_symbols_re: Regex::new(
+                r"(?m)^\s*(?:private|protected|private_class_method)\s+((?::\w+[?!=]?\s*,?\s*)+)$"
+            ).unwrap(),
+
+            // CONSTANT_NAME = value
+            constant_re: Regex::new(
+                r"(?m)^\s*([A-Z][A-Z0-9_]*)\s*=[^=~]"
+            ).unwrap(),
+
+            // require 'feature'
+            require_re: Regex::new(
+                r#"(?m)^\s*require\s*\(?\s*['"]([^'"]+)['"]"#
+            ).unwrap(),
+
+            // require_relative 'path'
+            require_relative_re: Regex::new(
+                r#"(?m)^\s*require_relative\s*\(?\s*['"]([^'"]+)['"]"#
+            ).unwrap(),
+
+            // raise ErrorClass[, message] / raise Module::ErrorClass.new(...)
+            raise_re: Regex::new(
+                r"\braise\s+([A-Z][\w:]*)"
+            ).unwrap(),
+
+            // Match a # comment block followed by a def
+            yard_def_re: Regex::new(
+                r"((?:[ \t]*#[^\n]*\n)+)[ \t]*def\s+(?:self\.)?(\w+[?!=]?)"
+            ).unwrap(),
+
+            // @param name [Type] description / @param [Type] name description
+            param_tag_re: Regex::new(
+                r"@param\s+(?:\[[^\]]*\]\s+)?(\w+)\s+(?:\[[^\]]*\]\s+)?([^\n]+)"
+            ).unwrap(),
+
+            // @return [Type] description
+            return_tag_re: Regex::new(
+                r"@return\s+(?:\[([^\]]*)\])?\s*([^\n]*)"
+            ).unwrap(),
+
+            // @raise [ErrorClass] description
+            raise_tag_re: Regex::new(
+                r"@raise\s+\[([\w:]+)\]"
+            ).unwrap(),
+
+            // AASM: state :idle, initial: true
+            aasm_state_re: Regex::new(
+                r"(?m)^\s*state\s+((?::\w+\s*,?\s*)+)"
+            ).unwrap(),
+
+            // STATES = %i[idle loading] / STATES = [:idle, :loading]
+            states_constant_re: Regex::new(
+                r"(?m)^\s*[A-Z_]*STATES?\s*=\s*(?:%[iw]\[([^\]]*)\]|\[([^\]]*)\])"
+            ).unwrap(),
+        }
+    }
+
+    /// Directory of a `require`d feature found under an ancestor `lib/` directory.
+    fn local_feature_dir(file_dir: &Path, feature: &str) -> Option<std::path::PathBuf> {
+        let file = format!("{}.rb", feature.trim_end_matches(".rb"));
+        file_dir.ancestors()
+            .map(|dir| dir.join("lib").join(&file))
+            .find(|candidate| candidate.is_file())
+            .and_then(|found| found.parent().map(|p| p.to_path_buf()))
+    }
+
+    /// Extract contracts from YARD comments.
+    fn extract_contracts(&self, content: &str) -> Vec<FunctionContract> {
+        let mut contracts = Vec::new();
+
+        for cap in self.yard_def_re.captures_iter(content) {
+            let comment = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let method_name = cap.get(2).map(|m| m.as_str()).unwrap_or("");
+
+            let mut contract = Contract::default();
+
+            // Extract preconditions from @param tags
+            // Look for patterns like "@param token [String] JWT token (must be non-empty)"
+            for param_cap in self.param_tag_re.captures_iter(comment) {
+                let param_name = param_cap.get(1).map(|m| m.as_str()).unwrap_or("");
+                let desc = param_cap.get(2).map(|m| m.as_str()).unwrap_or("");
+                if let Some(start) = desc.find('(') {
+                    if let Some(end) = desc.find(')') {
+                        let constraint = &desc[start + 1..end];
+                        let constraint_lower = constraint.to_lowercase();
+                        if constraint_lower.contains("must be") || constraint_lower.contains("required") || constraint_lower.contains("non-empty") {
+                            contract.preconditions.push(format!("{} {}", param_name, constraint.trim()));
+                        }
+                    }
+                }
+            }
+
+            // Extract postconditions from @return tag
+            if let Some(return_cap) = self.return_tag_re.captures(comment) {
+                let return_type = return_cap.get(1).map(|m| m.as_str().trim()).unwrap_or("");
+                let desc = return_cap.get(2).map(|m| m.as_str().trim()).unwrap_or("");
+                let postcondition = if desc.is_empty() { return_type } else { desc };
+                if !postcondition.is_empty() {
+                    contract.postconditions.push(postcondition.to_string());
+                }
+            }
+
+            // Extract throws from @raise tags
+            for raise_cap in self.raise_tag_re.captures_iter(comment) {
+                if let Some(error) = raise_cap.get(1) {
+                    contract.throws.push(error.as_str().to_string());
+                }
+            }
+
+            if !contract.preconditions.is_empty()
+                || !contract.postconditions.is_empty()
+                || !contract.throws.is_empty()
+            {
+                contracts.push(FunctionContract {
+                    function_name: method_name.to_string(),
+                    contract,
+                    location: None,
+                });
+            }
+        }
+
+        contracts
+    }
+
+    /// Extract protocol states from AASM `state` declarations and STATES constants.
+    fn extract_protocol(&self, content: &str) -> Option<Protocol> {
+        let mut protocol = Protocol::default();
+        let symbol_re = Regex::new(r":?(\w+)").unwrap();
+
+        let lists = self.aasm_state_re.captures_iter(content)
+            .filter_map(|c| c.get(1))
+            .chain(self.states_constant_re.captures_iter(content).filter_map(|c| c.get(1).or(c.get(2))));
+
+        for list in lists {
+            for sym in symbol_re.captures_iter(list.as_str()) {
+                let state = sym[1].to_string();
+                if !protocol.states.contains(&state) {
+                    protocol.states.push(state);
+                }
+            }
+        }
+
+        if !protocol.states.is_empty() {
+            Some(protocol)
+        } else {
+            None
+        }
+    }
+}
+
+impl LanguageAnalyzer for RubyAnalyzer {
+    fn analyze_file(&self, path: &Path, content: &str) -> Result<PartialAnalysis, AnalyzerError> {
+        let mut analysis = PartialAnalysis::default();
+
+        // Methods made private with `private :name` / `private_class_method :name`
+        let symbol_re = Regex::new(r":(\w+[?!=]?)").unwrap();
+        let private_symbols: Vec<String> = self.private_symbols_re.captures_iter(content)
+            .filter_map(|c| c.get(1))
+            .flat_map(|list| symbol_re.captures_iter(list.as_str()).map(|s| s[1].to_string()).collect::<Vec<_>>())
+            .collect();
+
+        // Walk lines, tracking class/module nesting and the current visibility
+        let mut scopes: Vec<Scope> = Vec::new();
+        for (index, line) in content.lines().enumerate() {
+            if let Some(cap) = self.class_re.captures(line) {
+                let indent = cap[1].len();
+                let name = cap[2].to_string();
+                let signature = match cap.get(3) {
+                    Some(base) => format!("class {} < {}", name, base.as_str()),
+                    None => format!("class {}", name),
+                };
+                if !line.trim_end().ends_with("end") {
+                    scopes.push(Scope { indent, public: true });
+                }
+                analysis.classes.push(ExportedClass {
+                    name,
+                    signature: Some(signature),
+                    description: None,
+                    location: line_number(index + 1),
+                });
+                continue;
+            }
+
+            if let Some(cap) = self.module_re.captures(line) {
+                let indent = cap[1].len();
+                let name = cap[2].to_string();
+                if !line.trim_end().ends_with("end") {
+                    scopes.push(Scope { indent, public: true });
+                }
+                analysis.classes.push(ExportedClass {
+                    signature: Some(format!("module {}", name)),
+                    name,
+                    description: None,
+                    location: line_number(index + 1),
+                });
+                continue;
+            }
+
+            if let Some(cap) = self.visibility_re.captures(line) {
+                if let Some(scope) = scopes.last_mut() {
+                    scope.public = &cap[1] == "public";
+                }
+                continue;
+            }
+
+            if let Some(cap) = self.end_re.captures(line) {
+                if scopes.last().map(|s| s.indent == cap[1].len()).unwrap_or(false) {
+                    scopes.pop();
+                }
+                continue;
+            }
+
+            if let Some(cap) = self.def_re.captures(line) {
+                let modifier = cap.get(2).map(|m| m.as_str());
+                let is_class_method = cap.get(3).is_some();
+                let name = cap.get(4).map(|m| m.as_str()).unwrap_or("");
+                let params = cap.get(5).or(cap.get(6)).map(|m| m.as_str().trim()).unwrap_or("");
+
+                // Class methods ignore `private` sections; instance methods follow them
+                let section_public = is_class_method || scopes.last().map(|s| s.public).unwrap_or(true);
+                let is_public = match modifier {
+                    Some(m) => m == "public",
+                    None => section_public,
+                };
+                if !is_public
+                    || name == "initialize"
+                    || name.starts_with('_')
+                    || private_symbols.iter().any(|p| p == name)
+                    || analysis.functions.iter().any(|f| f.name == name)
+                {
+                    continue;
+                }
+
+                let prefix = if is_class_method { "self." } else { "" };
+                analysis.functions.push(ExportedFunction {
+                    name: name.to_string(),
+                    signature: format!("def {}{}({})", prefix, name, params),
+                    description: None,
+                    location: line_number(index + 1),
+                    parsed_signature: None,
+                });
+            }
+        }
+
+        // Extract constants (private_constant ones are excluded)
+        let private_constant_re = Regex::new(r"private_constant\s+((?::\w+\s*,?\s*)+)").unwrap();
+        let private_constants: Vec<String> = private_constant_re.captures_iter(content)
+            .filter_map(|c| c.get(1))
+            .flat_map(|list| symbol_re.captures_iter(list.as_str()).map(|s| s[1].to_string()).collect::<Vec<_>>())
+            .collect();
+        for cap in self.constant_re.captures_iter(content) {
+            let name = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            if private_constants.iter().any(|c| c == name)
+                || analysis.variables.iter().any(|v| v.name == name)
+            {
+                continue;
+            }
+            analysis.variables.push(ExportedVariable {
+                name: name.to_string(),
+                var_type: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+            });
+        }
+
+        // Extract dependencies
+        let file_dir = path.parent().unwrap_or(Path::new("."));
+        let file_dir = file_dir.canonicalize().unwrap_or_else(|_| file_dir.to_path_buf());
+
+        // require_relative paths are internal, expressed as the directory of the required file
+        for cap in self.require_relative_re.captures_iter(content) {
+            let required = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let relative = match required.rsplit_once('/') {
+                Some((dir, _)) if dir.starts_with('.') => dir.to_string(),
+                Some((dir, _)) => format!("./{}", dir),
+                None => continue,
+            };
+            if !analysis.internal_deps.contains(&relative) {
+                analysis.internal_deps.push(relative);
+            }
+        }
+
+        for cap in self.require_re.captures_iter(content) {
+            let feature = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+
+            // Features found under the project's lib/ directory are internal
+            if let Some(dir) = Self::local_feature_dir(&file_dir, feature) {
+                let relative = relative_to_dir(&file_dir, &dir);
+                if relative != "." && !analysis.internal_deps.contains(&relative) {
+                    analysis.internal_deps.push(relative);
+                }
+                continue;
+            }
+
+            // Gem name is the first path segment (active_support/core_ext → active_support)
+            let gem = feature.split('/').next().unwrap_or(feature);
+            if STDLIB_FEATURES.contains(&gem) {
+                continue;
+            }
+            if !analysis.external_deps.contains(&gem.to_string()) {
+                analysis.external_deps.push(gem.to_string());
+            }
+        }
+
+        // Extract contracts from YARD comments
+        analysis.contracts = self.extract_contracts(content);
+
+        // Extract protocol information (states)
+        analysis.protocol = self.extract_protocol(content);
+
+        // Infer behaviors from raise statements
+        for cap in self.raise_re.captures_iter(content) {
+            let error = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let error_lower = error.to_lowercase();
+            let input = if error_lower.contains("expired") {
+                "Expired token"
+            } else if error_lower.contains("invalid") {
+                "Invalid token"
+            } else {
+                continue;
+            };
+
+            if !analysis.behaviors.iter().any(|b| b.input == input) {
+                analysis.behaviors.push(Behavior {
+                    input: input.to_string(),
+                    output: error.to_string(),
+                    category: BehaviorCategory::Error,
+                    location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+                });
+            }
+        }
+
+        // Add success behavior if we have validation methods
+        let validate = analysis.functions.iter().find(|f| f.name.contains("validate")).map(|f| f.location.clone());
+        if let Some(location) = validate.filter(|_| !analysis.behaviors.iter().any(|b| b.category == BehaviorCategory::Success)) {
+            analysis.behaviors.insert(0, Behavior {
+                input: "Valid JWT token".to_string(),
+                output: "TokenClaims object".to_string(),
+                category: BehaviorCategory::Success,
+                location,
+            });
+        }
+
+        Ok(analysis)
+    }
+}
+
+impl Default for RubyAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
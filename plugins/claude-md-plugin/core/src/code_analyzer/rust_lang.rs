@@ -3,11 +3,13 @@
 use std::path::Path;
 use regex::Regex;
 
+use crate::bracket_utils::split_respecting_brackets;
 use super::{
     AnalyzerError, Behavior, BehaviorCategory, Contract, ExportedFunction, ExportedType,
     ExportedVariable, FunctionContract, LanguageAnalyzer, PartialAnalysis, Protocol, ReExport,
-    TypeKind, ExportedEnum,
+    TypeKind, ExportedEnum, find_ancestor_containing, relative_to_dir,
 };
+use super::source_locator::line_at;
 
 /// Analyzer for Rust files.
 #[derive(Debug)]
@@ -87,7 +89,7 @@ impl RustAnalyzer {
 
             // use crate_name::...
             use_re: Regex::new(
-                r"use\s+(\w+)(?:::[^;]+)?\s*;"
+                r"use\s+(\w+)(?:::([^;]+))?\s*;"
             ).unwrap(),
 
             // #[derive(..., crate::Something, ...)] - extract crate names from derive macros
@@ -141,9 +143,89 @@ impl RustAnalyzer {
         }
     }
 
+    /// Split the tail of a local `use` path into one segment list per imported path.
+    /// `auth::{jwt, types::Claims}` → `[auth, jwt]`, `[auth, types, Claims]`.
+    pub(super) fn local_module_paths(rest: &str) -> Vec<Vec<String>> {
+        let rest = rest.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if let Some(open) = rest.find('{') {
+            let prefix = rest[..open].trim().trim_end_matches("::");
+            let close = rest.rfind('}').unwrap_or(rest.len());
+            let mut paths = Vec::new();
+            for item in split_respecting_brackets(&rest[open + 1..close], ',') {
+                let item = item.trim();
+                let combined = match (prefix.is_empty(), item) {
+                    (_, "") => continue,
+                    (_, "self") => prefix.to_string(),
+                    (true, _) => item.to_string(),
+                    (false, _) => format!("{}::{}", prefix, item),
+                };
+                paths.extend(Self::local_module_paths(&combined));
+            }
+            return paths;
+        }
+
+        let path = rest.split(" as ").next().unwrap_or("");
+        vec![path
+            .split("::")
+            .map(str::trim)
+            .filter(|seg| !seg.is_empty() && *seg != "*" && *seg != "self")
+            .map(String::from)
+            .collect()]
+    }
+
+    /// Resolve a `crate::`/`super::`/`self::` module path to a directory relative to
+    /// the analyzed file's directory.
+    /// Segments are consumed while they name an existing module directory or `.rs` file;
+    /// the remaining item segments (types, functions) are dropped.
+    pub(super) fn resolve_local_module(path: &Path, keyword: &str, segments: &[String]) -> Option<String> {
+        let file_dir = path.parent()?.canonicalize().ok()?;
+        let crate_root = find_ancestor_containing(&file_dir, "Cargo.toml")?;
+        let src_dir = crate_root.join("src");
+        let stem = path.file_stem()?.to_str()?;
+        let is_mod_root = matches!(stem, "mod" | "lib" | "main");
+
+        // `children` holds the submodules of the current module,
+        // `target` is the directory the current module lives in.
+        let (mut children, mut target) = match keyword {
+            "crate" if file_dir.starts_with(&src_dir) => (src_dir.clone(), src_dir),
+            "crate" => return None,
+            "self" if is_mod_root => (file_dir.clone(), file_dir.clone()),
+            "self" => (file_dir.join(stem), file_dir.clone()),
+            _ if is_mod_root => {
+                let parent = file_dir.parent()?.to_path_buf();
+                (parent.clone(), parent)
+            }
+            _ => (file_dir.clone(), file_dir.clone()),
+        };
+
+        for segment in segments {
+            if segment == "super" {
+                children = children.parent()?.to_path_buf();
+                target = children.clone();
+            } else if children.join(segment).is_dir() {
+                children = children.join(segment);
+                target = children.clone();
+            } else if children.join(format!("{}.rs", segment)).is_file() {
+                target = children.clone();
+                children = children.join(segment);
+            } else {
+                break;
+            }
+        }
+
+        if !target.starts_with(&crate_root) {
+            return None;
+        }
+        let relative = relative_to_dir(&file_dir, &target);
+        (relative != ".").then_some(relative)
+    }
+
     /// Extract contracts from Rust doc comments.
     fn extract_contracts(&self, content: &str) -> Vec<FunctionContract> {
         let mut contracts = Vec::new();
+        let arg_re = Regex::new(r"\*\s*`(\w+)`\s*-\s*(.+)").unwrap();
+        let err_re = Regex::new(r"[-*]\s*`([^`]+)`").unwrap();
 
         for cap in self.doc_comment_fn_re.captures_iter(content) {
             let raw_doc_block = cap.get(1).map(|m| m.as_str()).unwrap_or("");
@@ -163,7 +245,6 @@ impl RustAnalyzer {
             if let Some(args_cap) = self.arguments_re.captures(&doc_block) {
                 let args_content = args_cap.get(1).map(|m| m.as_str()).unwrap_or("");
                 // Parse argument lines: * `name` - description
-                let arg_re = Regex::new(r"\*\s*`(\w+)`\s*-\s*(.+)").unwrap();
                 for arg_cap in arg_re.captures_iter(args_content) {
                     let param_name = arg_cap.get(1).map(|m| m.as_str()).unwrap_or("");
                     let desc = arg_cap.get(2).map(|m| m.as_str()).unwrap_or("");
@@ -187,7 +268,6 @@ impl RustAnalyzer {
             if let Some(errors_cap) = self.errors_re.captures(&doc_block) {
                 let errors_content = errors_cap.get(1).map(|m| m.as_str()).unwrap_or("");
                 // Parse error lines: - `ErrorType::Variant` description
-                let err_re = Regex::new(r"[-*]\s*`([^`]+)`").unwrap();
                 for err_cap in err_re.captures_iter(errors_content) {
                     if let Some(err) = err_cap.get(1) {
                         contract.throws.push(err.as_str().to_string());
@@ -203,6 +283,7 @@ impl RustAnalyzer {
                 contracts.push(FunctionContract {
                     function_name: function_name.to_string(),
                     contract,
+                    location: None,
                 });
             }
         }
@@ -269,7 +350,7 @@ impl RustAnalyzer {
 }
 
 impl LanguageAnalyzer for RustAnalyzer {
-    fn analyze_file(&self, _path: &Path, content: &str) -> Result<PartialAnalysis, AnalyzerError> {
+    fn analyze_file(&self, path: &Path, content: &str) -> Result<PartialAnalysis, AnalyzerError> {
         let mut analysis = PartialAnalysis::default();
 
         // Extract pub functions
@@ -288,6 +369,8 @@ impl LanguageAnalyzer for RustAnalyzer {
                 name: name.to_string(),
                 signature,
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+                parsed_signature: None,
             });
         }
 
@@ -300,6 +383,7 @@ impl LanguageAnalyzer for RustAnalyzer {
                 kind: TypeKind::Struct,
                 definition: None,
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -315,12 +399,14 @@ impl LanguageAnalyzer for RustAnalyzer {
                 kind: TypeKind::Enum,
                 definition: None,
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
 
             // Also add to enums list
             analysis.enums.push(ExportedEnum {
                 name: name.to_string(),
                 variants: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
 
             // If it's an error enum, extract variants for behavior inference
@@ -364,6 +450,7 @@ impl LanguageAnalyzer for RustAnalyzer {
                                     input: input.to_string(),
                                     output,
                                     category: BehaviorCategory::Error,
+                                    location: line_at(content, start + var_cap.get(1).map_or(0, |m| m.start())),
                                 });
                             }
                         }
@@ -380,6 +467,7 @@ impl LanguageAnalyzer for RustAnalyzer {
             analysis.variables.push(ExportedVariable {
                 name: name.to_string(),
                 var_type: const_type,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -391,6 +479,7 @@ impl LanguageAnalyzer for RustAnalyzer {
             analysis.variables.push(ExportedVariable {
                 name: name.to_string(),
                 var_type: static_type,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -403,6 +492,7 @@ impl LanguageAnalyzer for RustAnalyzer {
                 kind: TypeKind::Type,
                 definition: None,
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -415,6 +505,7 @@ impl LanguageAnalyzer for RustAnalyzer {
                 kind: TypeKind::Trait,
                 definition: None,
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -453,7 +544,20 @@ impl LanguageAnalyzer for RustAnalyzer {
         for cap in self.use_re.captures_iter(content) {
             let crate_name = cap.get(1).map(|m| m.as_str()).unwrap_or("");
 
-            // Skip standard library and local references
+            // Local module paths (crate::, super::, self::) are internal dependencies
+            if matches!(crate_name, "crate" | "super" | "self") {
+                let rest = cap.get(2).map(|m| m.as_str()).unwrap_or("");
+                for segments in Self::local_module_paths(rest) {
+                    if let Some(dep) = Self::resolve_local_module(path, crate_name, &segments) {
+                        if !analysis.internal_deps.contains(&dep) {
+                            analysis.internal_deps.push(dep);
+                        }
+                    }
+                }
+                continue;
+            }
+
+            // Skip standard library references
             if std_crates.contains(&crate_name) {
                 continue;
             }
@@ -490,12 +594,13 @@ impl LanguageAnalyzer for RustAnalyzer {
         analysis.protocol = self.extract_protocol(content);
 
         // Add success behavior if we have validation functions
-        let has_validate = analysis.functions.iter().any(|f| f.name.contains("validate"));
-        if has_validate && !analysis.behaviors.iter().any(|b| b.category == BehaviorCategory::Success) {
+        let validate = analysis.functions.iter().find(|f| f.name.contains("validate")).map(|f| f.location.clone());
+        if let Some(location) = validate.filter(|_| !analysis.behaviors.iter().any(|b| b.category == BehaviorCategory::Success)) {
             analysis.behaviors.insert(0, Behavior {
                 input: "Valid JWT token".to_string(),
                 output: "Ok(Claims)".to_string(),
                 category: BehaviorCategory::Success,
+                location,
             });
         }
 
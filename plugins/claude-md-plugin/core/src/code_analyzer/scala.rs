@@ -0,0 +1,530 @@
+//! Scala code analyzer.
+
+use std::path::Path;
+use regex::Regex;
+
+use super::{
+    AnalyzerError, Behavior, BehaviorCategory, Contract, ExportedClass, ExportedFunction,
+    ExportedType, ExportedVariable, FunctionContract, LanguageAnalyzer, PartialAnalysis, Protocol,
+    TypeKind, ExportedEnum, package_of_import, project_package_dir,
+};
+use super::source_locator::line_at;
+
+/// Analyzer for Scala 2 and Scala 3 files.
+#[derive(Debug)]
+pub struct ScalaAnalyzer {
+    // Regex patterns for Scala analysis
+    def_re: Regex,
+    private_def_re: Regex,
+    class_re: Regex,
+    trait_re: Regex,
+    object_re: Regex,
+    enum_re: Regex,
+    enum_case_re: Regex,
+    val_re: Regex,
+    type_alias_re: Regex,
+    import_re: Regex,
+    package_re: Regex,
+    throw_re: Regex,
+    // Contract extraction patterns (Scaladoc)
+    scaladoc_def_re: Regex,
+    param_tag_re: Regex,
+    return_tag_re: Regex,
+    throws_tag_re: Regex,
+    // Sealed hierarchy patterns
+    sealed_re: Regex,
+    sealed_subtype_re: Regex,
+}
+
+impl ScalaAnalyzer {
+    pub fn new() -> Self {
+        Self {
+            // def name[T](params)(using ctx: Ctx): ReturnType
+            def_re: Regex::new(
+                r"(?m)^\s*(?:(?:override|final|implicit|inline|transparent)\s+)*def\s+(\w+)\s*(?:\[[^\]]*\])?\s*((?:\([^)]*\)\s*)*)(?::\s*([^=\n{]+))?"
+            ).unwrap(),
+
+            // private def / protected def / private[pkg] def
+            private_def_re: Regex::new(
+                r"(?:private|protected)(?:\[\w+\])?\s+(?:(?:override|final|implicit|inline)\s+)*def\s+(\w+)"
+            ).unwrap(),
+
+            // [modifiers] [case] class Name[T](fields) extends Base with Mixin
+            class_re: Regex::new(
+                r"(?m)^\s*((?:(?:private|protected)(?:\[\w+\])?\s+|(?:final|abstract|sealed|implicit|open)\s+)*)(case\s+)?class\s+(\w+)\s*(?:\[[^\]]*\])?\s*(\([^)]*\))?(?:\s*extends\s+([\w.]+))?"
+            ).unwrap(),
+
+            // [sealed] trait Name
+            trait_re: Regex::new(
+                r"(?m)^\s*((?:(?:private|protected)(?:\[\w+\])?\s+|sealed\s+)*)trait\s+(\w+)"
+            ).unwrap(),
+
+            // [case] object Name
+            object_re: Regex::new(
+                r"(?m)^\s*((?:(?:private|protected)(?:\[\w+\])?\s+|final\s+)*)(case\s+)?object\s+(\w+)"
+            ).unwrap(),
+
+            // Scala 3: enum Name[T] (extends Base)? (: | {)
+            enum_re: Regex::new(
+                r"(?m)^([ \t]*)enum\s+(\w+)"
+            ).unwrap(),
+
+            // case A, B, C / case Failed(reason: String) extends State
+            enum_case_re: Regex::new(
+                r"^\s*case\s+([^\n]+)"
+            ).unwrap(),
+
+            // val MaxTokenAge: Int = 3600 (upper camel case marks a constant)
+            val_re: Regex::new(
+                r"(?m)^\s*((?:(?:private|protected)(?:\[\w+\])?\s+|(?:final|lazy|override)\s+)*)val\s+([A-Z]\w*)\s*(?::\s*([^=\n]+?))?\s*="
+            ).unwrap(),
+
+            // type Name = Definition
+            type_alias_re: Regex::new(
+                r"(?m)^\s*((?:(?:private|protected)(?:\[\w+\])?\s+|opaque\s+)*)type\s+(\w+)(?:\[[^\]]*\])?\s*=\s*([^\n]+)"
+            ).unwrap(),
+
+            // import com.example.Class / import com.example.{A, B} / import com.example._
+            import_re: Regex::new(
+                r"(?m)^\s*import\s+([\w.]+)"
+            ).unwrap(),
+
+            // package com.example (chained clauses are concatenated)
+            package_re: Regex::new(
+                r"(?m)^package\s+([\w.]+)\s*$"
+            ).unwrap(),
+
+            // throw new ExceptionName(...)
+            throw_re: Regex::new(
+                r"throw\s+new\s+(\w+)"
+            ).unwrap(),
+
+            // Match Scaladoc block followed by def
+            scaladoc_def_re: Regex::new(
+                r"(?s)/\*\*(.*?)\*/\s*(?:@\w+\s*(?:\([^)]*\)\s*)?)*(?:(?:override|final|implicit|inline)\s+)*def\s+(\w+)"
+            ).unwrap(),
+
+            // @param name description (until end of line or next @)
+            param_tag_re: Regex::new(
+                r"@param\s+(\w+)\s+([^\n@]+)"
+            ).unwrap(),
+
+            // @return description (until end of line or next @)
+            return_tag_re: Regex::new(
+                r"@return\s+([^\n@]+)"
+            ).unwrap(),
+
+            // @throws[ExceptionName] or @throws ExceptionName
+            throws_tag_re: Regex::new(
+                r"@throws\s*(?:\[(\w+)\]|(\w+))"
+            ).unwrap(),
+
+            // sealed trait Name / sealed abstract class Name
+            sealed_re: Regex::new(
+                r"sealed\s+(?:abstract\s+)?(?:trait|class)\s+(\w+)"
+            ).unwrap(),
+
+            // case object Idle extends State / final case class Failed(e: Throwable) extends State
+            sealed_subtype_re: Regex::new(
+                r"(?:case\s+object|case\s+class|object|class)\s+(\w+)\s*(?:\[[^\]]*\])?\s*(?:\([^)]*\))?\s*extends\s+(\w+)"
+            ).unwrap(),
+        }
+    }
+
+    /// Whether a modifier prefix hides the declaration from other packages.
+    fn is_hidden(modifiers: &str) -> bool {
+        modifiers.contains("private") || modifiers.contains("protected")
+    }
+
+    /// Collect Scala 3 enum cases: `case` lines indented under the `enum` line,
+    /// for both brace and significant-indentation syntax.
+    fn enum_cases(&self, content: &str, enum_start: usize, indent: usize) -> Vec<String> {
+        let mut cases = Vec::new();
+
+        for line in content[enum_start..].lines().skip(1) {
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+            let line_indent = line.len() - line.trim_start().len();
+            if line_indent <= indent {
+                break;
+            }
+
+            // `case X => ...` belongs to a match expression in an enum method
+            if let Some(cap) = self.enum_case_re.captures(line).filter(|_| !trimmed.contains("=>")) {
+                let members = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+                let members = members.split(" extends ").next().unwrap_or(members);
+                let mut depth = 0;
+                let mut current = String::new();
+                for c in members.chars() {
+                    match c {
+                        '(' | '[' => depth += 1,
+                        ')' | ']' => depth -= 1,
+                        ',' if depth == 0 => {
+                            cases.push(std::mem::take(&mut current));
+                            continue;
+                        }
+                        _ => {}
+                    }
+                    if depth == 0 && c != ')' && c != ']' {
+                        current.push(c);
+                    }
+                }
+                cases.push(current);
+            }
+        }
+
+        cases.into_iter()
+            .map(|c| c.trim().to_string())
+            .filter(|c| !c.is_empty())
+            .collect()
+    }
+
+    /// Extract contracts from Scaladoc comments.
+    fn extract_contracts(&self, content: &str) -> Vec<FunctionContract> {
+        let mut contracts = Vec::new();
+
+        for cap in self.scaladoc_def_re.captures_iter(content) {
+            let doc_content = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let function_name = cap.get(2).map(|m| m.as_str()).unwrap_or("");
+
+            let mut contract = Contract::default();
+
+            // Extract preconditions from @param tags
+            // Look for patterns like "@param token JWT token (must be non-empty)"
+            for param_cap in self.param_tag_re.captures_iter(doc_content) {
+                let param_name = param_cap.get(1).map(|m| m.as_str()).unwrap_or("");
+                let desc = param_cap.get(2).map(|m| m.as_str()).unwrap_or("");
+                if let Some(start) = desc.find('(') {
+                    if let Some(end) = desc.find(')') {
+                        let constraint = &desc[start + 1..end];
+                        let constraint_lower = constraint.to_lowercase();
+                        if constraint_lower.contains("must be") || constraint_lower.contains("required") || constraint_lower.contains("non-empty") {
+                            contract.preconditions.push(format!("{} {}", param_name, constraint.trim()));
+                        }
+                    }
+                }
+            }
+
+            // Extract postconditions from @return tag
+            if let Some(return_cap) = self.return_tag_re.captures(doc_content) {
+                let return_desc = return_cap.get(1).map(|m| m.as_str()).unwrap_or("").trim();
+                if !return_desc.is_empty() {
+                    contract.postconditions.push(return_desc.to_string());
+                }
+            }
+
+            // Extract throws from @throws tags
+            for throws_cap in self.throws_tag_re.captures_iter(doc_content) {
+                if let Some(exc_name) = throws_cap.get(1).or(throws_cap.get(2)) {
+                    contract.throws.push(exc_name.as_str().to_string());
+                }
+            }
+
+            if !contract.preconditions.is_empty()
+                || !contract.postconditions.is_empty()
+                || !contract.throws.is_empty()
+            {
+                contracts.push(FunctionContract {
+                    function_name: function_name.to_string(),
+                    contract,
+                    location: None,
+                });
+            }
+        }
+
+        contracts
+    }
+
+    /// Extract protocol states from state enums and sealed hierarchies.
+    fn extract_protocol(&self, content: &str, enums: &[ExportedEnum]) -> Option<Protocol> {
+        let mut protocol = Protocol::default();
+
+        // States from Scala 3 enum State { case Idle, Loading }
+        for exported in enums {
+            let variants = exported.variants.as_deref().unwrap_or(&[]);
+            let lower: Vec<String> = variants.iter().map(|v| v.to_lowercase()).collect();
+            if exported.name.to_lowercase().contains("state")
+                || lower.iter().any(|v| v == "idle" || v == "loading")
+            {
+                for variant in variants {
+                    if !protocol.states.contains(variant) {
+                        protocol.states.push(variant.clone());
+                    }
+                }
+            }
+        }
+
+        // States from sealed trait State + case objects/classes extending it
+        let sealed_names: Vec<String> = self.sealed_re
+            .captures_iter(content)
+            .filter_map(|c| c.get(1).map(|m| m.as_str().to_string()))
+            .collect();
+
+        for cap in self.sealed_subtype_re.captures_iter(content) {
+            let subtype_name = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let parent_name = cap.get(2).map(|m| m.as_str()).unwrap_or("");
+
+            if sealed_names.contains(&parent_name.to_string())
+                && !protocol.states.contains(&subtype_name.to_string())
+            {
+                protocol.states.push(subtype_name.to_string());
+            }
+        }
+
+        if !protocol.states.is_empty() {
+            Some(protocol)
+        } else {
+            None
+        }
+    }
+}
+
+impl LanguageAnalyzer for ScalaAnalyzer {
+    fn analyze_file(&self, path: &Path, content: &str) -> Result<PartialAnalysis, AnalyzerError> {
+        let mut analysis = PartialAnalysis::default();
+
+        // Get list of private/protected defs
+        let private_defs: Vec<String> = self.private_def_re
+            .captures_iter(content)
+            .filter_map(|c| c.get(1).map(|m| m.as_str().to_string()))
+            .collect();
+
+        // Extract defs (public by default in Scala)
+        for cap in self.def_re.captures_iter(content) {
+            let name = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let params = cap.get(2).map(|m| m.as_str().trim()).unwrap_or("");
+            let return_type = cap.get(3).map(|m| m.as_str().trim()).filter(|r| !r.is_empty());
+
+            if private_defs.contains(&name.to_string())
+                || analysis.functions.iter().any(|f| f.name == name)
+            {
+                continue;
+            }
+
+            let signature = match return_type {
+                Some(ret) => format!("def {}{}: {}", name, params, ret),
+                None => format!("def {}{}", name, params),
+            };
+
+            analysis.functions.push(ExportedFunction {
+                name: name.to_string(),
+                signature,
+                description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+                parsed_signature: None,
+            });
+        }
+
+        // Extract classes (case classes are data classes)
+        for cap in self.class_re.captures_iter(content) {
+            let modifiers = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let is_case = cap.get(2).is_some();
+            let name = cap.get(3).map(|m| m.as_str()).unwrap_or("");
+            let base = cap.get(5).map(|m| m.as_str());
+
+            if Self::is_hidden(modifiers) {
+                continue;
+            }
+
+            if is_case {
+                analysis.types.push(ExportedType {
+                    name: name.to_string(),
+                    kind: TypeKind::DataClass,
+                    definition: cap.get(4).map(|m| m.as_str().to_string()),
+                    description: None,
+                    location: line_at(content, cap.get(3).map_or(0, |m| m.start())),
+                });
+                continue;
+            }
+
+            let signature = match base {
+                Some(base) => format!("class {} extends {}", name, base),
+                None => format!("class {}", name),
+            };
+            analysis.classes.push(ExportedClass {
+                name: name.to_string(),
+                signature: Some(signature),
+                description: None,
+                location: line_at(content, cap.get(3).map_or(0, |m| m.start())),
+            });
+        }
+
+        // Extract traits as types
+        for cap in self.trait_re.captures_iter(content) {
+            let modifiers = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let name = cap.get(2).map(|m| m.as_str()).unwrap_or("");
+            if Self::is_hidden(modifiers) {
+                continue;
+            }
+
+            analysis.types.push(ExportedType {
+                name: name.to_string(),
+                kind: TypeKind::Trait,
+                definition: None,
+                description: None,
+                location: line_at(content, cap.get(2).map_or(0, |m| m.start())),
+            });
+        }
+
+        // Extract objects as classes (case objects are sealed hierarchy members)
+        for cap in self.object_re.captures_iter(content) {
+            let modifiers = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let is_case = cap.get(2).is_some();
+            let name = cap.get(3).map(|m| m.as_str()).unwrap_or("");
+            if Self::is_hidden(modifiers) || analysis.classes.iter().any(|c| c.name == name) {
+                continue;
+            }
+
+            let keyword = if is_case { "case object" } else { "object" };
+            analysis.classes.push(ExportedClass {
+                name: name.to_string(),
+                signature: Some(format!("{} {}", keyword, name)),
+                description: None,
+                location: line_at(content, cap.get(3).map_or(0, |m| m.start())),
+            });
+        }
+
+        // Extract Scala 3 enums with their cases
+        for cap in self.enum_re.captures_iter(content) {
+            let indent = cap.get(1).map(|m| m.as_str().len()).unwrap_or(0);
+            let name = cap.get(2).map(|m| m.as_str()).unwrap_or("");
+            let start = cap.get(0).map(|m| m.start()).unwrap_or(0);
+            let variants = self.enum_cases(content, start, indent);
+
+            analysis.enums.push(ExportedEnum {
+                name: name.to_string(),
+                variants: if variants.is_empty() { None } else { Some(variants) },
+                location: line_at(content, cap.get(2).map_or(0, |m| m.start())),
+            });
+        }
+
+        // Extract constants (UpperCamelCase vals)
+        for cap in self.val_re.captures_iter(content) {
+            let modifiers = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let name = cap.get(2).map(|m| m.as_str()).unwrap_or("");
+            let val_type = cap.get(3).map(|m| m.as_str().trim().to_string());
+            if Self::is_hidden(modifiers) || analysis.variables.iter().any(|v| v.name == name) {
+                continue;
+            }
+
+            analysis.variables.push(ExportedVariable {
+                name: name.to_string(),
+                var_type: val_type,
+                location: line_at(content, cap.get(2).map_or(0, |m| m.start())),
+            });
+        }
+
+        // Extract type aliases
+        for cap in self.type_alias_re.captures_iter(content) {
+            let modifiers = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let name = cap.get(2).map(|m| m.as_str()).unwrap_or("");
+            if Self::is_hidden(modifiers) {
+                continue;
+            }
+
+            analysis.types.push(ExportedType {
+                name: name.to_string(),
+                kind: TypeKind::Type,
+                definition: cap.get(3).map(|m| m.as_str().trim().to_string()),
+                description: None,
+                location: line_at(content, cap.get(2).map_or(0, |m| m.start())),
+            });
+        }
+
+        // Extract dependencies from imports
+        // `package com.example` followed by `package auth` means `com.example.auth`
+        let current_package = self.package_re.captures_iter(content)
+            .filter_map(|cap| cap.get(1).map(|m| m.as_str()))
+            .collect::<Vec<_>>()
+            .join(".");
+
+        let file_dir = path.parent().unwrap_or(Path::new("."));
+        for cap in self.import_re.captures_iter(content) {
+            let import_path = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+            let import_path = import_path.trim_end_matches('.');
+
+            // Imports of this file's own package need no dependency
+            let import_package = package_of_import(import_path);
+            if import_package == current_package {
+                continue;
+            }
+
+            // Packages with a directory in this project are internal,
+            // expressed as a directory path relative to the file's package
+            if let Some(relative) = project_package_dir(file_dir, &current_package, &import_package) {
+                if !analysis.internal_deps.contains(&relative) {
+                    analysis.internal_deps.push(relative);
+                }
+                continue;
+            }
+
+            // Skip scala.* and java.* (standard library)
+            if import_path.starts_with("scala.")
+                || import_path.starts_with("java.")
+                || import_path.starts_with("javax.")
+            {
+                continue;
+            }
+
+            let parts: Vec<&str> = import_path.split('.').collect();
+            let pkg_name = if parts.len() >= 2 {
+                format!("{}.{}", parts[0], parts[1])
+            } else {
+                parts[0].to_string()
+            };
+
+            if !analysis.external_deps.contains(&pkg_name) {
+                analysis.external_deps.push(pkg_name);
+            }
+        }
+
+        // Extract contracts from Scaladoc comments
+        analysis.contracts = self.extract_contracts(content);
+
+        // Extract protocol information (states)
+        analysis.protocol = self.extract_protocol(content, &analysis.enums);
+
+        // Infer behaviors from thrown exceptions
+        for cap in self.throw_re.captures_iter(content) {
+            let exc_name = cap.get(1).map(|m| m.as_str()).unwrap_or("");
+
+            let input = if exc_name.contains("Expired") {
+                "Expired token"
+            } else if exc_name.contains("Invalid") {
+                "Invalid token"
+            } else {
+                continue;
+            };
+
+            if !analysis.behaviors.iter().any(|b| b.input == input) {
+                analysis.behaviors.push(Behavior {
+                    input: input.to_string(),
+                    output: exc_name.to_string(),
+                    category: BehaviorCategory::Error,
+                    location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+                });
+            }
+        }
+
+        // Add success behavior
+        let validate = analysis.functions.iter().find(|f| f.name.contains("validate")).map(|f| f.location.clone());
+        if let Some(location) = validate.filter(|_| !analysis.behaviors.iter().any(|b| b.category == BehaviorCategory::Success)) {
+            analysis.behaviors.insert(0, Behavior {
+                input: "Valid JWT token".to_string(),
+                output: "TokenClaims object".to_string(),
+                category: BehaviorCategory::Success,
+                location,
+            });
+        }
+
+        Ok(analysis)
+    }
+}
+
+impl Default for ScalaAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
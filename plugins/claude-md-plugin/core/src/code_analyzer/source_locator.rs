@@ -0,0 +1,310 @@
+//! Source locations for analyzed symbols.
+//!
+//! The regex analyzers record the line where they matched each symbol ([`line_at`]).
+//! After a file is analyzed, `CodeAnalyzer` calls [`locate`] to extend each line to the
+//! end of the declaration: the matching `}` for brace languages, or the last line
+//! indented under the declaration (plus a closing `end`) for Python, Ruby and Scala 3.
+//! Export spans from the tree-sitter backend are already complete and keep their lines.
+//! Symbols whose analyzer recorded no line keep `location: None`.
+
+use super::{BehaviorCategory, PartialAnalysis, SourceLocation};
+
+/// Location of the line containing byte `offset` of `content`, where an analyzer
+/// matched a symbol. [`locate`] extends it to the end of the declaration.
+pub(crate) fn line_at(content: &str, offset: usize) -> Option<SourceLocation> {
+    line_number(content.get(..offset)?.matches('\n').count() + 1)
+}
+
+/// Location of 1-based `line`, for analyzers that walk a file line by line.
+pub(crate) fn line_number(line: usize) -> Option<SourceLocation> {
+    Some(SourceLocation {
+        file: String::new(),
+        start_line: line,
+        end_line: line,
+    })
+}
+
+/// Extend recorded lines to full extents and stamp `file` on every location.
+/// `complete_spans` is set for the tree-sitter backend, whose export spans are final;
+/// behaviors always come from the regex analyzers and are extended either way.
+pub(crate) fn locate(partial: &mut PartialAnalysis, file: &str, language: &str, content: &str, complete_spans: bool) {
+    let source = Source::new(content, language);
+
+    let extend = |location: &mut Option<SourceLocation>, end: &dyn Fn(usize) -> usize| {
+        if let Some(location) = location {
+            location.end_line = end(location.start_line - 1) + 1;
+            location.file = file.to_string();
+        }
+    };
+    let declaration = |location: &mut Option<SourceLocation>| {
+        if complete_spans {
+            if let Some(location) = location {
+                location.file = file.to_string();
+            }
+        } else {
+            extend(location, &|line| source.block_end(line));
+        }
+    };
+
+    for function in &mut partial.functions {
+        declaration(&mut function.location);
+    }
+    for exported in &mut partial.types {
+        declaration(&mut exported.location);
+    }
+    for class in &mut partial.classes {
+        declaration(&mut class.location);
+    }
+    for variable in &mut partial.variables {
+        declaration(&mut variable.location);
+    }
+    for exported in &mut partial.enums {
+        match exported.location {
+            Some(_) => declaration(&mut exported.location),
+            // A Rust enum is also listed under types; share its location
+            None => {
+                exported.location = partial.types.iter()
+                    .find(|t| t.name == exported.name)
+                    .and_then(|t| t.location.clone());
+            }
+        }
+    }
+
+    // Contracts point at the function they document
+    for contract in &mut partial.contracts {
+        match contract.location {
+            Some(_) => declaration(&mut contract.location),
+            None => {
+                contract.location = partial.functions.iter()
+                    .find(|f| f.name == contract.function_name)
+                    .and_then(|f| f.location.clone());
+            }
+        }
+    }
+
+    // Error behaviors cover the raising statement; success behaviors the function they came from
+    for behavior in &mut partial.behaviors {
+        match behavior.category {
+            BehaviorCategory::Error => extend(&mut behavior.location, &|line| source.statement_end(line).0),
+            BehaviorCategory::Success => extend(&mut behavior.location, &|line| source.block_end(line)),
+        }
+    }
+}
+
+/// A source file split into lines, with comment lines marked.
+struct Source<'a> {
+    lines: Vec<&'a str>,
+    is_code: Vec<bool>,
+    hash_comments: bool,
+    char_quotes: bool,
+}
+
+impl<'a> Source<'a> {
+    fn new(content: &'a str, language: &str) -> Self {
+        let lines: Vec<&str> = content.lines().collect();
+        let hash_comments = matches!(language, "python" | "ruby" | "php");
+
+        // Mark lines inside /* */ blocks, Python docstrings and Ruby =begin/=end
+        let mut is_code = Vec::with_capacity(lines.len());
+        let mut block_end: Option<&str> = None;
+        for line in &lines {
+            let trimmed = line.trim();
+            if let Some(terminator) = block_end {
+                is_code.push(false);
+                if trimmed.contains(terminator) {
+                    block_end = None;
+                }
+                continue;
+            }
+
+            let opener = if trimmed.starts_with("/*") {
+                Some(("/*", "*/"))
+            } else if language == "python" && trimmed.starts_with("\"\"\"") {
+                Some(("\"\"\"", "\"\"\""))
+            } else if language == "python" && trimmed.starts_with("'''") {
+                Some(("'''", "'''"))
+            } else if language == "ruby" && trimmed.starts_with("=begin") {
+                Some(("=begin", "=end"))
+            } else {
+                None
+            };
+            if let Some((open, close)) = opener {
+                is_code.push(false);
+                if !trimmed[open.len()..].contains(close) {
+                    block_end = Some(close);
+                }
+                continue;
+            }
+
+            let comment = trimmed.is_empty()
+                || trimmed.starts_with("//")
+                || trimmed.starts_with('*')
+                || (hash_comments && trimmed.starts_with('#'));
+            is_code.push(!comment);
+        }
+
+        Self {
+            lines,
+            is_code,
+            hash_comments,
+            // Rust uses ' for lifetimes; elsewhere it delimits strings or chars
+            char_quotes: language == "rust",
+        }
+    }
+
+    /// Last line of the declaration starting at `start`: its brace-delimited body,
+    /// indented block, or the statement itself.
+    fn block_end(&self, start: usize) -> usize {
+        match self.statement_end(start) {
+            (end, true) => end,
+            (end, false) => self.indented_end(start, end),
+        }
+    }
+
+    /// Scan a statement from `start`. Returns its last line and whether it was closed
+    /// explicitly (`;` or a matched `{}` body) rather than by a line break.
+    fn statement_end(&self, start: usize) -> (usize, bool) {
+        let mut parens = 0i32;
+        let mut idx = start;
+
+        while idx < self.lines.len() {
+            let code = self.code_chars(self.lines[idx]);
+            for (col, c) in code.iter().copied() {
+                match c {
+                    '(' | '[' => parens += 1,
+                    ')' | ']' => parens -= 1,
+                    '{' if parens <= 0 => return (self.matching_brace(idx, col), true),
+                    ';' if parens <= 0 => return (idx, true),
+                    _ => {}
+                }
+            }
+
+            let text: String = code.iter().map(|(_, c)| *c).collect();
+            let continued = parens > 0 || ends_with_continuation(text.trim_end());
+            // Allman style: the body's `{` opens on the next line
+            let next = (idx + 1..self.lines.len()).find(|&i| self.is_code[i]);
+            let brace_next = next.map(|i| self.lines[i].trim_start().starts_with('{')).unwrap_or(false);
+            match next {
+                Some(next) if continued || brace_next => idx = next,
+                _ => return (idx, false),
+            }
+        }
+
+        (self.lines.len().saturating_sub(1), false)
+    }
+
+    /// Line of the `}` matching the `{` at (`line`, `col`), or the last line if unclosed.
+    fn matching_brace(&self, line: usize, col: usize) -> usize {
+        let mut depth = 0i32;
+        for idx in line..self.lines.len() {
+            if !self.is_code[idx] && idx != line {
+                continue;
+            }
+            for (c_col, c) in self.code_chars(self.lines[idx]) {
+                if idx == line && c_col < col {
+                    continue;
+                }
+                match c {
+                    '{' => depth += 1,
+                    '}' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return idx;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        self.lines.len().saturating_sub(1)
+    }
+
+    /// Extend a declaration through the lines indented under it, including a Ruby
+    /// `end` at the declaration's own indentation.
+    fn indented_end(&self, start: usize, statement_end: usize) -> usize {
+        let base = indentation(self.lines[start]);
+        let mut end = statement_end;
+
+        for idx in statement_end + 1..self.lines.len() {
+            let line = self.lines[idx];
+            if line.trim().is_empty() {
+                continue;
+            }
+            let indent = indentation(line);
+            if indent > base {
+                end = idx;
+                continue;
+            }
+            let trimmed = line.trim_start();
+            if indent == base && (trimmed == "end" || trimmed.starts_with("end ")) {
+                end = idx;
+            }
+            break;
+        }
+
+        end
+    }
+
+    /// Characters of a line that are code: string/char literal contents (but not their
+    /// opening quote) and trailing comments are dropped. Each character keeps its byte column.
+    fn code_chars(&self, line: &str) -> Vec<(usize, char)> {
+        let mut out = Vec::new();
+        let mut chars = line.char_indices().peekable();
+
+        while let Some((col, c)) = chars.next() {
+            match c {
+                '/' if chars.peek().map(|(_, n)| *n == '/').unwrap_or(false) => break,
+                '#' if self.hash_comments => break,
+                '"' | '`' => {
+                    // Keep the opening quote as a placeholder; skip to the closing one
+                    out.push((col, c));
+                    let mut escaped = false;
+                    for (_, s) in chars.by_ref() {
+                        if escaped {
+                            escaped = false;
+                        } else if s == '\\' {
+                            escaped = true;
+                        } else if s == c {
+                            break;
+                        }
+                    }
+                }
+                '\'' => {
+                    out.push((col, c));
+                    let rest = &line[col + 1..];
+                    let closing = if self.char_quotes {
+                        // Only char literals: 'x' or '\n'
+                        if let Some(escaped) = rest.strip_prefix('\\') {
+                            escaped.find('\'').map(|i| i + 1)
+                        } else {
+                            rest.char_indices().nth(1).filter(|(_, n)| *n == '\'').map(|(i, _)| i)
+                        }
+                    } else {
+                        rest.find('\'')
+                    };
+                    if let Some(offset) = closing {
+                        let target = col + 1 + offset;
+                        while chars.peek().map(|(i, _)| *i <= target).unwrap_or(false) {
+                            chars.next();
+                        }
+                    }
+                }
+                _ => out.push((col, c)),
+            }
+        }
+
+        out
+    }
+}
+
+/// Whether a line leaves its statement open (`=`, `,`, an operator or an open bracket).
+fn ends_with_continuation(code: &str) -> bool {
+    ["=", ",", "(", "[", "->", "=>", "&&", "||", "+", "|", "."]
+        .iter()
+        .any(|suffix| code.ends_with(suffix))
+        && !code.ends_with("==")
+}
+
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
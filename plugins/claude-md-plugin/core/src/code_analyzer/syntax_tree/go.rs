@@ -0,0 +1,179 @@
+//! Syntax-tree Go analyzer.
+
+use std::path::Path;
+use tree_sitter::Node;
+
+use super::{collapse_whitespace, field_text, named_children, parameter_list, parse, span, text};
+use crate::code_analyzer::{
+    AnalyzerError, ExportedFunction, ExportedType, ExportedVariable, GoAnalyzer,
+    LanguageAnalyzer, PartialAnalysis, TypeKind,
+};
+
+/// Go analyzer backed by the tree-sitter Go grammar.
+///
+/// Only top-level declarations with a capitalized name are exported. Unlike
+/// [`GoAnalyzer`], grouped `var (...)`/`const (...)` blocks and defined types such as
+/// `type State int` are reported; methods are not, as in [`GoAnalyzer`].
+#[derive(Debug)]
+pub struct TreeSitterGoAnalyzer {
+    regex: GoAnalyzer,
+}
+
+impl TreeSitterGoAnalyzer {
+    pub fn new() -> Self {
+        Self {
+            regex: GoAnalyzer::new(),
+        }
+    }
+
+    /// Collect exported declarations from the top level of a source file.
+    fn collect_declarations(&self, root: Node, src: &[u8], analysis: &mut PartialAnalysis) {
+        for declaration in named_children(root) {
+            match declaration.kind() {
+                "function_declaration" if field_text(declaration, "name", src).is_some_and(is_exported) => {
+                    analysis.functions.push(function(declaration, src));
+                }
+                "type_declaration" => {
+                    for spec in named_children(declaration) {
+                        let name = field_text(spec, "name", src).unwrap_or("");
+                        if !is_exported(name) {
+                            continue;
+                        }
+                        let kind = match (spec.kind(), spec.child_by_field_name("type").map(|t| t.kind())) {
+                            ("type_spec", Some("struct_type")) => TypeKind::Struct,
+                            ("type_spec", Some("interface_type")) => TypeKind::Interface,
+                            _ => TypeKind::Type,
+                        };
+                        analysis.types.push(ExportedType {
+                            name: name.to_string(),
+                            kind,
+                            definition: None,
+                            description: None,
+                            location: span(spec),
+                        });
+                    }
+                }
+                "var_declaration" | "const_declaration" => {
+                    for spec in value_specs(declaration) {
+                        collect_values(spec, src, analysis);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl LanguageAnalyzer for TreeSitterGoAnalyzer {
+    fn analyze_file(&self, path: &Path, content: &str) -> Result<PartialAnalysis, AnalyzerError> {
+        let tree = parse(&tree_sitter_go::LANGUAGE.into(), path, content)?;
+        let src = content.as_bytes();
+
+        // Behaviors, contracts and protocol come from the regex analyzer
+        let regex_analysis = self.regex.analyze_file(path, content)?;
+        let mut analysis = PartialAnalysis {
+            behaviors: regex_analysis.behaviors,
+            contracts: regex_analysis.contracts,
+            protocol: regex_analysis.protocol,
+            ..Default::default()
+        };
+
+        let root = tree.root_node();
+        self.collect_declarations(root, src, &mut analysis);
+
+        let mut packages = Vec::new();
+        for declaration in named_children(root).into_iter().filter(|d| d.kind() == "import_declaration") {
+            for spec in import_specs(declaration) {
+                if let Some(package) = field_text(spec, "path", src) {
+                    packages.push(package.trim_matches(|c| c == '"' || c == '`').to_string());
+                }
+            }
+        }
+        GoAnalyzer::add_imports(path, packages, &mut analysis);
+
+        Ok(analysis)
+    }
+}
+
+impl Default for TreeSitterGoAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Exported Go identifiers start with an uppercase letter.
+fn is_exported(name: &str) -> bool {
+    name.chars().next().is_some_and(char::is_uppercase)
+}
+
+/// `func Name(params) Result`; a parenthesized result list is written without
+/// parentheses (`*Claims, error`), as the regex analyzer does.
+fn function(declaration: Node, src: &[u8]) -> ExportedFunction {
+    let name = field_text(declaration, "name", src).unwrap_or("");
+    let params = declaration
+        .child_by_field_name("parameters")
+        .map(|p| parameter_list(p, src))
+        .unwrap_or_default();
+
+    let signature = match declaration.child_by_field_name("result") {
+        Some(result) if result.kind() == "parameter_list" => {
+            format!("func {}({}) {}", name, params, parameter_list(result, src))
+        }
+        Some(result) => format!("func {}({}) {}", name, params, collapse_whitespace(text(result, src))),
+        None => format!("func {}({})", name, params),
+    };
+
+    ExportedFunction {
+        name: name.to_string(),
+        signature,
+        description: None,
+        location: span(declaration),
+        parsed_signature: None,
+    }
+}
+
+/// `var_spec`/`const_spec` nodes of a declaration, inside a `(...)` group or not.
+fn value_specs(declaration: Node) -> Vec<Node> {
+    named_children(declaration)
+        .into_iter()
+        .flat_map(|child| match child.kind() {
+            "var_spec_list" => named_children(child),
+            _ => vec![child],
+        })
+        .filter(|spec| matches!(spec.kind(), "var_spec" | "const_spec"))
+        .collect()
+}
+
+/// `import_spec` nodes of an import declaration, inside a `(...)` group or not.
+fn import_specs(declaration: Node) -> Vec<Node> {
+    named_children(declaration)
+        .into_iter()
+        .flat_map(|child| match child.kind() {
+            "import_spec_list" => named_children(child),
+            _ => vec![child],
+        })
+        .filter(|spec| spec.kind() == "import_spec")
+        .collect()
+}
+
+/// Exported names of one `var`/`const` spec. The type is the declared one, or `error`
+/// for values created with `errors.New`.
+fn collect_values(spec: Node, src: &[u8], analysis: &mut PartialAnalysis) {
+    let var_type = field_text(spec, "type", src).map(collapse_whitespace).or_else(|| {
+        field_text(spec, "value", src)
+            .filter(|value| value.trim_start().starts_with("errors.New"))
+            .map(|_| "error".to_string())
+    });
+
+    let mut cursor = spec.walk();
+    for name in spec.children_by_field_name("name", &mut cursor).filter(|n| n.kind() == "identifier") {
+        let name = text(name, src);
+        if is_exported(name) && !analysis.variables.iter().any(|v| v.name == name) {
+            analysis.variables.push(ExportedVariable {
+                name: name.to_string(),
+                var_type: var_type.clone(),
+                location: span(spec),
+            });
+        }
+    }
+}
@@ -0,0 +1,221 @@
+//! Syntax-tree Java analyzer.
+
+use std::path::Path;
+use tree_sitter::Node;
+
+use super::{collapse_whitespace, field_text, named_children, parameter_list, parse, span, text};
+use crate::code_analyzer::{
+    AnalyzerError, ExportedClass, ExportedEnum, ExportedFunction, ExportedType, ExportedVariable,
+    JavaAnalyzer, LanguageAnalyzer, PartialAnalysis, TypeKind,
+};
+
+/// Java analyzer backed by the tree-sitter Java grammar.
+///
+/// `public` types are exported, along with the `public` methods and `public static final`
+/// UPPER_CASE constants declared in them; nested types only if every enclosing type is
+/// `public`. Constructors and accessors (`get*`/`set*`/`is*`) are skipped, as in
+/// [`JavaAnalyzer`]. Static and wildcard imports are dependencies too.
+#[derive(Debug)]
+pub struct TreeSitterJavaAnalyzer {
+    regex: JavaAnalyzer,
+}
+
+impl TreeSitterJavaAnalyzer {
+    pub fn new() -> Self {
+        Self {
+            regex: JavaAnalyzer::new(),
+        }
+    }
+
+    /// Collect exported types among the declarations of a file or type body, and the
+    /// members of those types.
+    fn collect_types(&self, declarations: &[Node], src: &[u8], analysis: &mut PartialAnalysis) {
+        for &declaration in declarations {
+            if !has_modifier(declaration, "public") {
+                continue;
+            }
+            let name = field_text(declaration, "name", src).unwrap_or("").to_string();
+            match declaration.kind() {
+                "class_declaration" => {
+                    let signature = match declaration.child_by_field_name("superclass") {
+                        Some(base) => format!("class {} extends {}", name, superclass(base, src)),
+                        None => format!("class {}", name),
+                    };
+                    analysis.classes.push(ExportedClass {
+                        name,
+                        signature: Some(signature),
+                        description: None,
+                        location: span(declaration),
+                    });
+                }
+                "record_declaration" => {
+                    let fields = declaration
+                        .child_by_field_name("parameters")
+                        .map(|p| parameter_list(p, src))
+                        .unwrap_or_default();
+                    analysis.classes.push(ExportedClass {
+                        signature: Some(format!("record {}({})", name, fields)),
+                        name,
+                        description: None,
+                        location: span(declaration),
+                    });
+                }
+                "interface_declaration" => analysis.types.push(ExportedType {
+                    name,
+                    kind: TypeKind::Interface,
+                    definition: None,
+                    description: None,
+                    location: span(declaration),
+                }),
+                "enum_declaration" => analysis.enums.push(ExportedEnum {
+                    name,
+                    variants: None,
+                    location: span(declaration),
+                }),
+                _ => continue,
+            }
+
+            if let Some(body) = declaration.child_by_field_name("body") {
+                self.collect_members(declaration, body, src, analysis);
+            }
+        }
+    }
+
+    /// Collect public methods, constants and nested types of a type body.
+    fn collect_members(&self, declaration: Node, body: Node, src: &[u8], analysis: &mut PartialAnalysis) {
+        // Interface methods are public unless declared otherwise
+        let implicitly_public = declaration.kind() == "interface_declaration";
+        // Enum bodies keep members after the constants in a declarations node
+        let members: Vec<Node> = named_children(body)
+            .into_iter()
+            .flat_map(|member| match member.kind() {
+                "enum_body_declarations" => named_children(member),
+                _ => vec![member],
+            })
+            .collect();
+
+        for member in &members {
+            match member.kind() {
+                "method_declaration" => {
+                    let public = has_modifier(*member, "public")
+                        || (implicitly_public && !has_modifier(*member, "private"));
+                    let name = field_text(*member, "name", src).unwrap_or("");
+                    if public && !is_accessor(name) {
+                        analysis.functions.push(method(name, *member, src));
+                    }
+                }
+                "field_declaration" => {
+                    if !["public", "static", "final"].iter().all(|m| has_modifier(*member, m)) {
+                        continue;
+                    }
+                    let var_type = field_text(*member, "type", src).map(collapse_whitespace);
+                    let mut cursor = member.walk();
+                    for declarator in member.children_by_field_name("declarator", &mut cursor) {
+                        let name = field_text(declarator, "name", src).unwrap_or("");
+                        if is_constant(name) {
+                            analysis.variables.push(ExportedVariable {
+                                name: name.to_string(),
+                                var_type: var_type.clone(),
+                                location: span(*member),
+                            });
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        self.collect_types(&members, src, analysis);
+    }
+}
+
+impl LanguageAnalyzer for TreeSitterJavaAnalyzer {
+    fn analyze_file(&self, path: &Path, content: &str) -> Result<PartialAnalysis, AnalyzerError> {
+        let tree = parse(&tree_sitter_java::LANGUAGE.into(), path, content)?;
+        let src = content.as_bytes();
+
+        // Behaviors, contracts and protocol come from the regex analyzer
+        let regex_analysis = self.regex.analyze_file(path, content)?;
+        let mut analysis = PartialAnalysis {
+            behaviors: regex_analysis.behaviors,
+            contracts: regex_analysis.contracts,
+            protocol: regex_analysis.protocol,
+            ..Default::default()
+        };
+
+        let statements = named_children(tree.root_node());
+        self.collect_types(&statements, src, &mut analysis);
+
+        let current_package = statements
+            .iter()
+            .find(|s| s.kind() == "package_declaration")
+            .and_then(|s| qualified_name(*s, src))
+            .unwrap_or("");
+        let imports = statements
+            .iter()
+            .filter(|s| s.kind() == "import_declaration")
+            .filter_map(|s| qualified_name(*s, src));
+        JavaAnalyzer::add_imports(path, current_package, imports, &mut analysis);
+
+        Ok(analysis)
+    }
+}
+
+impl Default for TreeSitterJavaAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether a declaration's `modifiers` include the keyword.
+fn has_modifier(declaration: Node, keyword: &str) -> bool {
+    named_children(declaration)
+        .into_iter()
+        .filter(|c| c.kind() == "modifiers")
+        .any(|modifiers| {
+            let mut cursor = modifiers.walk();
+            let found = modifiers.children(&mut cursor).any(|m| m.kind() == keyword);
+            found
+        })
+}
+
+fn is_accessor(name: &str) -> bool {
+    name.starts_with("get") || name.starts_with("set") || name.starts_with("is")
+}
+
+/// `MAX_RETRIES`: uppercase letters, digits and `_`.
+fn is_constant(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+        && name.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// `ReturnType name(params)`, without type parameters or `throws`.
+fn method(name: &str, declaration: Node, src: &[u8]) -> ExportedFunction {
+    let return_type = field_text(declaration, "type", src).map(collapse_whitespace).unwrap_or_default();
+    let params = declaration
+        .child_by_field_name("parameters")
+        .map(|p| parameter_list(p, src))
+        .unwrap_or_default();
+
+    ExportedFunction {
+        name: name.to_string(),
+        signature: format!("{} {}({})", return_type, name, params),
+        description: None,
+        location: span(declaration),
+        parsed_signature: None,
+    }
+}
+
+/// Base class of a `superclass` node, without type arguments.
+fn superclass(node: Node, src: &[u8]) -> String {
+    let base = named_children(node).into_iter().next().map(|t| text(t, src)).unwrap_or("");
+    base.split('<').next().unwrap_or(base).trim().to_string()
+}
+
+/// Dotted name of a `package` or `import` declaration.
+fn qualified_name<'a>(declaration: Node, src: &'a [u8]) -> Option<&'a str> {
+    named_children(declaration)
+        .into_iter()
+        .find(|c| matches!(c.kind(), "scoped_identifier" | "identifier"))
+        .map(|c| text(c, src))
+}
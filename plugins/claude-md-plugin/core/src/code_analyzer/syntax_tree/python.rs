@@ -0,0 +1,206 @@
+//! Syntax-tree Python analyzer.
+
+use std::path::Path;
+use tree_sitter::Node;
+
+use super::{collapse_whitespace, field_text, named_children, parameter_list, parse, span, text};
+use crate::code_analyzer::{
+    AnalyzerError, ExportedClass, ExportedFunction, ExportedType, ExportedVariable,
+    LanguageAnalyzer, PartialAnalysis, PythonAnalyzer, TypeKind,
+};
+
+/// `typing` constructs that make a PascalCase assignment a type alias.
+const TYPE_CONSTRUCTORS: &[&str] = &[
+    "Union", "Optional", "List", "Dict", "Tuple", "Set", "Type", "Callable", "Literal", "TypeVar", "NewType",
+];
+
+/// Python analyzer backed by the tree-sitter Python grammar.
+///
+/// Only module-level definitions are exported: public (no leading `_`) functions and
+/// classes, including decorated and `async` ones, UPPER_CASE constants and PascalCase
+/// type aliases. When `__all__` is defined outside `__init__.py`, only listed names are
+/// exported, as in [`PythonAnalyzer`].
+#[derive(Debug)]
+pub struct TreeSitterPythonAnalyzer {
+    regex: PythonAnalyzer,
+}
+
+impl TreeSitterPythonAnalyzer {
+    pub fn new() -> Self {
+        Self {
+            regex: PythonAnalyzer::new(),
+        }
+    }
+
+    /// Collect exported module-level definitions, keeping those `exported` accepts.
+    fn collect_definitions(&self, root: Node, src: &[u8], exported: &dyn Fn(&str) -> bool, analysis: &mut PartialAnalysis) {
+        for statement in named_children(root) {
+            let definition = match statement.kind() {
+                "decorated_definition" => statement.child_by_field_name("definition"),
+                _ => Some(statement),
+            };
+            let Some(definition) = definition else { continue };
+
+            match definition.kind() {
+                "function_definition" => {
+                    let name = field_text(definition, "name", src).unwrap_or("");
+                    if is_public(name) && exported(name) {
+                        analysis.functions.push(function(name, definition, statement, src));
+                    }
+                }
+                "class_definition" => {
+                    let name = field_text(definition, "name", src).unwrap_or("");
+                    if is_public(name) && exported(name) {
+                        analysis.classes.push(ExportedClass {
+                            name: name.to_string(),
+                            signature: Some(format!("class {}", name)),
+                            description: None,
+                            location: span(statement),
+                        });
+                    }
+                }
+                "expression_statement" => {
+                    for assignment in named_children(definition).into_iter().filter(|a| a.kind() == "assignment") {
+                        let Some(left) = assignment.child_by_field_name("left").filter(|l| l.kind() == "identifier") else {
+                            continue;
+                        };
+                        let name = text(left, src);
+                        if !exported(name) {
+                            continue;
+                        }
+                        if is_constant(name) {
+                            analysis.variables.push(ExportedVariable {
+                                name: name.to_string(),
+                                var_type: field_text(assignment, "type", src).map(collapse_whitespace),
+                                location: span(statement),
+                            });
+                        } else if is_type_alias(assignment, src) {
+                            analysis.types.push(ExportedType {
+                                name: name.to_string(),
+                                kind: TypeKind::Type,
+                                definition: None,
+                                description: None,
+                                location: span(statement),
+                            });
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl LanguageAnalyzer for TreeSitterPythonAnalyzer {
+    fn analyze_file(&self, path: &Path, content: &str) -> Result<PartialAnalysis, AnalyzerError> {
+        let tree = parse(&tree_sitter_python::LANGUAGE.into(), path, content)?;
+        let src = content.as_bytes();
+
+        // Behaviors, contracts and protocol come from the regex analyzer
+        let regex_analysis = self.regex.analyze_file(path, content)?;
+        let mut analysis = PartialAnalysis {
+            behaviors: regex_analysis.behaviors,
+            contracts: regex_analysis.contracts,
+            protocol: regex_analysis.protocol,
+            ..Default::default()
+        };
+
+        let root = tree.root_node();
+        let is_init = path.file_name().is_some_and(|s| s == "__init__.py");
+        let all_symbols = if is_init { Vec::new() } else { all_symbols(root, src) };
+        let exported = |name: &str| all_symbols.is_empty() || all_symbols.iter().any(|s| s == name);
+        self.collect_definitions(root, src, &exported, &mut analysis);
+
+        for statement in named_children(root) {
+            match statement.kind() {
+                "import_statement" => {
+                    let mut cursor = statement.walk();
+                    for name in statement.children_by_field_name("name", &mut cursor) {
+                        let module = match name.kind() {
+                            "aliased_import" => field_text(name, "name", src).unwrap_or(""),
+                            _ => text(name, src),
+                        };
+                        PythonAnalyzer::add_import(module, &mut analysis);
+                    }
+                }
+                "import_from_statement" => {
+                    if let Some(module) = field_text(statement, "module_name", src) {
+                        PythonAnalyzer::add_import(module, &mut analysis);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(analysis)
+    }
+}
+
+impl Default for TreeSitterPythonAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn is_public(name: &str) -> bool {
+    !name.starts_with('_')
+}
+
+/// `MAX_RETRIES`, `API_V2`: at least two characters, uppercase letters, digits and `_`.
+fn is_constant(name: &str) -> bool {
+    name.len() >= 2
+        && name.starts_with(|c: char| c.is_ascii_uppercase())
+        && name.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// `UserId = Union[str, int]`, `T = TypeVar("T")` or `Alias: TypeAlias = ...` with a PascalCase name.
+fn is_type_alias(assignment: Node, src: &[u8]) -> bool {
+    let name = field_text(assignment, "left", src).unwrap_or("");
+    if !name.starts_with(|c: char| c.is_ascii_uppercase()) {
+        return false;
+    }
+    if field_text(assignment, "type", src).is_some_and(|t| t.trim() == "TypeAlias") {
+        return true;
+    }
+    let constructor = assignment.child_by_field_name("right").and_then(|right| match right.kind() {
+        "subscript" => field_text(right, "value", src),
+        "call" => field_text(right, "function", src),
+        _ => None,
+    });
+    constructor.is_some_and(|c| TYPE_CONSTRUCTORS.contains(&c.trim_start_matches("typing.")))
+}
+
+/// Names listed in a module-level `__all__ = [...]`.
+fn all_symbols(root: Node, src: &[u8]) -> Vec<String> {
+    named_children(root)
+        .into_iter()
+        .filter(|s| s.kind() == "expression_statement")
+        .flat_map(named_children)
+        .filter(|a| a.kind() == "assignment" && field_text(*a, "left", src) == Some("__all__"))
+        .filter_map(|a| a.child_by_field_name("right"))
+        .flat_map(named_children)
+        .filter(|item| item.kind() == "string")
+        .map(|item| text(item, src).trim_matches(|c| c == '\'' || c == '"').to_string())
+        .collect()
+}
+
+/// `name(params) -> ReturnType`; the location covers decorators.
+fn function(name: &str, definition: Node, statement: Node, src: &[u8]) -> ExportedFunction {
+    let params = definition
+        .child_by_field_name("parameters")
+        .map(|p| parameter_list(p, src))
+        .unwrap_or_default();
+
+    let signature = match field_text(definition, "return_type", src) {
+        Some(ret) => format!("{}({}) -> {}", name, params, collapse_whitespace(ret)),
+        None => format!("{}({})", name, params),
+    };
+
+    ExportedFunction {
+        name: name.to_string(),
+        signature,
+        description: None,
+        location: span(statement),
+        parsed_signature: None,
+    }
+}
@@ -0,0 +1,240 @@
+//! Syntax-tree Rust analyzer.
+
+use std::path::Path;
+use regex::Regex;
+use tree_sitter::Node;
+
+use super::{collapse_whitespace, field_text, named_children, parameter_list, parse, span, text, walk_descendants};
+use crate::code_analyzer::{
+    AnalyzerError, ExportedEnum, ExportedFunction, ExportedType, ExportedVariable,
+    LanguageAnalyzer, PartialAnalysis, ReExport, RustAnalyzer, TypeKind,
+};
+
+/// Rust analyzer backed by the tree-sitter Rust grammar.
+///
+/// An item is exported only if it is `pub` and every enclosing `mod` is `pub` as well.
+/// Inherent `impl` methods marked `pub` are exported functions, as in [`RustAnalyzer`].
+#[derive(Debug)]
+pub struct TreeSitterRustAnalyzer {
+    regex: RustAnalyzer,
+    derive_crate_re: Regex,
+}
+
+impl TreeSitterRustAnalyzer {
+    pub fn new() -> Self {
+        Self {
+            regex: RustAnalyzer::new(),
+            // crate::Type inside #[derive(...)]
+            derive_crate_re: Regex::new(r"(\w+)::\w+").unwrap(),
+        }
+    }
+
+    /// Collect exported items of a source file or `pub mod` body.
+    fn collect_items(&self, container: Node, src: &[u8], analysis: &mut PartialAnalysis) {
+        for item in named_children(container) {
+            match item.kind() {
+                "impl_item" if item.child_by_field_name("trait").is_none() => {
+                    if let Some(body) = item.child_by_field_name("body") {
+                        for method in named_children(body) {
+                            if method.kind() == "function_item" && is_pub(method, src) {
+                                analysis.functions.push(function(method, src));
+                            }
+                        }
+                    }
+                }
+                _ if !is_pub(item, src) => {}
+                "mod_item" => {
+                    if let Some(body) = item.child_by_field_name("body") {
+                        self.collect_items(body, src, analysis);
+                    }
+                }
+                "function_item" => analysis.functions.push(function(item, src)),
+                "struct_item" => analysis.types.push(exported_type(item, TypeKind::Struct, src)),
+                "enum_item" => {
+                    let ty = exported_type(item, TypeKind::Enum, src);
+                    analysis.enums.push(ExportedEnum {
+                        name: ty.name.clone(),
+                        variants: None,
+                        location: ty.location.clone(),
+                    });
+                    analysis.types.push(ty);
+                }
+                "type_item" => analysis.types.push(exported_type(item, TypeKind::Type, src)),
+                "trait_item" => analysis.types.push(exported_type(item, TypeKind::Trait, src)),
+                "const_item" | "static_item" => analysis.variables.push(ExportedVariable {
+                    name: field_text(item, "name", src).unwrap_or("").to_string(),
+                    var_type: field_text(item, "type", src).map(collapse_whitespace),
+                    location: span(item),
+                }),
+                "use_declaration" => {
+                    if let Some(argument) = item.child_by_field_name("argument") {
+                        let mut names = Vec::new();
+                        flatten_use_tree(argument, "", src, &mut names);
+                        for (source, name) in names {
+                            if !analysis.re_exports.iter().any(|r| r.name == name && r.source == source) {
+                                analysis.re_exports.push(ReExport { name, source });
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Collect external crates and local module dependencies from every `use`
+    /// declaration and `#[derive(krate::Trait)]` attribute in the file.
+    fn collect_dependencies(&self, root: Node, path: &Path, src: &[u8], analysis: &mut PartialAnalysis) {
+        let std_crates = ["std", "core", "alloc", "self", "super", "crate"];
+        let add_external = |analysis: &mut PartialAnalysis, name: &str| {
+            if !std_crates.contains(&name) && !analysis.external_deps.iter().any(|d| d == name) {
+                analysis.external_deps.push(name.to_string());
+            }
+        };
+
+        let mut uses = Vec::new();
+        let mut derives = Vec::new();
+        walk_descendants(root, &mut |node| match node.kind() {
+            "use_declaration" => uses.push(node),
+            "attribute_item" if text(node, src).contains("derive") => derives.push(node),
+            _ => {}
+        });
+
+        for node in uses {
+            let Some(argument) = node.child_by_field_name("argument") else { continue };
+            let argument: String = text(argument, src).split_whitespace().collect::<Vec<_>>().join(" ");
+            let (crate_name, rest) = match argument.split_once("::") {
+                Some((head, rest)) => (head.trim(), rest),
+                None => (argument.split(' ').next().unwrap_or(""), ""),
+            };
+            if crate_name.is_empty() || crate_name.starts_with('{') {
+                continue;
+            }
+
+            if matches!(crate_name, "crate" | "super" | "self") {
+                for segments in RustAnalyzer::local_module_paths(rest) {
+                    if let Some(dep) = RustAnalyzer::resolve_local_module(path, crate_name, &segments) {
+                        if !analysis.internal_deps.contains(&dep) {
+                            analysis.internal_deps.push(dep);
+                        }
+                    }
+                }
+                continue;
+            }
+            add_external(analysis, crate_name);
+        }
+
+        for node in derives {
+            for cap in self.derive_crate_re.captures_iter(text(node, src)) {
+                add_external(analysis, cap.get(1).map(|m| m.as_str()).unwrap_or(""));
+            }
+        }
+    }
+}
+
+impl LanguageAnalyzer for TreeSitterRustAnalyzer {
+    fn analyze_file(&self, path: &Path, content: &str) -> Result<PartialAnalysis, AnalyzerError> {
+        let tree = parse(&tree_sitter_rust::LANGUAGE.into(), path, content)?;
+        let src = content.as_bytes();
+
+        // Behaviors, contracts and protocol come from the regex analyzer
+        let regex_analysis = self.regex.analyze_file(path, content)?;
+        let mut analysis = PartialAnalysis {
+            behaviors: regex_analysis.behaviors,
+            contracts: regex_analysis.contracts,
+            protocol: regex_analysis.protocol,
+            ..Default::default()
+        };
+
+        self.collect_items(tree.root_node(), src, &mut analysis);
+        self.collect_dependencies(tree.root_node(), path, src, &mut analysis);
+
+        Ok(analysis)
+    }
+}
+
+impl Default for TreeSitterRustAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether the item carries a plain `pub` (not `pub(crate)`/`pub(super)`).
+fn is_pub(item: Node, src: &[u8]) -> bool {
+    named_children(item)
+        .into_iter()
+        .any(|c| c.kind() == "visibility_modifier" && text(c, src) == "pub")
+}
+
+fn function(item: Node, src: &[u8]) -> ExportedFunction {
+    let name = field_text(item, "name", src).unwrap_or("");
+    let params = item
+        .child_by_field_name("parameters")
+        .map(|p| parameter_list(p, src))
+        .unwrap_or_default();
+
+    let signature = match field_text(item, "return_type", src) {
+        Some(ret) => format!("fn {}({}) -> {}", name, params, collapse_whitespace(ret)),
+        None => format!("fn {}({})", name, params),
+    };
+
+    ExportedFunction {
+        name: name.to_string(),
+        signature,
+        description: None,
+        location: span(item),
+        parsed_signature: None,
+    }
+}
+
+fn exported_type(item: Node, kind: TypeKind, src: &[u8]) -> ExportedType {
+    ExportedType {
+        name: field_text(item, "name", src).unwrap_or("").to_string(),
+        kind,
+        definition: None,
+        description: None,
+        location: span(item),
+    }
+}
+
+/// Flatten a `use` tree into `(source path, exported name)` pairs.
+/// `a::b::{C, d::E as F}` → `(a::b, C)`, `(a::b::d, F)`.
+fn flatten_use_tree(node: Node, prefix: &str, src: &[u8], out: &mut Vec<(String, String)>) {
+    let join = |prefix: &str, path: &str| match (prefix.is_empty(), path.is_empty()) {
+        (true, _) => path.to_string(),
+        (_, true) => prefix.to_string(),
+        _ => format!("{}::{}", prefix, path),
+    };
+
+    match node.kind() {
+        "scoped_identifier" => {
+            let path = field_text(node, "path", src).unwrap_or("");
+            let name = field_text(node, "name", src).unwrap_or("");
+            out.push((join(prefix, path), name.to_string()));
+        }
+        "identifier" if !prefix.is_empty() => {
+            out.push((prefix.to_string(), text(node, src).to_string()));
+        }
+        "use_as_clause" => {
+            let mut inner = Vec::new();
+            if let Some(path) = node.child_by_field_name("path") {
+                flatten_use_tree(path, prefix, src, &mut inner);
+            }
+            let alias = field_text(node, "alias", src).unwrap_or("");
+            out.extend(inner.into_iter().map(|(source, _)| (source, alias.to_string())));
+        }
+        "scoped_use_list" => {
+            let path = field_text(node, "path", src).unwrap_or("");
+            let prefix = join(prefix, &collapse_whitespace(path));
+            if let Some(list) = node.child_by_field_name("list") {
+                flatten_use_tree(list, &prefix, src, out);
+            }
+        }
+        "use_list" => {
+            for child in named_children(node) {
+                flatten_use_tree(child, prefix, src, out);
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,211 @@
+//! Syntax-tree TypeScript/JavaScript analyzer.
+
+use std::path::Path;
+use tree_sitter::Node;
+
+use super::{collapse_whitespace, field_text, named_children, parameter_list, parse, span, text, walk_descendants};
+use crate::code_analyzer::{
+    AnalyzerError, ExportedClass, ExportedEnum, ExportedFunction, ExportedType, ExportedVariable,
+    LanguageAnalyzer, PartialAnalysis, ReExport, TypeKind, TypeScriptAnalyzer,
+};
+
+/// TypeScript/JavaScript analyzer backed by the tree-sitter TypeScript grammars.
+///
+/// `.ts`/`.mts`/`.cts` files use the TypeScript grammar; everything else (`.tsx`, `.js`,
+/// `.jsx`, ...) uses the TSX grammar, which also accepts plain JavaScript.
+#[derive(Debug)]
+pub struct TreeSitterTypeScriptAnalyzer {
+    regex: TypeScriptAnalyzer,
+}
+
+impl TreeSitterTypeScriptAnalyzer {
+    pub fn new() -> Self {
+        Self {
+            regex: TypeScriptAnalyzer::new(),
+        }
+    }
+
+    /// Collect symbols from one top-level `export` statement.
+    fn collect_export(&self, statement: Node, src: &[u8], analysis: &mut PartialAnalysis) {
+        // export { a, b as c } from './module'
+        if let Some(source) = statement.child_by_field_name("source") {
+            let source = string_value(source, src);
+            for clause in named_children(statement).into_iter().filter(|c| c.kind() == "export_clause") {
+                for specifier in named_children(clause) {
+                    let name = field_text(specifier, "alias", src)
+                        .or_else(|| field_text(specifier, "name", src))
+                        .unwrap_or("");
+                    if !name.is_empty() && !analysis.re_exports.iter().any(|r| r.name == name && r.source == source) {
+                        analysis.re_exports.push(ReExport {
+                            name: name.to_string(),
+                            source: source.clone(),
+                        });
+                    }
+                }
+            }
+            return;
+        }
+
+        let Some(declaration) = statement.child_by_field_name("declaration") else { return };
+        match declaration.kind() {
+            "function_declaration" | "generator_function_declaration" => {
+                let name = field_text(declaration, "name", src).unwrap_or("");
+                analysis.functions.push(function(name, declaration, statement, src));
+            }
+            "class_declaration" | "abstract_class_declaration" => {
+                let name = field_text(declaration, "name", src).unwrap_or("");
+                let signature = match extends_clause(declaration, src) {
+                    Some(base) => format!("class {} extends {}", name, base),
+                    None => format!("class {}", name),
+                };
+                analysis.classes.push(ExportedClass {
+                    name: name.to_string(),
+                    signature: Some(signature),
+                    description: None,
+                    location: span(statement),
+                });
+            }
+            "interface_declaration" => analysis.types.push(exported_type(declaration, TypeKind::Interface, statement, src)),
+            "type_alias_declaration" => analysis.types.push(exported_type(declaration, TypeKind::Type, statement, src)),
+            "enum_declaration" => analysis.enums.push(ExportedEnum {
+                name: field_text(declaration, "name", src).unwrap_or("").to_string(),
+                variants: None,
+                location: span(statement),
+            }),
+            "lexical_declaration" | "variable_declaration" => {
+                for declarator in named_children(declaration)
+                    .into_iter()
+                    .filter(|d| d.kind() == "variable_declarator")
+                {
+                    let name = field_text(declarator, "name", src).unwrap_or("");
+                    match declarator.child_by_field_name("value") {
+                        Some(value) if matches!(value.kind(), "arrow_function" | "function_expression" | "function") => {
+                            analysis.functions.push(function(name, value, statement, src));
+                        }
+                        _ => analysis.variables.push(ExportedVariable {
+                            name: name.to_string(),
+                            var_type: field_text(declarator, "type", src).map(type_annotation),
+                            location: span(statement),
+                        }),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl LanguageAnalyzer for TreeSitterTypeScriptAnalyzer {
+    fn analyze_file(&self, path: &Path, content: &str) -> Result<PartialAnalysis, AnalyzerError> {
+        let language = match path.extension().and_then(|e| e.to_str()) {
+            Some("ts" | "mts" | "cts") => tree_sitter_typescript::LANGUAGE_TYPESCRIPT,
+            _ => tree_sitter_typescript::LANGUAGE_TSX,
+        };
+        let tree = parse(&language.into(), path, content)?;
+        let src = content.as_bytes();
+
+        // Behaviors, contracts and protocol come from the regex analyzer
+        let regex_analysis = self.regex.analyze_file(path, content)?;
+        let mut analysis = PartialAnalysis {
+            behaviors: regex_analysis.behaviors,
+            contracts: regex_analysis.contracts,
+            protocol: regex_analysis.protocol,
+            ..Default::default()
+        };
+
+        let root = tree.root_node();
+        for statement in named_children(root) {
+            if statement.kind() == "export_statement" {
+                self.collect_export(statement, src, &mut analysis);
+            }
+        }
+
+        let mut imports = Vec::new();
+        walk_descendants(root, &mut |node| {
+            if node.kind() == "import_statement" {
+                if let Some(source) = node.child_by_field_name("source") {
+                    imports.push(string_value(source, src));
+                }
+            }
+        });
+        for package in imports {
+            if package.starts_with('.') || package.starts_with('/') {
+                analysis.internal_deps.push(package);
+            } else {
+                // Extract package name (handle scoped packages)
+                let pkg_name = if package.starts_with('@') {
+                    package.split('/').take(2).collect::<Vec<_>>().join("/")
+                } else {
+                    package.split('/').next().unwrap_or(&package).to_string()
+                };
+                if !analysis.external_deps.contains(&pkg_name) {
+                    analysis.external_deps.push(pkg_name);
+                }
+            }
+        }
+
+        Ok(analysis)
+    }
+}
+
+impl Default for TreeSitterTypeScriptAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `name(params): ReturnType` for function declarations, arrow functions and function expressions.
+/// The location covers the whole `export` statement.
+fn function(name: &str, node: Node, statement: Node, src: &[u8]) -> ExportedFunction {
+    let params = match node.child_by_field_name("parameters") {
+        Some(p) => parameter_list(p, src),
+        // Single-parameter arrow function without parentheses: `x => ...`
+        None => field_text(node, "parameter", src).unwrap_or("").to_string(),
+    };
+
+    let signature = match field_text(node, "return_type", src) {
+        Some(ret) => format!("{}({}): {}", name, params, type_annotation(ret)),
+        None => format!("{}({})", name, params),
+    };
+
+    ExportedFunction {
+        name: name.to_string(),
+        signature,
+        description: None,
+        location: span(statement),
+        parsed_signature: None,
+    }
+}
+
+fn exported_type(node: Node, kind: TypeKind, statement: Node, src: &[u8]) -> ExportedType {
+    ExportedType {
+        name: field_text(node, "name", src).unwrap_or("").to_string(),
+        kind,
+        definition: None,
+        description: None,
+        location: span(statement),
+    }
+}
+
+/// Base class from `class X extends Base`, without type arguments.
+fn extends_clause(class: Node, src: &[u8]) -> Option<String> {
+    named_children(class)
+        .into_iter()
+        .filter(|c| c.kind() == "class_heritage")
+        .flat_map(named_children)
+        .find(|c| c.kind() == "extends_clause")
+        .and_then(|c| field_text(c, "value", src))
+        .map(str::to_string)
+}
+
+/// `: Promise<Claims>` → `Promise<Claims>`
+fn type_annotation(annotation: &str) -> String {
+    collapse_whitespace(annotation.trim_start().trim_start_matches(':').trim())
+}
+
+/// Contents of a string literal node, without quotes.
+fn string_value(node: Node, src: &[u8]) -> String {
+    text(node, src)
+        .trim_matches(|c| c == '\'' || c == '"' || c == '`')
+        .to_string()
+}
@@ -8,6 +8,7 @@ use super::{
     ExportedFunction, ExportedType, ExportedVariable, FunctionContract, LanguageAnalyzer,
     PartialAnalysis, Protocol, ReExport, TypeKind,
 };
+use super::source_locator::line_at;
 
 /// Analyzer for TypeScript and JavaScript files.
 #[derive(Debug)]
@@ -204,6 +205,7 @@ impl TypeScriptAnalyzer {
                 contracts.push(FunctionContract {
                     function_name: function_name.to_string(),
                     contract,
+                    location: None,
                 });
             }
         }
@@ -220,6 +222,12 @@ impl TypeScriptAnalyzer {
         let function_start_re = Regex::new(
             r"export\s+(?:async\s+)?function\s+(\w+)\s*\([^)]*\)[^{]*\{"
         ).unwrap();
+        let validation_re = Regex::new(
+            r"if\s*\(\s*!(\w+(?:\.\w+)+)\s*\)\s*\{?\s*throw"
+        ).unwrap();
+        let length_re = Regex::new(
+            r"if\s*\(\s*(\w+(?:\.\w+)+)\.length\s*===?\s*0\s*\)\s*\{?\s*throw"
+        ).unwrap();
 
         for cap in function_start_re.captures_iter(content) {
             let function_name = cap.get(1).map(|m| m.as_str()).unwrap_or("");
@@ -231,10 +239,6 @@ impl TypeScriptAnalyzer {
             let mut inferred_preconditions = Vec::new();
 
             // Look for validation patterns: if (!order.id) throw new Error
-            let validation_re = Regex::new(
-                r"if\s*\(\s*!(\w+(?:\.\w+)+)\s*\)\s*\{?\s*throw"
-            ).unwrap();
-
             for val_cap in validation_re.captures_iter(&body) {
                 if let Some(prop) = val_cap.get(1) {
                     let prop_str = prop.as_str();
@@ -243,10 +247,6 @@ impl TypeScriptAnalyzer {
             }
 
             // Look for: if (x.items.length === 0) throw
-            let length_re = Regex::new(
-                r"if\s*\(\s*(\w+(?:\.\w+)+)\.length\s*===?\s*0\s*\)\s*\{?\s*throw"
-            ).unwrap();
-
             for len_cap in length_re.captures_iter(&body) {
                 if let Some(prop) = len_cap.get(1) {
                     let prop_str = prop.as_str();
@@ -265,6 +265,7 @@ impl TypeScriptAnalyzer {
                             preconditions: inferred_preconditions,
                             ..Default::default()
                         },
+                        location: None,
                     });
                 }
             }
@@ -296,13 +297,13 @@ impl TypeScriptAnalyzer {
     /// Extract protocol information (states, transitions, lifecycle).
     fn extract_protocol(&self, content: &str) -> Option<Protocol> {
         let mut protocol = Protocol::default();
+        let variant_re = Regex::new(r"(\w+)\s*(?:=\s*[^,}]+)?").unwrap();
 
         // Extract states from State enum
         for cap in self.state_enum_re.captures_iter(content) {
             if let Some(body) = cap.get(1) {
                 let body_str = body.as_str();
                 // Parse enum variants: Idle = 'idle', Loading = 'loading', etc.
-                let variant_re = Regex::new(r"(\w+)\s*(?:=\s*[^,}]+)?").unwrap();
                 for var_cap in variant_re.captures_iter(body_str) {
                     if let Some(variant) = var_cap.get(1) {
                         let variant_name = variant.as_str().trim();
@@ -379,6 +380,8 @@ impl LanguageAnalyzer for TypeScriptAnalyzer {
                 name: name.to_string(),
                 signature,
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+                parsed_signature: None,
             });
         }
 
@@ -398,6 +401,8 @@ impl LanguageAnalyzer for TypeScriptAnalyzer {
                 name: name.to_string(),
                 signature,
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+                parsed_signature: None,
             });
         }
 
@@ -417,6 +422,8 @@ impl LanguageAnalyzer for TypeScriptAnalyzer {
                 name: name.to_string(),
                 signature,
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
+                parsed_signature: None,
             });
         }
 
@@ -480,6 +487,7 @@ impl LanguageAnalyzer for TypeScriptAnalyzer {
                 name: name.to_string(),
                 signature: Some(signature),
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -491,6 +499,7 @@ impl LanguageAnalyzer for TypeScriptAnalyzer {
                 kind: TypeKind::Interface,
                 definition: None,
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -502,6 +511,7 @@ impl LanguageAnalyzer for TypeScriptAnalyzer {
                 kind: TypeKind::Type,
                 definition: None,
                 description: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -511,6 +521,7 @@ impl LanguageAnalyzer for TypeScriptAnalyzer {
             analysis.enums.push(ExportedEnum {
                 name: name.to_string(),
                 variants: None,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -532,6 +543,7 @@ impl LanguageAnalyzer for TypeScriptAnalyzer {
             analysis.variables.push(ExportedVariable {
                 name: name.to_string(),
                 var_type,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -543,6 +555,7 @@ impl LanguageAnalyzer for TypeScriptAnalyzer {
             analysis.variables.push(ExportedVariable {
                 name: name.to_string(),
                 var_type,
+                location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
             });
         }
 
@@ -588,17 +601,19 @@ impl LanguageAnalyzer for TypeScriptAnalyzer {
                     input: input.to_string(),
                     output: error_name.to_string(),
                     category: BehaviorCategory::Error,
+                    location: line_at(content, cap.get(1).map_or(0, |m| m.start())),
                 });
             }
         }
 
         // Infer success behavior if we have validation functions
-        let has_validate = analysis.functions.iter().any(|f| f.name.contains("validate"));
-        if has_validate {
+        let validate = analysis.functions.iter().find(|f| f.name.contains("validate")).map(|f| f.location.clone());
+        if let Some(location) = validate {
             analysis.behaviors.insert(0, Behavior {
                 input: "Valid JWT token".to_string(),
                 output: "Claims object".to_string(),
                 category: BehaviorCategory::Success,
+                location,
             });
         }
 
@@ -0,0 +1,272 @@
+//! `claude-md.lock`: content hashes of specs and generated sources recorded at compile time.
+//!
+//! Lets `diff-compile-targets --lock` decide staleness without git history,
+//! e.g. in tarballs, shallow CI clones, or when several specs change in one commit.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+use crate::compile_target_resolver::CompileTargetResolver;
+
+/// Lock file name, stored at the project root
+pub const LOCK_FILE_NAME: &str = "claude-md.lock";
+
+/// Current lock file format version
+const LOCK_VERSION: u32 = 1;
+
+/// Errors reading or writing claude-md.lock
+#[derive(Debug, Error)]
+pub enum LockError {
+    #[error("Cannot read '{path}': {source}")]
+    ReadError {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("Invalid lock file '{path}': {source}")]
+    ParseError {
+        path: String,
+        #[source]
+        source: serde_json::Error,
+    },
+    #[error("Cannot write '{path}': {source}")]
+    WriteError {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+/// Contents of claude-md.lock
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompileLock {
+    /// Lock file format version
+    pub version: u32,
+    /// Per-module hashes keyed by directory (relative to root)
+    pub modules: BTreeMap<String, LockEntry>,
+}
+
+/// Hashes recorded for one module directory
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LockEntry {
+    /// Hash of CLAUDE.md
+    pub claude_md: String,
+    /// Hash of IMPLEMENTS.md (absent if the file did not exist)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub implements_md: Option<String>,
+    /// Hashes of source files (root-relative path -> hash)
+    #[serde(default)]
+    pub sources: BTreeMap<String, String>,
+}
+
+/// Result of updating the lock file
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LockUpdateResult {
+    /// Path of the written lock file
+    pub lock_file: String,
+    /// Module directories whose entries were recorded
+    pub updated: Vec<String>,
+}
+
+impl CompileLock {
+    pub fn new() -> Self {
+        Self {
+            version: LOCK_VERSION,
+            modules: BTreeMap::new(),
+        }
+    }
+
+    /// Load `<root>/claude-md.lock`. Returns Ok(None) if the file does not exist.
+    pub fn load(root: &Path) -> Result<Option<Self>, LockError> {
+        let path = root.join(LOCK_FILE_NAME);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = std::fs::read_to_string(&path).map_err(|e| LockError::ReadError {
+            path: path.display().to_string(),
+            source: e,
+        })?;
+        serde_json::from_str(&content)
+            .map(Some)
+            .map_err(|e| LockError::ParseError {
+                path: path.display().to_string(),
+                source: e,
+            })
+    }
+
+    /// Write the lock file to `<root>/claude-md.lock`
+    pub fn save(&self, root: &Path) -> Result<PathBuf, LockError> {
+        let path = root.join(LOCK_FILE_NAME);
+        let json = serde_json::to_string_pretty(self).map_err(|e| LockError::ParseError {
+            path: path.display().to_string(),
+            source: e,
+        })?;
+        std::fs::write(&path, format!("{}\n", json)).map_err(|e| LockError::WriteError {
+            path: path.display().to_string(),
+            source: e,
+        })?;
+        Ok(path)
+    }
+
+    /// Record current hashes for the given module directories (relative to root).
+    /// Directories without a CLAUDE.md are ignored. Returns the recorded directories.
+    pub fn record(&mut self, root: &Path, dirs: &[PathBuf]) -> Vec<String> {
+        let resolver = CompileTargetResolver::new();
+        let mut updated = Vec::new();
+
+        for dir in dirs {
+            if !root.join(dir).join("CLAUDE.md").is_file() {
+                continue;
+            }
+            let dir_str = dir.to_string_lossy().to_string();
+            let sources = resolver.source_files_in(root, dir);
+            self.modules.insert(dir_str.clone(), LockEntry::compute(root, dir, &sources));
+            updated.push(dir_str);
+        }
+
+        updated
+    }
+
+    /// Load (or create) the lock at `root`, record `dirs`, and save it.
+    /// An empty `dirs` records every module and drops entries whose CLAUDE.md is gone.
+    pub fn update(root: &Path, dirs: &[PathBuf]) -> Result<LockUpdateResult, LockError> {
+        let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+        let mut lock = Self::load(&root)?.unwrap_or_default();
+
+        let updated = if dirs.is_empty() {
+            let all_dirs = CompileTargetResolver::new().scan_claude_md_dirs(&root);
+            let updated = lock.record(&root, &all_dirs);
+            lock.modules.retain(|dir, _| updated.contains(dir));
+            updated
+        } else {
+            lock.record(&root, dirs)
+        };
+
+        let path = lock.save(&root)?;
+        Ok(LockUpdateResult {
+            lock_file: path.display().to_string(),
+            updated,
+        })
+    }
+}
+
+impl Default for CompileLock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LockEntry {
+    /// Hash the spec files of `dir` and the given source files (all relative to root)
+    pub fn compute(root: &Path, dir: &Path, source_files: &[String]) -> Self {
+        let abs_dir = root.join(dir);
+        Self {
+            claude_md: hash_file(&abs_dir.join("CLAUDE.md")).unwrap_or_default(),
+            implements_md: hash_file(&abs_dir.join("IMPLEMENTS.md")),
+            sources: source_files
+                .iter()
+                .filter_map(|f| hash_file(&root.join(f)).map(|h| (f.clone(), h)))
+                .collect(),
+        }
+    }
+
+    /// Whether CLAUDE.md or IMPLEMENTS.md differ between two entries
+    pub fn spec_differs(&self, other: &LockEntry) -> bool {
+        !self.changed_specs(other).is_empty()
+    }
+
+    /// Names of spec files whose hashes differ
+    pub fn changed_specs(&self, other: &LockEntry) -> Vec<&'static str> {
+        let mut changed = Vec::new();
+        if self.claude_md != other.claude_md {
+            changed.push("CLAUDE.md");
+        }
+        if self.implements_md != other.implements_md {
+            changed.push("IMPLEMENTS.md");
+        }
+        changed
+    }
+}
+
+fn hash_file(path: &Path) -> Option<String> {
+    std::fs::read(path).ok().map(|bytes| content_hash(&bytes))
+}
+
+/// Stable 64-bit FNV-1a content hash, formatted as `fnv1a64:<hex>`.
+/// Line endings are normalized so checkouts with CRLF conversion hash the same.
+pub fn content_hash(bytes: &[u8]) -> String {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash = OFFSET_BASIS;
+    let mut prev_cr = false;
+    for &b in bytes {
+        if prev_cr && b == b'\n' {
+            prev_cr = false;
+            continue;
+        }
+        prev_cr = b == b'\r';
+        let b = if b == b'\r' { b'\n' } else { b };
+        hash ^= b as u64;
+        hash = hash.wrapping_mul(PRIME);
+    }
+
+    format!("fnv1a64:{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_content_hash_is_stable_and_normalizes_crlf() {
+        assert_eq!(content_hash(b""), "fnv1a64:cbf29ce484222325");
+        assert_eq!(content_hash(b"a\r\nb\n"), content_hash(b"a\nb\n"));
+        assert_ne!(content_hash(b"a\nb\n"), content_hash(b"a\nc\n"));
+    }
+
+    #[test]
+    fn test_record_save_and_load_roundtrip() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        std::fs::create_dir_all(root.join("src/auth")).unwrap();
+        std::fs::write(root.join("src/auth/CLAUDE.md"), "# auth\n").unwrap();
+        std::fs::write(root.join("src/auth/token.ts"), "export const x = 1;\n").unwrap();
+        std::fs::create_dir_all(root.join("src/empty")).unwrap();
+
+        let mut lock = CompileLock::new();
+        let updated = lock.record(root, &[PathBuf::from("src/auth"), PathBuf::from("src/empty")]);
+        assert_eq!(updated, vec!["src/auth"]);
+        lock.save(root).unwrap();
+
+        let loaded = CompileLock::load(root).unwrap().unwrap();
+        let entry = &loaded.modules["src/auth"];
+        assert!(entry.implements_md.is_none());
+        assert!(entry.sources.contains_key("src/auth/token.ts"));
+    }
+
+    #[test]
+    fn test_load_missing_lock_returns_none() {
+        let temp = TempDir::new().unwrap();
+        assert!(CompileLock::load(temp.path()).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_changed_specs() {
+        let base = LockEntry {
+            claude_md: "a".to_string(),
+            implements_md: Some("b".to_string()),
+            sources: BTreeMap::new(),
+        };
+        let changed = LockEntry {
+            implements_md: Some("c".to_string()),
+            ..base.clone()
+        };
+        assert_eq!(base.changed_specs(&changed), vec!["IMPLEMENTS.md"]);
+        assert!(!base.spec_differs(&base.clone()));
+    }
+}
@@ -3,6 +3,8 @@ use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use crate::compile_lock::{CompileLock, LockEntry, LOCK_FILE_NAME};
+use crate::markdown::{find_section, parse_sections, with_subsections};
 use crate::{EXCLUDED_DIRS, SOURCE_EXTENSIONS};
 
 /// Result of incremental diff analysis
@@ -20,6 +22,9 @@ pub struct DiffResult {
     /// Dependency cascade warnings
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub dependency_warnings: Vec<DependencyWarning>,
+    /// Leaf-first compile batches (cascade mode only)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub compile_plan: Vec<CompileBatch>,
 }
 
 /// A CLAUDE.md that needs recompilation
@@ -51,6 +56,45 @@ pub enum TargetReason {
     SpecNewer,
     /// No source code files exist in the directory
     NoSourceCode,
+    /// A module this spec depends on (directly or transitively) is a target
+    Cascade,
+    /// CLAUDE.md or IMPLEMENTS.md hash differs from claude-md.lock
+    SpecChanged,
+    /// Module has no entry in claude-md.lock
+    NotInLock,
+    /// Full compilation was requested (--all)
+    Forced,
+    /// CLAUDE.md changed between the --since ref and HEAD
+    ChangedSince,
+    /// Source code has newer commits than the spec files (decompile)
+    SourceNewer,
+    /// Source code has uncommitted changes while the spec does not (decompile)
+    SourceModified,
+    /// Source hashes differ from claude-md.lock while spec hashes match (decompile)
+    SourceChanged,
+}
+
+/// A group of targets that can be compiled in parallel.
+/// Every dependency of a batch member is in an earlier batch.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CompileBatch {
+    /// 1-based batch order
+    pub batch: usize,
+    /// Target directories in this batch
+    pub dirs: Vec<String>,
+}
+
+/// Options for compile target resolution
+#[derive(Debug, Clone, Default)]
+pub struct ResolveOptions {
+    /// Add transitive dependents of changed targets and build a compile plan
+    pub cascade: bool,
+    /// Decide staleness from content hashes in claude-md.lock instead of git
+    pub use_lock: bool,
+    /// Target every CLAUDE.md directory regardless of state
+    pub all: bool,
+    /// Also target specs changed between this git ref (merge base) and HEAD
+    pub since: Option<String>,
 }
 
 /// A CLAUDE.md that was skipped
@@ -99,14 +143,18 @@ impl CompileTargetResolver {
 
     /// Resolve which CLAUDE.md files need recompilation based on git state
     pub fn resolve(&self, root: &Path) -> DiffResult {
+        self.resolve_with(root, &ResolveOptions::default())
+    }
+
+    /// Resolve compile targets with the given options
+    pub fn resolve_with(&self, root: &Path, options: &ResolveOptions) -> DiffResult {
         let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
         let mut targets = Vec::new();
         let mut skipped = Vec::new();
         let mut warnings = Vec::new();
-        let dependency_warnings;
 
-        // 1. Check git repo
-        if !is_git_repo(&root) {
+        // 1. Check git repo (lock-based staleness and --all do not need git)
+        if !options.use_lock && !options.all && !is_git_repo(&root) {
             warnings.push(DiffWarning {
                 warning_type: "no-git-repo".to_string(),
                 message: "Not a git repository. Use --all for full compilation.".to_string(),
@@ -117,24 +165,282 @@ impl CompileTargetResolver {
                 skipped,
                 warnings,
                 dependency_warnings: vec![],
+                compile_plan: vec![],
             };
         }
 
         // 2. Scan all directories containing CLAUDE.md
         let all_claude_md_dirs = self.scan_claude_md_dirs(&root);
 
-        // 3. Get git status (one-time calls)
-        let staged_files = git_staged_files(&root);
-        let modified_files = git_modified_files(&root);
-        let untracked_files = git_untracked_files(&root);
+        // 3-4. Evaluate each directory by content hash or git state
+        if options.all {
+            for dir in &all_claude_md_dirs {
+                let dir_str = dir.to_string_lossy().to_string();
+                targets.push(CompileTarget {
+                    claude_md_path: format!("{}/CLAUDE.md", dir_str),
+                    implements_md_path: format!("{}/IMPLEMENTS.md", dir_str),
+                    dir: dir_str,
+                    reason: TargetReason::Forced,
+                    details: "Full compilation requested (--all)".to_string(),
+                });
+            }
+        } else if options.use_lock {
+            self.evaluate_with_lock(&root, &all_claude_md_dirs, &mut targets, &mut skipped, &mut warnings);
+        } else {
+            self.evaluate_with_git(&root, &all_claude_md_dirs, &mut targets, &mut skipped);
+        }
+
+        // 4b. Promote specs changed since the given ref (skipped entries only)
+        if let Some(since) = options.since.as_ref().filter(|_| !options.all) {
+            match git_changed_files_since(&root, since) {
+                Some(changed_files) => {
+                    let changed_dirs = extract_spec_dirs(&changed_files);
+                    skipped.retain(|s| {
+                        if !changed_dirs.contains(&s.dir) {
+                            return true;
+                        }
+                        targets.push(CompileTarget {
+                            claude_md_path: format!("{}/CLAUDE.md", s.dir),
+                            implements_md_path: format!("{}/IMPLEMENTS.md", s.dir),
+                            dir: s.dir.clone(),
+                            reason: TargetReason::ChangedSince,
+                            details: format!("CLAUDE.md changed since {}", since),
+                        });
+                        false
+                    });
+                }
+                None => warnings.push(DiffWarning {
+                    warning_type: "invalid-since-ref".to_string(),
+                    message: format!("Cannot diff against '{}'; --since was ignored.", since),
+                }),
+            }
+        }
+
+        // 5. Cascade to transitive dependents (opt-in)
+        let reverse_deps = self.build_reverse_dependency_map(&root, &all_claude_md_dirs);
+        if options.cascade {
+            let changed: Vec<&str> = targets.iter().map(|t| t.dir.as_str()).collect();
+            for (dir, cause) in cascade_dependents(&reverse_deps, &changed) {
+                skipped.retain(|s| s.dir != dir);
+                targets.push(CompileTarget {
+                    claude_md_path: format!("{}/CLAUDE.md", dir),
+                    implements_md_path: format!("{}/IMPLEMENTS.md", dir),
+                    dir,
+                    reason: TargetReason::Cascade,
+                    details: format!("Depends on changed module {}", cause),
+                });
+            }
+        }
+
+        // 6. Build dependency warnings
+        let target_dirs: HashSet<&str> = targets.iter().map(|t| t.dir.as_str()).collect();
+        let dependency_warnings = self.generate_dependency_warnings(&reverse_deps, &target_dirs);
+
+        // 7. Order targets leaf-first (opt-in)
+        let compile_plan = if options.cascade {
+            let (plan, cyclic) = build_compile_plan(&reverse_deps, &target_dirs);
+            if !cyclic.is_empty() {
+                warnings.push(DiffWarning {
+                    warning_type: "dependency-cycle".to_string(),
+                    message: format!(
+                        "Cyclic dependencies between {}; compiled together in the last batch",
+                        cyclic.join(", ")
+                    ),
+                });
+            }
+            plan
+        } else {
+            Vec::new()
+        };
+
+        // Sort targets by dir for consistent output
+        targets.sort_by(|a, b| a.dir.cmp(&b.dir));
+        skipped.sort_by(|a, b| a.dir.cmp(&b.dir));
+
+        DiffResult {
+            root,
+            targets,
+            skipped,
+            warnings,
+            dependency_warnings,
+            compile_plan,
+        }
+    }
+
+    /// Resolve which CLAUDE.md files are outdated by newer source code (decompile worklist).
+    /// This is the reverse of [`resolve`](Self::resolve): a directory is a target when its
+    /// source files changed after its spec.
+    pub fn resolve_decompile(&self, root: &Path, use_lock: bool) -> DiffResult {
+        let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+        let mut targets = Vec::new();
+        let mut skipped = Vec::new();
+        let mut warnings = Vec::new();
+
+        if !use_lock && !is_git_repo(&root) {
+            warnings.push(DiffWarning {
+                warning_type: "no-git-repo".to_string(),
+                message: "Not a git repository. Use --lock to compare against claude-md.lock.".to_string(),
+            });
+            return DiffResult {
+                root,
+                targets,
+                skipped,
+                warnings,
+                dependency_warnings: vec![],
+                compile_plan: vec![],
+            };
+        }
+
+        let all_claude_md_dirs = self.scan_claude_md_dirs(&root);
+
+        if use_lock {
+            let mut lock_targets = Vec::new();
+            self.evaluate_with_lock(&root, &all_claude_md_dirs, &mut lock_targets, &mut skipped, &mut warnings);
+            // Invert the compile view: source-only changes become targets, everything else is skipped
+            skipped = skipped
+                .into_iter()
+                .filter_map(|entry| {
+                    if entry.reason != "source-changed" {
+                        return Some(entry);
+                    }
+                    targets.push(CompileTarget {
+                        claude_md_path: format!("{}/CLAUDE.md", entry.dir),
+                        implements_md_path: format!("{}/IMPLEMENTS.md", entry.dir),
+                        dir: entry.dir,
+                        reason: TargetReason::SourceChanged,
+                        details: entry.details,
+                    });
+                    None
+                })
+                .collect();
+            skipped.extend(lock_targets.into_iter().map(|t| SkippedEntry {
+                dir: t.dir,
+                reason: "spec-pending-compile".to_string(),
+                details: t.details,
+            }));
+        } else {
+            self.evaluate_decompile_with_git(&root, &all_claude_md_dirs, &mut targets, &mut skipped);
+        }
+
+        targets.sort_by(|a, b| a.dir.cmp(&b.dir));
+        skipped.sort_by(|a, b| a.dir.cmp(&b.dir));
+
+        DiffResult {
+            root,
+            targets,
+            skipped,
+            warnings,
+            dependency_warnings: vec![],
+            compile_plan: vec![],
+        }
+    }
+
+    /// Classify directories by source working-tree state and commit timestamps (decompile direction)
+    fn evaluate_decompile_with_git(
+        &self,
+        root: &Path,
+        all_claude_md_dirs: &[PathBuf],
+        targets: &mut Vec<CompileTarget>,
+        skipped: &mut Vec<SkippedEntry>,
+    ) {
+        let mut changed_files = git_staged_files(root);
+        changed_files.extend(git_modified_files(root));
+        changed_files.extend(git_untracked_files(root));
+
+        let changed_spec_dirs = extract_spec_dirs(&changed_files);
+        let changed_source_dirs = self.extract_source_dirs(&changed_files);
+
+        for dir in all_claude_md_dirs {
+            let dir_str = dir.to_string_lossy().to_string();
+
+            if changed_spec_dirs.contains(&dir_str) {
+                skipped.push(SkippedEntry {
+                    dir: dir_str,
+                    reason: "spec-modified".to_string(),
+                    details: "CLAUDE.md has uncommitted changes; see diff-compile-targets".to_string(),
+                });
+                continue;
+            }
+            if changed_source_dirs.contains(&dir_str) {
+                targets.push(CompileTarget {
+                    claude_md_path: format!("{}/CLAUDE.md", dir_str),
+                    implements_md_path: format!("{}/IMPLEMENTS.md", dir_str),
+                    dir: dir_str,
+                    reason: TargetReason::SourceModified,
+                    details: "Source files have uncommitted changes".to_string(),
+                });
+                continue;
+            }
+
+            let spec_paths = self.spec_files_in(root, dir);
+            let source_files = self.source_files_in(root, dir);
+            if source_files.is_empty() {
+                skipped.push(SkippedEntry {
+                    dir: dir_str,
+                    reason: "no-source-code".to_string(),
+                    details: "No source code files found".to_string(),
+                });
+                continue;
+            }
+
+            let spec_ts = git_last_commit_ts(root, &spec_paths);
+            let source_ts = git_last_commit_ts(root, &source_files);
+            match (spec_ts, source_ts) {
+                (Some(s), Some(c)) if c > s => {
+                    targets.push(CompileTarget {
+                        claude_md_path: format!("{}/CLAUDE.md", dir_str),
+                        implements_md_path: format!("{}/IMPLEMENTS.md", dir_str),
+                        dir: dir_str,
+                        reason: TargetReason::SourceNewer,
+                        details: format!("Source updated at {} > spec at {}", c, s),
+                    });
+                }
+                _ => {
+                    skipped.push(SkippedEntry {
+                        dir: dir_str,
+                        reason: "up-to-date".to_string(),
+                        details: "Spec is up-to-date with source code".to_string(),
+                    });
+                }
+            }
+        }
+    }
+
+    /// Directories containing changed source files (by extension, spec files excluded)
+    fn extract_source_dirs(&self, files: &[String]) -> HashSet<String> {
+        files
+            .iter()
+            .map(Path::new)
+            .filter(|p| {
+                p.extension()
+                    .and_then(|e| e.to_str())
+                    .is_some_and(|e| self.source_extensions.contains(e))
+            })
+            .filter_map(|p| p.parent().map(|d| d.to_string_lossy().to_string()))
+            .filter(|d| !d.is_empty())
+            .collect()
+    }
+
+    /// Classify directories by git staging state and commit timestamps
+    fn evaluate_with_git(
+        &self,
+        root: &Path,
+        all_claude_md_dirs: &[PathBuf],
+        targets: &mut Vec<CompileTarget>,
+        skipped: &mut Vec<SkippedEntry>,
+    ) {
+        // Get git status (one-time calls)
+        let staged_files = git_staged_files(root);
+        let modified_files = git_modified_files(root);
+        let untracked_files = git_untracked_files(root);
 
         // Extract dirs from staged/modified/untracked spec files
         let staged_spec_dirs = extract_spec_dirs(&staged_files);
         let modified_spec_dirs = extract_spec_dirs(&modified_files);
         let untracked_spec_dirs = extract_spec_dirs(&untracked_files);
 
-        // 4. Evaluate each directory
-        for dir in &all_claude_md_dirs {
+        // Evaluate each directory
+        for dir in all_claude_md_dirs {
             let dir_str = dir.to_string_lossy().to_string();
 
             if staged_spec_dirs.contains(&dir_str) {
@@ -163,11 +469,11 @@ impl CompileTargetResolver {
                 });
             } else {
                 // Compare commit timestamps
-                let spec_paths = self.spec_files_in(&root, dir);
-                let source_files = self.source_files_in(&root, dir);
+                let spec_paths = self.spec_files_in(root, dir);
+                let source_files = self.source_files_in(root, dir);
 
-                let spec_ts = git_last_commit_ts(&root, &spec_paths);
-                let source_ts = git_last_commit_ts(&root, &source_files);
+                let spec_ts = git_last_commit_ts(root, &spec_paths);
+                let source_ts = git_last_commit_ts(root, &source_files);
 
                 match (spec_ts, source_ts) {
                     (Some(_), None) if source_files.is_empty() => {
@@ -215,27 +521,84 @@ impl CompileTargetResolver {
                 }
             }
         }
+    }
 
-        // 5. Build dependency warnings
-        let reverse_deps = self.build_reverse_dependency_map(&root, &all_claude_md_dirs);
-        let target_dirs: HashSet<&str> = targets.iter().map(|t| t.dir.as_str()).collect();
-        dependency_warnings = self.generate_dependency_warnings(&reverse_deps, &target_dirs);
+    /// Classify directories by comparing content hashes against claude-md.lock
+    fn evaluate_with_lock(
+        &self,
+        root: &Path,
+        all_claude_md_dirs: &[PathBuf],
+        targets: &mut Vec<CompileTarget>,
+        skipped: &mut Vec<SkippedEntry>,
+        warnings: &mut Vec<DiffWarning>,
+    ) {
+        let lock = match CompileLock::load(root) {
+            Ok(Some(lock)) => lock,
+            Ok(None) => {
+                warnings.push(DiffWarning {
+                    warning_type: "no-lock-file".to_string(),
+                    message: format!("{} not found; every module is treated as not yet compiled.", LOCK_FILE_NAME),
+                });
+                CompileLock::default()
+            }
+            Err(e) => {
+                warnings.push(DiffWarning {
+                    warning_type: "invalid-lock-file".to_string(),
+                    message: format!("{}; every module is treated as not yet compiled.", e),
+                });
+                CompileLock::default()
+            }
+        };
 
-        // Sort targets by dir for consistent output
-        targets.sort_by(|a, b| a.dir.cmp(&b.dir));
-        skipped.sort_by(|a, b| a.dir.cmp(&b.dir));
+        for dir in all_claude_md_dirs {
+            let dir_str = dir.to_string_lossy().to_string();
+            let source_files = self.source_files_in(root, dir);
+            let current = LockEntry::compute(root, dir, &source_files);
+
+            let (reason, details) = match lock.modules.get(&dir_str) {
+                _ if source_files.is_empty() => (
+                    Some(TargetReason::NoSourceCode),
+                    "No source code files found (first compile)".to_string(),
+                ),
+                None => (
+                    Some(TargetReason::NotInLock),
+                    format!("No entry in {} (never compiled)", LOCK_FILE_NAME),
+                ),
+                Some(recorded) if recorded.spec_differs(&current) => (
+                    Some(TargetReason::SpecChanged),
+                    format!("Spec content changed since last compile ({})", recorded.changed_specs(&current).join(", ")),
+                ),
+                Some(recorded) if recorded.sources != current.sources => (
+                    None,
+                    "Source files changed since last compile; spec unchanged".to_string(),
+                ),
+                Some(_) => (None, String::new()),
+            };
 
-        DiffResult {
-            root,
-            targets,
-            skipped,
-            warnings,
-            dependency_warnings,
+            match reason {
+                Some(reason) => targets.push(CompileTarget {
+                    claude_md_path: format!("{}/CLAUDE.md", dir_str),
+                    implements_md_path: format!("{}/IMPLEMENTS.md", dir_str),
+                    dir: dir_str,
+                    reason,
+                    details,
+                }),
+                None if details.is_empty() => skipped.push(SkippedEntry {
+                    dir: dir_str,
+                    reason: "up-to-date".to_string(),
+                    details: "Content hashes match claude-md.lock".to_string(),
+                }),
+                None => skipped.push(SkippedEntry {
+                    dir: dir_str,
+                    reason: "source-changed".to_string(),
+                    details,
+                }),
+            }
         }
     }
 
     /// Scan for directories containing CLAUDE.md, excluding build dirs
-    fn scan_claude_md_dirs(&self, root: &Path) -> Vec<PathBuf> {
+    pub(crate) fn scan_claude_md_dirs(&self, root: &Path) -> Vec<PathBuf> {
         let mut dirs = Vec::new();
 
         let walker = walkdir::WalkDir::new(root)
@@ -277,7 +640,7 @@ impl CompileTargetResolver {
     }
 
     /// Get relative paths to source files in a directory (non-recursive)
-    fn source_files_in(&self, root: &Path, dir: &Path) -> Vec<String> {
+    pub(crate) fn source_files_in(&self, root: &Path, dir: &Path) -> Vec<String> {
         let abs_dir = root.join(dir);
         let mut paths = Vec::new();
 
@@ -304,7 +667,7 @@ impl CompileTargetResolver {
     }
 
     /// Build reverse dependency map: { dependency_dir -> [dependent_dirs] }
-    fn build_reverse_dependency_map(
+    pub(crate) fn build_reverse_dependency_map(
         &self,
         root: &Path,
         dirs: &[PathBuf],
@@ -380,6 +743,95 @@ impl Default for CompileTargetResolver {
     }
 }
 
+/// Transitive dependents of the changed dirs that are not changed themselves.
+/// Returns (dependent_dir, changed module it was reached from), sorted by dir.
+fn cascade_dependents(
+    reverse_deps: &HashMap<String, Vec<String>>,
+    changed: &[&str],
+) -> Vec<(String, String)> {
+    let mut reached: HashMap<String, String> = HashMap::new();
+    let mut queue: std::collections::VecDeque<(String, String)> = changed
+        .iter()
+        .map(|d| (d.to_string(), d.to_string()))
+        .collect();
+
+    while let Some((dir, cause)) = queue.pop_front() {
+        for dependent in reverse_deps.get(&dir).into_iter().flatten() {
+            if changed.contains(&dependent.as_str()) || reached.contains_key(dependent) {
+                continue;
+            }
+            reached.insert(dependent.clone(), cause.clone());
+            queue.push_back((dependent.clone(), cause.clone()));
+        }
+    }
+
+    let mut result: Vec<(String, String)> = reached.into_iter().collect();
+    result.sort();
+    result
+}
+
+/// Group targets into leaf-first batches (Kahn's algorithm, one layer per batch).
+/// Only edges between targets are considered. Returns the plan and any dirs left
+/// in a dependency cycle, which are appended as a final batch.
+fn build_compile_plan(
+    reverse_deps: &HashMap<String, Vec<String>>,
+    target_dirs: &HashSet<&str>,
+) -> (Vec<CompileBatch>, Vec<String>) {
+    // dependent -> dependencies, restricted to targets
+    let mut pending: HashMap<&str, HashSet<&str>> =
+        target_dirs.iter().map(|d| (*d, HashSet::new())).collect();
+    for (dependency, dependents) in reverse_deps {
+        if !target_dirs.contains(dependency.as_str()) {
+            continue;
+        }
+        for dependent in dependents {
+            if dependent != dependency {
+                if let Some(deps) = pending.get_mut(dependent.as_str()) {
+                    deps.insert(dependency.as_str());
+                }
+            }
+        }
+    }
+
+    let mut plan = Vec::new();
+    while !pending.is_empty() {
+        let mut ready: Vec<&str> = pending
+            .iter()
+            .filter(|(_, deps)| deps.is_empty())
+            .map(|(dir, _)| *dir)
+            .collect();
+        if ready.is_empty() {
+            break;
+        }
+        ready.sort();
+
+        for dir in &ready {
+            pending.remove(dir);
+        }
+        for deps in pending.values_mut() {
+            for dir in &ready {
+                deps.remove(dir);
+            }
+        }
+
+        plan.push(CompileBatch {
+            batch: plan.len() + 1,
+            dirs: ready.iter().map(|d| d.to_string()).collect(),
+        });
+    }
+
+    let mut cyclic: Vec<String> = pending.keys().map(|d| d.to_string()).collect();
+    cyclic.sort();
+    if !cyclic.is_empty() {
+        plan.push(CompileBatch {
+            batch: plan.len() + 1,
+            dirs: cyclic.clone(),
+        });
+    }
+
+    (plan, cyclic)
+}
+
 // ============== Git helper functions ==============
 
 /// Check if the given path is inside a git repository
@@ -487,6 +939,29 @@ fn git_last_commit_ts(root: &Path, paths: &[String]) -> Option<u64> {
         })
 }
 
+/// Get files changed between the merge base of `since` and HEAD, relative to root.
+/// Returns None if the ref cannot be resolved.
+fn git_changed_files_since(root: &Path, since: &str) -> Option<Vec<String>> {
+    Command::new("git")
+        .args(["diff", "--name-only", "--relative", &format!("{}...HEAD", since)])
+        .current_dir(root)
+        .output()
+        .ok()
+        .and_then(|o| {
+            if o.status.success() {
+                Some(
+                    String::from_utf8_lossy(&o.stdout)
+                        .lines()
+                        .filter(|l| !l.is_empty())
+                        .map(|l| l.to_string())
+                        .collect(),
+                )
+            } else {
+                None
+            }
+        })
+}
+
 // ============== Utility functions ==============
 
 /// Extract directory paths from file paths that contain CLAUDE.md or IMPLEMENTS.md
@@ -510,30 +985,15 @@ fn extract_spec_dirs(files: &[String]) -> HashSet<String> {
 
 /// Extract the "### Internal" subsection from Dependencies section of CLAUDE.md content
 fn extract_internal_deps_section(content: &str) -> Option<String> {
-    let lines: Vec<&str> = content.lines().collect();
-    let mut in_deps = false;
-    let mut in_internal = false;
-    let mut section_lines = Vec::new();
-
-    for line in &lines {
-        if line.starts_with("## Dependencies") {
-            in_deps = true;
-            continue;
-        }
-        if in_deps && line.starts_with("## ") && !line.starts_with("## Dependencies") {
-            break; // Exit Dependencies section
-        }
-        if in_deps && line.starts_with("### Internal") {
-            in_internal = true;
-            continue;
-        }
-        if in_internal && line.starts_with("### ") {
-            break; // Exit Internal subsection
-        }
-        if in_internal {
-            section_lines.push(*line);
-        }
-    }
+    let sections = parse_sections(content);
+    let deps_index = find_section(&sections, 2, "Dependencies")?;
+    let deps_tree = with_subsections(&sections, deps_index);
+    let internal_index = find_section(deps_tree, 3, "Internal")?;
+
+    let section_lines: Vec<&str> = with_subsections(deps_tree, internal_index)
+        .iter()
+        .flat_map(|s| s.content.iter().map(String::as_str))
+        .collect();
 
     if section_lines.is_empty() {
         None
@@ -631,4 +1091,48 @@ Test module
         assert!(!result.warnings.is_empty());
         assert_eq!(result.warnings[0].warning_type, "no-git-repo");
     }
+
+    fn reverse_map(edges: &[(&str, &str)]) -> HashMap<String, Vec<String>> {
+        // (dependent, dependency) pairs → { dependency -> [dependents] }
+        let mut map: HashMap<String, Vec<String>> = HashMap::new();
+        for (dependent, dependency) in edges {
+            map.entry(dependency.to_string()).or_default().push(dependent.to_string());
+        }
+        map
+    }
+
+    #[test]
+    fn test_cascade_dependents_is_transitive() {
+        let reverse = reverse_map(&[("src/auth", "core/domain"), ("src/api", "src/auth"), ("src/web", "src/api")]);
+        let cascaded = cascade_dependents(&reverse, &["core/domain"]);
+
+        assert_eq!(cascaded, vec![
+            ("src/api".to_string(), "core/domain".to_string()),
+            ("src/auth".to_string(), "core/domain".to_string()),
+            ("src/web".to_string(), "core/domain".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_build_compile_plan_leaf_first() {
+        let reverse = reverse_map(&[("b", "a"), ("c", "a"), ("d", "b"), ("d", "c")]);
+        let targets: HashSet<&str> = ["a", "b", "c", "d"].into_iter().collect();
+        let (plan, cyclic) = build_compile_plan(&reverse, &targets);
+
+        assert!(cyclic.is_empty());
+        let batches: Vec<Vec<String>> = plan.into_iter().map(|b| b.dirs).collect();
+        assert_eq!(batches, vec![vec!["a"], vec!["b", "c"], vec!["d"]]);
+    }
+
+    #[test]
+    fn test_build_compile_plan_cycle_goes_last() {
+        let reverse = reverse_map(&[("x", "y"), ("y", "x"), ("x", "leaf")]);
+        let targets: HashSet<&str> = ["x", "y", "leaf"].into_iter().collect();
+        let (plan, cyclic) = build_compile_plan(&reverse, &targets);
+
+        assert_eq!(cyclic, vec!["x", "y"]);
+        assert_eq!(plan.len(), 2);
+        assert_eq!(plan[0].dirs, vec!["leaf"]);
+        assert_eq!(plan[1].dirs, vec!["x", "y"]);
+    }
 }
@@ -1,8 +1,9 @@
-use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
+use crate::markdown::{find_section, parse_sections, with_subsections, MarkdownSection};
+
 // Include generated constants from schema-rules.yaml (SSOT)
 include!(concat!(env!("OUT_DIR"), "/schema_rules.rs"));
 
@@ -34,17 +35,11 @@ pub struct ModuleConventionResult {
     pub project_convention_override: Option<ConventionCheck>,
 }
 
-pub struct ConventionValidator {
-    h2_pattern: Regex,
-    h3_pattern: Regex,
-}
+pub struct ConventionValidator;
 
 impl ConventionValidator {
     pub fn new() -> Self {
-        Self {
-            h2_pattern: Regex::new(r"^##\s+(.+)$").unwrap(),
-            h3_pattern: Regex::new(r"^###\s+(.+)$").unwrap(),
-        }
+        Self
     }
 
     /// Main validation entry point
@@ -110,7 +105,7 @@ impl ConventionValidator {
                 } else {
                     // Single-module: already validated at project_root level (1b)
                     // Or section_found=true but malformed → report errors
-                    if !(module_root == project_root) {
+                    if module_root != project_root {
                         // Only add errors for non-root modules with malformed sections
                         for err in &code_convention.errors {
                             errors.push(err.clone());
@@ -212,10 +207,8 @@ impl ConventionValidator {
                 continue;
             }
 
-            if self.is_module_root(&path) && path != project_root {
-                if !results.contains(&path) {
-                    results.push(path.clone());
-                }
+            if self.is_module_root(&path) && path != project_root && !results.contains(&path) {
+                results.push(path.clone());
             }
 
             self.find_module_roots_recursive(&path, project_root, results, depth + 1, max_depth);
@@ -257,9 +250,10 @@ impl ConventionValidator {
         };
 
         // Find the H2 section
-        let section_content = self.extract_h2_section(&content, section_name);
+        let sections = parse_sections(&content);
+        let section_tree = find_section(&sections, 2, section_name).map(|index| with_subsections(&sections, index));
 
-        match section_content {
+        match section_tree {
             None => ConventionCheck {
                 valid: false,
                 file: file_str,
@@ -274,13 +268,13 @@ impl ConventionValidator {
                     section_name
                 )],
             },
-            Some(section_text) => {
+            Some(section_tree) => {
                 let mut errors = Vec::new();
                 let mut subsection_map = HashMap::new();
 
                 // Check each required subsection (H3)
                 for subsection in required_subsections {
-                    let found = self.has_h3_subsection(&section_text, subsection);
+                    let found = self.has_h3_subsection(section_tree, subsection);
                     subsection_map.insert(subsection.to_string(), found);
                     if !found {
                         errors.push(format!(
@@ -303,45 +297,11 @@ impl ConventionValidator {
         }
     }
 
-    /// Extract the content of an H2 section (from ## header to next ## header or EOF)
-    fn extract_h2_section(&self, content: &str, section_name: &str) -> Option<String> {
-        let mut in_section = false;
-        let mut section_lines = Vec::new();
-
-        for line in content.lines() {
-            if let Some(caps) = self.h2_pattern.captures(line) {
-                if in_section {
-                    // Hit the next H2 section, stop
-                    break;
-                }
-                let header = caps.get(1).map(|m| m.as_str()).unwrap_or("");
-                if header.eq_ignore_ascii_case(section_name) {
-                    in_section = true;
-                    continue;
-                }
-            } else if in_section {
-                section_lines.push(line);
-            }
-        }
-
-        if in_section {
-            Some(section_lines.join("\n"))
-        } else {
-            None
-        }
-    }
-
-    /// Check if section content contains a specific H3 subsection
-    fn has_h3_subsection(&self, section_content: &str, subsection_name: &str) -> bool {
-        for line in section_content.lines() {
-            if let Some(caps) = self.h3_pattern.captures(line) {
-                let header = caps.get(1).map(|m| m.as_str()).unwrap_or("");
-                if header.eq_ignore_ascii_case(subsection_name) {
-                    return true;
-                }
-            }
-        }
-        false
+    /// Check if an H2 section tree contains a specific H3 subsection
+    fn has_h3_subsection(&self, section_tree: &[MarkdownSection], subsection_name: &str) -> bool {
+        section_tree
+            .iter()
+            .any(|s| s.level == 3 && s.name.eq_ignore_ascii_case(subsection_name))
     }
 }
 
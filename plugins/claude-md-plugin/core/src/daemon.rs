@@ -0,0 +1,929 @@
+//! Long-running JSON-RPC daemon (`serve`) that exposes every subcommand as a method.
+//!
+//! Analyzers are built on first use and kept for the daemon's lifetime instead of once
+//! per invocation, and results of read-only methods are cached. A cached result is
+//! reused while the fingerprint (path, mtime, size) of the files it was computed from
+//! is unchanged, so repeated queries skip re-reading and re-parsing the project. Code
+//! analysis is fingerprinted over the whole enclosing project, since imports are
+//! classified against go.mod, Cargo.toml and sibling packages outside the analyzed
+//! path, and is not cached when no project root is found. The cache holds at most [`MAX_CACHE_ENTRIES`] results, evicting the least recently used.
+//! Methods that depend on git state or write files are never cached.
+//!
+//! Method names are the subcommand names (`parse-tree`, `analyze-code`, ...) and params
+//! are the long flags in snake_case (`{"root": "."}`, `{"path": "src/auth", "backend": ["tree-sitter"]}`).
+//! Results are returned instead of written, so there is no `output` param (except
+//! `fix-schema`, where it is the file to write). Markdown/DOT outputs are JSON strings.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+use walkdir::WalkDir;
+
+use crate::boundary_resolver::BoundaryResolver;
+use crate::changelog::ChangelogGenerator;
+use crate::claude_md_formatter::ClaudeMdFormatter;
+use crate::claude_md_parser::{ClaudeMdParser, ClaudeMdSpec};
+use crate::claude_md_renderer::render_claude_md;
+use crate::claude_md_scanner::ClaudeMdScanner;
+use crate::code_analyzer::{AnalysisResult, CodeAnalyzer};
+use crate::compile_lock::CompileLock;
+use crate::compile_target_resolver::{CompileTargetResolver, ResolveOptions};
+use crate::convention_validator::ConventionValidator;
+use crate::dependency_graph::DependencyGraph;
+use crate::dependency_resolver::{self, DependencyResolver};
+use crate::drift_detector::DriftDetector;
+use crate::implements_md_parser::ImplementsMdParser;
+use crate::jsonrpc::{self, JsonRpcError};
+use crate::schema_validator::SchemaValidator;
+use crate::spec_diff::{Severity, SpecDiffer};
+use crate::tree_parser::{TreeParser, TreeResult};
+use crate::{analysis_formatter, exports_formatter, EXCLUDED_DIRS};
+
+#[derive(Debug, Error)]
+pub enum DaemonError {
+    #[error("Unknown method: {0}")]
+    UnknownMethod(String),
+
+    #[error("Invalid params: {0}")]
+    InvalidParams(String),
+
+    /// The command itself failed; `data` carries its result when it failed a `strict` check
+    #[error("{message}")]
+    CommandFailed { message: String, data: Option<Value> },
+
+    #[error(transparent)]
+    Transport(#[from] JsonRpcError),
+
+    #[error("Socket error: {0}")]
+    Socket(#[from] std::io::Error),
+}
+
+impl DaemonError {
+    /// JSON-RPC error response to the request `id`
+    pub fn to_response(&self, id: &Value) -> Value {
+        let code = match self {
+            DaemonError::UnknownMethod(_) => jsonrpc::METHOD_NOT_FOUND,
+            DaemonError::InvalidParams(_) => jsonrpc::INVALID_PARAMS,
+            _ => jsonrpc::SERVER_ERROR,
+        };
+        match self {
+            DaemonError::CommandFailed { data: Some(data), .. } => {
+                jsonrpc::error_response_with_data(id, code, &self.to_string(), data.clone())
+            }
+            _ => jsonrpc::error_response(id, code, &self.to_string()),
+        }
+    }
+}
+
+/// Cache counters for the lifetime of the daemon
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct CacheStats {
+    pub entries: usize,
+    pub hits: usize,
+    pub misses: usize,
+}
+
+/// Code analyzer for `backend` specs, built on first use and kept for the session.
+/// Takes the map rather than the daemon so callers can borrow other fields alongside it.
+fn analyzer<'a>(analyzers: &'a mut HashMap<String, CodeAnalyzer>, backend: &[String]) -> Result<&'a CodeAnalyzer, DaemonError> {
+    let key = backend.join(",");
+    if !analyzers.contains_key(&key) {
+        let analyzer = CodeAnalyzer::with_backend_specs(backend).map_err(failed)?;
+        analyzers.insert(key.clone(), analyzer);
+    }
+    Ok(&analyzers[&key])
+}
+
+/// Files a cached result was computed from
+enum Scope {
+    /// Directory (or single file) walked recursively, excluded dirs pruned
+    Tree(PathBuf),
+    /// A file together with its directory listing (covers siblings like IMPLEMENTS.md)
+    File(PathBuf),
+}
+
+/// Cached results kept before the least recently used one is evicted
+pub const MAX_CACHE_ENTRIES: usize = 256;
+
+struct CacheEntry {
+    fingerprint: u64,
+    result: Value,
+    /// Value of `Daemon::clock` when the entry was last stored or served
+    last_used: u64,
+}
+
+pub struct Daemon {
+    tree_parser: TreeParser,
+    /// Code analyzers by joined `backend` specs ("" = default backends)
+    analyzers: HashMap<String, CodeAnalyzer>,
+    scanner: ClaudeMdScanner,
+    validator: SchemaValidator,
+    boundary: BoundaryResolver,
+    claude_md_parser: ClaudeMdParser,
+    implements_parser: ImplementsMdParser,
+    convention: ConventionValidator,
+    drift: DriftDetector,
+    formatter: ClaudeMdFormatter,
+    /// Results by `method:params`
+    cache: HashMap<String, CacheEntry>,
+    cache_capacity: usize,
+    /// Incremented on every cached lookup, orders entries for eviction
+    clock: u64,
+    stats: CacheStats,
+}
+
+impl Daemon {
+    pub fn new() -> Self {
+        Self {
+            tree_parser: TreeParser::new(),
+            analyzers: HashMap::new(),
+            scanner: ClaudeMdScanner::new(),
+            validator: SchemaValidator::new(),
+            boundary: BoundaryResolver::new(),
+            claude_md_parser: ClaudeMdParser::new(),
+            implements_parser: ImplementsMdParser::new(),
+            convention: ConventionValidator::new(),
+            drift: DriftDetector::new(),
+            formatter: ClaudeMdFormatter::new(),
+            cache: HashMap::new(),
+            cache_capacity: MAX_CACHE_ENTRIES,
+            clock: 0,
+            stats: CacheStats::default(),
+        }
+    }
+
+    pub fn cache_stats(&self) -> CacheStats {
+        CacheStats {
+            entries: self.cache.len(),
+            ..self.stats
+        }
+    }
+
+    /// Serve framed JSON-RPC requests until end of input or a `shutdown` request.
+    /// Returns `true` when the client asked the daemon to shut down.
+    pub fn serve<R: BufRead, W: Write>(&mut self, reader: &mut R, writer: &mut W) -> Result<bool, JsonRpcError> {
+        loop {
+            let message = match jsonrpc::read_message(reader) {
+                Ok(Some(message)) => message,
+                Ok(None) => return Ok(false),
+                Err(JsonRpcError::InvalidJson(e)) => {
+                    let reply = jsonrpc::error_response(&Value::Null, jsonrpc::PARSE_ERROR, &e.to_string());
+                    jsonrpc::write_message(writer, &reply)?;
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+
+            // Notifications get no reply, and no method has side effects worth running blind
+            let Some(id) = message.get("id") else { continue };
+            let method = message["method"].as_str().unwrap_or_default();
+            if method == "shutdown" {
+                jsonrpc::write_message(writer, &jsonrpc::response(id, Value::Null))?;
+                return Ok(true);
+            }
+
+            let reply = match self.call(method, message["params"].clone()) {
+                Ok(result) => jsonrpc::response(id, result),
+                Err(e) => e.to_response(id),
+            };
+            jsonrpc::write_message(writer, &reply)?;
+        }
+    }
+
+    /// Listen on a unix socket, serving one client connection at a time until `shutdown`.
+    #[cfg(unix)]
+    pub fn serve_socket(&mut self, path: &Path) -> Result<(), DaemonError> {
+        use std::io::BufReader;
+        use std::os::unix::fs::FileTypeExt;
+        use std::os::unix::net::{UnixListener, UnixStream};
+
+        // Replace a socket left behind by a daemon that is no longer running
+        let stale = std::fs::symlink_metadata(path).is_ok_and(|m| m.file_type().is_socket())
+            && UnixStream::connect(path).is_err();
+        if stale {
+            std::fs::remove_file(path)?;
+        }
+
+        let listener = UnixListener::bind(path)?;
+        for stream in listener.incoming() {
+            let stream = stream?;
+            let mut reader = BufReader::new(stream.try_clone()?);
+            let mut writer = stream;
+            match self.serve(&mut reader, &mut writer) {
+                Ok(true) => break,
+                Ok(false) => {}
+                Err(e) => eprintln!("Warning: client connection closed: {}", e),
+            }
+        }
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+
+    #[cfg(not(unix))]
+    pub fn serve_socket(&mut self, _path: &Path) -> Result<(), DaemonError> {
+        Err(DaemonError::Socket(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "unix sockets are not supported on this platform",
+        )))
+    }
+
+    /// Run one method, answering from the cache while its input files are unchanged.
+    pub fn call(&mut self, method: &str, params: Value) -> Result<Value, DaemonError> {
+        let params = if params.is_null() { json!({}) } else { params };
+        let Some(scopes) = cache_scopes(method, &params) else {
+            return self.dispatch(method, &params);
+        };
+
+        let key = format!("{}:{}", method, params);
+        let fingerprint = fingerprint(&scopes);
+        self.clock += 1;
+        if let Some(entry) = self.cache.get_mut(&key) {
+            if entry.fingerprint == fingerprint {
+                entry.last_used = self.clock;
+                self.stats.hits += 1;
+                return Ok(entry.result.clone());
+            }
+        }
+
+        self.stats.misses += 1;
+        let result = self.dispatch(method, &params)?;
+        if !self.cache.contains_key(&key) && self.cache.len() >= self.cache_capacity {
+            self.evict_least_recently_used();
+        }
+        let entry = CacheEntry { fingerprint, result: result.clone(), last_used: self.clock };
+        self.cache.insert(key, entry);
+        Ok(result)
+    }
+
+    fn evict_least_recently_used(&mut self) {
+        let oldest = self.cache.iter()
+            .min_by_key(|(_, entry)| entry.last_used)
+            .map(|(key, _)| key.clone());
+        if let Some(key) = oldest {
+            self.cache.remove(&key);
+        }
+    }
+
+    fn dispatch(&mut self, method: &str, params: &Value) -> Result<Value, DaemonError> {
+        match method {
+            "parse-tree" => {
+                let p: RootParams = from_params(params)?;
+                to_json(&self.tree_parser.parse(&p.root))
+            }
+            "resolve-boundary" => {
+                let p: ResolveBoundaryParams = from_params(params)?;
+                to_json(&self.boundary.resolve(&p.path, p.claude_md.as_ref()))
+            }
+            "validate-schema" => {
+                let p: ValidateParams = from_params(params)?;
+                let mut result = self.validator.validate(&p.file);
+                if p.strict {
+                    result.promote_inv3_warnings();
+                }
+                to_json(&result)
+            }
+            "parse-claude-md" => {
+                let p: ParseClaudeMdParams = from_params(params)?;
+                if p.lenient {
+                    to_json(&self.claude_md_parser.parse_lenient(&p.file).map_err(failed)?)
+                } else {
+                    to_json(&self.claude_md_parser.parse(&p.file).map_err(failed)?)
+                }
+            }
+            "render-claude-md" => {
+                let p: InputParams = from_params(params)?;
+                let spec: ClaudeMdSpec = read_json(&p.input, "parse-claude-md")?;
+                Ok(Value::String(render_claude_md(&spec).trim_end().to_string()))
+            }
+            "fmt-claude-md" => self.fmt_claude_md(from_params(params)?),
+            "parse-implements-md" => {
+                let p: FileParams = from_params(params)?;
+                to_json(&self.implements_parser.parse(&p.file).map_err(failed)?)
+            }
+            "validate-implements" => {
+                let p: ValidateParams = from_params(params)?;
+                let mut result = self.validator.validate_implements(&p.file);
+                if p.strict {
+                    result.promote_inv3_warnings();
+                }
+                to_json(&result)
+            }
+            "validate-convention" => {
+                let p: ValidateConventionParams = from_params(params)?;
+                to_json(&self.convention.validate(&p.project_root, p.module_roots))
+            }
+            "analyze-code" => {
+                let p: AnalyzeCodeParams = from_params(params)?;
+                let files: Option<Vec<&str>> = p.files.as_ref()
+                    .map(|f| f.iter().map(String::as_str).collect());
+                let mut result = analyzer(&mut self.analyzers, &p.backend)?
+                    .analyze_directory(&p.path, files.as_deref())
+                    .map_err(failed)?;
+                if let Some(tree_path) = &p.tree_result {
+                    let tree: TreeResult = read_json(tree_path, "parse-tree")?;
+                    let source_dir = p.path.strip_prefix(&tree.root).unwrap_or(&p.path);
+                    DependencyResolver::new(&tree).resolve(&mut result, source_dir);
+                }
+                to_json(&result)
+            }
+            "index-project" => {
+                let p: RootParams = from_params(params)?;
+                let tree_result = self.tree_parser.parse(&p.root);
+                let analyzer = analyzer(&mut self.analyzers, &[])?;
+                to_json(&dependency_resolver::index_project(&p.root, tree_result, analyzer))
+            }
+            "scan-claude-md" => {
+                let p: RootParams = from_params(params)?;
+                to_json(&self.scanner.scan(&p.root))
+            }
+            "diff-compile-targets" => {
+                let p: DiffCompileTargetsParams = from_params(params)?;
+                let options = ResolveOptions {
+                    cascade: p.cascade,
+                    use_lock: p.lock,
+                    all: p.all,
+                    since: p.since,
+                };
+                to_json(&CompileTargetResolver::new().resolve_with(&p.root, &options))
+            }
+            "diff-decompile-targets" => {
+                let p: DiffDecompileTargetsParams = from_params(params)?;
+                to_json(&CompileTargetResolver::new().resolve_decompile(&p.root, p.lock))
+            }
+            "update-lock" => {
+                let p: UpdateLockParams = from_params(params)?;
+                to_json(&CompileLock::update(&p.root, &p.dirs).map_err(failed)?)
+            }
+            "format-exports" => {
+                let p: InputParams = from_params(params)?;
+                let analysis: AnalysisResult = read_json(&p.input, "analyze-code")?;
+                Ok(Value::String(exports_formatter::format_exports(&analysis.exports)))
+            }
+            "format-analysis" => {
+                let p: InputParams = from_params(params)?;
+                let analysis: AnalysisResult = read_json(&p.input, "analyze-code")?;
+                Ok(Value::String(analysis_formatter::format_analysis(&analysis)))
+            }
+            "fix-schema" => {
+                let p: FixSchemaParams = from_params(params)?;
+                let content = std::fs::read_to_string(&p.file)
+                    .map_err(|e| failed(format!("Failed to read CLAUDE.md '{}': {}", p.file.display(), e)))?;
+                let (fixed, added) = self.validator.fix_missing_sections(&content);
+                if added.is_empty() {
+                    return Ok(json!({ "added": added, "output": null }));
+                }
+                let target = p.output.unwrap_or(p.file);
+                std::fs::write(&target, &fixed)
+                    .map_err(|e| failed(format!("Failed to write fixed CLAUDE.md to '{}': {}", target.display(), e)))?;
+                Ok(json!({ "added": added, "output": target }))
+            }
+            "check-drift" => {
+                let p: StrictPathParams = from_params(params)?;
+                let analyzer = analyzer(&mut self.analyzers, &[])?;
+                let report = self.drift.check(analyzer, &p.path).map_err(failed)?;
+                let result = to_json(&report)?;
+                if p.strict && report.has_drift {
+                    return Err(strict_failure(
+                        format!(
+                            "{} drift finding(s) between '{}' and its source code",
+                            report.findings.len(), report.claude_md
+                        ),
+                        result,
+                    ));
+                }
+                Ok(result)
+            }
+            "diff-spec" => {
+                let p: DiffSpecParams = from_params(params)?;
+                let report = SpecDiffer::new().diff_against(&p.file, &p.base).map_err(failed)?;
+                let result = to_json(&report)?;
+                if p.strict && report.bump == Some(Severity::Major) {
+                    return Err(strict_failure(
+                        format!(
+                            "{} breaking change(s) in '{}' since '{}'",
+                            report.summary.major, report.claude_md, report.base
+                        ),
+                        result,
+                    ));
+                }
+                Ok(result)
+            }
+            "changelog" => {
+                let p: ChangelogParams = from_params(params)?;
+                let changelog = ChangelogGenerator::new()
+                    .generate(&p.root, p.since.as_deref())
+                    .map_err(failed)?;
+                match p.format {
+                    ChangelogFormat::Markdown => Ok(Value::String(changelog.to_markdown().trim_end().to_string())),
+                    ChangelogFormat::Json => to_json(&changelog),
+                }
+            }
+            "graph" => {
+                let p: GraphParams = from_params(params)?;
+                let graph = DependencyGraph::build(&p.root);
+                let result = match p.format {
+                    GraphFormat::Json => to_json(&graph)?,
+                    GraphFormat::Dot => Value::String(graph.to_dot()),
+                    GraphFormat::Mermaid => Value::String(graph.to_mermaid()),
+                };
+                if graph.has_cycles() {
+                    let cycles: Vec<String> = graph.cycles.iter().map(|c| c.message.clone()).collect();
+                    return Err(strict_failure(
+                        format!("{} dependency cycle(s) found: {}", cycles.len(), cycles.join("; ")),
+                        result,
+                    ));
+                }
+                Ok(result)
+            }
+            _ => Err(DaemonError::UnknownMethod(method.to_string())),
+        }
+    }
+
+    /// Formatted text, or `{"reformatted": [...]}` with `check`/`write`
+    fn fmt_claude_md(&self, p: FmtClaudeMdParams) -> Result<Value, DaemonError> {
+        if p.check && p.write {
+            return Err(DaemonError::InvalidParams("'check' cannot be used with 'write'".to_string()));
+        }
+
+        let mut text = String::new();
+        let mut reformatted = Vec::new();
+        for file in &p.file {
+            let content = std::fs::read_to_string(file)
+                .map_err(|e| failed(format!("Failed to read CLAUDE.md '{}': {}", file.display(), e)))?;
+            let formatted = self.formatter.format(&content);
+            if !p.check && !p.write {
+                text.push_str(&formatted);
+                continue;
+            }
+            if formatted == content {
+                continue;
+            }
+            if p.write {
+                std::fs::write(file, &formatted)
+                    .map_err(|e| failed(format!("Failed to write CLAUDE.md '{}': {}", file.display(), e)))?;
+            }
+            reformatted.push(file.clone());
+        }
+
+        if !p.check && !p.write {
+            return Ok(Value::String(text));
+        }
+        let result = json!({ "reformatted": reformatted });
+        if p.check && !reformatted.is_empty() {
+            return Err(strict_failure(format!("{} file(s) not formatted", reformatted.len()), result));
+        }
+        Ok(result)
+    }
+}
+
+impl Default for Daemon {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Input scopes of cacheable methods; `None` for methods that read git state or write files
+fn cache_scopes(method: &str, params: &Value) -> Option<Vec<Scope>> {
+    let path = |key: &str| params.get(key).and_then(Value::as_str).map(PathBuf::from);
+    let root = path("root").unwrap_or_else(|| PathBuf::from("."));
+
+    let scopes = match method {
+        "parse-tree" | "scan-claude-md" | "index-project" | "graph" => vec![Scope::Tree(root)],
+        "resolve-boundary" => path("path").map(Scope::Tree).into_iter()
+            .chain(path("claude_md").map(Scope::File))
+            .collect(),
+        // Imports are classified against go.mod, Cargo.toml and sibling package
+        // directories outside `path`, so the whole project is in scope
+        "analyze-code" | "check-drift" => {
+            let project = project_root(&path("path")?)?;
+            std::iter::once(Scope::Tree(project))
+                .chain(path("tree_result").map(Scope::File))
+                .collect()
+        }
+        "validate-schema" | "parse-claude-md" | "parse-implements-md" | "validate-implements" => {
+            path("file").map(Scope::File).into_iter().collect()
+        }
+        "render-claude-md" | "format-exports" | "format-analysis" => {
+            path("input").map(Scope::File).into_iter().collect()
+        }
+        "validate-convention" => {
+            let module_roots = params.get("module_roots").and_then(Value::as_array).into_iter().flatten();
+            path("project_root").into_iter()
+                .chain(module_roots.filter_map(Value::as_str).map(PathBuf::from))
+                .map(Scope::Tree)
+                .collect()
+        }
+        _ => return None,
+    };
+    Some(scopes)
+}
+
+/// Files and directories marking a project root (build manifests, repository root)
+const PROJECT_ROOT_MARKERS: &[&str] = &[
+    "go.mod", "Cargo.toml", "Package.swift", "pom.xml", "build.gradle", "build.gradle.kts",
+    "build.sbt", "package.json", "pyproject.toml", ".git",
+];
+
+/// Outermost directory at or above `path` containing a project root marker; `None`
+/// when there is none, since import resolution may then read anywhere above `path`.
+fn project_root(path: &Path) -> Option<PathBuf> {
+    let path = path.canonicalize().ok()?;
+    path.ancestors()
+        .filter(|dir| PROJECT_ROOT_MARKERS.iter().any(|marker| dir.join(marker).exists()))
+        .last()
+        .map(Path::to_path_buf)
+}
+
+/// Hash of (path, size, mtime) for every file and directory in `scopes`. Directory
+/// entries are included so that added, removed and renamed files change the hash too.
+fn fingerprint(scopes: &[Scope]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for scope in scopes {
+        let (dir, max_depth) = match scope {
+            Scope::Tree(path) => (path.as_path(), usize::MAX),
+            Scope::File(path) => (
+                path.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new(".")),
+                1,
+            ),
+        };
+        let walker = WalkDir::new(dir)
+            .max_depth(max_depth)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_entry(|e| {
+                e.depth() == 0
+                    || !e.file_type().is_dir()
+                    || !e.file_name().to_str().is_some_and(|n| EXCLUDED_DIRS.contains(&n))
+            });
+        for entry in walker.filter_map(Result::ok) {
+            entry.path().hash(&mut hasher);
+            if let Ok(metadata) = entry.metadata() {
+                metadata.len().hash(&mut hasher);
+                metadata.modified().ok().hash(&mut hasher);
+            }
+        }
+    }
+    hasher.finish()
+}
+
+fn from_params<T: DeserializeOwned>(params: &Value) -> Result<T, DaemonError> {
+    serde_json::from_value(params.clone()).map_err(|e| DaemonError::InvalidParams(e.to_string()))
+}
+
+fn to_json<T: Serialize>(result: &T) -> Result<Value, DaemonError> {
+    serde_json::to_value(result).map_err(|e| failed(format!("Failed to serialize result to JSON: {}", e)))
+}
+
+/// Read a JSON file produced by another command (`kind`)
+fn read_json<T: DeserializeOwned>(path: &Path, kind: &str) -> Result<T, DaemonError> {
+    let json = std::fs::read_to_string(path)
+        .map_err(|e| failed(format!("Failed to read input file '{}': {}", path.display(), e)))?;
+    serde_json::from_str(&json)
+        .map_err(|e| failed(format!("Failed to parse {} JSON from '{}': {}", kind, path.display(), e)))
+}
+
+fn failed(error: impl std::fmt::Display) -> DaemonError {
+    DaemonError::CommandFailed { message: error.to_string(), data: None }
+}
+
+fn strict_failure(message: String, result: Value) -> DaemonError {
+    DaemonError::CommandFailed { message, data: Some(result) }
+}
+
+fn default_root() -> PathBuf {
+    PathBuf::from(".")
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RootParams {
+    #[serde(default = "default_root")]
+    root: PathBuf,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FileParams {
+    file: PathBuf,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct InputParams {
+    input: PathBuf,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ValidateParams {
+    file: PathBuf,
+    #[serde(default)]
+    strict: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StrictPathParams {
+    path: PathBuf,
+    #[serde(default)]
+    strict: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ResolveBoundaryParams {
+    path: PathBuf,
+    claude_md: Option<PathBuf>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ParseClaudeMdParams {
+    file: PathBuf,
+    #[serde(default)]
+    lenient: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FmtClaudeMdParams {
+    file: Vec<PathBuf>,
+    #[serde(default)]
+    check: bool,
+    #[serde(default)]
+    write: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ValidateConventionParams {
+    project_root: PathBuf,
+    module_roots: Option<Vec<PathBuf>>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AnalyzeCodeParams {
+    path: PathBuf,
+    files: Option<Vec<String>>,
+    tree_result: Option<PathBuf>,
+    #[serde(default)]
+    backend: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DiffCompileTargetsParams {
+    #[serde(default = "default_root")]
+    root: PathBuf,
+    #[serde(default)]
+    cascade: bool,
+    #[serde(default)]
+    lock: bool,
+    #[serde(default)]
+    all: bool,
+    since: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DiffDecompileTargetsParams {
+    #[serde(default = "default_root")]
+    root: PathBuf,
+    #[serde(default)]
+    lock: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct UpdateLockParams {
+    #[serde(default = "default_root")]
+    root: PathBuf,
+    #[serde(default)]
+    dirs: Vec<PathBuf>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FixSchemaParams {
+    file: PathBuf,
+    output: Option<PathBuf>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DiffSpecParams {
+    file: PathBuf,
+    base: String,
+    #[serde(default)]
+    strict: bool,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+enum ChangelogFormat {
+    #[default]
+    Markdown,
+    Json,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ChangelogParams {
+    #[serde(default = "default_root")]
+    root: PathBuf,
+    since: Option<String>,
+    #[serde(default)]
+    format: ChangelogFormat,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+enum GraphFormat {
+    #[default]
+    Json,
+    Dot,
+    Mermaid,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GraphParams {
+    #[serde(default = "default_root")]
+    root: PathBuf,
+    #[serde(default)]
+    format: GraphFormat,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::io::Cursor;
+    use tempfile::TempDir;
+
+    fn write_spec(dir: &Path, purpose: &str) {
+        fs::create_dir_all(dir).unwrap();
+        fs::write(dir.join("CLAUDE.md"), format!("# module\n\n## Purpose\n{}\n", purpose)).unwrap();
+    }
+
+    fn purposes(result: &Value) -> Vec<&str> {
+        result["entries"].as_array().unwrap().iter().map(|e| e["purpose"].as_str().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_repeated_call_is_served_from_cache() {
+        let temp = TempDir::new().unwrap();
+        write_spec(&temp.path().join("src/auth"), "Token validation.");
+        let mut daemon = Daemon::new();
+        let params = json!({ "root": temp.path() });
+
+        let first = daemon.call("scan-claude-md", params.clone()).unwrap();
+        let second = daemon.call("scan-claude-md", params).unwrap();
+
+        assert_eq!(first, second);
+        let stats = daemon.cache_stats();
+        assert_eq!((stats.entries, stats.hits, stats.misses), (1, 1, 1));
+    }
+
+    #[test]
+    fn test_cache_invalidated_by_changed_and_added_files() {
+        let temp = TempDir::new().unwrap();
+        write_spec(&temp.path().join("src/auth"), "Token validation.");
+        let mut daemon = Daemon::new();
+        let params = json!({ "root": temp.path() });
+        daemon.call("scan-claude-md", params.clone()).unwrap();
+
+        write_spec(&temp.path().join("src/auth"), "Session token validation and refresh.");
+        let changed = daemon.call("scan-claude-md", params.clone()).unwrap();
+        assert_eq!(purposes(&changed), vec!["Session token validation and refresh."]);
+
+        write_spec(&temp.path().join("src/api"), "HTTP handlers.");
+        let added = daemon.call("scan-claude-md", params).unwrap();
+        assert_eq!(purposes(&added).len(), 2);
+        assert_eq!(daemon.cache_stats().hits, 0);
+    }
+
+    #[test]
+    fn test_cache_evicts_least_recently_used_entry() {
+        let temp = TempDir::new().unwrap();
+        for name in ["auth", "api", "db"] {
+            write_spec(&temp.path().join(name), "Module.");
+        }
+        let mut daemon = Daemon::new();
+        daemon.cache_capacity = 2;
+        let params = |name: &str| json!({ "root": temp.path().join(name) });
+
+        daemon.call("scan-claude-md", params("auth")).unwrap();
+        daemon.call("scan-claude-md", params("api")).unwrap();
+        daemon.call("scan-claude-md", params("auth")).unwrap();
+        daemon.call("scan-claude-md", params("db")).unwrap();
+        assert_eq!(daemon.cache_stats().entries, 2);
+
+        daemon.call("scan-claude-md", params("auth")).unwrap();
+        daemon.call("scan-claude-md", params("api")).unwrap();
+        let stats = daemon.cache_stats();
+        assert_eq!((stats.entries, stats.hits, stats.misses), (2, 2, 4));
+    }
+
+    #[test]
+    fn test_analyzers_are_built_on_first_use() {
+        let mut daemon = Daemon::new();
+        assert!(daemon.analyzers.is_empty());
+
+        let temp = TempDir::new().unwrap();
+        daemon.call("analyze-code", json!({ "path": temp.path() })).unwrap();
+        assert_eq!(daemon.analyzers.len(), 1);
+    }
+
+    #[test]
+    fn test_analysis_cache_invalidated_by_module_file_outside_path() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        fs::write(root.join("go.mod"), "module example.com/app\n").unwrap();
+        fs::create_dir_all(root.join("auth")).unwrap();
+        fs::create_dir_all(root.join("api")).unwrap();
+        fs::write(
+            root.join("api/handler.go"),
+            "package api\n\nimport \"example.com/app/auth\"\n\nfunc Handle() { auth.Check() }\n",
+        ).unwrap();
+        let mut daemon = Daemon::new();
+        let params = json!({ "path": root.join("api") });
+
+        let first = daemon.call("analyze-code", params.clone()).unwrap();
+        assert_eq!(first["dependencies"]["external"], json!([]));
+        assert_eq!(daemon.call("analyze-code", params.clone()).unwrap(), first);
+
+        fs::write(root.join("go.mod"), "module example.com/renamed\n").unwrap();
+        let second = daemon.call("analyze-code", params).unwrap();
+        assert_eq!(second["dependencies"]["external"], json!(["example.com/app/auth"]));
+        let stats = daemon.cache_stats();
+        assert_eq!((stats.hits, stats.misses), (1, 2));
+    }
+
+    #[test]
+    fn test_analysis_without_project_root_is_not_cached() {
+        let temp = TempDir::new().unwrap();
+        let mut daemon = Daemon::new();
+        daemon.call("analyze-code", json!({ "path": temp.path() })).unwrap();
+        assert_eq!(daemon.cache_stats().entries, 0);
+    }
+
+    #[test]
+    fn test_git_and_writing_methods_are_not_cached() {
+        let temp = TempDir::new().unwrap();
+        write_spec(temp.path(), "Token validation.");
+        let mut daemon = Daemon::new();
+        let params = json!({ "file": [temp.path().join("CLAUDE.md")] });
+
+        daemon.call("fmt-claude-md", params.clone()).unwrap();
+        daemon.call("fmt-claude-md", params).unwrap();
+        assert_eq!(daemon.cache_stats().entries, 0);
+    }
+
+    #[test]
+    fn test_strict_failure_carries_result() {
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("CLAUDE.md");
+        fs::write(&file, "# module\n\n## Purpose\nToken validation.\n\n## Behavior\n* valid token -> Claims\n").unwrap();
+        let mut daemon = Daemon::new();
+
+        let error = daemon.call("fmt-claude-md", json!({ "file": [file], "check": true })).unwrap_err();
+        let response = error.to_response(&json!(7));
+        assert_eq!(response["error"]["code"], jsonrpc::SERVER_ERROR);
+        assert_eq!(response["error"]["message"], "1 file(s) not formatted");
+        assert_eq!(response["error"]["data"]["reformatted"][0], json!(file));
+    }
+
+    #[test]
+    fn test_serve_reports_errors_and_stops_on_shutdown() {
+        let mut input = Vec::new();
+        for message in [
+            json!({ "jsonrpc": "2.0", "id": 1, "method": "parse-tree", "params": { "rooot": "." } }),
+            json!({ "jsonrpc": "2.0", "id": 2, "method": "lsp" }),
+            json!({ "jsonrpc": "2.0", "id": 3, "method": "shutdown" }),
+            json!({ "jsonrpc": "2.0", "id": 4, "method": "parse-tree" }),
+        ] {
+            jsonrpc::write_message(&mut input, &message).unwrap();
+        }
+
+        let mut output = Vec::new();
+        let shutdown = Daemon::new().serve(&mut Cursor::new(input), &mut output).unwrap();
+        assert!(shutdown);
+
+        let mut reader = Cursor::new(output);
+        let invalid = jsonrpc::read_message(&mut reader).unwrap().unwrap();
+        assert_eq!(invalid["error"]["code"], jsonrpc::INVALID_PARAMS);
+        let unknown = jsonrpc::read_message(&mut reader).unwrap().unwrap();
+        assert_eq!(unknown["error"]["code"], jsonrpc::METHOD_NOT_FOUND);
+        let stopped = jsonrpc::read_message(&mut reader).unwrap().unwrap();
+        assert_eq!((stopped["id"].clone(), stopped["result"].clone()), (json!(3), Value::Null));
+        assert!(jsonrpc::read_message(&mut reader).unwrap().is_none());
+    }
+}
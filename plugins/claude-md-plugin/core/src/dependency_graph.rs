@@ -0,0 +1,316 @@
+//! Project-wide module dependency graph built from CLAUDE.md Dependencies > Internal entries.
+//!
+//! Nodes are directories containing CLAUDE.md (root-relative). An edge `a → b` means
+//! the CLAUDE.md in `a` lists `b/CLAUDE.md` as an internal dependency.
+//! Strongly connected components with more than one node (or a self-loop) are reported
+//! as cycles, since /compile cannot order such modules.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use crate::compile_target_resolver::CompileTargetResolver;
+
+/// Module dependency graph
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DependencyGraph {
+    /// Root directory that was scanned
+    pub root: PathBuf,
+    /// All modules (directories with CLAUDE.md, plus referenced dependencies)
+    pub nodes: Vec<String>,
+    /// Adjacency list: { module -> [modules it depends on] }
+    pub adjacency: BTreeMap<String, Vec<String>>,
+    /// Dependencies pointing to directories without a CLAUDE.md
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub dangling: Vec<String>,
+    /// Dependency cycles (strongly connected components)
+    pub cycles: Vec<DependencyCycle>,
+}
+
+/// A strongly connected component in the dependency graph
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DependencyCycle {
+    /// Modules in the cycle, sorted
+    pub modules: Vec<String>,
+    /// Human-readable explanation
+    pub message: String,
+}
+
+impl DependencyGraph {
+    /// Build the graph for every CLAUDE.md under `root` (root-level CLAUDE.md excluded).
+    pub fn build(root: &Path) -> Self {
+        let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+        let resolver = CompileTargetResolver::new();
+        let dirs = resolver.scan_claude_md_dirs(&root);
+        let reverse_deps = resolver.build_reverse_dependency_map(&root, &dirs);
+
+        let spec_dirs: BTreeSet<String> = dirs
+            .iter()
+            .map(|d| d.to_string_lossy().to_string())
+            .collect();
+
+        let mut adjacency: BTreeMap<String, Vec<String>> = spec_dirs
+            .iter()
+            .map(|d| (d.clone(), Vec::new()))
+            .collect();
+        for (dependency, dependents) in &reverse_deps {
+            for dependent in dependents {
+                let deps = adjacency.entry(dependent.clone()).or_default();
+                if !deps.contains(dependency) {
+                    deps.push(dependency.clone());
+                }
+            }
+        }
+        for deps in adjacency.values_mut() {
+            deps.sort();
+        }
+
+        let dangling: Vec<String> = reverse_deps
+            .keys()
+            .filter(|d| !spec_dirs.contains(*d))
+            .cloned()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        let nodes: Vec<String> = spec_dirs
+            .iter()
+            .chain(dangling.iter())
+            .cloned()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        Self::from_parts(root, nodes, adjacency, dangling)
+    }
+
+    /// Assemble a graph from precomputed nodes/edges and detect cycles.
+    pub fn from_parts(
+        root: PathBuf,
+        nodes: Vec<String>,
+        adjacency: BTreeMap<String, Vec<String>>,
+        dangling: Vec<String>,
+    ) -> Self {
+        let cycles = strongly_connected_components(&nodes, &adjacency)
+            .into_iter()
+            .filter(|scc| {
+                scc.len() > 1
+                    || adjacency.get(&scc[0]).is_some_and(|deps| deps.contains(&scc[0]))
+            })
+            .map(|mut modules| {
+                modules.sort();
+                let message = if modules.len() == 1 {
+                    format!("{} depends on itself", modules[0])
+                } else {
+                    format!("Dependency cycle between {}", modules.join(", "))
+                };
+                DependencyCycle { modules, message }
+            })
+            .collect::<Vec<_>>();
+
+        let mut graph = Self {
+            root,
+            nodes,
+            adjacency,
+            dangling,
+            cycles,
+        };
+        graph.cycles.sort_by(|a, b| a.modules.cmp(&b.modules));
+        graph
+    }
+
+    /// Whether any dependency cycle was found
+    pub fn has_cycles(&self) -> bool {
+        !self.cycles.is_empty()
+    }
+
+    /// Render the graph in Graphviz DOT format. Cycle edges are drawn in red.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph dependencies {\n    rankdir=LR;\n    node [shape=box];\n");
+
+        for node in &self.nodes {
+            let style = if self.dangling.contains(node) { " style=dashed" } else { "" };
+            out.push_str(&format!("    \"{}\" [label=\"{}\"{}];\n", node, node, style));
+        }
+        for (from, deps) in &self.adjacency {
+            for to in deps {
+                let attrs = if self.is_cycle_edge(from, to) { " [color=red]" } else { "" };
+                out.push_str(&format!("    \"{}\" -> \"{}\"{};\n", from, to, attrs));
+            }
+        }
+
+        out.push('}');
+        out
+    }
+
+    /// Render the graph as a Mermaid flowchart. Modules in a cycle get the `cycle` class.
+    pub fn to_mermaid(&self) -> String {
+        let ids: BTreeMap<&str, String> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (n.as_str(), format!("n{}", i)))
+            .collect();
+
+        let mut out = String::from("flowchart LR\n");
+        for node in &self.nodes {
+            out.push_str(&format!("    {}[\"{}\"]\n", ids[node.as_str()], node));
+        }
+        for (from, deps) in &self.adjacency {
+            for to in deps {
+                out.push_str(&format!("    {} --> {}\n", ids[from.as_str()], ids[to.as_str()]));
+            }
+        }
+
+        let in_cycle: Vec<&str> = self
+            .cycles
+            .iter()
+            .flat_map(|c| c.modules.iter().map(|m| ids[m.as_str()].as_str()))
+            .collect();
+        if !in_cycle.is_empty() {
+            out.push_str("    classDef cycle stroke:#d33,stroke-width:2px\n");
+            out.push_str(&format!("    class {} cycle\n", in_cycle.join(",")));
+        }
+
+        out.trim_end().to_string()
+    }
+
+    fn is_cycle_edge(&self, from: &str, to: &str) -> bool {
+        self.cycles.iter().any(|c| {
+            c.modules.iter().any(|m| m == from) && c.modules.iter().any(|m| m == to)
+        })
+    }
+}
+
+/// Tarjan's strongly connected components (iterative, to stay safe on deep graphs).
+fn strongly_connected_components(
+    nodes: &[String],
+    adjacency: &BTreeMap<String, Vec<String>>,
+) -> Vec<Vec<String>> {
+    let index_of: BTreeMap<&str, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, n)| (n.as_str(), i))
+        .collect();
+    let edges: Vec<Vec<usize>> = nodes
+        .iter()
+        .map(|n| {
+            adjacency
+                .get(n)
+                .map(|deps| deps.iter().filter_map(|d| index_of.get(d.as_str()).copied()).collect())
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let n = nodes.len();
+    let mut index = vec![usize::MAX; n];
+    let mut lowlink = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = Vec::new();
+    let mut next_index = 0;
+    let mut components = Vec::new();
+
+    for start in 0..n {
+        if index[start] != usize::MAX {
+            continue;
+        }
+        // (node, next edge position)
+        let mut work = vec![(start, 0)];
+        index[start] = next_index;
+        lowlink[start] = next_index;
+        next_index += 1;
+        stack.push(start);
+        on_stack[start] = true;
+
+        while let Some(&mut (v, ref mut edge_pos)) = work.last_mut() {
+            if let Some(&w) = edges[v].get(*edge_pos) {
+                *edge_pos += 1;
+                if index[w] == usize::MAX {
+                    index[w] = next_index;
+                    lowlink[w] = next_index;
+                    next_index += 1;
+                    stack.push(w);
+                    on_stack[w] = true;
+                    work.push((w, 0));
+                } else if on_stack[w] {
+                    lowlink[v] = lowlink[v].min(index[w]);
+                }
+                continue;
+            }
+
+            work.pop();
+            if let Some(&(parent, _)) = work.last() {
+                lowlink[parent] = lowlink[parent].min(lowlink[v]);
+            }
+            if lowlink[v] == index[v] {
+                let mut component = Vec::new();
+                while let Some(w) = stack.pop() {
+                    on_stack[w] = false;
+                    component.push(nodes[w].clone());
+                    if w == v {
+                        break;
+                    }
+                }
+                components.push(component);
+            }
+        }
+    }
+
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(edges: &[(&str, &str)]) -> DependencyGraph {
+        let mut adjacency: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        let mut nodes = BTreeSet::new();
+        for (from, to) in edges {
+            adjacency.entry(from.to_string()).or_default().push(to.to_string());
+            nodes.insert(from.to_string());
+            nodes.insert(to.to_string());
+        }
+        DependencyGraph::from_parts(PathBuf::from("."), nodes.into_iter().collect(), adjacency, vec![])
+    }
+
+    #[test]
+    fn test_acyclic_graph_has_no_cycles() {
+        let g = graph(&[("src/api", "src/auth"), ("src/auth", "core/domain"), ("src/api", "core/domain")]);
+        assert!(!g.has_cycles());
+    }
+
+    #[test]
+    fn test_detects_multi_node_cycle() {
+        let g = graph(&[("a", "b"), ("b", "c"), ("c", "a"), ("c", "d")]);
+        assert_eq!(g.cycles.len(), 1);
+        assert_eq!(g.cycles[0].modules, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_detects_self_loop_and_separate_cycles() {
+        let g = graph(&[("a", "a"), ("b", "c"), ("c", "b")]);
+        assert_eq!(g.cycles.len(), 2);
+        assert_eq!(g.cycles[0].modules, vec!["a"]);
+        assert_eq!(g.cycles[1].modules, vec!["b", "c"]);
+    }
+
+    #[test]
+    fn test_dot_output_marks_cycle_edges() {
+        let g = graph(&[("a", "b"), ("b", "a"), ("b", "c")]);
+        let dot = g.to_dot();
+        assert!(dot.starts_with("digraph dependencies {"));
+        assert!(dot.contains("\"a\" -> \"b\" [color=red];"));
+        assert!(dot.contains("\"b\" -> \"c\";"));
+    }
+
+    #[test]
+    fn test_mermaid_output() {
+        let g = graph(&[("a", "b"), ("b", "a")]);
+        let mermaid = g.to_mermaid();
+        assert!(mermaid.starts_with("flowchart LR"));
+        assert!(mermaid.contains("n0[\"a\"]"));
+        assert!(mermaid.contains("n0 --> n1"));
+        assert!(mermaid.contains("class n0,n1 cycle"));
+    }
+}
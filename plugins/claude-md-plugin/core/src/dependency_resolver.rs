@@ -1,7 +1,8 @@
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 
-use crate::code_analyzer::{AnalysisResult, InternalDependency, ResolutionStatus};
+use crate::code_analyzer::{AnalysisResult, CodeAnalyzer, InternalDependency, ResolutionStatus};
 use crate::tree_parser::TreeResult;
 
 /// Resolves raw import paths to CLAUDE.md directory paths.
@@ -160,6 +161,50 @@ impl DependencyResolver {
     }
 }
 
+/// Whole-project index: tree-parse plus code analysis for every directory that needs a CLAUDE.md
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IndexResult {
+    pub root: PathBuf,
+    pub directories: Vec<DirectoryAnalysis>,
+    pub excluded: Vec<PathBuf>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DirectoryAnalysis {
+    pub path: PathBuf,
+    pub depth: usize,
+    pub analysis: AnalysisResult,
+}
+
+/// Analyze each directory of `tree_result` under `root` and resolve its internal deps.
+/// Directories that fail to analyze are skipped with a warning.
+pub fn index_project(root: &Path, tree_result: TreeResult, analyzer: &CodeAnalyzer) -> IndexResult {
+    // Borrows tree_result temporarily; copies needed data internally via clone.
+    let resolver = DependencyResolver::new(&tree_result);
+
+    let mut directories = Vec::new();
+    for dir_info in &tree_result.needs_claude_md {
+        let dir_path = root.join(&dir_info.path);
+        match analyzer.analyze_directory(&dir_path, None) {
+            Ok(mut analysis) => {
+                resolver.resolve(&mut analysis, &dir_info.path);
+                directories.push(DirectoryAnalysis {
+                    path: dir_info.path.clone(),
+                    depth: dir_info.depth,
+                    analysis,
+                });
+            }
+            Err(e) => eprintln!("Warning: skipping {}: {}", dir_info.path.display(), e),
+        }
+    }
+
+    IndexResult {
+        root: tree_result.root,
+        directories,
+        excluded: tree_result.excluded,
+    }
+}
+
 /// Normalize a path by resolving `.` and `..` components without filesystem access.
 /// Guards against path traversal beyond the root (excessive `..` components are ignored).
 fn normalize_path(path: &Path) -> PathBuf {
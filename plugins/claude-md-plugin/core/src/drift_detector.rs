@@ -0,0 +1,420 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::Path;
+use thiserror::Error;
+
+use crate::claude_md_parser::{ClaudeMdParser, ClaudeMdSpec, ParseError};
+use crate::code_analyzer::{AnalysisResult, AnalyzerError, CodeAnalyzer};
+use crate::signature::{Language, Signature};
+
+/// Errors that can occur during drift detection
+#[derive(Debug, Error)]
+pub enum DriftError {
+    #[error("Failed to parse CLAUDE.md: {0}")]
+    Spec(#[from] ParseError),
+
+    #[error("Failed to analyze source code: {0}")]
+    Code(#[from] AnalyzerError),
+}
+
+/// Result of comparing a CLAUDE.md spec against the code in its directory
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DriftReport {
+    /// CLAUDE.md file that was compared
+    pub claude_md: String,
+    /// Directory whose source files were analyzed
+    pub source_dir: String,
+    /// Whether any drift was found
+    pub has_drift: bool,
+    /// Per-symbol findings, sorted by kind then symbol
+    pub findings: Vec<DriftFinding>,
+    /// Finding counts per kind
+    pub summary: DriftSummary,
+}
+
+/// A single difference between spec and code
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DriftFinding {
+    /// Kind of drift
+    pub kind: DriftKind,
+    /// Export name or dependency name the finding refers to
+    pub symbol: String,
+    /// What the spec declares (if anything)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec: Option<String>,
+    /// What the code contains (if anything)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// Human-readable explanation
+    pub message: String,
+}
+
+/// Kind of spec-vs-code drift
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "kebab-case")]
+pub enum DriftKind {
+    /// Export declared in CLAUDE.md but not found in code
+    MissingInCode,
+    /// Export found in code but not mentioned in CLAUDE.md
+    UndocumentedInCode,
+    /// Function exists on both sides with different signatures
+    SignatureMismatch,
+    /// External dependency listed in CLAUDE.md but not imported by code
+    ExternalDepSpecOnly,
+    /// External dependency imported by code but not listed in CLAUDE.md
+    ExternalDepCodeOnly,
+}
+
+/// Finding counts per drift kind
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct DriftSummary {
+    pub missing_in_code: usize,
+    pub undocumented_in_code: usize,
+    pub signature_mismatch: usize,
+    pub external_dep_spec_only: usize,
+    pub external_dep_code_only: usize,
+}
+
+pub struct DriftDetector {
+    parser: ClaudeMdParser,
+}
+
+impl DriftDetector {
+    pub fn new() -> Self {
+        Self {
+            parser: ClaudeMdParser::new(),
+        }
+    }
+
+    /// Parse `dir/CLAUDE.md`, analyze the source files in `dir` with `analyzer`, and compare them.
+    pub fn check(&self, analyzer: &CodeAnalyzer, dir: &Path) -> Result<DriftReport, DriftError> {
+        let claude_md = dir.join("CLAUDE.md");
+        let spec = self.parser.parse(&claude_md)?;
+        let analysis = analyzer.analyze_directory(dir, None)?;
+
+        let findings = self.compare(&spec, &analysis);
+        let summary = summarize(&findings);
+
+        Ok(DriftReport {
+            claude_md: claude_md.display().to_string(),
+            source_dir: dir.display().to_string(),
+            has_drift: !findings.is_empty(),
+            findings,
+            summary,
+        })
+    }
+
+    /// Compare a parsed spec with an analysis result and list every difference.
+    pub fn compare(&self, spec: &ClaudeMdSpec, analysis: &AnalysisResult) -> Vec<DriftFinding> {
+        let mut findings = Vec::new();
+
+        let spec_names = spec_export_names(spec);
+        let code_names = code_export_names(analysis);
+
+        for name in &spec_names {
+            if !code_names.contains(name) {
+                findings.push(DriftFinding {
+                    kind: DriftKind::MissingInCode,
+                    symbol: name.clone(),
+                    spec: spec_signature(spec, name),
+                    code: None,
+                    message: format!("'{}' is declared in CLAUDE.md Exports but not exported by code", name),
+                });
+            }
+        }
+
+        for name in &code_names {
+            if !spec_names.contains(name) {
+                findings.push(DriftFinding {
+                    kind: DriftKind::UndocumentedInCode,
+                    symbol: name.clone(),
+                    spec: None,
+                    code: code_signature(analysis, name),
+                    message: format!("'{}' is exported by code but missing from CLAUDE.md Exports", name),
+                });
+            }
+        }
+
+        for spec_fn in &spec.exports.functions {
+            let Some(code_fn) = analysis.exports.functions.iter().find(|f| f.name == spec_fn.name) else {
+                continue;
+            };
+            let spec_shape = structured_signature(spec_fn.parsed_signature.as_ref(), &spec_fn.signature, &spec_fn.name);
+            let code_shape = structured_signature(code_fn.parsed_signature.as_ref(), &code_fn.signature, &code_fn.name);
+            if let (Some(s), Some(c)) = (spec_shape, code_shape) {
+                if !s.matches(&c) {
+                    findings.push(DriftFinding {
+                        kind: DriftKind::SignatureMismatch,
+                        symbol: spec_fn.name.clone(),
+                        spec: Some(spec_fn.signature.clone()),
+                        code: Some(code_fn.signature.clone()),
+                        message: format!("Signature of '{}' differs between CLAUDE.md and code", spec_fn.name),
+                    });
+                }
+            }
+        }
+
+        let spec_deps: Vec<(String, String)> = spec.dependencies.external.iter()
+            .map(|d| (normalize_dependency(d), d.clone()))
+            .filter(|(n, _)| !n.is_empty())
+            .collect();
+        let code_deps: Vec<(String, String)> = analysis.dependencies.external.iter()
+            .map(|d| (normalize_dependency(d), d.clone()))
+            .filter(|(n, _)| !n.is_empty())
+            .collect();
+
+        for (norm, raw) in &spec_deps {
+            if !code_deps.iter().any(|(c, _)| dependency_matches(norm, c)) {
+                findings.push(DriftFinding {
+                    kind: DriftKind::ExternalDepSpecOnly,
+                    symbol: norm.clone(),
+                    spec: Some(raw.clone()),
+                    code: None,
+                    message: format!("External dependency '{}' is listed in CLAUDE.md but not imported by code", norm),
+                });
+            }
+        }
+
+        for (norm, raw) in &code_deps {
+            if !spec_deps.iter().any(|(s, _)| dependency_matches(s, norm)) {
+                findings.push(DriftFinding {
+                    kind: DriftKind::ExternalDepCodeOnly,
+                    symbol: norm.clone(),
+                    spec: None,
+                    code: Some(raw.clone()),
+                    message: format!("External dependency '{}' is imported by code but not listed in CLAUDE.md", norm),
+                });
+            }
+        }
+
+        findings.sort_by(|a, b| a.kind.cmp(&b.kind).then_with(|| a.symbol.cmp(&b.symbol)));
+        findings
+    }
+}
+
+impl Default for DriftDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Count findings per kind
+pub fn summarize(findings: &[DriftFinding]) -> DriftSummary {
+    let mut summary = DriftSummary::default();
+    for finding in findings {
+        match finding.kind {
+            DriftKind::MissingInCode => summary.missing_in_code += 1,
+            DriftKind::UndocumentedInCode => summary.undocumented_in_code += 1,
+            DriftKind::SignatureMismatch => summary.signature_mismatch += 1,
+            DriftKind::ExternalDepSpecOnly => summary.external_dep_spec_only += 1,
+            DriftKind::ExternalDepCodeOnly => summary.external_dep_code_only += 1,
+        }
+    }
+    summary
+}
+
+/// All export names declared in the spec (deduplicated, in declaration order)
+fn spec_export_names(spec: &ClaudeMdSpec) -> Vec<String> {
+    let exports = &spec.exports;
+    let names = exports.functions.iter().map(|f| &f.name)
+        .chain(exports.types.iter().map(|t| &t.name))
+        .chain(exports.classes.iter().map(|c| &c.name))
+        .chain(exports.enums.iter().map(|e| &e.name))
+        .chain(exports.variables.iter().map(|v| &v.name));
+    dedup_names(names)
+}
+
+/// All export names found in code, including re-exports (deduplicated, in analysis order)
+fn code_export_names(analysis: &AnalysisResult) -> Vec<String> {
+    let exports = &analysis.exports;
+    let names = exports.functions.iter().map(|f| &f.name)
+        .chain(exports.types.iter().map(|t| &t.name))
+        .chain(exports.classes.iter().map(|c| &c.name))
+        .chain(exports.enums.iter().map(|e| &e.name))
+        .chain(exports.variables.iter().map(|v| &v.name))
+        .chain(exports.re_exports.iter().map(|r| &r.name));
+    dedup_names(names)
+}
+
+fn dedup_names<'a>(names: impl Iterator<Item = &'a String>) -> Vec<String> {
+    let mut seen = HashSet::new();
+    names.filter(|n| seen.insert(n.as_str())).cloned().collect()
+}
+
+fn spec_signature(spec: &ClaudeMdSpec, name: &str) -> Option<String> {
+    let exports = &spec.exports;
+    exports.functions.iter().find(|f| f.name == name).map(|f| f.signature.clone())
+        .or_else(|| exports.types.iter().find(|t| t.name == name).map(|t| t.definition.clone()))
+        .or_else(|| exports.classes.iter().find(|c| c.name == name).map(|c| c.constructor_signature.clone()))
+        .or_else(|| exports.enums.iter().find(|e| e.name == name).map(|e| format!("{}: {}", e.name, e.variants.join(" | "))))
+        .or_else(|| exports.variables.iter().find(|v| v.name == name).and_then(|v| v.value.clone()))
+}
+
+fn code_signature(analysis: &AnalysisResult, name: &str) -> Option<String> {
+    let exports = &analysis.exports;
+    exports.functions.iter().find(|f| f.name == name).map(|f| f.signature.clone())
+        .or_else(|| exports.types.iter().find(|t| t.name == name).and_then(|t| t.definition.clone()))
+        .or_else(|| exports.classes.iter().find(|c| c.name == name).and_then(|c| c.signature.clone()))
+        .or_else(|| exports.enums.iter().find(|e| e.name == name)
+            .and_then(|e| e.variants.as_ref().map(|v| format!("{}: {}", e.name, v.join(" | ")))))
+        .or_else(|| exports.variables.iter().find(|v| v.name == name).and_then(|v| v.var_type.clone()))
+        .or_else(|| exports.re_exports.iter().find(|r| r.name == name).map(|r| format!("re-export from {}", r.source)))
+}
+
+/// The structured form of a signature, parsed on the fly for results serialized without one.
+fn structured_signature(parsed: Option<&Signature>, signature: &str, name: &str) -> Option<Signature> {
+    parsed.cloned().or_else(|| Signature::parse(signature, name, Language::Unknown))
+}
+
+/// Reduce a dependency entry to its bare package name.
+///
+/// `\`jsonwebtoken@9.0.0\`: sign, verify` → `jsonwebtoken`, `@scope/pkg@1.2` → `@scope/pkg`.
+fn normalize_dependency(dep: &str) -> String {
+    let trimmed = dep.trim().trim_start_matches('-').trim();
+    let first = trimmed.split_whitespace().next().unwrap_or("");
+    let first = first.trim_matches('`');
+    let first = first.split("`:").next().unwrap_or(first);
+    let first = first.trim_end_matches(':').trim_matches('`');
+
+    // Strip a trailing @version, keeping a leading @scope
+    let name = match first.rfind('@') {
+        Some(idx) if idx > 0 => &first[..idx],
+        _ => first,
+    };
+
+    name.to_lowercase()
+}
+
+/// Two dependency names match if they are equal or one is a path prefix of the other
+/// (e.g. `github.com/golang-jwt/jwt` vs `github.com/golang-jwt/jwt/v5`).
+fn dependency_matches(a: &str, b: &str) -> bool {
+    a == b
+        || b.starts_with(&format!("{}/", a))
+        || a.starts_with(&format!("{}/", b))
+        || b.starts_with(&format!("{}.", a))
+        || a.starts_with(&format!("{}.", b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::code_analyzer::{Dependencies, ExportedFunction, Exports};
+
+    fn spec_from(body: &str) -> ClaudeMdSpec {
+        let content = format!(
+            "# test\n\n## Purpose\nTest module.\n\n{}\n\n## Behavior\n- input → output\n\n\
+             ## Contract\nNone\n\n## Protocol\nNone\n\n## Domain Context\nNone\n",
+            body
+        );
+        ClaudeMdParser::new().parse_content(&content).unwrap()
+    }
+
+    fn analysis_with(functions: Vec<(&str, &str)>, external: Vec<&str>) -> AnalysisResult {
+        AnalysisResult {
+            exports: Exports {
+                functions: functions
+                    .into_iter()
+                    .map(|(name, sig)| ExportedFunction {
+                        name: name.to_string(),
+                        signature: sig.to_string(),
+                        description: None,
+                        location: None,
+                        parsed_signature: None,
+                    })
+                    .collect(),
+                ..Default::default()
+            },
+            dependencies: Dependencies {
+                external: external.into_iter().map(|s| s.to_string()).collect(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_no_drift_when_spec_matches_code() {
+        let spec = spec_from("## Exports\n- `validateToken(token: string): Promise<Claims>`");
+        let analysis = analysis_with(vec![("validateToken", "validateToken(token: string): Promise<Claims>")], vec![]);
+
+        let findings = DriftDetector::new().compare(&spec, &analysis);
+        assert!(findings.is_empty(), "Unexpected findings: {:?}", findings);
+    }
+
+    #[test]
+    fn test_missing_and_undocumented_exports() {
+        let spec = spec_from("## Exports\n- `validateToken(token: string): Claims`");
+        let analysis = analysis_with(vec![("generateToken", "generateToken(id: string): string")], vec![]);
+
+        let findings = DriftDetector::new().compare(&spec, &analysis);
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].kind, DriftKind::MissingInCode);
+        assert_eq!(findings[0].symbol, "validateToken");
+        assert_eq!(findings[1].kind, DriftKind::UndocumentedInCode);
+        assert_eq!(findings[1].symbol, "generateToken");
+    }
+
+    #[test]
+    fn test_signature_mismatch_detected() {
+        let spec = spec_from("## Exports\n- `validateToken(token: string): Claims`");
+        let analysis = analysis_with(
+            vec![("validateToken", "validateToken(token: string, secret: string): Claims")],
+            vec![],
+        );
+
+        let findings = DriftDetector::new().compare(&spec, &analysis);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].kind, DriftKind::SignatureMismatch);
+    }
+
+    #[test]
+    fn test_signature_syntax_differences_are_not_drift() {
+        let spec = spec_from("## Exports\n- `validate_token(token: &str) -> Result<Claims, TokenError>`");
+        let analysis = analysis_with(
+            vec![("validate_token", "fn validate_token(token: &str) -> Result<Claims, TokenError>")],
+            vec![],
+        );
+
+        let findings = DriftDetector::new().compare(&spec, &analysis);
+        assert!(findings.is_empty(), "Unexpected findings: {:?}", findings);
+    }
+
+    #[test]
+    fn test_external_dependency_drift() {
+        let spec = spec_from(
+            "## Dependencies\n- external:\n  - `jsonwebtoken@9.0.0`: sign, verify\n  - `lodash@4.17.21`\n\n\
+             ## Exports\n- `validate(): void`",
+        );
+        let analysis = analysis_with(vec![("validate", "validate(): void")], vec!["jsonwebtoken", "axios"]);
+
+        let findings = DriftDetector::new().compare(&spec, &analysis);
+        let kinds: Vec<(&DriftKind, &str)> = findings.iter().map(|f| (&f.kind, f.symbol.as_str())).collect();
+        assert_eq!(kinds, vec![
+            (&DriftKind::ExternalDepSpecOnly, "lodash"),
+            (&DriftKind::ExternalDepCodeOnly, "axios"),
+        ]);
+    }
+
+    #[test]
+    fn test_java_style_spec_matches_analyzed_code() {
+        let spec = spec_from("## Exports\n- `TokenClaims validateToken(String token)`");
+        let mut analysis = analysis_with(vec![("validateToken", "public TokenClaims validateToken(String token)")], vec![]);
+        analysis.exports.functions[0].parsed_signature =
+            Signature::parse("public TokenClaims validateToken(String token)", "validateToken", Language::Java);
+
+        assert!(DriftDetector::new().compare(&spec, &analysis).is_empty());
+
+        analysis.exports.functions[0].parsed_signature =
+            Signature::parse("public TokenClaims validateToken(byte[] token)", "validateToken", Language::Java);
+        let findings = DriftDetector::new().compare(&spec, &analysis);
+        assert_eq!(findings[0].kind, DriftKind::SignatureMismatch);
+    }
+
+    #[test]
+    fn test_normalize_dependency() {
+        assert_eq!(normalize_dependency("`jsonwebtoken@9.0.0`: sign, verify"), "jsonwebtoken");
+        assert_eq!(normalize_dependency("@scope/pkg@1.2.3"), "@scope/pkg");
+        assert_eq!(normalize_dependency("github.com/golang-jwt/jwt/v5"), "github.com/golang-jwt/jwt/v5");
+        assert!(dependency_matches("github.com/golang-jwt/jwt", "github.com/golang-jwt/jwt/v5"));
+    }
+}
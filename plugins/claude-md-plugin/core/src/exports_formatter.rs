@@ -10,7 +10,7 @@ use crate::code_analyzer::{
 };
 
 /// Category order for subsection rendering (fixed).
-const CATEGORY_ORDER: &[&str] = &[
+pub(crate) const CATEGORY_ORDER: &[&str] = &[
     "Functions",
     "Types",
     "Classes",
@@ -173,6 +173,8 @@ mod tests {
             name: "greet".to_string(),
             signature: "greet(name: string): string".to_string(),
             description: None,
+            location: None,
+            parsed_signature: None,
         });
         let result = format_exports(&exports);
         assert_eq!(result, "- `greet(name: string): string`");
@@ -185,11 +187,15 @@ mod tests {
             name: "zebra".to_string(),
             signature: "zebra(): void".to_string(),
             description: None,
+            location: None,
+            parsed_signature: None,
         });
         exports.functions.push(ExportedFunction {
             name: "alpha".to_string(),
             signature: "alpha(): void".to_string(),
             description: None,
+            location: None,
+            parsed_signature: None,
         });
         let result = format_exports(&exports);
         assert_eq!(result, "- `alpha(): void`\n- `zebra(): void`");
@@ -202,12 +208,15 @@ mod tests {
             name: "doWork".to_string(),
             signature: "doWork(): void".to_string(),
             description: None,
+            location: None,
+            parsed_signature: None,
         });
         exports.types.push(ExportedType {
             name: "Config".to_string(),
             kind: TypeKind::Interface,
             definition: Some("timeout: number".to_string()),
             description: None,
+            location: None,
         });
         let result = format_exports(&exports);
         let expected = "\
@@ -229,6 +238,7 @@ mod tests {
             kind: TypeKind::Type,
             definition: None,
             description: None,
+            location: None,
         });
         let result = format_exports(&exports);
         assert_eq!(result, "- `Opaque`");
@@ -242,6 +252,7 @@ mod tests {
             kind: TypeKind::Struct,
             definition: Some(String::new()),
             description: None,
+            location: None,
         });
         let result = format_exports(&exports);
         assert_eq!(result, "- `Empty`");
@@ -254,6 +265,7 @@ mod tests {
             name: "UserService".to_string(),
             signature: Some("class UserService extends BaseService".to_string()),
             description: None,
+            location: None,
         });
         let result = format_exports(&exports);
         assert_eq!(result, "- `class UserService extends BaseService`");
@@ -266,6 +278,7 @@ mod tests {
             name: "SimpleClass".to_string(),
             signature: None,
             description: None,
+            location: None,
         });
         let result = format_exports(&exports);
         assert_eq!(result, "- `SimpleClass`");
@@ -281,6 +294,7 @@ mod tests {
                 "Inactive".to_string(),
                 "Pending".to_string(),
             ]),
+            location: None,
         });
         let result = format_exports(&exports);
         assert_eq!(result, "- `Status: Active | Inactive | Pending`");
@@ -292,6 +306,7 @@ mod tests {
         exports.enums.push(ExportedEnum {
             name: "Color".to_string(),
             variants: None,
+            location: None,
         });
         let result = format_exports(&exports);
         assert_eq!(result, "- `Color`");
@@ -303,6 +318,7 @@ mod tests {
         exports.variables.push(ExportedVariable {
             name: "MAX_RETRIES".to_string(),
             var_type: Some("number".to_string()),
+            location: None,
         });
         let result = format_exports(&exports);
         assert_eq!(result, "- `MAX_RETRIES: number`");
@@ -314,6 +330,7 @@ mod tests {
         exports.variables.push(ExportedVariable {
             name: "DEFAULT_NAME".to_string(),
             var_type: None,
+            location: None,
         });
         let result = format_exports(&exports);
         assert_eq!(result, "- `DEFAULT_NAME`");
@@ -337,23 +354,29 @@ mod tests {
             name: "beta".to_string(),
             signature: "beta(): void".to_string(),
             description: None,
+            location: None,
+            parsed_signature: None,
         });
         exports.functions.push(ExportedFunction {
             name: "alpha".to_string(),
             signature: "alpha(): void".to_string(),
             description: None,
+            location: None,
+            parsed_signature: None,
         });
         exports.types.push(ExportedType {
             name: "Zeta".to_string(),
             kind: TypeKind::Interface,
             definition: Some("x: number".to_string()),
             description: None,
+            location: None,
         });
         exports.types.push(ExportedType {
             name: "Alpha".to_string(),
             kind: TypeKind::Type,
             definition: None,
             description: None,
+            location: None,
         });
 
         let run1 = format_exports(&exports);
@@ -372,26 +395,32 @@ mod tests {
         exports.variables.push(ExportedVariable {
             name: "VAR".to_string(),
             var_type: Some("string".to_string()),
+            location: None,
         });
         exports.enums.push(ExportedEnum {
             name: "Dir".to_string(),
             variants: Some(vec!["Up".to_string(), "Down".to_string()]),
+            location: None,
         });
         exports.classes.push(ExportedClass {
             name: "Svc".to_string(),
             signature: None,
             description: None,
+            location: None,
         });
         exports.types.push(ExportedType {
             name: "Cfg".to_string(),
             kind: TypeKind::Interface,
             definition: None,
             description: None,
+            location: None,
         });
         exports.functions.push(ExportedFunction {
             name: "run".to_string(),
             signature: "run(): void".to_string(),
             description: None,
+            location: None,
+            parsed_signature: None,
         });
 
         let result = format_exports(&exports);
@@ -419,6 +448,8 @@ mod tests {
             name: "run".to_string(),
             signature: "run(): void".to_string(),
             description: None,
+            location: None,
+            parsed_signature: None,
         });
         // types, classes, enums, variables empty
         exports.re_exports.push(ReExport {
@@ -441,6 +472,7 @@ mod tests {
         exports.enums.push(ExportedEnum {
             name: "Color".to_string(),
             variants: Some(vec![]),
+            location: None,
         });
         let result = format_exports(&exports);
         assert_eq!(result, "- `Color`");
@@ -453,6 +485,7 @@ mod tests {
             name: "SimpleClass".to_string(),
             signature: Some(String::new()),
             description: None,
+            location: None,
         });
         let result = format_exports(&exports);
         assert_eq!(result, "- `SimpleClass`");
@@ -464,6 +497,7 @@ mod tests {
         exports.variables.push(ExportedVariable {
             name: "DEFAULT".to_string(),
             var_type: Some(String::new()),
+            location: None,
         });
         let result = format_exports(&exports);
         assert_eq!(result, "- `DEFAULT`");
@@ -476,22 +510,28 @@ mod tests {
             name: "fetchData".to_string(),
             signature: "async fetchData(url: string): Promise<Response>".to_string(),
             description: Some("Fetches data from URL".to_string()),
+            location: None,
+            parsed_signature: None,
         });
         exports.functions.push(ExportedFunction {
             name: "createUser".to_string(),
             signature: "createUser(name: string, email: string): User".to_string(),
             description: None,
+            location: None,
+            parsed_signature: None,
         });
         exports.types.push(ExportedType {
             name: "UserConfig".to_string(),
             kind: TypeKind::Interface,
             definition: Some("name: string, email: string".to_string()),
             description: None,
+            location: None,
         });
         exports.classes.push(ExportedClass {
             name: "AuthService".to_string(),
             signature: Some("class AuthService".to_string()),
             description: None,
+            location: None,
         });
         exports.enums.push(ExportedEnum {
             name: "Role".to_string(),
@@ -500,10 +540,12 @@ mod tests {
                 "User".to_string(),
                 "Guest".to_string(),
             ]),
+            location: None,
         });
         exports.variables.push(ExportedVariable {
             name: "API_VERSION".to_string(),
             var_type: Some("string".to_string()),
+            location: None,
         });
         exports.re_exports.push(ReExport {
             name: "Logger".to_string(),
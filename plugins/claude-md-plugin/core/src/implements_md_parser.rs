@@ -0,0 +1,660 @@
+//! IMPLEMENTS.md parser.
+//!
+//! IMPLEMENTS.md is the HOW counterpart of CLAUDE.md (see templates/implements-md-schema.md).
+//! The Planning Section (Dependencies Direction, Implementation Approach, Technology Choices)
+//! is written by /impl, the Implementation Section (Algorithm, Key Constants, Error Handling,
+//! State Management, Implementation Guide) by /compile.
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::claude_md_parser::ParseError;
+use crate::markdown::{parse_sections, MarkdownSection};
+
+// Include generated constants from schema-rules.yaml (SSOT)
+include!(concat!(env!("OUT_DIR"), "/schema_rules.rs"));
+
+/// Which IMPLEMENTS.md section rule from schema-rules.yaml was broken
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SectionRuleKind {
+    /// A required section is absent
+    MissingSection,
+    /// A section without `allow_none` is marked "None"
+    NoneNotAllowed,
+    /// A `table` section has no data row
+    MissingTable,
+}
+
+/// A section of IMPLEMENTS.md that breaks its rule in schema-rules.yaml
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SectionRuleViolation {
+    pub kind: SectionRuleKind,
+    pub section: String,
+    /// 1-based heading line; `None` for a missing section
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+/// Check the H2 sections of IMPLEMENTS.md against schema-rules.yaml: required sections
+/// are present, "None" appears only where `allow_none` is set, and `table` sections hold
+/// at least one data row.
+pub fn check_section_rules(sections: &[MarkdownSection]) -> Vec<SectionRuleViolation> {
+    let matches = |names: &[&str], name: &str| names.iter().any(|s| s.eq_ignore_ascii_case(name));
+    let mut violations = Vec::new();
+
+    for required in IMPLEMENTS_REQUIRED_SECTIONS {
+        if !sections.iter().any(|s| s.level == 2 && s.name.eq_ignore_ascii_case(required)) {
+            violations.push(SectionRuleViolation {
+                kind: SectionRuleKind::MissingSection,
+                section: required.to_string(),
+                line: None,
+                message: format!("Missing required section: {}", required),
+            });
+        }
+    }
+
+    for (index, section) in sections.iter().enumerate().filter(|(_, s)| s.level == 2) {
+        if !matches(IMPLEMENTS_SECTIONS, &section.name) {
+            continue;
+        }
+        // H3+ subsections are content too
+        let has_subsections = sections.get(index + 1).is_some_and(|next| next.level > 2);
+        let lines: Vec<&str> = section.content.iter().map(|s| s.as_str()).collect();
+        let is_none = !has_subsections && crate::is_none_marker_content(&lines);
+        let allows_none = matches(IMPLEMENTS_ALLOW_NONE_SECTIONS, &section.name);
+
+        if is_none && !allows_none {
+            violations.push(SectionRuleViolation {
+                kind: SectionRuleKind::NoneNotAllowed,
+                section: section.name.clone(),
+                line: Some(section.line),
+                message: format!("Section '{}' does not allow 'None' as value", section.name),
+            });
+        } else if !is_none && matches(IMPLEMENTS_TABLE_SECTIONS, &section.name) {
+            // Header and separator rows come first
+            let has_row = section.content.iter().filter(|line| line.trim().starts_with('|')).nth(2).is_some();
+            if !has_row {
+                let alternative = if allows_none { " or 'None'" } else { "" };
+                violations.push(SectionRuleViolation {
+                    kind: SectionRuleKind::MissingTable,
+                    section: section.name.clone(),
+                    line: Some(section.line),
+                    message: format!(
+                        "Section '{}' must contain a table with at least one row{}", section.name, alternative
+                    ),
+                });
+            }
+        }
+    }
+
+    violations
+}
+
+/// Complete specification parsed from IMPLEMENTS.md
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ImplementsMdSpec {
+    /// Module name (from H1 header, e.g. "auth/IMPLEMENTS.md")
+    pub name: String,
+    /// Dependencies Direction (Planning)
+    pub dependencies_direction: DependenciesDirectionSpec,
+    /// Implementation Approach (Planning)
+    pub implementation_approach: ImplementationApproachSpec,
+    /// Technology Choices table (Planning, "None" allowed)
+    pub technology_choices: Vec<TechnologyChoiceSpec>,
+    /// Algorithm notes (Implementation, conditional)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub algorithm: Vec<AlgorithmSpec>,
+    /// Key Constants table (Implementation, conditional)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub key_constants: Vec<KeyConstantSpec>,
+    /// Error Handling table (Implementation, "None" allowed)
+    pub error_handling: Vec<ErrorHandlingSpec>,
+    /// State Management (Implementation, "None" allowed)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_management: Option<StateManagementSpec>,
+    /// Implementation Guide entries (Implementation, conditional)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub implementation_guide: Vec<String>,
+    /// Validation warnings (non-fatal issues found during parsing)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// Dependencies Direction section
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct DependenciesDirectionSpec {
+    pub external: Vec<DirectionEntry>,
+    pub internal: Vec<DirectionEntry>,
+}
+
+/// A dependency entry: `target`: description
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DirectionEntry {
+    /// Package (`pkg@version`) or module path (`../utils/crypto`)
+    pub target: String,
+    /// Usage and rationale
+    pub description: String,
+}
+
+/// Implementation Approach section
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ImplementationApproachSpec {
+    /// Chosen strategy
+    pub strategy: Vec<String>,
+    /// Alternatives considered but not chosen
+    pub alternatives: Vec<String>,
+}
+
+/// Technology Choices row
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TechnologyChoiceSpec {
+    pub choice: String,
+    pub alternative: String,
+    pub reason: String,
+}
+
+/// Algorithm note (one per ### heading)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlgorithmSpec {
+    pub name: String,
+    pub details: Vec<String>,
+}
+
+/// Key Constants row
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyConstantSpec {
+    pub name: String,
+    pub value: String,
+    pub rationale: String,
+    pub scope: String,
+}
+
+/// Error Handling row
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorHandlingSpec {
+    pub error: String,
+    pub retry: String,
+    pub recovery: String,
+    pub log_level: String,
+}
+
+/// State Management section
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct StateManagementSpec {
+    pub initial_state: Vec<String>,
+    pub persistence: Vec<String>,
+    pub cleanup: Vec<String>,
+}
+
+/// IMPLEMENTS.md Parser
+pub struct ImplementsMdParser {
+    entry_pattern: Regex,
+}
+
+impl ImplementsMdParser {
+    pub fn new() -> Self {
+        Self {
+            // Match dependency entry: - `target`: description
+            entry_pattern: Regex::new(r"^[-*]\s*`([^`]+)`\s*(?::\s*(.*))?$").unwrap_or_else(|_| Regex::new(r".^").unwrap()),
+        }
+    }
+
+    /// Parse an IMPLEMENTS.md file
+    pub fn parse(&self, file: &Path) -> Result<ImplementsMdSpec, ParseError> {
+        let content = std::fs::read_to_string(file).map_err(|e| ParseError::FileReadError {
+            path: file.to_string_lossy().to_string(),
+            source: e,
+        })?;
+
+        self.parse_content(&content)
+    }
+
+    /// Parse IMPLEMENTS.md content directly
+    /// Returns Err on the first section that breaks its rule in schema-rules.yaml (SSOT),
+    /// see [`check_section_rules`].
+    pub fn parse_content(&self, content: &str) -> Result<ImplementsMdSpec, ParseError> {
+        let mut spec = ImplementsMdSpec::default();
+        let headings = parse_sections(content);
+
+        // FAIL FAST
+        if let Some(violation) = check_section_rules(&headings).into_iter().next() {
+            return Err(match violation.kind {
+                SectionRuleKind::MissingSection => ParseError::MissingRequiredSection { section: violation.section },
+                _ => ParseError::InvalidSectionFormat { section: violation.section, details: violation.message },
+            });
+        }
+
+        let (name, sections) = self.extract_sections(headings);
+        spec.name = name;
+
+        for section in &sections {
+            if !IMPLEMENTS_SECTIONS.iter().any(|s| s.eq_ignore_ascii_case(&section.name)) {
+                spec.warnings.push(format!("Unknown IMPLEMENTS.md section: {}", section.name));
+                continue;
+            }
+            if section.is_none_marker() {
+                continue;
+            }
+
+            match section.name.to_lowercase().as_str() {
+                "dependencies direction" => {
+                    spec.dependencies_direction = self.parse_dependencies_direction(section);
+                }
+                "implementation approach" => {
+                    spec.implementation_approach = self.parse_implementation_approach(section);
+                }
+                "technology choices" => {
+                    spec.technology_choices = parse_table_rows(&section.content)
+                        .into_iter()
+                        .map(|row| TechnologyChoiceSpec {
+                            choice: cell(&row, 0),
+                            alternative: cell(&row, 1),
+                            reason: cell(&row, 2),
+                        })
+                        .collect();
+                }
+                "algorithm" => {
+                    spec.algorithm = self.parse_algorithm(section);
+                }
+                "key constants" => {
+                    spec.key_constants = parse_table_rows(&section.content)
+                        .into_iter()
+                        .map(|row| KeyConstantSpec {
+                            name: cell(&row, 0),
+                            value: cell(&row, 1),
+                            rationale: cell(&row, 2),
+                            scope: cell(&row, 3),
+                        })
+                        .collect();
+                }
+                "error handling" => {
+                    spec.error_handling = parse_table_rows(&section.content)
+                        .into_iter()
+                        .map(|row| ErrorHandlingSpec {
+                            error: cell(&row, 0),
+                            retry: cell(&row, 1),
+                            recovery: cell(&row, 2),
+                            log_level: cell(&row, 3),
+                        })
+                        .collect();
+                }
+                "state management" => {
+                    spec.state_management = Some(self.parse_state_management(section));
+                }
+                "implementation guide" => {
+                    spec.implementation_guide = list_items(&section.content);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(spec)
+    }
+
+    /// Group parsed headings into H2 sections, each holding its direct lines and H3+ subsections.
+    /// Returns the H1 title alongside the sections. HTML comment lines are blank.
+    fn extract_sections(&self, headings: Vec<MarkdownSection>) -> (String, Vec<ImplementsSection>) {
+        let mut name = String::new();
+        let mut sections: Vec<ImplementsSection> = Vec::new();
+
+        for heading in headings {
+            match heading.level {
+                1 => {
+                    if name.is_empty() {
+                        name = heading.name;
+                    }
+                }
+                2 => sections.push(ImplementsSection {
+                    name: heading.name,
+                    content: heading.content,
+                    subsections: Vec::new(),
+                }),
+                _ => {
+                    if let Some(section) = sections.last_mut() {
+                        section.subsections.push((heading.name, heading.content));
+                    }
+                }
+            }
+        }
+
+        (name, sections)
+    }
+
+    fn parse_dependencies_direction(&self, section: &ImplementsSection) -> DependenciesDirectionSpec {
+        let mut spec = DependenciesDirectionSpec::default();
+
+        for (title, lines) in &section.subsections {
+            let is_internal = title.eq_ignore_ascii_case("internal");
+            for line in lines {
+                if let Some(entry) = self.parse_direction_entry(line) {
+                    if is_internal {
+                        spec.internal.push(entry);
+                    } else {
+                        spec.external.push(entry);
+                    }
+                }
+            }
+        }
+
+        // Entries written directly under the H2 header: classify by path form
+        for line in &section.content {
+            if let Some(entry) = self.parse_direction_entry(line) {
+                if entry.target.starts_with("./") || entry.target.starts_with("../") {
+                    spec.internal.push(entry);
+                } else {
+                    spec.external.push(entry);
+                }
+            }
+        }
+
+        spec
+    }
+
+    fn parse_direction_entry(&self, line: &str) -> Option<DirectionEntry> {
+        let caps = self.entry_pattern.captures(line.trim())?;
+        Some(DirectionEntry {
+            target: caps.get(1)?.as_str().trim().to_string(),
+            description: caps.get(2).map(|m| m.as_str().trim().to_string()).unwrap_or_default(),
+        })
+    }
+
+    fn parse_implementation_approach(&self, section: &ImplementsSection) -> ImplementationApproachSpec {
+        let mut spec = ImplementationApproachSpec {
+            strategy: list_items(&section.content),
+            alternatives: Vec::new(),
+        };
+
+        for (title, lines) in &section.subsections {
+            let title_lower = title.to_lowercase();
+            if title_lower.contains("대안") || title_lower.contains("alternative") {
+                spec.alternatives.extend(list_items(lines));
+            } else {
+                spec.strategy.extend(list_items(lines));
+            }
+        }
+
+        spec
+    }
+
+    fn parse_algorithm(&self, section: &ImplementsSection) -> Vec<AlgorithmSpec> {
+        let mut algorithms = Vec::new();
+
+        let direct = non_empty_lines(&section.content);
+        if !direct.is_empty() {
+            algorithms.push(AlgorithmSpec {
+                name: String::new(),
+                details: direct,
+            });
+        }
+
+        for (title, lines) in &section.subsections {
+            algorithms.push(AlgorithmSpec {
+                name: title.clone(),
+                details: non_empty_lines(lines),
+            });
+        }
+
+        algorithms
+    }
+
+    fn parse_state_management(&self, section: &ImplementsSection) -> StateManagementSpec {
+        let mut spec = StateManagementSpec {
+            initial_state: list_items(&section.content),
+            ..Default::default()
+        };
+
+        for (title, lines) in &section.subsections {
+            let title_lower = title.to_lowercase();
+            let items = list_items(lines);
+            if title_lower.contains("persist") {
+                spec.persistence.extend(items);
+            } else if title_lower.contains("cleanup") {
+                spec.cleanup.extend(items);
+            } else {
+                spec.initial_state.extend(items);
+            }
+        }
+
+        spec
+    }
+}
+
+impl Default for ImplementsMdParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// H2 section of IMPLEMENTS.md with its H3+ subsections.
+struct ImplementsSection {
+    name: String,
+    content: Vec<String>,
+    subsections: Vec<(String, Vec<String>)>,
+}
+
+impl ImplementsSection {
+    /// Check if the section contains only a "None" marker (None, N/A, etc.)
+    fn is_none_marker(&self) -> bool {
+        if !self.subsections.is_empty() {
+            return false;
+        }
+        let lines: Vec<&str> = self.content.iter().map(|s| s.as_str()).collect();
+        crate::is_none_marker_content(&lines)
+    }
+}
+
+/// Data rows of a markdown table (header and separator rows skipped).
+fn parse_table_rows(lines: &[String]) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut in_table = false;
+
+    for line in lines {
+        let trimmed = line.trim();
+        if !trimmed.starts_with('|') {
+            in_table = false;
+            continue;
+        }
+        // Separator row (|------|------|) starts the data rows
+        if trimmed.contains("---") {
+            in_table = true;
+            continue;
+        }
+        if in_table {
+            rows.push(
+                trimmed
+                    .trim_matches('|')
+                    .split('|')
+                    .map(|c| c.trim().to_string())
+                    .collect(),
+            );
+        }
+    }
+
+    rows
+}
+
+fn cell(row: &[String], idx: usize) -> String {
+    row.get(idx).cloned().unwrap_or_default()
+}
+
+/// Bullet list items with the marker stripped.
+fn list_items(lines: &[String]) -> Vec<String> {
+    lines
+        .iter()
+        .map(|l| l.trim())
+        .filter_map(|l| l.strip_prefix("- ").or_else(|| l.strip_prefix("* ")))
+        .map(|l| l.trim().to_string())
+        .filter(|l| !l.is_empty())
+        .collect()
+}
+
+fn non_empty_lines(lines: &[String]) -> Vec<String> {
+    lines
+        .iter()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"# auth/IMPLEMENTS.md
+<!-- 소스코드에서 읽을 수 없는 "왜?"와 "어떤 맥락?"을 기술 -->
+
+## Dependencies Direction
+
+### External
+- `jsonwebtoken@9.0.0`: JWT 검증 (선택 이유: 기존 프로젝트 호환)
+
+### Internal
+- `../utils/crypto`: 해시 유틸리티 (hashPassword, verifyPassword)
+- `../config`: 환경 설정 (JWT_SECRET, TOKEN_EXPIRY)
+
+## Implementation Approach
+
+### 전략
+- HMAC-SHA256 기반 토큰 검증
+- Fail-fast: 첫 번째 실패 시 즉시 반환
+
+### 고려했으나 선택하지 않은 대안
+- RSA 서명: 키 관리 복잡성
+
+## Technology Choices
+
+| 선택 | 대안 | 선택 이유 |
+|------|------|----------|
+| jsonwebtoken | jose | 기존 코드베이스 호환성 |
+| HMAC-SHA256 | RS256 | 키 관리 단순화 |
+
+## Algorithm
+
+### tokenCache 무효화 전략
+1. 토큰 갱신 시 → 해당 userId의 기존 캐시 삭제
+2. 로그아웃 시 → 해당 userId의 모든 캐시 삭제
+
+## Key Constants
+
+| Name | Value | Rationale | 영향 범위 |
+|------|-------|-----------|----------|
+| TOKEN_EXPIRY_DAYS | 7 | PCI-DSS 요구사항 | 보안 정책 |
+
+## Error Handling
+
+| Error | Retry | Recovery | Log Level |
+|-------|-------|----------|-----------|
+| TokenExpiredError | ✗ | 401 반환, 재로그인 유도 | WARN |
+
+## State Management
+
+### Initial State
+- tokenCache: new Map<string, CachedClaims>()
+
+### Persistence
+- 위치: 메모리 전용
+
+### Cleanup
+- 5분 주기 setInterval
+
+## Implementation Guide
+
+- 토큰 검증 → ../jwt/CLAUDE.md#validateToken
+"#;
+
+    #[test]
+    fn test_parse_full_implements_md() {
+        let parser = ImplementsMdParser::new();
+        let spec = parser.parse_content(SAMPLE).unwrap();
+
+        assert_eq!(spec.name, "auth/IMPLEMENTS.md");
+        assert_eq!(spec.dependencies_direction.external.len(), 1);
+        assert_eq!(spec.dependencies_direction.external[0].target, "jsonwebtoken@9.0.0");
+        assert_eq!(spec.dependencies_direction.internal.len(), 2);
+        assert_eq!(spec.dependencies_direction.internal[1].target, "../config");
+        assert_eq!(spec.implementation_approach.strategy.len(), 2);
+        assert_eq!(spec.implementation_approach.alternatives, vec!["RSA 서명: 키 관리 복잡성"]);
+        assert_eq!(spec.technology_choices.len(), 2);
+        assert_eq!(spec.technology_choices[1].alternative, "RS256");
+        assert_eq!(spec.algorithm[0].name, "tokenCache 무효화 전략");
+        assert_eq!(spec.algorithm[0].details.len(), 2);
+        assert_eq!(spec.key_constants[0].value, "7");
+        assert_eq!(spec.error_handling[0].log_level, "WARN");
+        let state = spec.state_management.unwrap();
+        assert_eq!(state.persistence, vec!["위치: 메모리 전용"]);
+        assert_eq!(state.cleanup, vec!["5분 주기 setInterval"]);
+        assert_eq!(spec.implementation_guide.len(), 1);
+        assert!(spec.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_none_sections_parse_as_empty() {
+        let parser = ImplementsMdParser::new();
+        let content = r#"# util/IMPLEMENTS.md
+
+## Dependencies Direction
+
+### External
+- `lodash@4.17.21`: 유틸리티
+
+## Implementation Approach
+- 순수 함수로 구현
+
+## Technology Choices
+None
+
+## Error Handling
+None
+
+## State Management
+None
+"#;
+        let spec = parser.parse_content(content).unwrap();
+        assert!(spec.technology_choices.is_empty());
+        assert!(spec.error_handling.is_empty());
+        assert!(spec.state_management.is_none());
+        assert_eq!(spec.implementation_approach.strategy, vec!["순수 함수로 구현"]);
+    }
+
+    #[test]
+    fn test_missing_required_section_fails() {
+        let parser = ImplementsMdParser::new();
+        let content = SAMPLE.replace("## Error Handling", "## Error Notes");
+        let err = parser.parse_content(&content).unwrap_err();
+        assert!(matches!(err, ParseError::MissingRequiredSection { ref section } if section == "Error Handling"));
+    }
+
+    #[test]
+    fn test_none_not_allowed_for_implementation_approach() {
+        let parser = ImplementsMdParser::new();
+        let content = SAMPLE.replace(
+            "### 전략\n- HMAC-SHA256 기반 토큰 검증\n- Fail-fast: 첫 번째 실패 시 즉시 반환\n\n### 고려했으나 선택하지 않은 대안\n- RSA 서명: 키 관리 복잡성\n",
+            "None\n",
+        );
+        let err = parser.parse_content(&content).unwrap_err();
+        assert!(matches!(err, ParseError::InvalidSectionFormat { ref section, .. } if section == "Implementation Approach"));
+    }
+
+    #[test]
+    fn test_none_not_allowed_for_optional_sections() {
+        let parser = ImplementsMdParser::new();
+        let content = format!("{}\n## Key Constants\nNone\n\n## Algorithm\nNone\n", SAMPLE.replace("## Key Constants", "## Old Constants"));
+        let err = parser.parse_content(&content).unwrap_err();
+        assert!(matches!(err, ParseError::InvalidSectionFormat { ref section, .. } if section == "Key Constants"));
+
+        let violations = check_section_rules(&parse_sections(&content));
+        let sections: Vec<&str> = violations.iter().map(|v| v.section.as_str()).collect();
+        assert_eq!(sections, vec!["Key Constants", "Algorithm"]);
+        assert!(violations.iter().all(|v| v.kind == SectionRuleKind::NoneNotAllowed));
+    }
+
+    #[test]
+    fn test_unknown_section_warns() {
+        let parser = ImplementsMdParser::new();
+        let content = format!("{}\n## Notes\n- something\n", SAMPLE);
+        let spec = parser.parse_content(&content).unwrap();
+        assert_eq!(spec.warnings, vec!["Unknown IMPLEMENTS.md section: Notes"]);
+    }
+}
@@ -0,0 +1,115 @@
+//! JSON-RPC 2.0 message framing over byte streams (`Content-Length` headers, as used by LSP).
+
+use serde_json::{json, Value};
+use std::io::{BufRead, Write};
+use thiserror::Error;
+
+/// Standard JSON-RPC error codes
+pub const PARSE_ERROR: i64 = -32700;
+pub const INVALID_REQUEST: i64 = -32600;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+/// Implementation-defined server error (command failed)
+pub const SERVER_ERROR: i64 = -32000;
+
+#[derive(Debug, Error)]
+pub enum JsonRpcError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid message header: {0}")]
+    InvalidHeader(String),
+
+    #[error("Invalid JSON-RPC message: {0}")]
+    InvalidJson(#[from] serde_json::Error),
+}
+
+/// Read one framed message. Returns `Ok(None)` at end of input.
+pub fn read_message<R: BufRead>(reader: &mut R) -> Result<Option<Value>, JsonRpcError> {
+    let mut content_length = None;
+    let mut line = String::new();
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return if content_length.is_none() {
+                Ok(None)
+            } else {
+                Err(JsonRpcError::InvalidHeader("unexpected end of input".to_string()))
+            };
+        }
+        let header = line.trim_end_matches(['\r', '\n']);
+        if header.is_empty() {
+            if content_length.is_some() {
+                break;
+            }
+            continue;
+        }
+        let Some((name, value)) = header.split_once(':') else {
+            return Err(JsonRpcError::InvalidHeader(header.to_string()));
+        };
+        if name.trim().eq_ignore_ascii_case("Content-Length") {
+            let length = value.trim().parse::<usize>()
+                .map_err(|_| JsonRpcError::InvalidHeader(header.to_string()))?;
+            content_length = Some(length);
+        }
+    }
+
+    let mut body = vec![0; content_length.unwrap_or(0)];
+    reader.read_exact(&mut body)?;
+    Ok(Some(serde_json::from_slice(&body)?))
+}
+
+/// Write one framed message and flush.
+pub fn write_message<W: Write>(writer: &mut W, message: &Value) -> Result<(), JsonRpcError> {
+    let body = serde_json::to_string(message)?;
+    write!(writer, "Content-Length: {}\r\n\r\n{}", body.len(), body)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Successful response to the request with `id`
+pub fn response(id: &Value, result: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "result": result })
+}
+
+/// Error response to the request with `id`
+pub fn error_response(id: &Value, code: i64, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+/// Error response carrying additional `data` (e.g. the report behind a `--strict` failure)
+pub fn error_response_with_data(id: &Value, code: i64, message: &str, data: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message, "data": data } })
+}
+
+/// Notification (no response expected)
+pub fn notification(method: &str, params: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "method": method, "params": params })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_write_then_read_round_trip() {
+        let mut buffer = Vec::new();
+        let first = notification("initialized", json!({}));
+        let second = response(&json!(1), json!({ "text": "héllo" }));
+        write_message(&mut buffer, &first).unwrap();
+        write_message(&mut buffer, &second).unwrap();
+
+        let mut reader = Cursor::new(buffer);
+        assert_eq!(read_message(&mut reader).unwrap(), Some(first));
+        assert_eq!(read_message(&mut reader).unwrap(), Some(second));
+        assert_eq!(read_message(&mut reader).unwrap(), None);
+    }
+
+    #[test]
+    fn test_read_rejects_bad_header() {
+        let mut reader = Cursor::new(b"Content-Length: abc\r\n\r\n{}".to_vec());
+        assert!(matches!(read_message(&mut reader), Err(JsonRpcError::InvalidHeader(_))));
+    }
+}
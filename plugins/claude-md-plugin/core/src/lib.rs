@@ -60,21 +60,36 @@ pub mod boundary_resolver;
 pub mod schema_validator;
 pub mod code_analyzer;
 pub mod claude_md_parser;
+pub mod claude_md_renderer;
+pub mod claude_md_formatter;
+pub mod implements_md_parser;
 pub mod bracket_utils;
+pub mod signature;
 pub mod convention_validator;
 pub mod dependency_resolver;
 pub mod claude_md_scanner;
 pub mod compile_target_resolver;
+pub mod compile_lock;
+pub mod dependency_graph;
 pub mod exports_formatter;
 pub mod analysis_formatter;
+pub mod drift_detector;
+pub mod spec_diff;
+pub mod changelog;
+pub mod jsonrpc;
+pub mod lsp;
+pub mod daemon;
+pub mod markdown;
 
 pub use tree_parser::TreeParser;
 pub use boundary_resolver::BoundaryResolver;
 pub use schema_validator::SchemaValidator;
 pub use code_analyzer::CodeAnalyzer;
 pub use claude_md_parser::ClaudeMdParser;
+pub use implements_md_parser::ImplementsMdParser;
 pub use bracket_utils::{split_respecting_brackets, find_matching_bracket, extract_parenthesized};
 pub use convention_validator::ConventionValidator;
+pub use drift_detector::DriftDetector;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,578 @@
+//! Language server for editing CLAUDE.md files (stdio, LSP over JSON-RPC).
+//!
+//! Open CLAUDE.md buffers are validated on every change with `SchemaValidator` and
+//! `BoundaryResolver`, and the results are published as diagnostics. `path/CLAUDE.md`
+//! references support go-to-definition and hover (the module purpose from
+//! `ClaudeMdScanner`), and `## ` headings complete the missing required sections.
+
+use regex::Regex;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use crate::boundary_resolver::BoundaryResolver;
+use crate::claude_md_scanner::{ClaudeMdEntry, ClaudeMdScanner};
+use crate::jsonrpc::{self, JsonRpcError};
+use crate::markdown::parse_sections;
+use crate::schema_validator::{SchemaValidator, REQUIRED_SECTIONS};
+
+const SOURCE: &str = "claude-md-core";
+
+/// LSP DiagnosticSeverity
+const SEVERITY_ERROR: u8 = 1;
+const SEVERITY_WARNING: u8 = 2;
+
+/// LSP CompletionItemKind::Keyword
+const COMPLETION_KIND_KEYWORD: u8 = 14;
+
+/// LSP TextDocumentSyncKind::Full
+const SYNC_FULL: u8 = 1;
+
+/// A `path/CLAUDE.md` reference found in a line, with its char span
+struct Reference {
+    target: String,
+    start: usize,
+    end: usize,
+}
+
+pub struct LanguageServer {
+    /// Project root (from `initialize`); references resolve against it first
+    root: Option<PathBuf>,
+    /// Open documents by URI
+    documents: HashMap<String, String>,
+    /// Module index, refreshed on `initialize` and whenever a CLAUDE.md is saved
+    modules: Vec<ClaudeMdEntry>,
+    shutdown_requested: bool,
+    exited: bool,
+    validator: SchemaValidator,
+    boundary: BoundaryResolver,
+    scanner: ClaudeMdScanner,
+    reference_pattern: Regex,
+}
+
+impl LanguageServer {
+    pub fn new() -> Self {
+        Self {
+            root: None,
+            documents: HashMap::new(),
+            modules: Vec::new(),
+            shutdown_requested: false,
+            exited: false,
+            validator: SchemaValidator::new(),
+            boundary: BoundaryResolver::new(),
+            scanner: ClaudeMdScanner::new(),
+            reference_pattern: Regex::new(r"[\w./-]+/CLAUDE\.md").unwrap(),
+        }
+    }
+
+    /// Serve messages from `reader` until `exit` or end of input.
+    pub fn run<R: BufRead, W: Write>(&mut self, reader: &mut R, writer: &mut W) -> Result<(), JsonRpcError> {
+        while !self.exited {
+            let message = match jsonrpc::read_message(reader) {
+                Ok(Some(message)) => message,
+                Ok(None) => break,
+                Err(JsonRpcError::InvalidJson(e)) => {
+                    let reply = jsonrpc::error_response(&Value::Null, jsonrpc::PARSE_ERROR, &e.to_string());
+                    jsonrpc::write_message(writer, &reply)?;
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+            for reply in self.handle_message(&message) {
+                jsonrpc::write_message(writer, &reply)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether the client sent `exit`
+    pub fn has_exited(&self) -> bool {
+        self.exited
+    }
+
+    /// Handle one request or notification; returns responses and notifications to send.
+    pub fn handle_message(&mut self, message: &Value) -> Vec<Value> {
+        let method = message["method"].as_str().unwrap_or_default();
+        let params = &message["params"];
+
+        let Some(id) = message.get("id") else {
+            return self.handle_notification(method, params);
+        };
+
+        if self.shutdown_requested {
+            return vec![jsonrpc::error_response(id, jsonrpc::INVALID_REQUEST, "Server is shutting down")];
+        }
+
+        let result = match method {
+            "initialize" => self.initialize(params),
+            "shutdown" => {
+                self.shutdown_requested = true;
+                Value::Null
+            }
+            "textDocument/definition" => self.definition(params),
+            "textDocument/hover" => self.hover(params),
+            "textDocument/completion" => self.completion(params),
+            _ => {
+                return vec![jsonrpc::error_response(
+                    id,
+                    jsonrpc::METHOD_NOT_FOUND,
+                    &format!("Unsupported method: {}", method),
+                )]
+            }
+        };
+        vec![jsonrpc::response(id, result)]
+    }
+
+    fn handle_notification(&mut self, method: &str, params: &Value) -> Vec<Value> {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default().to_string();
+
+        match method {
+            "exit" => {
+                self.exited = true;
+                vec![]
+            }
+            "textDocument/didOpen" => {
+                let text = params["textDocument"]["text"].as_str().unwrap_or_default();
+                self.documents.insert(uri.clone(), text.to_string());
+                self.publish_diagnostics(&uri)
+            }
+            "textDocument/didChange" => {
+                // Full sync: the last change carries the whole document
+                let Some(text) = params["contentChanges"]
+                    .as_array()
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change["text"].as_str())
+                else {
+                    return vec![];
+                };
+                self.documents.insert(uri.clone(), text.to_string());
+                self.publish_diagnostics(&uri)
+            }
+            "textDocument/didSave" => {
+                if is_claude_md(&uri) {
+                    self.refresh_modules();
+                }
+                vec![]
+            }
+            "textDocument/didClose" => {
+                self.documents.remove(&uri);
+                if is_claude_md(&uri) {
+                    vec![diagnostics_notification(&uri, vec![])]
+                } else {
+                    vec![]
+                }
+            }
+            _ => vec![],
+        }
+    }
+
+    fn initialize(&mut self, params: &Value) -> Value {
+        self.root = params["rootUri"]
+            .as_str()
+            .or_else(|| params["workspaceFolders"][0]["uri"].as_str())
+            .and_then(uri_to_path)
+            .or_else(|| params["rootPath"].as_str().map(PathBuf::from));
+        self.refresh_modules();
+
+        json!({
+            "capabilities": {
+                "textDocumentSync": { "openClose": true, "change": SYNC_FULL, "save": true },
+                "definitionProvider": true,
+                "hoverProvider": true,
+                "completionProvider": { "triggerCharacters": ["#", " "] },
+            },
+            "serverInfo": { "name": SOURCE, "version": env!("CARGO_PKG_VERSION") },
+        })
+    }
+
+    fn refresh_modules(&mut self) {
+        if let Some(root) = &self.root {
+            self.modules = self.scanner.scan(root).entries;
+        }
+    }
+
+    fn publish_diagnostics(&self, uri: &str) -> Vec<Value> {
+        let (Some(path), Some(text)) = (uri_to_path(uri), self.documents.get(uri)) else {
+            return vec![];
+        };
+        if !is_claude_md(uri) {
+            return vec![];
+        }
+        vec![diagnostics_notification(uri, self.diagnostics(&path, text))]
+    }
+
+    /// SchemaValidator errors/warnings and BoundaryResolver violations for `text`
+    pub(crate) fn diagnostics(&self, path: &Path, text: &str) -> Vec<Value> {
+        let lines: Vec<&str> = text.lines().collect();
+        let mut diagnostics = Vec::new();
+
+        let validation = self.validator.validate_content(path, text);
+        for error in &validation.errors {
+            diagnostics.push(diagnostic(&lines, error.line_number, SEVERITY_ERROR, &error.error_type, &error.message));
+        }
+        for warning in &validation.warnings {
+            let (line_number, message) = split_line_prefix(warning);
+            diagnostics.push(diagnostic(&lines, line_number, SEVERITY_WARNING, "Warning", message));
+        }
+
+        if let Some(dir) = path.parent() {
+            for violation in self.boundary.check_references(dir, text) {
+                let message = format!(
+                    "{} reference '{}' crosses the module boundary (only child directories may be referenced)",
+                    violation.violation_type, violation.reference
+                );
+                let code = format!("{}Reference", violation.violation_type);
+                diagnostics.push(diagnostic(&lines, Some(violation.line_number), SEVERITY_ERROR, &code, &message));
+            }
+        }
+
+        diagnostics
+    }
+
+    fn definition(&self, params: &Value) -> Value {
+        let Some((uri, reference)) = self.reference_at(params) else {
+            return Value::Null;
+        };
+        match self.resolve_reference(&uri, &reference.target) {
+            Some(target) => json!({
+                "uri": path_to_uri(&target),
+                "range": range(0, 0, 0),
+            }),
+            None => Value::Null,
+        }
+    }
+
+    fn hover(&self, params: &Value) -> Value {
+        let Some((uri, reference)) = self.reference_at(params) else {
+            return Value::Null;
+        };
+        let Some(target) = self.resolve_reference(&uri, &reference.target) else {
+            return Value::Null;
+        };
+        let Some(entry) = self.module_entry(&target) else {
+            return Value::Null;
+        };
+
+        let line = params["position"]["line"].as_u64().unwrap_or(0);
+        let text = self.documents.get(&uri).map(String::as_str).unwrap_or_default();
+        let line_text = text.lines().nth(line as usize).unwrap_or_default();
+        let mut contents = format!("**{}**\n\n{}", entry.dir.display(), entry.purpose);
+        if !entry.export_names.is_empty() {
+            contents.push_str(&format!("\n\nExports: {}", entry.export_names.join(", ")));
+        }
+        json!({
+            "contents": { "kind": "markdown", "value": contents },
+            "range": {
+                "start": { "line": line, "character": utf16_offset(line_text, reference.start) },
+                "end": { "line": line, "character": utf16_offset(line_text, reference.end) },
+            },
+        })
+    }
+
+    fn completion(&self, params: &Value) -> Value {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+        let Some(text) = self.documents.get(uri) else {
+            return json!([]);
+        };
+        let line = params["position"]["line"].as_u64().unwrap_or(0);
+        let line_text = text.lines().nth(line as usize).unwrap_or_default();
+        if !line_text.trim_start().starts_with('#') {
+            return json!([]);
+        }
+
+        let present: Vec<String> = parse_sections(text)
+            .into_iter()
+            .filter(|s| s.level == 2)
+            .map(|s| s.name.to_lowercase())
+            .collect();
+        let replace = json!({
+            "start": { "line": line, "character": 0 },
+            "end": { "line": line, "character": utf16_offset(line_text, line_text.chars().count()) },
+        });
+
+        let items: Vec<Value> = REQUIRED_SECTIONS
+            .iter()
+            .filter(|section| !present.contains(&section.to_lowercase()))
+            .map(|section| json!({
+                "label": section,
+                "kind": COMPLETION_KIND_KEYWORD,
+                "detail": "Required CLAUDE.md section",
+                "textEdit": { "range": replace, "newText": format!("## {}", section) },
+            }))
+            .collect();
+        json!(items)
+    }
+
+    /// The `path/CLAUDE.md` reference under the cursor in an open document
+    fn reference_at(&self, params: &Value) -> Option<(String, Reference)> {
+        let uri = params["textDocument"]["uri"].as_str()?;
+        let text = self.documents.get(uri)?;
+        let line = params["position"]["line"].as_u64()? as usize;
+        let character = params["position"]["character"].as_u64()? as usize;
+        let line_text = text.lines().nth(line)?;
+        let cursor = char_index(line_text, character);
+
+        self.reference_pattern.find_iter(line_text).find_map(|m| {
+            let start = line_text[..m.start()].chars().count();
+            let end = start + m.as_str().chars().count();
+            (start..=end).contains(&cursor).then(|| Reference {
+                target: m.as_str().to_string(),
+                start,
+                end,
+            })
+        }).map(|reference| (uri.to_string(), reference))
+    }
+
+    /// Resolve a reference root-relative first (the Dependencies convention), then
+    /// relative to the referencing document.
+    fn resolve_reference(&self, uri: &str, target: &str) -> Option<PathBuf> {
+        let document_dir = uri_to_path(uri).and_then(|p| p.parent().map(Path::to_path_buf));
+        self.root
+            .iter()
+            .chain(document_dir.iter())
+            .map(|base| base.join(target))
+            .find(|candidate| candidate.is_file())
+    }
+
+    fn module_entry(&self, claude_md: &Path) -> Option<&ClaudeMdEntry> {
+        let root = self.root.as_ref()?.canonicalize().ok()?;
+        let dir = claude_md.parent()?.canonicalize().ok()?;
+        let relative = dir.strip_prefix(&root).ok()?;
+        self.modules.iter().find(|entry| entry.dir == relative)
+    }
+}
+
+impl Default for LanguageServer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn is_claude_md(uri: &str) -> bool {
+    uri.ends_with("/CLAUDE.md")
+}
+
+fn diagnostics_notification(uri: &str, diagnostics: Vec<Value>) -> Value {
+    jsonrpc::notification(
+        "textDocument/publishDiagnostics",
+        json!({ "uri": uri, "diagnostics": diagnostics }),
+    )
+}
+
+/// Diagnostic spanning the whole 1-based `line_number` (first line when unknown)
+fn diagnostic(lines: &[&str], line_number: Option<usize>, severity: u8, code: &str, message: &str) -> Value {
+    let line = line_number.map(|n| n.saturating_sub(1)).unwrap_or(0);
+    let text = lines.get(line).copied().unwrap_or_default();
+    json!({
+        "range": range(line, 0, utf16_offset(text, text.chars().count())),
+        "severity": severity,
+        "code": code,
+        "source": SOURCE,
+        "message": message,
+    })
+}
+
+fn range(line: usize, start: usize, end: usize) -> Value {
+    json!({
+        "start": { "line": line, "character": start },
+        "end": { "line": line, "character": end },
+    })
+}
+
+/// Split a "Line N: message" warning into its line number and message
+fn split_line_prefix(warning: &str) -> (Option<usize>, &str) {
+    warning
+        .strip_prefix("Line ")
+        .and_then(|rest| rest.split_once(": "))
+        .and_then(|(number, message)| number.parse().ok().map(|n| (Some(n), message)))
+        .unwrap_or((None, warning))
+}
+
+/// UTF-16 offset (LSP column) of the char index `chars` in `line`
+fn utf16_offset(line: &str, chars: usize) -> usize {
+    line.chars().take(chars).map(char::len_utf16).sum()
+}
+
+/// Char index for the UTF-16 offset `utf16` in `line`
+fn char_index(line: &str, utf16: usize) -> usize {
+    let mut offset = 0;
+    for (index, c) in line.chars().enumerate() {
+        if offset >= utf16 {
+            return index;
+        }
+        offset += c.len_utf16();
+    }
+    line.chars().count()
+}
+
+/// Convert a `file://` URI to a path, decoding percent escapes
+pub(crate) fn uri_to_path(uri: &str) -> Option<PathBuf> {
+    let encoded = uri.strip_prefix("file://")?;
+    let bytes = encoded.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or_default();
+            if let Ok(byte) = u8::from_str_radix(hex, 16) {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8(decoded).ok().map(PathBuf::from)
+}
+
+/// Convert an absolute path to a `file://` URI, percent-encoding reserved bytes
+pub(crate) fn path_to_uri(path: &Path) -> String {
+    let mut uri = String::from("file://");
+    for byte in path.to_string_lossy().bytes() {
+        if byte.is_ascii_alphanumeric() || b"/-_.~".contains(&byte) {
+            uri.push(byte as char);
+        } else {
+            uri.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    uri
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const AUTH_SPEC: &str = "# auth\n\n## Purpose\nIssues and validates session tokens.\n\n## Exports\n- `validateToken(token: string): Claims`\n\n## Behavior\n- valid token → Claims\n\n## Contract\nNone\n\n## Protocol\nNone\n\n## Domain Context\nNone\n";
+
+    fn project() -> (TempDir, LanguageServer) {
+        let temp = TempDir::new().unwrap();
+        for dir in ["src/auth", "src/api"] {
+            fs::create_dir_all(temp.path().join(dir)).unwrap();
+        }
+        fs::write(temp.path().join("src/auth/CLAUDE.md"), AUTH_SPEC).unwrap();
+
+        let mut server = LanguageServer::new();
+        server.handle_message(&json!({
+            "jsonrpc": "2.0", "id": 1, "method": "initialize",
+            "params": { "rootUri": path_to_uri(temp.path()) },
+        }));
+        (temp, server)
+    }
+
+    fn open(server: &mut LanguageServer, uri: &str, text: &str) -> Vec<Value> {
+        server.handle_message(&json!({
+            "jsonrpc": "2.0", "method": "textDocument/didOpen",
+            "params": { "textDocument": { "uri": uri, "languageId": "markdown", "version": 1, "text": text } },
+        }))
+    }
+
+    fn request(server: &mut LanguageServer, method: &str, uri: &str, line: usize, character: usize) -> Value {
+        let replies = server.handle_message(&json!({
+            "jsonrpc": "2.0", "id": 2, "method": method,
+            "params": { "textDocument": { "uri": uri }, "position": { "line": line, "character": character } },
+        }));
+        replies[0]["result"].clone()
+    }
+
+    #[test]
+    fn test_open_publishes_schema_and_boundary_diagnostics() {
+        let (temp, mut server) = project();
+        let uri = path_to_uri(&temp.path().join("src/api/CLAUDE.md"));
+        let text = "# api\n\n## Purpose\nHTTP handlers.\n\n## Dependencies\n- ../shared/utils\n";
+
+        let replies = open(&mut server, &uri, text);
+        assert_eq!(replies[0]["method"], "textDocument/publishDiagnostics");
+        let diagnostics = replies[0]["params"]["diagnostics"].as_array().unwrap();
+
+        assert!(diagnostics.iter().any(|d| d["code"] == "MissingSection"
+            && d["message"].as_str().unwrap().contains("Exports")));
+        let parent = diagnostics.iter().find(|d| d["code"] == "ParentReference").unwrap();
+        assert_eq!(parent["range"]["start"]["line"], 6);
+        assert_eq!(parent["severity"], SEVERITY_ERROR);
+    }
+
+    #[test]
+    fn test_change_revalidates_and_close_clears() {
+        let (temp, mut server) = project();
+        let uri = path_to_uri(&temp.path().join("src/auth/CLAUDE.md"));
+        open(&mut server, &uri, "# auth\n");
+
+        let replies = server.handle_message(&json!({
+            "jsonrpc": "2.0", "method": "textDocument/didChange",
+            "params": { "textDocument": { "uri": uri, "version": 2 }, "contentChanges": [{ "text": AUTH_SPEC }] },
+        }));
+        let diagnostics = replies[0]["params"]["diagnostics"].as_array().unwrap();
+        assert!(diagnostics.iter().all(|d| d["severity"] != SEVERITY_ERROR));
+
+        let replies = server.handle_message(&json!({
+            "jsonrpc": "2.0", "method": "textDocument/didClose",
+            "params": { "textDocument": { "uri": uri } },
+        }));
+        assert_eq!(replies[0]["params"]["diagnostics"], json!([]));
+    }
+
+    #[test]
+    fn test_definition_and_hover_on_dependency_reference() {
+        let (temp, mut server) = project();
+        let uri = path_to_uri(&temp.path().join("src/api/CLAUDE.md"));
+        open(&mut server, &uri, "# api\n\n## Dependencies\n\n### Internal\n- `src/auth/CLAUDE.md`: token checks\n");
+
+        let location = request(&mut server, "textDocument/definition", &uri, 5, 8);
+        let target = uri_to_path(location["uri"].as_str().unwrap()).unwrap();
+        assert_eq!(target.canonicalize().unwrap(), temp.path().join("src/auth/CLAUDE.md").canonicalize().unwrap());
+
+        let hover = request(&mut server, "textDocument/hover", &uri, 5, 8);
+        let contents = hover["contents"]["value"].as_str().unwrap();
+        assert!(contents.contains("src/auth"));
+        assert!(contents.contains("Issues and validates session tokens."));
+        assert_eq!(hover["range"]["start"]["character"], 3);
+
+        assert_eq!(request(&mut server, "textDocument/definition", &uri, 5, 30), Value::Null);
+    }
+
+    #[test]
+    fn test_completion_offers_missing_required_sections() {
+        let (temp, mut server) = project();
+        let uri = path_to_uri(&temp.path().join("src/api/CLAUDE.md"));
+        open(&mut server, &uri, "# api\n\n## Purpose\nHTTP handlers.\n\n## \n");
+
+        let items = request(&mut server, "textDocument/completion", &uri, 5, 3);
+        let labels: Vec<&str> = items.as_array().unwrap().iter().map(|i| i["label"].as_str().unwrap()).collect();
+        assert!(labels.contains(&"Exports"));
+        assert!(!labels.contains(&"Purpose"));
+        assert_eq!(items[0]["textEdit"]["newText"], format!("## {}", labels[0]));
+
+        assert_eq!(request(&mut server, "textDocument/completion", &uri, 3, 1), json!([]));
+    }
+
+    #[test]
+    fn test_shutdown_then_exit_over_stdio_framing() {
+        let mut input = Vec::new();
+        jsonrpc::write_message(&mut input, &json!({ "jsonrpc": "2.0", "id": 1, "method": "shutdown" })).unwrap();
+        jsonrpc::write_message(&mut input, &json!({ "jsonrpc": "2.0", "id": 2, "method": "workspace/symbol" })).unwrap();
+        jsonrpc::write_message(&mut input, &json!({ "jsonrpc": "2.0", "method": "exit" })).unwrap();
+
+        let mut server = LanguageServer::new();
+        let mut output = Vec::new();
+        server.run(&mut std::io::Cursor::new(input), &mut output).unwrap();
+        assert!(server.has_exited());
+
+        let mut reader = std::io::Cursor::new(output);
+        let shutdown = jsonrpc::read_message(&mut reader).unwrap().unwrap();
+        assert_eq!(shutdown["result"], Value::Null);
+        let rejected = jsonrpc::read_message(&mut reader).unwrap().unwrap();
+        assert_eq!(rejected["error"]["code"], jsonrpc::INVALID_REQUEST);
+    }
+
+    #[test]
+    fn test_uri_round_trip() {
+        let path = PathBuf::from("/tmp/my project/CLAUDE.md");
+        let uri = path_to_uri(&path);
+        assert_eq!(uri, "file:///tmp/my%20project/CLAUDE.md");
+        assert_eq!(uri_to_path(&uri), Some(path));
+    }
+}
@@ -1,18 +1,27 @@
-use clap::{Parser, Subcommand};
-use serde::{Deserialize, Serialize};
+use clap::{Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
 
 use claude_md_core::{
     TreeParser, BoundaryResolver, SchemaValidator,
-    ClaudeMdParser, ConventionValidator, CodeAnalyzer,
+    ClaudeMdParser, ConventionValidator, CodeAnalyzer, ImplementsMdParser,
 };
 use claude_md_core::tree_parser;
 use claude_md_core::code_analyzer;
-use claude_md_core::dependency_resolver::DependencyResolver;
+use claude_md_core::dependency_resolver::{self, DependencyResolver};
 use claude_md_core::claude_md_scanner::ClaudeMdScanner;
-use claude_md_core::compile_target_resolver::CompileTargetResolver;
+use claude_md_core::compile_target_resolver::{CompileTargetResolver, ResolveOptions};
+use claude_md_core::compile_lock::CompileLock;
+use claude_md_core::claude_md_parser::ClaudeMdSpec;
+use claude_md_core::claude_md_renderer;
+use claude_md_core::claude_md_formatter::ClaudeMdFormatter;
 use claude_md_core::exports_formatter;
 use claude_md_core::analysis_formatter;
+use claude_md_core::drift_detector::DriftDetector;
+use claude_md_core::spec_diff::{Severity, SpecDiffer};
+use claude_md_core::changelog::ChangelogGenerator;
+use claude_md_core::dependency_graph::DependencyGraph;
+use claude_md_core::lsp::LanguageServer;
+use claude_md_core::daemon::{Daemon, DaemonError};
 
 #[derive(Parser)]
 #[command(name = "claude-md-core")]
@@ -74,6 +83,62 @@ enum Commands {
         /// Output JSON file path
         #[arg(short, long)]
         output: Option<PathBuf>,
+
+        /// Keep parsing past errors and report every problem as a diagnostic
+        #[arg(long, default_value_t = false)]
+        lenient: bool,
+    },
+
+    /// Render a parse-claude-md JSON spec back into canonical CLAUDE.md markdown
+    RenderClaudeMd {
+        /// parse-claude-md output JSON file
+        #[arg(short, long)]
+        input: PathBuf,
+
+        /// Output markdown file path (stdout if omitted)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Format CLAUDE.md files canonically (section order, list markers, arrows, Exports order)
+    FmtClaudeMd {
+        /// CLAUDE.md files to format
+        #[arg(short, long, required = true, num_args = 1..)]
+        file: Vec<PathBuf>,
+
+        /// Report files that are not formatted and exit with an error, without writing
+        #[arg(long, default_value_t = false, conflicts_with = "write")]
+        check: bool,
+
+        /// Rewrite files in place (prints formatted content to stdout otherwise)
+        #[arg(long, default_value_t = false)]
+        write: bool,
+    },
+
+    /// Parse IMPLEMENTS.md into structured JSON spec
+    ParseImplementsMd {
+        /// IMPLEMENTS.md file to parse
+        #[arg(short, long)]
+        file: PathBuf,
+
+        /// Output JSON file path
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Validate IMPLEMENTS.md schema
+    ValidateImplements {
+        /// IMPLEMENTS.md file to validate
+        #[arg(short, long)]
+        file: PathBuf,
+
+        /// Output JSON file path
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Enforce INV-3 (CLAUDE.md existence) as error instead of warning
+        #[arg(long, default_value_t = false)]
+        strict: bool,
     },
 
     /// Validate convention sections in CLAUDE.md files
@@ -106,6 +171,12 @@ enum Commands {
         #[arg(short, long)]
         tree_result: Option<PathBuf>,
 
+        /// Analyzer backend per language, e.g. `rust=tree-sitter,typescript=tree-sitter`.
+        /// A bare `tree-sitter` selects it for every language that supports it: rust, typescript
+        /// (and javascript), go, python and java. Other languages only have the regex backend.
+        #[arg(short, long, value_delimiter = ',')]
+        backend: Vec<String>,
+
         /// Output JSON file path
         #[arg(short, long)]
         output: Option<PathBuf>,
@@ -142,6 +213,52 @@ enum Commands {
         /// Output JSON file path
         #[arg(short, long)]
         output: Option<PathBuf>,
+
+        /// Also target transitive dependents and emit a leaf-first compile plan
+        #[arg(long, default_value_t = false)]
+        cascade: bool,
+
+        /// Compare content hashes in claude-md.lock instead of git history
+        #[arg(long, default_value_t = false)]
+        lock: bool,
+
+        /// Target every CLAUDE.md directory (full compilation)
+        #[arg(long, default_value_t = false)]
+        all: bool,
+
+        /// Also target specs changed since this git ref (e.g. main)
+        #[arg(long)]
+        since: Option<String>,
+    },
+
+    /// Determine which CLAUDE.md files are outdated by newer source code (decompile worklist)
+    DiffDecompileTargets {
+        /// Root directory to scan
+        #[arg(short, long, default_value = ".")]
+        root: PathBuf,
+
+        /// Output JSON file path
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Compare content hashes in claude-md.lock instead of git history
+        #[arg(long, default_value_t = false)]
+        lock: bool,
+    },
+
+    /// Record CLAUDE.md/IMPLEMENTS.md/source hashes in claude-md.lock after compiling
+    UpdateLock {
+        /// Root directory containing claude-md.lock
+        #[arg(short, long, default_value = ".")]
+        root: PathBuf,
+
+        /// Module directories to record, relative to root (all modules if omitted)
+        #[arg(short, long, value_delimiter = ',')]
+        dirs: Vec<PathBuf>,
+
+        /// Output JSON file path
+        #[arg(short, long)]
+        output: Option<PathBuf>,
     },
 
     /// Format analyze-code exports into deterministic CLAUDE.md Exports markdown
@@ -166,6 +283,84 @@ enum Commands {
         output: Option<PathBuf>,
     },
 
+    /// Compare a CLAUDE.md spec against the source code in its directory
+    CheckDrift {
+        /// Directory containing CLAUDE.md and its source files
+        #[arg(short, long)]
+        path: PathBuf,
+
+        /// Output JSON file path
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Exit with an error when any drift is found
+        #[arg(long, default_value_t = false)]
+        strict: bool,
+    },
+
+    /// Classify CLAUDE.md Exports, Contract and Protocol changes since a git revision as major, minor or patch
+    DiffSpec {
+        /// CLAUDE.md file to compare (working tree copy)
+        #[arg(short, long)]
+        file: PathBuf,
+
+        /// Git revision to compare against (branch, tag or commit)
+        #[arg(long)]
+        base: String,
+
+        /// Output JSON file path
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Exit with an error when any major (breaking) change is found
+        #[arg(long, default_value_t = false)]
+        strict: bool,
+    },
+
+    /// Build a per-module changelog of Exports, Behavior and Contract changes from the git history of each CLAUDE.md
+    Changelog {
+        /// Root directory to scan
+        #[arg(short, long, default_value = ".")]
+        root: PathBuf,
+
+        /// Only include commits after this git revision (e.g., the last release tag)
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = ChangelogFormat::Markdown)]
+        format: ChangelogFormat,
+
+        /// Output file path
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Build the module dependency graph from CLAUDE.md Dependencies and report cycles
+    Graph {
+        /// Root directory to scan
+        #[arg(short, long, default_value = ".")]
+        root: PathBuf,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = GraphFormat::Json)]
+        format: GraphFormat,
+
+        /// Output file path
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Run a language server on stdio: diagnostics, go-to-definition, hover and section completion for CLAUDE.md
+    Lsp,
+
+    /// Run a persistent JSON-RPC daemon exposing every subcommand as a method, with results cached until input files change
+    Serve {
+        /// Listen on this unix socket instead of stdio
+        #[arg(long)]
+        socket: Option<PathBuf>,
+    },
+
     /// Fix missing allow-none sections in CLAUDE.md by appending "## Section\nNone\n"
     FixSchema {
         /// CLAUDE.md file to fix
@@ -178,18 +373,22 @@ enum Commands {
     },
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct IndexResult {
-    root: PathBuf,
-    directories: Vec<DirectoryAnalysis>,
-    excluded: Vec<PathBuf>,
+#[derive(Clone, Copy, ValueEnum)]
+enum ChangelogFormat {
+    /// Markdown release notes
+    Markdown,
+    /// JSON entries per module
+    Json,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct DirectoryAnalysis {
-    path: PathBuf,
-    depth: usize,
-    analysis: code_analyzer::AnalysisResult,
+#[derive(Clone, Copy, ValueEnum)]
+enum GraphFormat {
+    /// JSON adjacency list with cycles
+    Json,
+    /// Graphviz DOT
+    Dot,
+    /// Mermaid flowchart
+    Mermaid,
 }
 
 fn main() {
@@ -209,44 +408,73 @@ fn main() {
         Commands::ValidateSchema { file, output, strict } => {
             let validator = SchemaValidator::new();
             let mut validation_result = validator.validate(file);
-
             if *strict {
-                // Promote INV-3 warnings to errors
-                let (inv3_warnings, remaining): (Vec<_>, Vec<_>) = validation_result.warnings
-                    .into_iter()
-                    .partition(|w| w.starts_with("INV-3:"));
-
-                for warning in inv3_warnings {
-                    validation_result.errors.push(claude_md_core::schema_validator::ValidationError {
-                        error_type: "INV3Violation".to_string(),
-                        message: warning,
-                        line_number: None,
-                        section: None,
-                    });
-                }
-                validation_result.warnings = remaining;
-                validation_result.valid = validation_result.errors.is_empty();
+                validation_result.promote_inv3_warnings();
             }
 
             output_result(&validation_result, output.as_ref(), "validate-schema")
         }
-        Commands::ParseClaudeMd { file, output } => {
+        Commands::ParseClaudeMd { file, output, lenient } => {
             let parser = ClaudeMdParser::new();
+            if *lenient {
+                match parser.parse_lenient(file) {
+                    Ok(result) => output_result(&result, output.as_ref(), "parse-claude-md"),
+                    Err(e) => Err(Box::new(e) as Box<dyn std::error::Error>),
+                }
+            } else {
+                match parser.parse(file) {
+                    Ok(spec) => output_result(&spec, output.as_ref(), "parse-claude-md"),
+                    Err(e) => Err(Box::new(e) as Box<dyn std::error::Error>),
+                }
+            }
+        }
+        Commands::RenderClaudeMd { input, output } => {
+            match std::fs::read_to_string(input) {
+                Ok(json) => match serde_json::from_str::<ClaudeMdSpec>(&json) {
+                    Ok(spec) => {
+                        let markdown = claude_md_renderer::render_claude_md(&spec);
+                        output_text(markdown.trim_end(), output.as_ref(), "render-claude-md")
+                    }
+                    Err(e) => Err(format!(
+                        "Failed to parse parse-claude-md JSON from '{}': {}",
+                        input.display(), e
+                    ).into()),
+                },
+                Err(e) => Err(format!(
+                    "Failed to read input file '{}': {}",
+                    input.display(), e
+                ).into()),
+            }
+        }
+        Commands::FmtClaudeMd { file, check, write } => fmt_claude_md(file, *check, *write),
+        Commands::ParseImplementsMd { file, output } => {
+            let parser = ImplementsMdParser::new();
             match parser.parse(file) {
-                Ok(spec) => output_result(&spec, output.as_ref(), "parse-claude-md"),
+                Ok(spec) => output_result(&spec, output.as_ref(), "parse-implements-md"),
                 Err(e) => Err(Box::new(e) as Box<dyn std::error::Error>),
             }
         }
+        Commands::ValidateImplements { file, output, strict } => {
+            let validator = SchemaValidator::new();
+            let mut validation_result = validator.validate_implements(file);
+            if *strict {
+                validation_result.promote_inv3_warnings();
+            }
+
+            output_result(&validation_result, output.as_ref(), "validate-implements")
+        }
         Commands::ValidateConvention { project_root, module_roots, output } => {
             let validator = ConventionValidator::new();
             let result = validator.validate(project_root, module_roots.clone());
             output_result(&result, output.as_ref(), "validate-convention")
         }
-        Commands::AnalyzeCode { path, files, tree_result, output } => {
-            let analyzer = CodeAnalyzer::new();
+        Commands::AnalyzeCode { path, files, tree_result, backend, output } => {
             let file_refs: Option<Vec<&str>> = files.as_ref()
                 .map(|f| f.iter().map(|s| s.as_str()).collect());
-            match analyzer.analyze_directory(path, file_refs.as_deref()) {
+            let analysis = CodeAnalyzer::with_backend_specs(backend).and_then(|analyzer| {
+                analyzer.analyze_directory(path, file_refs.as_deref())
+            }).map_err(|e| Box::new(e) as Box<dyn std::error::Error>);
+            match analysis {
                 Ok(mut result) => {
                     // Resolve internal deps if tree-parse result provided
                     if let Some(tree_path) = tree_result {
@@ -268,7 +496,7 @@ fn main() {
                     }
                     output_result(&result, output.as_ref(), "analyze-code")
                 }
-                Err(e) => Err(Box::new(e) as Box<dyn std::error::Error>),
+                Err(e) => Err(e),
             }
         }
         Commands::ScanClaudeMd { root, output } => {
@@ -276,11 +504,28 @@ fn main() {
             let scan_result = scanner.scan(root);
             output_result(&scan_result, output.as_ref(), "scan-claude-md")
         }
-        Commands::DiffCompileTargets { root, output } => {
+        Commands::DiffCompileTargets { root, output, cascade, lock, all, since } => {
             let resolver = CompileTargetResolver::new();
-            let result = resolver.resolve(root);
+            let options = ResolveOptions {
+                cascade: *cascade,
+                use_lock: *lock,
+                all: *all,
+                since: since.clone(),
+            };
+            let result = resolver.resolve_with(root, &options);
             output_result(&result, output.as_ref(), "diff-compile-targets")
         }
+        Commands::DiffDecompileTargets { root, output, lock } => {
+            let resolver = CompileTargetResolver::new();
+            let result = resolver.resolve_decompile(root, *lock);
+            output_result(&result, output.as_ref(), "diff-decompile-targets")
+        }
+        Commands::UpdateLock { root, dirs, output } => {
+            match CompileLock::update(root, dirs) {
+                Ok(result) => output_result(&result, output.as_ref(), "update-lock"),
+                Err(e) => Err(Box::new(e) as Box<dyn std::error::Error>),
+            }
+        }
         Commands::FormatExports { input, output } => {
             match std::fs::read_to_string(input) {
                 Ok(json) => match serde_json::from_str::<code_analyzer::AnalysisResult>(&json) {
@@ -318,7 +563,7 @@ fn main() {
             }
         }
         Commands::FixSchema { file, output } => {
-            match std::fs::read_to_string(&file) {
+            match std::fs::read_to_string(file) {
                 Ok(content) => {
                     let validator = SchemaValidator::new();
                     let (fixed, added) = validator.fix_missing_sections(&content);
@@ -326,7 +571,7 @@ fn main() {
                         println!("No missing sections to fix.");
                         Ok(())
                     } else {
-                        let target = output.as_ref().unwrap_or(&file);
+                        let target = output.as_ref().unwrap_or(file);
                         match std::fs::write(target, &fixed) {
                             Ok(()) => {
                                 println!("Fixed {} section(s): {}", added.len(), added.join(", "));
@@ -346,34 +591,85 @@ fn main() {
                 ).into()),
             }
         }
-        Commands::IndexProject { root, output } => {
-            let tree_parser = TreeParser::new();
-            let tree_result = tree_parser.parse(root);
-            let analyzer = CodeAnalyzer::new();
-            // Borrows tree_result temporarily; copies needed data internally via clone.
-            let resolver = DependencyResolver::new(&tree_result);
-
-            let mut directories = Vec::new();
-            for dir_info in &tree_result.needs_claude_md {
-                let dir_path = root.join(&dir_info.path);
-                match analyzer.analyze_directory(&dir_path, None) {
-                    Ok(mut analysis) => {
-                        resolver.resolve(&mut analysis, &dir_info.path);
-                        directories.push(DirectoryAnalysis {
-                            path: dir_info.path.clone(),
-                            depth: dir_info.depth,
-                            analysis,
-                        });
+        Commands::CheckDrift { path, output, strict } => {
+            let detector = DriftDetector::new();
+            match detector.check(&CodeAnalyzer::new(), path) {
+                Ok(report) => {
+                    let written = output_result(&report, output.as_ref(), "check-drift");
+                    if written.is_ok() && *strict && report.has_drift {
+                        Err(format!(
+                            "{} drift finding(s) between '{}' and its source code",
+                            report.findings.len(), report.claude_md
+                        ).into())
+                    } else {
+                        written
                     }
-                    Err(e) => eprintln!("Warning: skipping {}: {}", dir_info.path.display(), e),
                 }
+                Err(e) => Err(Box::new(e) as Box<dyn std::error::Error>),
             }
-
-            let index_result = IndexResult {
-                root: tree_result.root,
-                directories,
-                excluded: tree_result.excluded,
+        }
+        Commands::DiffSpec { file, base, output, strict } => {
+            match SpecDiffer::new().diff_against(file, base) {
+                Ok(report) => {
+                    let written = output_result(&report, output.as_ref(), "diff-spec");
+                    if written.is_ok() && *strict && report.bump == Some(Severity::Major) {
+                        Err(format!(
+                            "{} breaking change(s) in '{}' since '{}'",
+                            report.summary.major, report.claude_md, report.base
+                        ).into())
+                    } else {
+                        written
+                    }
+                }
+                Err(e) => Err(Box::new(e) as Box<dyn std::error::Error>),
+            }
+        }
+        Commands::Changelog { root, since, format, output } => {
+            match ChangelogGenerator::new().generate(root, since.as_deref()) {
+                Ok(changelog) => match format {
+                    ChangelogFormat::Markdown => output_text(changelog.to_markdown().trim_end(), output.as_ref(), "changelog"),
+                    ChangelogFormat::Json => output_result(&changelog, output.as_ref(), "changelog"),
+                },
+                Err(e) => Err(Box::new(e) as Box<dyn std::error::Error>),
+            }
+        }
+        Commands::Graph { root, format, output } => {
+            let graph = DependencyGraph::build(root);
+            let written = match format {
+                GraphFormat::Json => output_result(&graph, output.as_ref(), "graph"),
+                GraphFormat::Dot => output_text(&graph.to_dot(), output.as_ref(), "graph"),
+                GraphFormat::Mermaid => output_text(&graph.to_mermaid(), output.as_ref(), "graph"),
+            };
+            if written.is_ok() && graph.has_cycles() {
+                let cycles: Vec<String> = graph.cycles.iter().map(|c| c.message.clone()).collect();
+                Err(format!(
+                    "{} dependency cycle(s) found: {}",
+                    cycles.len(), cycles.join("; ")
+                ).into())
+            } else {
+                written
+            }
+        }
+        Commands::Lsp => {
+            let mut server = LanguageServer::new();
+            server
+                .run(&mut std::io::stdin().lock(), &mut std::io::stdout().lock())
+                .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+        }
+        Commands::Serve { socket } => {
+            let mut daemon = Daemon::new();
+            let served = match socket {
+                Some(path) => daemon.serve_socket(path),
+                None => daemon
+                    .serve(&mut std::io::stdin().lock(), &mut std::io::stdout().lock())
+                    .map(|_| ())
+                    .map_err(DaemonError::from),
             };
+            served.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+        }
+        Commands::IndexProject { root, output } => {
+            let tree_result = TreeParser::new().parse(root);
+            let index_result = dependency_resolver::index_project(root, tree_result, &CodeAnalyzer::new());
             output_result(&index_result, output.as_ref(), "index-project")
         }
     };
@@ -385,15 +681,27 @@ fn main() {
             Commands::ValidateSchema { .. } => "validate-schema",
 
             Commands::ParseClaudeMd { .. } => "parse-claude-md",
+            Commands::RenderClaudeMd { .. } => "render-claude-md",
+            Commands::FmtClaudeMd { .. } => "fmt-claude-md",
+            Commands::ParseImplementsMd { .. } => "parse-implements-md",
+            Commands::ValidateImplements { .. } => "validate-implements",
             Commands::ValidateConvention { .. } => "validate-convention",
             Commands::AnalyzeCode { .. } => "analyze-code",
 
             Commands::ScanClaudeMd { .. } => "scan-claude-md",
             Commands::DiffCompileTargets { .. } => "diff-compile-targets",
+            Commands::DiffDecompileTargets { .. } => "diff-decompile-targets",
+            Commands::UpdateLock { .. } => "update-lock",
             Commands::IndexProject { .. } => "index-project",
             Commands::FixSchema { .. } => "fix-schema",
             Commands::FormatExports { .. } => "format-exports",
             Commands::FormatAnalysis { .. } => "format-analysis",
+            Commands::CheckDrift { .. } => "check-drift",
+            Commands::DiffSpec { .. } => "diff-spec",
+            Commands::Changelog { .. } => "changelog",
+            Commands::Graph { .. } => "graph",
+            Commands::Lsp => "lsp",
+            Commands::Serve { .. } => "serve",
         };
         eprintln!("Error in '{}' command: {}", command_name, e);
         eprintln!("Hint: Use --help for usage information");
@@ -401,6 +709,36 @@ fn main() {
     }
 }
 
+/// Format each CLAUDE.md: print it, rewrite it (`--write`) or only report it (`--check`).
+fn fmt_claude_md(files: &[PathBuf], check: bool, write: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let formatter = ClaudeMdFormatter::new();
+    let mut unformatted = 0;
+    for file in files {
+        let content = std::fs::read_to_string(file)
+            .map_err(|e| format!("Failed to read CLAUDE.md '{}': {}", file.display(), e))?;
+        let formatted = formatter.format(&content);
+        if !check && !write {
+            print!("{}", formatted);
+            continue;
+        }
+        if formatted == content {
+            continue;
+        }
+        unformatted += 1;
+        if check {
+            println!("Would reformat: {}", file.display());
+        } else {
+            std::fs::write(file, &formatted)
+                .map_err(|e| format!("Failed to write CLAUDE.md '{}': {}", file.display(), e))?;
+            println!("Formatted: {}", file.display());
+        }
+    }
+    if check && unformatted > 0 {
+        return Err(format!("{} file(s) not formatted", unformatted).into());
+    }
+    Ok(())
+}
+
 fn output_text(
     text: &str,
     output_path: Option<&PathBuf>,
@@ -0,0 +1,298 @@
+//! Shared markdown section model for CLAUDE.md and IMPLEMENTS.md.
+//!
+//! Splits a document into heading-delimited sections following CommonMark block rules,
+//! so every consumer agrees on what a heading is:
+//! - ATX headings (`## Name`, up to 3 spaces of indent, optional closing `#`s)
+//! - setext headings (a paragraph underlined with `===` or `---`)
+//! - nothing inside a fenced code block (```` ``` ```` or `~~~`) is a heading
+//! - nothing inside an HTML comment is a heading; comment lines are blanked in content
+//!
+//! Section content keeps every other line verbatim (fence delimiters included), so
+//! `content[i]` is always on line `content_line(i)`.
+
+/// A heading and the lines up to the next heading of any level.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownSection {
+    /// Heading text, trimmed, without `#` markers
+    pub name: String,
+    /// Heading level (1-6)
+    pub level: usize,
+    /// 1-based line of the heading (first text line for setext headings)
+    pub line: usize,
+    /// 1-based line of `content[0]`
+    pub content_start: usize,
+    /// Lines below the heading; HTML comment lines are empty strings
+    pub content: Vec<String>,
+    /// Whether each content line belongs to a code block (fence delimiters included)
+    code: Vec<bool>,
+}
+
+impl MarkdownSection {
+    /// 1-based line of `content[index]`.
+    pub fn content_line(&self, index: usize) -> usize {
+        self.content_start + index
+    }
+
+    /// Whether `content[index]` is part of a fenced or indented code block.
+    pub fn is_code(&self, index: usize) -> bool {
+        self.code.get(index).copied().unwrap_or(false)
+    }
+
+    /// Content lines paired with their 1-based line numbers.
+    pub fn numbered_content(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.content
+            .iter()
+            .enumerate()
+            .map(move |(i, line)| (self.content_line(i), line.as_str()))
+    }
+}
+
+/// Block-level role of a single line, before headings are assembled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LineKind {
+    Blank,
+    /// ATX heading: level and text
+    Atx(usize, String),
+    /// Line made only of `=` (level 1) or `-` (level 2); a setext underline if it follows a paragraph
+    Underline(usize),
+    /// Paragraph text that may become a setext heading
+    Paragraph,
+    /// List item, block quote or table row: a following underline does not make a heading
+    Container,
+    /// Fence delimiter or line inside a fenced code block
+    Code,
+    /// Line inside an HTML comment
+    Comment,
+}
+
+/// Split `content` into sections. Lines before the first heading are not part of any section.
+pub fn parse_sections(content: &str) -> Vec<MarkdownSection> {
+    let lines: Vec<&str> = content.lines().collect();
+    let kinds = classify(&lines);
+
+    // Resolve setext headings: underline index -> (first paragraph line, level)
+    let mut setext = std::collections::HashMap::new();
+    for (i, kind) in kinds.iter().enumerate() {
+        let LineKind::Underline(level) = kind else { continue };
+        let mut start = i;
+        while start > 0 && kinds[start - 1] == LineKind::Paragraph {
+            start -= 1;
+        }
+        // A paragraph that lazily continues a list item or quote cannot become a heading
+        let opens_paragraph = start == 0 || !matches!(kinds[start - 1], LineKind::Container | LineKind::Paragraph);
+        if start < i && opens_paragraph {
+            setext.insert(i, (start, *level));
+        }
+    }
+    let setext_text: std::collections::HashSet<usize> = setext
+        .iter()
+        .flat_map(|(&underline, &(start, _))| start..underline)
+        .collect();
+
+    let mut sections = Vec::new();
+    let mut current: Option<MarkdownSection> = None;
+    let mut open = |name: String, level: usize, line: usize, content_start: usize, current: &mut Option<MarkdownSection>| {
+        if let Some(section) = current.take() {
+            sections.push(section);
+        }
+        *current = Some(MarkdownSection { name, level, line, content_start, content: Vec::new(), code: Vec::new() });
+    };
+
+    for (i, line) in lines.iter().enumerate() {
+        if setext_text.contains(&i) {
+            continue;
+        }
+        if let Some(&(start, level)) = setext.get(&i) {
+            let name = lines[start..i].iter().map(|l| l.trim()).collect::<Vec<_>>().join(" ");
+            open(name, level, start + 1, i + 2, &mut current);
+            continue;
+        }
+        if let LineKind::Atx(level, name) = &kinds[i] {
+            open(name.clone(), *level, i + 1, i + 2, &mut current);
+            continue;
+        }
+        if let Some(section) = current.as_mut() {
+            let text = if kinds[i] == LineKind::Comment { "" } else { line };
+            section.content.push(text.to_string());
+            section.code.push(kinds[i] == LineKind::Code);
+        }
+    }
+
+    if let Some(section) = current {
+        sections.push(section);
+    }
+    sections
+}
+
+/// The section at `index` followed by all of its subsections.
+pub fn with_subsections(sections: &[MarkdownSection], index: usize) -> &[MarkdownSection] {
+    let level = sections[index].level;
+    let end = sections[index + 1..]
+        .iter()
+        .position(|s| s.level <= level)
+        .map(|offset| index + 1 + offset)
+        .unwrap_or(sections.len());
+    &sections[index..end]
+}
+
+/// Index of the first section with the given level and name (case-insensitive).
+pub fn find_section(sections: &[MarkdownSection], level: usize, name: &str) -> Option<usize> {
+    sections
+        .iter()
+        .position(|s| s.level == level && s.name.eq_ignore_ascii_case(name))
+}
+
+fn classify(lines: &[&str]) -> Vec<LineKind> {
+    let mut kinds = Vec::with_capacity(lines.len());
+    // Open fence: delimiter char and run length
+    let mut fence: Option<(char, usize)> = None;
+    let mut in_comment = false;
+
+    for line in lines {
+        if let Some((ch, len)) = fence {
+            if let Some((close_ch, close_len, rest)) = fence_delimiter(line) {
+                if close_ch == ch && close_len >= len && rest.trim().is_empty() {
+                    fence = None;
+                }
+            }
+            kinds.push(LineKind::Code);
+            continue;
+        }
+        if in_comment {
+            in_comment = !line.contains("-->");
+            kinds.push(LineKind::Comment);
+            continue;
+        }
+
+        let indent = line.len() - line.trim_start_matches(' ').len();
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            kinds.push(LineKind::Blank);
+        } else if indent >= 4 {
+            // Continuation of the previous paragraph, otherwise indented code
+            let continues = kinds.last() == Some(&LineKind::Paragraph);
+            kinds.push(if continues { LineKind::Paragraph } else { LineKind::Code });
+        } else if let Some((ch, len, _)) = fence_delimiter(line).filter(|(ch, _, info)| *ch == '~' || !info.contains('`')) {
+            fence = Some((ch, len));
+            kinds.push(LineKind::Code);
+        } else if let Some(rest) = trimmed.strip_prefix("<!--") {
+            in_comment = !rest.contains("-->");
+            kinds.push(LineKind::Comment);
+        } else if let Some((level, name)) = atx_heading(trimmed) {
+            kinds.push(LineKind::Atx(level, name));
+        } else if trimmed.chars().all(|c| c == '=') {
+            kinds.push(LineKind::Underline(1));
+        } else if trimmed.chars().all(|c| c == '-') {
+            kinds.push(LineKind::Underline(2));
+        } else if is_container(trimmed) {
+            kinds.push(LineKind::Container);
+        } else {
+            kinds.push(LineKind::Paragraph);
+        }
+    }
+    kinds
+}
+
+/// Fence delimiter (```` ``` ```` or `~~~`, 3+ chars, up to 3 spaces of indent):
+/// delimiter char, run length and the rest of the line.
+fn fence_delimiter(line: &str) -> Option<(char, usize, &str)> {
+    let stripped = line.trim_start_matches(' ');
+    if line.len() - stripped.len() > 3 {
+        return None;
+    }
+    let ch = stripped.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let len = stripped.chars().take_while(|c| *c == ch).count();
+    (len >= 3).then(|| (ch, len, &stripped[len..]))
+}
+
+/// `## Name ##` → `(2, "Name")`. Requires a space (or nothing) after the `#` run.
+fn atx_heading(trimmed: &str) -> Option<(usize, String)> {
+    let level = trimmed.chars().take_while(|c| *c == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    let rest = &trimmed[level..];
+    if !rest.is_empty() && !rest.starts_with([' ', '\t']) {
+        return None;
+    }
+    // Optional closing sequence: whitespace followed by `#`s only
+    let mut name = rest.trim();
+    let without_closing = name.trim_end_matches('#');
+    if without_closing.is_empty() || without_closing.ends_with([' ', '\t']) {
+        name = without_closing.trim_end();
+    }
+    Some((level, name.to_string()))
+}
+
+/// List item, block quote or table row.
+fn is_container(trimmed: &str) -> bool {
+    if trimmed.starts_with(['>', '|']) {
+        return true;
+    }
+    if let Some(rest) = trimmed.strip_prefix(['-', '*', '+']) {
+        return rest.is_empty() || rest.starts_with([' ', '\t']);
+    }
+    let digits = trimmed.chars().take_while(|c| c.is_ascii_digit()).count();
+    digits > 0 && digits <= 9 && trimmed[digits..].starts_with(['.', ')'])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(sections: &[MarkdownSection]) -> Vec<(usize, &str)> {
+        sections.iter().map(|s| (s.level, s.name.as_str())).collect()
+    }
+
+    #[test]
+    fn test_atx_headings_and_closing_sequence() {
+        let sections = parse_sections("# module\n\n## Purpose ##\nText.\n   ### Indented\n#NoSpace\n####### Seven\n");
+        assert_eq!(names(&sections), vec![(1, "module"), (2, "Purpose"), (3, "Indented")]);
+        assert_eq!(sections[1].line, 3);
+        assert_eq!(sections[2].content, vec!["#NoSpace", "####### Seven"]);
+    }
+
+    #[test]
+    fn test_fenced_code_is_not_a_heading() {
+        let content = "## Behavior\n```bash\n# install\n## not a section\n```\n~~~~python\n# comment\n~~~\n~~~~\n## Exports\n";
+        let sections = parse_sections(content);
+        assert_eq!(names(&sections), vec![(2, "Behavior"), (2, "Exports")]);
+        assert_eq!(sections[0].content.len(), 8);
+        assert!((0..8).all(|i| sections[0].is_code(i)));
+        assert_eq!(sections[1].line, 10);
+    }
+
+    #[test]
+    fn test_unclosed_fence_runs_to_end() {
+        let sections = parse_sections("## Exports\n```\n## Behavior\n");
+        assert_eq!(names(&sections), vec![(2, "Exports")]);
+    }
+
+    #[test]
+    fn test_setext_headings() {
+        let content = "auth\n====\n\nPurpose\n-------\nHandles auth.\n\n- item\n---\n\ntext\n\n---\n";
+        let sections = parse_sections(content);
+        assert_eq!(names(&sections), vec![(1, "auth"), (2, "Purpose")]);
+        assert_eq!(sections[1].line, 4);
+        assert_eq!(sections[1].content_start, 6);
+        // A list item followed by `---` and a thematic break after a blank line stay content
+        assert!(sections[1].content.contains(&"---".to_string()));
+    }
+
+    #[test]
+    fn test_html_comments_are_blanked() {
+        let content = "## Purpose\n<!--\n## Hidden\n-->\nText.\n<!-- one line --> \n## Exports\n";
+        let sections = parse_sections(content);
+        assert_eq!(names(&sections), vec![(2, "Purpose"), (2, "Exports")]);
+        assert_eq!(sections[0].content, vec!["", "", "", "Text.", ""]);
+        assert_eq!(sections[0].content_line(3), 5);
+    }
+
+    #[test]
+    fn test_with_subsections() {
+        let sections = parse_sections("## Exports\n### Functions\n#### Detail\n## Behavior\n");
+        let idx = find_section(&sections, 2, "exports").unwrap();
+        assert_eq!(with_subsections(&sections, idx).len(), 3);
+        assert_eq!(with_subsections(&sections, 3).len(), 1);
+    }
+}
@@ -2,6 +2,9 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
+use crate::implements_md_parser::{check_section_rules, SectionRuleKind};
+use crate::markdown::{find_section, parse_sections, with_subsections, MarkdownSection};
+
 /// Result of schema validation
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ValidationResult {
@@ -30,6 +33,26 @@ pub struct ValidationError {
     pub section: Option<String>,
 }
 
+impl ValidationResult {
+    /// Promote INV-3 warnings (missing CLAUDE.md/IMPLEMENTS.md pair) to errors (`--strict`)
+    pub fn promote_inv3_warnings(&mut self) {
+        let (inv3_warnings, remaining): (Vec<_>, Vec<_>) = std::mem::take(&mut self.warnings)
+            .into_iter()
+            .partition(|w| w.starts_with("INV-3:"));
+
+        for warning in inv3_warnings {
+            self.errors.push(ValidationError {
+                error_type: "INV3Violation".to_string(),
+                message: warning,
+                line_number: None,
+                section: None,
+            });
+        }
+        self.warnings = remaining;
+        self.valid = self.errors.is_empty();
+    }
+}
+
 // Include generated constants from schema-rules.yaml (SSOT)
 include!(concat!(env!("OUT_DIR"), "/schema_rules.rs"));
 
@@ -42,8 +65,6 @@ const GO_BUILTIN_TYPES: &[&str] = &[
 ];
 
 pub struct SchemaValidator {
-    /// Pattern to match section headers
-    section_pattern: Regex,
     /// Pattern to match behavior scenarios
     behavior_pattern: Regex,
     /// Pre-compiled: Java/Kotlin signature pattern
@@ -58,9 +79,6 @@ pub struct SchemaValidator {
 
 impl SchemaValidator {
     pub fn new() -> Self {
-        // Match markdown headers like "## Purpose", "### Functions"
-        let section_pattern = Regex::new(r"^#+\s+(.+)$").unwrap();
-
         // Match behavior scenarios: input → output
         let behavior_pattern = Regex::new(r"→|->").unwrap();
 
@@ -78,7 +96,6 @@ impl SchemaValidator {
             .collect();
 
         Self {
-            section_pattern,
             behavior_pattern,
             java_kotlin_sig_re,
             incomplete_sig_re,
@@ -108,11 +125,17 @@ impl SchemaValidator {
             }
         };
 
+        self.validate_content(file, &content)
+    }
+
+    /// Validate in-memory CLAUDE.md content (e.g. an unsaved editor buffer) for `file`
+    pub fn validate_content(&self, file: &Path, content: &str) -> ValidationResult {
+        let file_str = file.to_string_lossy().to_string();
         let mut errors = Vec::new();
         let mut warnings = Vec::new();
 
         // Parse sections
-        let sections = self.parse_sections(&content);
+        let sections = parse_sections(content);
 
         // Check required sections
         for required in REQUIRED_SECTIONS {
@@ -136,7 +159,7 @@ impl SchemaValidator {
                         errors.push(ValidationError {
                             error_type: "InvalidSectionContent".to_string(),
                             message: format!("Section '{}' does not allow 'None' as value", required),
-                            line_number: Some(section.start_line),
+                            line_number: Some(section.line),
                             section: Some(required.to_string()),
                         });
                     }
@@ -155,7 +178,7 @@ impl SchemaValidator {
         }
 
         // Validate Dependencies for forbidden references (INV-1: tree structure)
-        self.validate_dependencies(&content, &mut errors);
+        self.validate_dependencies(&sections, &mut errors);
 
         // INV-3: 1:1 mapping required. WARNING (not ERROR) because:
         // - /impl creates CLAUDE.md first, then IMPLEMENTS.md (transient absence)
@@ -177,50 +200,80 @@ impl SchemaValidator {
         }
     }
 
-    fn parse_sections(&self, content: &str) -> Vec<ValidatorSection> {
-        let mut sections = Vec::new();
-        let mut current_section: Option<ValidatorSection> = None;
+    /// Validate an IMPLEMENTS.md file against the IMPLEMENTS.md sections in schema-rules.yaml
+    pub fn validate_implements(&self, file: &Path) -> ValidationResult {
+        let file_str = file.to_string_lossy().to_string();
 
-        for (line_num, line) in content.lines().enumerate() {
-            if let Some(caps) = self.section_pattern.captures(line) {
-                // Save previous section
-                if let Some(section) = current_section.take() {
-                    sections.push(section);
-                }
+        let content = match std::fs::read_to_string(file) {
+            Ok(c) => c,
+            Err(e) => {
+                return ValidationResult {
+                    file: file_str,
+                    valid: false,
+                    errors: vec![ValidationError {
+                        error_type: "FileError".to_string(),
+                        message: format!("Cannot read file: {}", e),
+                        line_number: None,
+                        section: None,
+                    }],
+                    warnings: vec![],
+                };
+            }
+        };
 
-                // Start new section - use map().unwrap_or() instead of unwrap()
-                let section_name = caps
-                    .get(1)
-                    .map(|m| m.as_str().to_string())
-                    .unwrap_or_else(|| line.trim_start_matches('#').trim().to_string());
-
-                current_section = Some(ValidatorSection {
-                    name: section_name,
-                    start_line: line_num + 1,
-                    content: Vec::new(),
-                });
-            } else if let Some(ref mut section) = current_section {
-                section.content.push((line_num + 1, line.to_string()));
+        let mut errors = Vec::new();
+        let mut warnings = Vec::new();
+
+        let sections = parse_sections(&content);
+
+        // Required sections, "None" markers and tables (shared with the parser)
+        for violation in check_section_rules(&sections) {
+            let error_type = match violation.kind {
+                SectionRuleKind::MissingSection => "MissingSection",
+                SectionRuleKind::NoneNotAllowed => "InvalidSectionContent",
+                SectionRuleKind::MissingTable => "InvalidTable",
+            };
+            errors.push(ValidationError {
+                error_type: error_type.to_string(),
+                message: violation.message,
+                line_number: violation.line,
+                section: Some(violation.section),
+            });
+        }
+
+        // H2 headers not defined in the schema are reported but not rejected
+        for section in sections.iter().filter(|s| s.level == 2) {
+            if !IMPLEMENTS_SECTIONS.iter().any(|s| s.eq_ignore_ascii_case(&section.name)) {
+                warnings.push(format!("Line {}: Unknown IMPLEMENTS.md section '{}'", section.line, section.name));
             }
         }
 
-        // Save last section
-        if let Some(section) = current_section {
-            sections.push(section);
+        // INV-3 (reverse direction): every IMPLEMENTS.md pairs with a CLAUDE.md
+        let claude_md_path = file.with_file_name("CLAUDE.md");
+        if !claude_md_path.exists() {
+            warnings.push(format!(
+                "INV-3: CLAUDE.md not found at '{}' (expected 1:1 mapping with IMPLEMENTS.md)",
+                claude_md_path.display()
+            ));
         }
 
-        sections
+        ValidationResult {
+            file: file_str,
+            valid: errors.is_empty(),
+            errors,
+            warnings,
+        }
     }
 
     /// Check if a section contains only a "None" marker (None, N/A, etc.)
-    fn is_none_marker(&self, section: &ValidatorSection) -> bool {
-        let lines: Vec<&str> = section.content.iter().map(|(_, s)| s.as_str()).collect();
+    fn is_none_marker(&self, section: &MarkdownSection) -> bool {
+        let lines: Vec<&str> = section.content.iter().map(|s| s.as_str()).collect();
         crate::is_none_marker_content(&lines)
     }
 
     fn validate_exports(
         &self,
-        section: &ValidatorSection,
+        section: &MarkdownSection,
         errors: &mut Vec<ValidationError>,
         warnings: &mut Vec<String>,
     ) {
@@ -228,7 +281,7 @@ impl SchemaValidator {
         let mut in_list = false;
         let mut in_table = false;
 
-        for (line_num, line) in &section.content {
+        for (line_num, line) in section.numbered_content() {
             let trimmed = line.trim();
 
             // Skip empty lines and subsection headers
@@ -274,9 +327,7 @@ impl SchemaValidator {
                             line_num
                         ));
                     }
-                } else if self.looks_like_enum_line(trimmed) {
-                    found_valid_export = true;
-                } else if self.looks_like_variable_line(trimmed) {
+                } else if self.looks_like_enum_line(trimmed) || self.looks_like_variable_line(trimmed) {
                     found_valid_export = true;
                 } else if self.looks_like_incomplete_signature(trimmed) {
                     // No parentheses but looks like incomplete function definition
@@ -292,7 +343,7 @@ impl SchemaValidator {
             errors.push(ValidationError {
                 error_type: "InvalidExports".to_string(),
                 message: "Exports section must contain valid function signatures or 'None'".to_string(),
-                line_number: Some(section.start_line),
+                line_number: Some(section.line),
                 section: Some("Exports".to_string()),
             });
         }
@@ -300,14 +351,14 @@ impl SchemaValidator {
 
     fn validate_behavior(
         &self,
-        section: &ValidatorSection,
+        section: &MarkdownSection,
         errors: &mut Vec<ValidationError>,
         _warnings: &mut Vec<String>,
     ) {
         let mut found_valid_behavior = false;
         let mut in_table = false;
 
-        for (_, line) in &section.content {
+        for line in &section.content {
             let trimmed = line.trim();
 
             // Skip empty lines and headers
@@ -344,51 +395,38 @@ impl SchemaValidator {
             errors.push(ValidationError {
                 error_type: "InvalidBehavior".to_string(),
                 message: "Behavior section must contain scenarios in 'input → output' format or 'None'".to_string(),
-                line_number: Some(section.start_line),
+                line_number: Some(section.line),
                 section: Some("Behavior".to_string()),
             });
         }
     }
 
     /// Validate Dependencies section for forbidden reference patterns (e.g. parent `../` references).
-    /// Scans raw content between `## Dependencies` and the next `##` header to handle subsections.
+    /// Covers `## Dependencies` together with its subsections.
     fn validate_dependencies(
         &self,
-        content: &str,
+        sections: &[MarkdownSection],
         errors: &mut Vec<ValidationError>,
     ) {
-        let mut in_dependencies = false;
-
-        for (idx, line) in content.lines().enumerate() {
-            let trimmed = line.trim();
-
-            // Detect ## Dependencies header (H2 only)
-            if trimmed.starts_with("## ")
-                && trimmed[3..].trim().eq_ignore_ascii_case("Dependencies")
-            {
-                in_dependencies = true;
-                continue;
-            }
-
-            // Exit when reaching next H2 header
-            if in_dependencies && trimmed.starts_with("## ") {
-                break;
-            }
+        let Some(index) = find_section(sections, 2, "Dependencies") else { return };
 
-            // Skip empty lines and H3+ subsection headers within Dependencies
-            if !in_dependencies || trimmed.is_empty() || trimmed.starts_with('#') {
-                continue;
-            }
+        for section in with_subsections(sections, index) {
+            for (line_num, line) in section.numbered_content() {
+                let trimmed = line.trim();
+                if trimmed.is_empty() {
+                    continue;
+                }
 
-            // Check each line against forbidden patterns
-            for (pattern, description) in &self.forbidden_ref_patterns {
-                if pattern.is_match(trimmed) {
-                    errors.push(ValidationError {
-                        error_type: "ForbiddenReference".to_string(),
-                        message: format!("{}: {}", description, trimmed),
-                        line_number: Some(idx + 1),
-                        section: Some("Dependencies".to_string()),
-                    });
+                // Check each line against forbidden patterns
+                for (pattern, description) in &self.forbidden_ref_patterns {
+                    if pattern.is_match(trimmed) {
+                        errors.push(ValidationError {
+                            error_type: "ForbiddenReference".to_string(),
+                            message: format!("{}: {}", description, trimmed),
+                            line_number: Some(line_num),
+                            section: Some("Dependencies".to_string()),
+                        });
+                    }
                 }
             }
         }
@@ -447,7 +485,7 @@ impl SchemaValidator {
 
         // Go: Func(param type) ReturnType — single return (no parens around return)
         // Match pattern: closing paren followed by space and a capitalized type or basic type
-        if let Some(after_paren) = line.split(')').last() {
+        if let Some(after_paren) = line.split(')').next_back() {
             let after = after_paren.trim().trim_end_matches('`');
             if !after.is_empty()
                 && !after.starts_with('(')
@@ -500,7 +538,7 @@ impl SchemaValidator {
     /// Fix missing required sections that allow "None" by appending them with "None" content.
     /// Returns the fixed content and a list of sections that were added.
     pub fn fix_missing_sections(&self, content: &str) -> (String, Vec<String>) {
-        let sections = self.parse_sections(content);
+        let sections = parse_sections(content);
         let mut fixed = content.to_string();
         let mut added = Vec::new();
 
@@ -529,13 +567,6 @@ impl Default for SchemaValidator {
     }
 }
 
-/// Section representation for schema validation, tracking line numbers for error reporting.
-struct ValidatorSection {
-    name: String,
-    start_line: usize,
-    content: Vec<(usize, String)>,
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1027,4 +1058,92 @@ Provides server management.
 
         assert!(result.valid, "*Server pointer return type should be valid: {:?}", result.errors);
     }
+
+    fn create_implements_file(content: &str) -> (TempDir, std::path::PathBuf) {
+        let temp = TempDir::new().unwrap();
+        let file_path = temp.path().join("IMPLEMENTS.md");
+        let mut file = File::create(&file_path).unwrap();
+        write!(file, "{}", content).unwrap();
+        (temp, file_path)
+    }
+
+    const VALID_IMPLEMENTS: &str = r#"# auth/IMPLEMENTS.md
+
+## Dependencies Direction
+
+### External
+- `jsonwebtoken@9.0.0`: JWT 검증
+
+## Implementation Approach
+
+### 전략
+- HMAC-SHA256 기반 토큰 검증
+
+## Technology Choices
+None
+
+## Error Handling
+
+| Error | Retry | Recovery | Log Level |
+|-------|-------|----------|-----------|
+| TokenExpiredError | ✗ | 재로그인 유도 | WARN |
+
+## State Management
+None
+"#;
+
+    #[test]
+    fn test_validate_implements_valid() {
+        let (_temp, path) = create_implements_file(VALID_IMPLEMENTS);
+
+        let validator = SchemaValidator::new();
+        let result = validator.validate_implements(&path);
+
+        assert!(result.valid, "Valid IMPLEMENTS.md should pass: {:?}", result.errors);
+        assert!(result.warnings.iter().any(|w| w.starts_with("INV-3:")));
+    }
+
+    #[test]
+    fn test_validate_implements_missing_section_fails() {
+        let content = VALID_IMPLEMENTS.replace("## State Management\nNone\n", "");
+        let (_temp, path) = create_implements_file(&content);
+
+        let validator = SchemaValidator::new();
+        let result = validator.validate_implements(&path);
+
+        assert!(!result.valid);
+        assert!(result.errors.iter().any(|e| e.error_type == "MissingSection"
+            && e.section.as_deref() == Some("State Management")));
+    }
+
+    #[test]
+    fn test_validate_implements_table_without_rows_fails() {
+        let content = VALID_IMPLEMENTS.replace(
+            "| TokenExpiredError | ✗ | 재로그인 유도 | WARN |\n",
+            "",
+        );
+        let (_temp, path) = create_implements_file(&content);
+
+        let validator = SchemaValidator::new();
+        let result = validator.validate_implements(&path);
+
+        assert!(!result.valid);
+        assert!(result.errors.iter().any(|e| e.error_type == "InvalidTable"));
+    }
+
+    #[test]
+    fn test_validate_implements_none_in_optional_section_fails() {
+        let content = format!("{}\n## Key Constants\nNone\n\n## Algorithm\nNone\n", VALID_IMPLEMENTS);
+        let (_temp, path) = create_implements_file(&content);
+
+        let validator = SchemaValidator::new();
+        let result = validator.validate_implements(&path);
+
+        assert!(!result.valid);
+        let sections: Vec<&str> = result.errors.iter()
+            .filter(|e| e.error_type == "InvalidSectionContent")
+            .filter_map(|e| e.section.as_deref())
+            .collect();
+        assert_eq!(sections, vec!["Key Constants", "Algorithm"]);
+    }
 }
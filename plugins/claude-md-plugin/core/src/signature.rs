@@ -0,0 +1,800 @@
+//! Structured function signatures.
+//!
+//! Spec and analyzer signatures are free-form strings in each language's surface syntax
+//! (`fn f(x: u32) -> T`, `T f(int x)`, `func F(x int) (T, error)`). [`Signature::parse`]
+//! reads them into one language-tagged model so they can be compared by meaning instead
+//! of by text.
+
+use serde::{Deserialize, Serialize};
+
+use crate::bracket_utils::{find_matching_bracket, split_respecting_brackets};
+
+/// Source language a signature was written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    /// Spec signatures: syntax is inferred per parameter
+    #[default]
+    Unknown,
+    TypeScript,
+    JavaScript,
+    Python,
+    Go,
+    Rust,
+    Java,
+    Kotlin,
+    Scala,
+    CSharp,
+    Swift,
+    C,
+    Cpp,
+    Ruby,
+    Php,
+}
+
+impl Language {
+    /// Language for an analyzer language name (`typescript`, `cpp`, ...).
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "typescript" => Language::TypeScript,
+            "javascript" => Language::JavaScript,
+            "python" => Language::Python,
+            "go" => Language::Go,
+            "rust" => Language::Rust,
+            "java" => Language::Java,
+            "kotlin" => Language::Kotlin,
+            "scala" => Language::Scala,
+            "csharp" => Language::CSharp,
+            "swift" => Language::Swift,
+            "c" => Language::C,
+            "cpp" => Language::Cpp,
+            "ruby" => Language::Ruby,
+            "php" => Language::Php,
+            _ => Language::Unknown,
+        }
+    }
+
+    fn param_style(self) -> ParamStyle {
+        match self {
+            Language::TypeScript
+            | Language::JavaScript
+            | Language::Python
+            | Language::Rust
+            | Language::Kotlin
+            | Language::Scala
+            | Language::Swift => ParamStyle::NameColonType,
+            Language::Java | Language::CSharp | Language::C | Language::Cpp | Language::Php => ParamStyle::TypeName,
+            Language::Go => ParamStyle::NameType,
+            Language::Ruby => ParamStyle::Ruby,
+            Language::Unknown => ParamStyle::Infer,
+        }
+    }
+}
+
+/// How a single parameter declaration is laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ParamStyle {
+    /// `name: Type` (TypeScript, Python, Rust, Kotlin, Scala, Swift)
+    NameColonType,
+    /// `Type name` (Java, C#, C, C++, PHP)
+    TypeName,
+    /// `name Type` (Go)
+    NameType,
+    /// `name` or `keyword: default` (Ruby)
+    Ruby,
+    /// Decide per parameter
+    Infer,
+}
+
+/// One entry of a parameter list.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Parameter {
+    pub name: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    #[serde(default)]
+    pub variadic: bool,
+}
+
+/// A generic type parameter and its bounds (`T: Clone`, `T extends Base`).
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct GenericParam {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bounds: Vec<String>,
+}
+
+/// A function signature broken into its parts.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Signature {
+    #[serde(default)]
+    pub language: Language,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub generics: Vec<GenericParam>,
+    /// Method receiver: `self`, `&mut self`, Go `s *Server`, C++ `TokenService`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receiver: Option<String>,
+    /// Parameters in declaration order, curried lists flattened
+    #[serde(default)]
+    pub params: Vec<Parameter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_type: Option<String>,
+    #[serde(default)]
+    pub is_async: bool,
+    #[serde(default)]
+    pub throws: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub thrown_types: Vec<String>,
+}
+
+/// Words before the name that carry no type information
+const MODIFIERS: &[&str] = &[
+    "export", "default", "declare", "pub", "public", "private", "protected", "internal", "static",
+    "function", "fn", "func", "fun", "def", "override", "open", "final", "abstract", "virtual",
+    "inline", "extern", "unsafe", "sealed", "synchronized", "native", "mutating", "nonmutating",
+    "operator", "infix", "tailrec", "explicit", "constexpr", "implicit", "new", "partial", "extension",
+];
+
+/// Words that mark a function as asynchronous
+const ASYNC_MODIFIERS: &[&str] = &["async", "suspend"];
+
+/// Return types that mean "returns nothing"
+const UNIT_TYPES: &[&str] = &["void", "Unit", "()", "None", "Void"];
+
+/// Return type wrappers that imply an asynchronous function
+const ASYNC_RETURN_PREFIXES: &[&str] = &["Promise<", "Task<", "ValueTask<", "Future[", "Future<", "CompletableFuture<", "Deferred<"];
+
+/// Trailing qualifiers after the parameter list (C++, Swift)
+const TRAILING_QUALIFIERS: &[&str] = &["const", "noexcept", "override", "final", "volatile", "mutable"];
+
+/// Go and C primitive types, used to tell `name type` from `type name` in inferred mode
+const PRIMITIVE_TYPES: &[&str] = &[
+    "string", "int", "int8", "int16", "int32", "int64", "uint", "uint8", "uint16", "uint32", "uint64",
+    "uintptr", "float", "float32", "float64", "double", "complex64", "complex128", "bool", "boolean",
+    "byte", "rune", "error", "any", "char", "long", "short", "number", "object", "unknown", "str",
+    "usize", "isize", "u8", "u16", "u32", "u64", "i8", "i16", "i32", "i64", "f32", "f64",
+];
+
+impl Signature {
+    /// Parse `text` as a signature of the function `name`.
+    ///
+    /// Returns `None` when `name` is not followed by a parameter list (or, in Scala,
+    /// by a `: Type` of a parameterless method).
+    pub fn parse(text: &str, name: &str, language: Language) -> Option<Self> {
+        let cleaned = text.trim().trim_matches('`').trim();
+        let (name_pos, name_len) = find_name(cleaned, name)?;
+        let prefix = &cleaned[..name_pos];
+        let mut rest = cleaned[name_pos + name_len..].trim_start();
+
+        let mut sig = Signature { language, name: name.to_string(), ..Default::default() };
+        if let Some((owner, _)) = cleaned[name_pos..name_pos + name_len].rsplit_once("::") {
+            sig.receiver = Some(owner.to_string());
+        }
+        sig.parse_prefix(prefix);
+
+        // Generics after the name: `f<T>(…)`, Scala/Go `f[T any](…)`
+        if let Some(open) = rest.chars().next().filter(|c| *c == '<' || *c == '[') {
+            let close = if open == '<' { '>' } else { ']' };
+            let end = find_matching_bracket(rest, 0, open, close)?;
+            sig.generics.extend(parse_generics(&rest[1..end]));
+            rest = rest[end + 1..].trim_start();
+        }
+
+        // Scala curries parameter lists; elsewhere a second list is a Go result list
+        let mut lists = Vec::new();
+        while rest.starts_with('(') {
+            let end = find_matching_bracket(rest, 0, '(', ')')?;
+            let curried = language == Language::Scala || (language == Language::Unknown && rest[1..end].contains(':'));
+            if !lists.is_empty() && !curried {
+                break;
+            }
+            lists.push(&rest[1..end]);
+            rest = rest[end + 1..].trim_start();
+        }
+        sig.parse_params(&lists);
+        sig.parse_suffix(rest);
+
+        if sig.return_type.as_deref().is_some_and(|r| ASYNC_RETURN_PREFIXES.iter().any(|p| r.starts_with(p))) {
+            sig.is_async = true;
+        }
+        Some(sig)
+    }
+
+    /// Whether two signatures describe the same function shape.
+    ///
+    /// Parameter names and types are compared only where both sides state them, so a
+    /// spec that omits types still matches typed code. Types ignore whitespace, and
+    /// `void`/`Unit`/`()`/`None` return types count as no return type.
+    pub fn matches(&self, other: &Signature) -> bool {
+        self.params.len() == other.params.len()
+            && self.params.iter().zip(&other.params).all(|(a, b)| a.matches(b))
+            && normalized_return(self.return_type.as_deref()) == normalized_return(other.return_type.as_deref())
+    }
+
+    /// Keywords, receiver, Java-style generics and return type written before the name.
+    fn parse_prefix(&mut self, prefix: &str) {
+        let mut prefix = prefix.trim().to_string();
+
+        // Go receiver: `func (s *Server) Name`
+        if let Some(open) = prefix.find('(') {
+            if let Some(close) = find_matching_bracket(&prefix, open, '(', ')') {
+                let receiver = prefix[open + 1..close].trim().to_string();
+                if !receiver.is_empty() {
+                    self.receiver = Some(receiver);
+                }
+                prefix.replace_range(open..=close, " ");
+            }
+        }
+
+        // Extension/class receiver: Ruby `def self.name`, Kotlin `fun String.name`
+        let trimmed = prefix.trim_end();
+        if let Some(qualified) = trimmed.strip_suffix('.') {
+            let start = qualified.rfind(char::is_whitespace).map(|i| i + 1).unwrap_or(0);
+            self.receiver = Some(qualified[start..].to_string());
+            prefix.truncate(start);
+        }
+
+        // Generics before the name: Java `<T> T f`, Kotlin `fun <T : Any> f`, C++ `template<typename T>`
+        while let Some(open) = leading_generic_open(&prefix) {
+            let Some(close) = find_matching_bracket(&prefix, open, '<', '>') else { break };
+            self.generics.extend(parse_generics(&prefix[open + 1..close]));
+            let start = if prefix[..open].trim_end().ends_with("template") {
+                prefix[..open].trim_end().len() - "template".len()
+            } else {
+                open
+            };
+            prefix.replace_range(start..=close, " ");
+        }
+
+        let mut return_words = Vec::new();
+        for word in prefix.split_whitespace() {
+            if ASYNC_MODIFIERS.contains(&word) {
+                self.is_async = true;
+            } else if MODIFIERS.contains(&word) || word.starts_with('@') || word.starts_with("pub(") {
+                continue;
+            } else {
+                return_words.push(word);
+            }
+        }
+        if !return_words.is_empty() {
+            self.return_type = Some(return_words.join(" "));
+        }
+    }
+
+    fn parse_params(&mut self, lists: &[&str]) {
+        let style = self.language.param_style();
+        for (index, list) in lists.iter().enumerate() {
+            let mut params: Vec<Parameter> = split_respecting_brackets(list, ',')
+                .iter()
+                .filter(|raw| !matches!(raw.as_str(), "" | "*" | "/"))
+                .filter(|raw| !(raw.as_str() == "void" && matches!(self.language, Language::C | Language::Cpp | Language::Unknown)))
+                .map(|raw| parse_param(raw, style))
+                .collect();
+
+            // `self`/`&mut self`/`cls` in the first list is the receiver, not a parameter
+            if index == 0 && self.receiver.is_none() {
+                if let Some(first) = params.first() {
+                    if is_self_param(&first.name) {
+                        let receiver = match &first.type_name {
+                            Some(t) => format!("{}: {}", first.name, t),
+                            None => first.name.clone(),
+                        };
+                        self.receiver = Some(receiver);
+                        params.remove(0);
+                    }
+                }
+            }
+
+            // Go groups names that share a type: `userID, role string`
+            if matches!(style, ParamStyle::NameType | ParamStyle::Infer) {
+                let mut shared: Option<String> = None;
+                for param in params.iter_mut().rev() {
+                    match &param.type_name {
+                        Some(t) => shared = Some(t.clone()),
+                        None if style == ParamStyle::NameType => param.type_name = shared.clone(),
+                        None => {}
+                    }
+                }
+            }
+            self.params.extend(params);
+        }
+    }
+
+    /// Text after the parameter lists: async/throws markers, return type, where clause.
+    fn parse_suffix(&mut self, suffix: &str) {
+        let mut rest = suffix.trim().trim_end_matches('{').trim_end();
+        loop {
+            if let Some(r) = strip_word(rest, "async") {
+                self.is_async = true;
+                rest = r;
+            } else if let Some(r) = strip_word(rest, "throws").or_else(|| strip_word(rest, "rethrows")) {
+                self.throws = true;
+                rest = r;
+                if rest.starts_with('(') {
+                    // Swift typed throws: `throws(ReceiptError)`
+                    if let Some(end) = find_matching_bracket(rest, 0, '(', ')') {
+                        self.thrown_types.push(rest[1..end].trim().to_string());
+                        rest = rest[end + 1..].trim_start();
+                    }
+                } else if !rest.is_empty() && !rest.starts_with("->") {
+                    // Java: `throws IOException, TokenException` runs to the end
+                    self.thrown_types.extend(rest.split(',').map(|t| t.trim().to_string()).filter(|t| !t.is_empty()));
+                    rest = "";
+                }
+            } else if let Some(r) = TRAILING_QUALIFIERS.iter().find_map(|q| strip_word(rest, q)) {
+                rest = r;
+            } else {
+                break;
+            }
+        }
+
+        let (rest, where_clause) = match find_word(rest, "where") {
+            Some(i) => (rest[..i].trim(), Some(rest[i + "where".len()..].trim())),
+            None => (rest, None),
+        };
+        if let Some(clause) = where_clause {
+            self.apply_where_clause(clause);
+        }
+
+        let explicit = ["->", "=>", ":"].iter().find_map(|sep| rest.strip_prefix(sep));
+        let return_type = match explicit {
+            Some(r) => Some(r),
+            // Go writes the return type bare after the parameters
+            None if !rest.is_empty() && matches!(self.language, Language::Go | Language::Unknown) => Some(rest),
+            None => None,
+        };
+        if let Some(r) = return_type.map(|r| r.trim().trim_end_matches('=').trim()).filter(|r| !r.is_empty()) {
+            self.return_type = Some(r.to_string());
+        }
+    }
+
+    /// `where T: Clone + Send, U: Into<String>` (Rust, Kotlin, C# `where T : class`)
+    fn apply_where_clause(&mut self, clause: &str) {
+        let clause = clause.replace(" where ", ", ");
+        for part in split_respecting_brackets(&clause, ',') {
+            let Some((name, bounds)) = part.split_once(':') else { continue };
+            let name = name.trim();
+            let bounds = split_bounds(bounds);
+            match self.generics.iter_mut().find(|g| g.name == name) {
+                Some(generic) => generic.bounds.extend(bounds),
+                None => self.generics.push(GenericParam { name: name.to_string(), bounds }),
+            }
+        }
+    }
+}
+
+impl Parameter {
+    fn matches(&self, other: &Parameter) -> bool {
+        let names_match = self.name.is_empty() || other.name.is_empty() || self.name == other.name;
+        let types_match = match (&self.type_name, &other.type_name) {
+            (Some(a), Some(b)) => same_type(a, b),
+            _ => true,
+        };
+        names_match && types_match && self.variadic == other.variadic
+    }
+}
+
+/// Byte offset and length of `name` (optionally `Owner::name`) where it is followed by
+/// its parameter list or generics.
+pub(crate) fn find_name(signature: &str, name: &str) -> Option<(usize, usize)> {
+    let candidates = signature.match_indices(name).map(|(i, _)| i);
+    let mut fallback = None;
+    for i in candidates {
+        let before_ok = signature[..i]
+            .chars()
+            .last()
+            .map(|c| !c.is_alphanumeric() && c != '_' && c != '$')
+            .unwrap_or(true);
+        if !before_ok {
+            continue;
+        }
+        let after = signature[i + name.len()..].trim_start();
+        // Include a C++ `Owner::` qualifier in the matched span
+        let start = qualified_start(signature, i);
+        if after.starts_with('(') || after.starts_with('<') || after.starts_with('[') {
+            return Some((start, i + name.len() - start));
+        }
+        // Scala parameterless method: `def isRevoked: Boolean`
+        if fallback.is_none() && (after.is_empty() || after.starts_with(':')) && signature[..i].trim_end().ends_with("def") {
+            fallback = Some((start, i + name.len() - start));
+        }
+    }
+    fallback
+}
+
+fn qualified_start(signature: &str, name_start: usize) -> usize {
+    let before = &signature[..name_start];
+    if !before.ends_with("::") {
+        return name_start;
+    }
+    let owner = &before[..before.len() - 2];
+    owner
+        .rfind(|c: char| !(c.is_alphanumeric() || c == '_' || c == ':'))
+        .map(|i| i + 1)
+        .unwrap_or(0)
+}
+
+/// Offset of a `<` that opens a generic parameter list in a prefix (not part of a return type).
+fn leading_generic_open(prefix: &str) -> Option<usize> {
+    let open = prefix.find('<')?;
+    let before = prefix[..open].trim_end();
+    let word_start = open == 0 || prefix[..open].ends_with(char::is_whitespace) || before.ends_with("template");
+    word_start.then_some(open)
+}
+
+/// `T: Clone + Send, U extends Base` → generic params with bounds.
+fn parse_generics(list: &str) -> Vec<GenericParam> {
+    split_respecting_brackets(list, ',')
+        .into_iter()
+        .filter(|g| !g.is_empty())
+        .map(|g| {
+            let g = g.trim_start_matches("typename ").trim_start_matches("class ").trim();
+            // Go `T any`, Java `T extends Base`, Rust/Kotlin `T: Bound`, Scala `T <: Base`
+            let split = g
+                .find(" extends ")
+                .map(|i| (&g[..i], &g[i + " extends ".len()..]))
+                .or_else(|| g.find("<:").map(|i| (&g[..i], &g[i + 2..])))
+                .or_else(|| g.find(':').map(|i| (&g[..i], &g[i + 1..])))
+                .or_else(|| g.split_once(' '));
+            match split {
+                Some((name, bounds)) => GenericParam { name: name.trim().to_string(), bounds: split_bounds(bounds) },
+                None => GenericParam { name: g.to_string(), bounds: Vec::new() },
+            }
+        })
+        .collect()
+}
+
+fn split_bounds(bounds: &str) -> Vec<String> {
+    split_respecting_brackets(bounds, '+')
+        .into_iter()
+        .flat_map(|b| split_respecting_brackets(&b, '&'))
+        .map(|b| b.trim().to_string())
+        .filter(|b| !b.is_empty())
+        .collect()
+}
+
+fn parse_param(raw: &str, style: ParamStyle) -> Parameter {
+    let (decl, default) = split_default(raw);
+    let mut decl = decl.trim();
+    let mut param = Parameter { default, ..Default::default() };
+
+    if style == ParamStyle::Ruby {
+        if let Some((name, value)) = decl.split_once(':') {
+            let value = value.trim();
+            if !value.is_empty() {
+                param.default = Some(value.to_string());
+            }
+            decl = name.trim();
+        }
+    }
+
+    for marker in ["vararg ", "params "] {
+        if let Some(rest) = decl.strip_prefix(marker) {
+            param.variadic = true;
+            decl = rest.trim_start();
+        }
+    }
+    for marker in ["...", "**", "*"] {
+        if let Some(rest) = decl.strip_prefix(marker) {
+            param.variadic = true;
+            decl = rest;
+            break;
+        }
+    }
+    if style == ParamStyle::Ruby {
+        decl = decl.trim_start_matches('&');
+    }
+
+    let style = match style {
+        ParamStyle::Infer => infer_style(decl),
+        style => style,
+    };
+    let (name, type_name) = match style {
+        ParamStyle::NameColonType => match split_colon(decl) {
+            Some((lhs, ty)) => (last_word(lhs), Some(ty.to_string())),
+            None => (decl, None),
+        },
+        ParamStyle::NameType => match decl.split_once(char::is_whitespace) {
+            Some((name, ty)) => (name, Some(ty.to_string())),
+            None => (decl, None),
+        },
+        ParamStyle::TypeName => match decl.rsplit_once(char::is_whitespace) {
+            Some((ty, name)) => {
+                // C `char *buf`, C++ `const std::string &token`: pointer marks belong to the type
+                let marks = name.len() - name.trim_start_matches(['*', '&']).len();
+                let ty = format!("{}{}", ty.trim(), &name[..marks]);
+                (&name[marks..], Some(ty))
+            }
+            None => (decl, None),
+        },
+        ParamStyle::Ruby | ParamStyle::Infer => (decl, None),
+    };
+
+    let mut name = name.trim_end_matches('?').trim_start_matches('$');
+    if let Some(rest) = name.strip_prefix("...") {
+        // PHP `string ...$args`
+        param.variadic = true;
+        name = rest.trim_start_matches('$');
+    }
+    param.name = name.to_string();
+
+    if let Some(mut ty) = type_name.as_deref().map(str::trim) {
+        // Java `String... args`, Swift `Int...`, Go `...string`
+        if let Some(t) = ty.strip_suffix("...") {
+            param.variadic = true;
+            ty = t.trim_end();
+        } else if let Some(t) = ty.strip_prefix("...") {
+            param.variadic = true;
+            ty = t.trim_start();
+        }
+        if !ty.is_empty() {
+            param.type_name = Some(ty.to_string());
+        }
+    }
+    param
+}
+
+/// Pick a declaration style for a parameter of unknown language.
+fn infer_style(decl: &str) -> ParamStyle {
+    if split_colon(decl).is_some() {
+        return ParamStyle::NameColonType;
+    }
+    let words: Vec<&str> = decl.split_whitespace().collect();
+    match words.as_slice() {
+        [first, .., last] if looks_like_type(last) && !looks_like_type(first) => ParamStyle::NameType,
+        [_, _, ..] => ParamStyle::TypeName,
+        _ => ParamStyle::NameColonType,
+    }
+}
+
+fn looks_like_type(word: &str) -> bool {
+    word.starts_with(|c: char| c.is_uppercase() || matches!(c, '*' | '[' | '&' | '.'))
+        || word.contains(['<', '[', '.'])
+        || word.starts_with("map[")
+        || word.starts_with("chan ")
+        || word.starts_with("func(")
+        || PRIMITIVE_TYPES.contains(&word)
+}
+
+/// Split `decl = value` at a top-level `=` (not `=>`, `==`, `<=`, `>=`, `!=`).
+fn split_default(raw: &str) -> (&str, Option<String>) {
+    let bytes = raw.as_bytes();
+    let mut depth: i32 = 0;
+    for (i, c) in raw.char_indices() {
+        match c {
+            '(' | '[' | '{' | '<' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            '>' if i == 0 || bytes[i - 1] != b'=' && bytes[i - 1] != b'-' => depth -= 1,
+            '=' if depth == 0 => {
+                let next = bytes.get(i + 1).copied();
+                let prev = if i > 0 { Some(bytes[i - 1]) } else { None };
+                if next != Some(b'>') && next != Some(b'=') && !matches!(prev, Some(b'!' | b'<' | b'>' | b'=')) {
+                    return (&raw[..i], Some(raw[i + 1..].trim().to_string()));
+                }
+            }
+            _ => {}
+        }
+    }
+    (raw, None)
+}
+
+/// Split `name: Type` at the first top-level single `:` (not `::`).
+fn split_colon(decl: &str) -> Option<(&str, &str)> {
+    let bytes = decl.as_bytes();
+    let mut depth: i32 = 0;
+    for (i, c) in decl.char_indices() {
+        match c {
+            '(' | '[' | '{' | '<' => depth += 1,
+            ')' | ']' | '}' | '>' => depth -= 1,
+            ':' if depth == 0 => {
+                let doubled = bytes.get(i + 1) == Some(&b':') || (i > 0 && bytes[i - 1] == b':');
+                if !doubled {
+                    return Some((&decl[..i], &decl[i + 1..]));
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Last word of `lhs`: Swift labels (`_ token`), Kotlin `val`, Rust `mut x`, TS `readonly x`.
+fn last_word(lhs: &str) -> &str {
+    lhs.split_whitespace().last().unwrap_or("")
+}
+
+fn is_self_param(name: &str) -> bool {
+    matches!(name, "self" | "&self" | "&mut self" | "mut self" | "cls" | "this")
+}
+
+/// `rest` with a leading keyword `word` removed, if it starts with one.
+fn strip_word<'a>(rest: &'a str, word: &str) -> Option<&'a str> {
+    let after = rest.strip_prefix(word)?;
+    let boundary = after.is_empty() || !after.starts_with(|c: char| c.is_alphanumeric() || c == '_');
+    boundary.then(|| after.trim_start())
+}
+
+/// Offset of `word` as a standalone top-level word.
+fn find_word(text: &str, word: &str) -> Option<usize> {
+    text.match_indices(word).map(|(i, _)| i).find(|&i| {
+        let before = text[..i].chars().last();
+        let after = text[i + word.len()..].chars().next();
+        before.is_none_or(char::is_whitespace) && after.is_none_or(char::is_whitespace)
+    })
+}
+
+/// Whether two type expressions are the same, ignoring whitespace.
+pub(crate) fn same_type(a: &str, b: &str) -> bool {
+    strip_whitespace(a) == strip_whitespace(b)
+}
+
+/// Return type without whitespace; `None` for unit types.
+pub(crate) fn normalized_return(return_type: Option<&str>) -> Option<String> {
+    let stripped = strip_whitespace(return_type?);
+    // Go multiple results may be written with or without parentheses
+    let unwrapped = match stripped.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+        Some(inner) if split_respecting_brackets(inner, ',').len() > 1 => inner.to_string(),
+        _ => stripped,
+    };
+    (!UNIT_TYPES.contains(&unwrapped.as_str())).then_some(unwrapped)
+}
+
+fn strip_whitespace(s: &str) -> String {
+    s.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str, name: &str, language: Language) -> Signature {
+        Signature::parse(text, name, language).unwrap_or_else(|| panic!("failed to parse {text}"))
+    }
+
+    fn shape(sig: &Signature) -> Vec<(String, Option<String>)> {
+        sig.params.iter().map(|p| (p.name.clone(), p.type_name.clone())).collect()
+    }
+
+    fn p(name: &str, ty: &str) -> (String, Option<String>) {
+        (name.to_string(), Some(ty.to_string()))
+    }
+
+    #[test]
+    fn test_typescript_and_python() {
+        let sig = parse("validateToken(token: string, ...scopes: string[]): Promise<Claims>", "validateToken", Language::TypeScript);
+        assert_eq!(shape(&sig), vec![p("token", "string"), p("scopes", "string[]")]);
+        assert!(sig.params[1].variadic);
+        assert_eq!(sig.return_type.as_deref(), Some("Promise<Claims>"));
+        assert!(sig.is_async);
+
+        let sig = parse("def refresh(self, token: str, leeway: int = 0, *args, **kwargs) -> Claims", "refresh", Language::Python);
+        assert_eq!(sig.receiver.as_deref(), Some("self"));
+        assert_eq!(sig.params.len(), 4);
+        assert_eq!(sig.params[1].default.as_deref(), Some("0"));
+        assert!(sig.params[2].variadic && sig.params[3].variadic);
+        assert_eq!(sig.params[3].name, "kwargs");
+    }
+
+    #[test]
+    fn test_go_receiver_grouped_params_and_bare_results() {
+        let sig = parse("func (s *Service) Issue(userID, role string, ttl time.Duration) (*Claims, error)", "Issue", Language::Go);
+        assert_eq!(sig.receiver.as_deref(), Some("s *Service"));
+        assert_eq!(shape(&sig), vec![p("userID", "string"), p("role", "string"), p("ttl", "time.Duration")]);
+        assert_eq!(sig.return_type.as_deref(), Some("(*Claims, error)"));
+
+        let bare = parse("func Issue(userID string, role string, ttl time.Duration) *Claims, error", "Issue", Language::Go);
+        assert_eq!(normalized_return(bare.return_type.as_deref()), normalized_return(sig.return_type.as_deref()));
+    }
+
+    #[test]
+    fn test_rust_generics_receiver_and_where_clause() {
+        let sig = parse(
+            "pub async fn store<K: Hash + Eq, V>(&mut self, key: K, value: V) -> Result<(), Error> where V: Serialize",
+            "store",
+            Language::Rust,
+        );
+        assert_eq!(sig.receiver.as_deref(), Some("&mut self"));
+        assert!(sig.is_async);
+        assert_eq!(sig.generics, vec![
+            GenericParam { name: "K".into(), bounds: vec!["Hash".into(), "Eq".into()] },
+            GenericParam { name: "V".into(), bounds: vec!["Serialize".into()] },
+        ]);
+        assert_eq!(shape(&sig), vec![p("key", "K"), p("value", "V")]);
+        assert_eq!(sig.return_type.as_deref(), Some("Result<(), Error>"));
+    }
+
+    #[test]
+    fn test_type_first_languages() {
+        let sig = parse(
+            "public static <T extends Comparable<T>> List<T> sort(List<T> items, String... keys) throws IOException, SortException",
+            "sort",
+            Language::Java,
+        );
+        assert_eq!(sig.generics[0].name, "T");
+        assert_eq!(sig.generics[0].bounds, vec!["Comparable<T>"]);
+        assert_eq!(shape(&sig), vec![p("items", "List<T>"), p("keys", "String")]);
+        assert!(sig.params[1].variadic);
+        assert_eq!(sig.return_type.as_deref(), Some("List<T>"));
+        assert_eq!(sig.thrown_types, vec!["IOException", "SortException"]);
+
+        let sig = parse("TokenClaims TokenService::validate(const std::string& token) const", "validate", Language::Cpp);
+        assert_eq!(sig.receiver.as_deref(), Some("TokenService"));
+        assert_eq!(shape(&sig), vec![p("token", "const std::string&")]);
+        assert_eq!(sig.return_type.as_deref(), Some("TokenClaims"));
+
+        let sig = parse("static function fromEnv(?string $prefix = null, Role ...$roles): self", "fromEnv", Language::Php);
+        assert_eq!(shape(&sig), vec![p("prefix", "?string"), p("roles", "Role")]);
+        assert_eq!(sig.params[0].default.as_deref(), Some("null"));
+        assert!(sig.params[1].variadic);
+        assert_eq!(sig.return_type.as_deref(), Some("self"));
+
+        let sig = parse("Task<string> Sign(string subject, params string[] scopes)", "Sign", Language::CSharp);
+        assert!(sig.is_async && sig.params[1].variadic);
+    }
+
+    #[test]
+    fn test_swift_kotlin_scala_ruby() {
+        let sig = parse("func verify(_ token: String, leeway: Int = 0) async throws(ReceiptError) -> Receipt", "verify", Language::Swift);
+        assert_eq!(shape(&sig), vec![p("token", "String"), p("leeway", "Int")]);
+        assert!(sig.is_async && sig.throws);
+        assert_eq!(sig.thrown_types, vec!["ReceiptError"]);
+        assert_eq!(sig.return_type.as_deref(), Some("Receipt"));
+
+        let sig = parse("suspend fun String.toSlug(vararg parts: String): String?", "toSlug", Language::Kotlin);
+        assert_eq!(sig.receiver.as_deref(), Some("String"));
+        assert!(sig.is_async && sig.params[0].variadic);
+
+        let sig = parse("def fetch(id: String)(implicit ec: ExecutionContext): Future[String]", "fetch", Language::Scala);
+        assert_eq!(shape(&sig), vec![p("id", "String"), p("ec", "ExecutionContext")]);
+        let sig = parse("def isRevoked: Boolean", "isRevoked", Language::Scala);
+        assert!(sig.params.is_empty());
+        assert_eq!(sig.return_type.as_deref(), Some("Boolean"));
+
+        let sig = parse("def self.issue(user_id, role: :user, *scopes, &block)", "issue", Language::Ruby);
+        assert_eq!(sig.receiver.as_deref(), Some("self"));
+        let names: Vec<&str> = sig.params.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["user_id", "role", "scopes", "block"]);
+        assert_eq!(sig.params[1].default.as_deref(), Some(":user"));
+        assert!(sig.params[2].variadic);
+    }
+
+    #[test]
+    fn test_unknown_language_infers_each_parameter() {
+        let spec = parse("ValidateToken(token string, leeway int) (*Claims, error)", "ValidateToken", Language::Unknown);
+        let code = parse("func ValidateToken(token string, leeway int) (*Claims, error)", "ValidateToken", Language::Go);
+        assert_eq!(shape(&spec), shape(&code));
+        assert!(spec.matches(&code));
+
+        let spec = parse("TokenClaims validateToken(String token)", "validateToken", Language::Unknown);
+        assert_eq!(shape(&spec), vec![p("token", "String")]);
+        assert_eq!(spec.return_type.as_deref(), Some("TokenClaims"));
+    }
+
+    #[test]
+    fn test_semantic_matching() {
+        let spec = parse("fn validate_token(token: &str) -> Result<Claims, TokenError>", "validate_token", Language::Unknown);
+        let code = parse("pub fn validate_token(token : &str)->Result<Claims,TokenError>", "validate_token", Language::Rust);
+        assert!(spec.matches(&code));
+
+        // Untyped spec parameters match typed code; unit return types are equivalent
+        let spec = parse("save(record)", "save", Language::Unknown);
+        let code = parse("save(record: Record): void", "save", Language::TypeScript);
+        assert!(spec.matches(&code));
+
+        let extra = parse("save(record: Record, force: boolean): void", "save", Language::TypeScript);
+        assert!(!code.matches(&extra));
+        let renamed = parse("save(entry: Record): void", "save", Language::TypeScript);
+        assert!(!code.matches(&renamed));
+        let retyped = parse("save(record: Record): boolean", "save", Language::TypeScript);
+        assert!(!code.matches(&retyped));
+    }
+
+    #[test]
+    fn test_name_must_precede_parameters() {
+        assert!(Signature::parse("validateTokens(a: string)", "validateToken", Language::TypeScript).is_none());
+        assert!(Signature::parse("const validateToken = 1", "validateToken", Language::TypeScript).is_none());
+    }
+}
@@ -0,0 +1,853 @@
+//! Semver classification of CLAUDE.md changes between a git revision and the working tree.
+//!
+//! Downstream modules consume a module through its Exports, Contract and Protocol
+//! sections, so every change there is rated by what it does to existing callers:
+//! - major: callers can break (export removed, parameter added without default, precondition tightened)
+//! - minor: callers keep working and gain something (export added, optional parameter, state added)
+//! - patch: nothing observable changes (signature reformatted, parameter renamed)
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::process::{Command, Output};
+use thiserror::Error;
+
+use crate::bracket_utils::split_respecting_brackets;
+use crate::claude_md_parser::{ClaudeMdParser, ClaudeMdSpec, ContractSpec, ProtocolSpec};
+use crate::signature::{normalized_return, same_type, Language, Parameter, Signature};
+
+#[derive(Debug, Error)]
+pub enum SpecDiffError {
+    #[error("Cannot read file '{path}': {source}")]
+    FileReadError {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Unknown git revision '{0}'")]
+    UnknownRevision(String),
+
+    #[error("Git command failed: {0}")]
+    GitError(String),
+}
+
+/// Semver impact of a change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Patch,
+    Minor,
+    Major,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    ExportRemoved,
+    ExportAdded,
+    /// Same name, different kind of export (function → class, ...)
+    ExportKindChanged,
+    ParameterAddedWithoutDefault,
+    ParameterAddedWithDefault,
+    ParameterRemoved,
+    ParameterTypeChanged,
+    ParameterDefaultRemoved,
+    ParameterDefaultAdded,
+    ParameterRenamed,
+    ReturnTypeChanged,
+    /// Generics changed, or a signature that could not be parsed changed
+    SignatureChanged,
+    /// Text changed but the parsed signature is the same
+    SignatureReformatted,
+    TypeChanged,
+    TypeFieldAdded,
+    EnumVariantRemoved,
+    EnumVariantAdded,
+    VariableChanged,
+    PreconditionTightened,
+    PreconditionLoosened,
+    PostconditionWeakened,
+    PostconditionStrengthened,
+    ThrowsAdded,
+    ThrowsRemoved,
+    InvariantRemoved,
+    InvariantAdded,
+    StateRemoved,
+    StateAdded,
+    TransitionRemoved,
+    TransitionAdded,
+    LifecycleMethodRemoved,
+    LifecycleMethodAdded,
+    LifecycleOrderChanged,
+}
+
+impl ChangeKind {
+    /// Semver rating of this kind of change.
+    pub fn severity(self) -> Severity {
+        use ChangeKind::*;
+        match self {
+            ExportRemoved | ExportKindChanged | ParameterAddedWithoutDefault | ParameterRemoved
+            | ParameterTypeChanged | ParameterDefaultRemoved | ReturnTypeChanged | SignatureChanged
+            | TypeChanged | EnumVariantRemoved | VariableChanged | PreconditionTightened
+            | PostconditionWeakened | ThrowsAdded | InvariantRemoved | StateRemoved
+            | TransitionRemoved | LifecycleMethodRemoved | LifecycleMethodAdded
+            | LifecycleOrderChanged => Severity::Major,
+            ExportAdded | ParameterAddedWithDefault | ParameterDefaultAdded | TypeFieldAdded
+            | EnumVariantAdded | PreconditionLoosened | PostconditionStrengthened | ThrowsRemoved
+            | InvariantAdded | StateAdded | TransitionAdded => Severity::Minor,
+            ParameterRenamed | SignatureReformatted => Severity::Patch,
+        }
+    }
+
+    /// Rating for a signature in `language`: renaming a parameter breaks callers that
+    /// pass it by name, in languages with named or keyword arguments.
+    pub fn severity_in(self, language: Language) -> Severity {
+        use Language::*;
+        match (self, language) {
+            (ChangeKind::ParameterRenamed, Python | Kotlin | Swift | CSharp | Scala | Php) => Severity::Major,
+            _ => self.severity(),
+        }
+    }
+}
+
+/// A single classified change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpecChange {
+    pub kind: ChangeKind,
+    pub severity: Severity,
+    /// CLAUDE.md section: Exports, Contract or Protocol
+    pub section: String,
+    /// Export, contract function, state, transition or lifecycle method
+    pub symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SpecDiffSummary {
+    pub major: usize,
+    pub minor: usize,
+    pub patch: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpecDiffReport {
+    pub claude_md: String,
+    pub base: String,
+    /// False when the file did not exist at `base`
+    pub base_exists: bool,
+    /// Highest severity among the changes; null when nothing changed
+    pub bump: Option<Severity>,
+    pub changes: Vec<SpecChange>,
+    pub summary: SpecDiffSummary,
+}
+
+pub struct SpecDiffer {
+    parser: ClaudeMdParser,
+}
+
+impl SpecDiffer {
+    pub fn new() -> Self {
+        Self {
+            parser: ClaudeMdParser::new(),
+        }
+    }
+
+    /// Compare `file` at git revision `base` with the working tree copy.
+    ///
+    /// Both versions are parsed leniently, so a base written against an older schema
+    /// still diffs. A file that did not exist at `base` diffs against an empty spec.
+    pub fn diff_against(&self, file: &Path, base: &str) -> Result<SpecDiffReport, SpecDiffError> {
+        let content = std::fs::read_to_string(file).map_err(|e| SpecDiffError::FileReadError {
+            path: file.display().to_string(),
+            source: e,
+        })?;
+        let head = self.parser.parse_content_lenient(&content).spec;
+        let base_content = git_show(file, base)?;
+        let base_spec = base_content
+            .as_deref()
+            .map(|c| self.parser.parse_content_lenient(c).spec)
+            .unwrap_or_default();
+
+        let changes = diff_specs(&base_spec, &head);
+        Ok(SpecDiffReport {
+            claude_md: file.display().to_string(),
+            base: base.to_string(),
+            base_exists: base_content.is_some(),
+            bump: changes.iter().map(|c| c.severity).max(),
+            summary: summarize(&changes),
+            changes,
+        })
+    }
+}
+
+impl Default for SpecDiffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Count changes per severity
+pub fn summarize(changes: &[SpecChange]) -> SpecDiffSummary {
+    let mut summary = SpecDiffSummary::default();
+    for change in changes {
+        match change.severity {
+            Severity::Major => summary.major += 1,
+            Severity::Minor => summary.minor += 1,
+            Severity::Patch => summary.patch += 1,
+        }
+    }
+    summary
+}
+
+/// Classify every Exports, Contract and Protocol change from `base` to `head`, most severe first.
+pub fn diff_specs(base: &ClaudeMdSpec, head: &ClaudeMdSpec) -> Vec<SpecChange> {
+    let mut changes = Vec::new();
+    diff_exports(base, head, &mut changes);
+    diff_contracts(base, head, &mut changes);
+    diff_protocol(
+        base.protocol.as_ref().unwrap_or(&ProtocolSpec::default()),
+        head.protocol.as_ref().unwrap_or(&ProtocolSpec::default()),
+        &mut changes,
+    );
+    changes.sort_by_key(|c| std::cmp::Reverse(c.severity));
+    changes
+}
+
+fn change(kind: ChangeKind, section: &str, symbol: &str, before: Option<String>, after: Option<String>, message: String) -> SpecChange {
+    SpecChange {
+        kind,
+        severity: kind.severity(),
+        section: section.to_string(),
+        symbol: symbol.to_string(),
+        before,
+        after,
+        message,
+    }
+}
+
+// ============== Exports ==============
+
+/// Every export as `(name, kind, text)`
+fn export_entries(spec: &ClaudeMdSpec) -> Vec<(&str, &'static str, String)> {
+    let exports = &spec.exports;
+    let mut entries: Vec<(&str, &'static str, String)> = Vec::new();
+    entries.extend(exports.functions.iter().map(|f| (f.name.as_str(), "function", f.signature.clone())));
+    entries.extend(exports.types.iter().map(|t| (t.name.as_str(), "type", t.definition.clone())));
+    entries.extend(exports.classes.iter().map(|c| (c.name.as_str(), "class", c.constructor_signature.clone())));
+    entries.extend(exports.enums.iter().map(|e| (e.name.as_str(), "enum", e.variants.join(" | "))));
+    entries.extend(exports.variables.iter().map(|v| (v.name.as_str(), "variable", v.value.clone().unwrap_or_default())));
+    entries
+}
+
+fn diff_exports(base: &ClaudeMdSpec, head: &ClaudeMdSpec, changes: &mut Vec<SpecChange>) {
+    let base_entries = export_entries(base);
+    let head_entries = export_entries(head);
+
+    for (name, kind, text) in &base_entries {
+        match head_entries.iter().find(|(n, _, _)| n == name) {
+            None => changes.push(change(
+                ChangeKind::ExportRemoved, "Exports", name, Some(text.clone()), None,
+                format!("{} '{}' was removed from Exports", kind, name),
+            )),
+            Some((_, head_kind, head_text)) if head_kind != kind => changes.push(change(
+                ChangeKind::ExportKindChanged, "Exports", name, Some(text.clone()), Some(head_text.clone()),
+                format!("'{}' changed from a {} to a {}", name, kind, head_kind),
+            )),
+            Some(_) => {}
+        }
+    }
+    for (name, kind, text) in &head_entries {
+        if !base_entries.iter().any(|(n, _, _)| n == name) {
+            changes.push(change(
+                ChangeKind::ExportAdded, "Exports", name, None, Some(text.clone()),
+                format!("{} '{}' was added to Exports", kind, name),
+            ));
+        }
+    }
+
+    let (base_exports, head_exports) = (&base.exports, &head.exports);
+    for b in &base_exports.functions {
+        if let Some(h) = head_exports.functions.iter().find(|h| h.name == b.name) {
+            let base_sig = b.parsed_signature.clone().or_else(|| Signature::parse(&b.signature, &b.name, Language::Unknown));
+            let head_sig = h.parsed_signature.clone().or_else(|| Signature::parse(&h.signature, &h.name, Language::Unknown));
+            diff_signature(&b.name, &b.signature, &h.signature, base_sig, head_sig, changes);
+        }
+    }
+    for b in &base_exports.classes {
+        if let Some(h) = head_exports.classes.iter().find(|h| h.name == b.name) {
+            let base_sig = Signature::parse(&b.constructor_signature, &b.name, Language::Unknown);
+            let head_sig = Signature::parse(&h.constructor_signature, &h.name, Language::Unknown);
+            diff_signature(&b.name, &b.constructor_signature, &h.constructor_signature, base_sig, head_sig, changes);
+        }
+    }
+    for b in &base_exports.types {
+        if let Some(h) = head_exports.types.iter().find(|h| h.name == b.name) {
+            diff_type(&b.name, &b.definition, &h.definition, changes);
+        }
+    }
+    for b in &base_exports.enums {
+        if let Some(h) = head_exports.enums.iter().find(|h| h.name == b.name) {
+            for variant in b.variants.iter().filter(|v| !h.variants.contains(v)) {
+                changes.push(change(
+                    ChangeKind::EnumVariantRemoved, "Exports", &b.name, Some(variant.clone()), None,
+                    format!("Variant '{}' was removed from enum '{}'", variant, b.name),
+                ));
+            }
+            for variant in h.variants.iter().filter(|v| !b.variants.contains(v)) {
+                changes.push(change(
+                    ChangeKind::EnumVariantAdded, "Exports", &b.name, None, Some(variant.clone()),
+                    format!("Variant '{}' was added to enum '{}'", variant, b.name),
+                ));
+            }
+        }
+    }
+    for b in &base_exports.variables {
+        if let Some(h) = head_exports.variables.iter().find(|h| h.name == b.name) {
+            let (before, after) = (b.value.clone().unwrap_or_default(), h.value.clone().unwrap_or_default());
+            if !same_type(&before, &after) {
+                changes.push(change(
+                    ChangeKind::VariableChanged, "Exports", &b.name, Some(before), Some(after),
+                    format!("Type or value of '{}' changed", b.name),
+                ));
+            }
+        }
+    }
+}
+
+/// Compare two signatures of the same function or constructor parameter by parameter.
+fn diff_signature(
+    name: &str,
+    base_text: &str,
+    head_text: &str,
+    base: Option<Signature>,
+    head: Option<Signature>,
+    changes: &mut Vec<SpecChange>,
+) {
+    if same_type(base_text, head_text) {
+        return;
+    }
+    let texts = || (Some(base_text.to_string()), Some(head_text.to_string()));
+    let (Some(base), Some(head)) = (base, head) else {
+        let (before, after) = texts();
+        changes.push(change(
+            ChangeKind::SignatureChanged, "Exports", name, before, after,
+            format!("Signature of '{}' changed", name),
+        ));
+        return;
+    };
+
+    let language = if head.language == Language::Unknown { base.language } else { head.language };
+    let found = changes.len();
+    let mut push = |kind: ChangeKind, message: String| {
+        let (before, after) = texts();
+        let mut entry = change(kind, "Exports", name, before, after, message);
+        entry.severity = kind.severity_in(language);
+        changes.push(entry);
+    };
+
+    for (b, h) in match_params(&base.params, &head.params) {
+        match (b, h) {
+            (Some(b), Some(h)) => {
+                let retyped = matches!((&b.type_name, &h.type_name), (Some(bt), Some(ht)) if !same_type(bt, ht));
+                if retyped || b.variadic != h.variadic {
+                    push(ChangeKind::ParameterTypeChanged, format!("Type of parameter '{}' of '{}' changed", h.name, name));
+                } else if b.default.is_some() && h.default.is_none() {
+                    push(ChangeKind::ParameterDefaultRemoved, format!("Parameter '{}' of '{}' no longer has a default", h.name, name));
+                } else if b.default.is_none() && h.default.is_some() {
+                    push(ChangeKind::ParameterDefaultAdded, format!("Parameter '{}' of '{}' now has a default", h.name, name));
+                }
+                if b.name != h.name && !b.name.is_empty() && !h.name.is_empty() {
+                    push(ChangeKind::ParameterRenamed, format!("Parameter '{}' of '{}' was renamed to '{}'", b.name, name, h.name));
+                }
+            }
+            (None, Some(h)) if h.default.is_some() || h.variadic => {
+                push(ChangeKind::ParameterAddedWithDefault, format!("Optional parameter '{}' was added to '{}'", h.name, name));
+            }
+            (None, Some(h)) => {
+                push(ChangeKind::ParameterAddedWithoutDefault, format!("Required parameter '{}' was added to '{}'", h.name, name));
+            }
+            (Some(b), None) => {
+                push(ChangeKind::ParameterRemoved, format!("Parameter '{}' was removed from '{}'", b.name, name));
+            }
+            (None, None) => {}
+        }
+    }
+
+    let base_return = normalized_return(base.return_type.as_deref());
+    if base_return != normalized_return(head.return_type.as_deref()) || base.is_async != head.is_async {
+        push(ChangeKind::ReturnTypeChanged, format!("Return type of '{}' changed", name));
+    }
+
+    let added_throws = (head.throws && !base.throws) || head.thrown_types.iter().any(|t| !base.thrown_types.contains(t));
+    let removed_throws = (base.throws && !head.throws) || base.thrown_types.iter().any(|t| !head.thrown_types.contains(t));
+    if added_throws {
+        push(ChangeKind::ThrowsAdded, format!("'{}' can throw errors it did not throw before", name));
+    }
+    if removed_throws {
+        push(ChangeKind::ThrowsRemoved, format!("'{}' no longer throws some errors", name));
+    }
+
+    if base.generics != head.generics || base.receiver != head.receiver {
+        push(ChangeKind::SignatureChanged, format!("Generic parameters or receiver of '{}' changed", name));
+    }
+
+    if changes.len() == found {
+        let (before, after) = texts();
+        changes.push(change(
+            ChangeKind::SignatureReformatted, "Exports", name, before, after,
+            format!("Signature of '{}' was reformatted without changing its shape", name),
+        ));
+    }
+}
+
+/// Pair base and head parameters: by name first, then by position when either side is
+/// unnamed or the type is unchanged (a rename). Unpaired parameters were removed or added.
+fn match_params<'a>(base: &'a [Parameter], head: &'a [Parameter]) -> Vec<(Option<&'a Parameter>, Option<&'a Parameter>)> {
+    let by_name = |p: &Parameter, others: &[Parameter]| {
+        others.iter().position(|o| !p.name.is_empty() && o.name == p.name)
+    };
+    let mut head_used = vec![false; head.len()];
+    let mut pairs: Vec<(Option<&Parameter>, Option<&Parameter>)> = Vec::new();
+
+    for (i, b) in base.iter().enumerate() {
+        let index = by_name(b, head).or_else(|| {
+            let h = head.get(i)?;
+            let positional = b.name.is_empty() || h.name.is_empty()
+                || (by_name(h, base).is_none() && same_param_type(b, h));
+            positional.then_some(i)
+        });
+        match index.filter(|&j| !head_used[j]) {
+            Some(j) => {
+                head_used[j] = true;
+                pairs.push((Some(b), Some(&head[j])));
+            }
+            None => pairs.push((Some(b), None)),
+        }
+    }
+    pairs.extend(head.iter().zip(head_used).filter(|(_, used)| !used).map(|(h, _)| (None, Some(h))));
+    pairs
+}
+
+fn same_param_type(a: &Parameter, b: &Parameter) -> bool {
+    match (&a.type_name, &b.type_name) {
+        (Some(a), Some(b)) => same_type(a, b),
+        (a, b) => a == b,
+    }
+}
+
+/// Added fields are minor; any other definition change is major.
+fn diff_type(name: &str, base: &str, head: &str, changes: &mut Vec<SpecChange>) {
+    if same_type(base, head) {
+        return;
+    }
+    let (before, after) = (Some(base.to_string()), Some(head.to_string()));
+    match (type_fields(base), type_fields(head)) {
+        (Some(base_fields), Some(head_fields)) if base_fields.iter().all(|f| head_fields.contains(f)) => {
+            let added: Vec<&str> = head_fields.iter().filter(|f| !base_fields.contains(f)).map(String::as_str).collect();
+            changes.push(change(
+                ChangeKind::TypeFieldAdded, "Exports", name, before, after,
+                format!("Fields added to '{}': {}", name, added.join(", ")),
+            ));
+        }
+        _ => changes.push(change(
+            ChangeKind::TypeChanged, "Exports", name, before, after,
+            format!("Definition of '{}' changed", name),
+        )),
+    }
+}
+
+/// Fields of a `Name { a: T, b: U }` definition, whitespace removed
+fn type_fields(definition: &str) -> Option<Vec<String>> {
+    let open = definition.find('{')?;
+    let close = definition.rfind('}')?;
+    let body = definition.get(open + 1..close)?;
+    Some(
+        split_respecting_brackets(body, ',')
+            .iter()
+            .flat_map(|f| split_respecting_brackets(f, ';'))
+            .map(|f| f.split_whitespace().collect::<String>())
+            .filter(|f| !f.is_empty())
+            .collect(),
+    )
+}
+
+// ============== Contract ==============
+
+/// One clause list of a contract (preconditions, postconditions, ...)
+type ClauseList = fn(&ContractSpec) -> &Vec<String>;
+
+fn diff_contracts(base: &ClaudeMdSpec, head: &ClaudeMdSpec, changes: &mut Vec<SpecChange>) {
+    let base_names: Vec<&str> = export_entries(base).into_iter().map(|(n, _, _)| n).collect();
+    let head_names: Vec<&str> = export_entries(head).into_iter().map(|(n, _, _)| n).collect();
+
+    let mut functions: Vec<&str> = base.contracts.iter().map(|c| c.function_name.as_str()).collect();
+    for contract in &head.contracts {
+        if !functions.contains(&contract.function_name.as_str()) {
+            functions.push(&contract.function_name);
+        }
+    }
+
+    for function in functions {
+        // Contracts of added or removed exports are covered by the export change
+        if base_names.contains(&function) != head_names.contains(&function) {
+            continue;
+        }
+        let base_contract = base.contracts.iter().find(|c| c.function_name == function);
+        let head_contract = head.contracts.iter().find(|c| c.function_name == function);
+        let clauses = |contract: Option<&ContractSpec>, pick: ClauseList| -> Vec<String> {
+            contract.map(|c| pick(c).iter().map(|s| normalize_text(s)).collect()).unwrap_or_default()
+        };
+
+        let rules: [(ClauseList, ChangeKind, ChangeKind, &str); 4] = [
+            (|c| &c.preconditions, ChangeKind::PreconditionTightened, ChangeKind::PreconditionLoosened, "precondition"),
+            (|c| &c.postconditions, ChangeKind::PostconditionStrengthened, ChangeKind::PostconditionWeakened, "postcondition"),
+            (|c| &c.throws, ChangeKind::ThrowsAdded, ChangeKind::ThrowsRemoved, "throws clause"),
+            (|c| &c.invariants, ChangeKind::InvariantAdded, ChangeKind::InvariantRemoved, "invariant"),
+        ];
+        for (pick, added_kind, removed_kind, label) in rules {
+            let before = clauses(base_contract, pick);
+            let after = clauses(head_contract, pick);
+            for clause in before.iter().filter(|c| !after.contains(c)) {
+                changes.push(change(
+                    removed_kind, "Contract", function, Some(clause.clone()), None,
+                    format!("{} of '{}' was removed: {}", capitalize(label), function, clause),
+                ));
+            }
+            for clause in after.iter().filter(|c| !before.contains(c)) {
+                changes.push(change(
+                    added_kind, "Contract", function, None, Some(clause.clone()),
+                    format!("{} of '{}' was added: {}", capitalize(label), function, clause),
+                ));
+            }
+        }
+    }
+}
+
+fn normalize_text(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    chars.next().map(|c| c.to_uppercase().chain(chars).collect()).unwrap_or_default()
+}
+
+// ============== Protocol ==============
+
+fn diff_protocol(base: &ProtocolSpec, head: &ProtocolSpec, changes: &mut Vec<SpecChange>) {
+    for state in base.states.iter().filter(|s| !head.states.contains(s)) {
+        changes.push(change(
+            ChangeKind::StateRemoved, "Protocol", state, Some(state.clone()), None,
+            format!("State '{}' was removed", state),
+        ));
+    }
+    for state in head.states.iter().filter(|s| !base.states.contains(s)) {
+        changes.push(change(
+            ChangeKind::StateAdded, "Protocol", state, None, Some(state.clone()),
+            format!("State '{}' was added", state),
+        ));
+    }
+
+    let transition = |t: &crate::claude_md_parser::TransitionSpec| format!("{} --{}--> {}", t.from, t.trigger, t.to);
+    let base_transitions: Vec<String> = base.transitions.iter().map(transition).collect();
+    let head_transitions: Vec<String> = head.transitions.iter().map(transition).collect();
+    for t in base_transitions.iter().filter(|t| !head_transitions.contains(t)) {
+        changes.push(change(
+            ChangeKind::TransitionRemoved, "Protocol", t, Some(t.clone()), None,
+            format!("Transition '{}' was removed", t),
+        ));
+    }
+    for t in head_transitions.iter().filter(|t| !base_transitions.contains(t)) {
+        changes.push(change(
+            ChangeKind::TransitionAdded, "Protocol", t, None, Some(t.clone()),
+            format!("Transition '{}' was added", t),
+        ));
+    }
+
+    let base_methods: Vec<&str> = base.lifecycle.iter().map(|m| m.method.as_str()).collect();
+    let head_methods: Vec<&str> = head.lifecycle.iter().map(|m| m.method.as_str()).collect();
+    for method in base_methods.iter().filter(|m| !head_methods.contains(m)) {
+        changes.push(change(
+            ChangeKind::LifecycleMethodRemoved, "Protocol", method, Some(method.to_string()), None,
+            format!("Lifecycle step '{}' was removed", method),
+        ));
+    }
+    for method in head_methods.iter().filter(|m| !base_methods.contains(m)) {
+        changes.push(change(
+            ChangeKind::LifecycleMethodAdded, "Protocol", method, None, Some(method.to_string()),
+            format!("Lifecycle step '{}' was added; existing callers do not perform it", method),
+        ));
+    }
+    let kept_base: Vec<&str> = base_methods.iter().copied().filter(|m| head_methods.contains(m)).collect();
+    let kept_head: Vec<&str> = head_methods.iter().copied().filter(|m| base_methods.contains(m)).collect();
+    if kept_base != kept_head {
+        changes.push(change(
+            ChangeKind::LifecycleOrderChanged, "Protocol", "lifecycle", Some(kept_base.join(" → ")), Some(kept_head.join(" → ")),
+            "Lifecycle steps are called in a different order".to_string(),
+        ));
+    }
+}
+
+// ============== Git ==============
+
+/// Content of `file` at revision `base`, or `None` if it did not exist there.
+fn git_show(file: &Path, base: &str) -> Result<Option<String>, SpecDiffError> {
+    verify_revision(containing_dir(file), base)?;
+    show_at_revision(file, base)
+}
+
+/// Directory to run git in for `file`
+pub(crate) fn containing_dir(file: &Path) -> &Path {
+    file.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new("."))
+}
+
+/// Fail unless `revision` names a commit in the repository containing `dir`.
+pub(crate) fn verify_revision(dir: &Path, revision: &str) -> Result<(), SpecDiffError> {
+    let verify = git(dir, &["rev-parse", "--verify", "--quiet", &format!("{}^{{commit}}", revision)])?;
+    if verify.status.success() {
+        return Ok(());
+    }
+    let stderr = String::from_utf8_lossy(&verify.stderr).trim().to_string();
+    Err(if stderr.is_empty() {
+        SpecDiffError::UnknownRevision(revision.to_string())
+    } else {
+        SpecDiffError::GitError(stderr)
+    })
+}
+
+/// Content of `file` at an existing `revision`, or `None` if it did not exist there.
+pub(crate) fn show_at_revision(file: &Path, revision: &str) -> Result<Option<String>, SpecDiffError> {
+    let name = file.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    // `./` makes the path relative to the file's directory instead of the repository root
+    let show = git(containing_dir(file), &["show", &format!("{}:./{}", revision, name)])?;
+    Ok(show.status.success().then(|| String::from_utf8_lossy(&show.stdout).into_owned()))
+}
+
+pub(crate) fn git(dir: &Path, args: &[&str]) -> Result<Output, SpecDiffError> {
+    Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .map_err(|e| SpecDiffError::GitError(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec_from(exports: &str, contract: &str, protocol: &str) -> ClaudeMdSpec {
+        let content = format!(
+            "# auth\n\n## Purpose\nAuth.\n\n## Exports\n{}\n\n## Behavior\n- input → output\n\n\
+             ## Contract\n{}\n\n## Protocol\n{}\n\n## Domain Context\nNone\n",
+            exports, contract, protocol
+        );
+        ClaudeMdParser::new().parse_content(&content).unwrap()
+    }
+
+    fn kinds(changes: &[SpecChange]) -> Vec<(ChangeKind, &str)> {
+        changes.iter().map(|c| (c.kind, c.symbol.as_str())).collect()
+    }
+
+    #[test]
+    fn test_identical_specs_have_no_changes() {
+        let spec = spec_from("- `validateToken(token: string): Claims`", "None", "None");
+        assert!(diff_specs(&spec, &spec).is_empty());
+    }
+
+    #[test]
+    fn test_export_added_and_removed() {
+        let base = spec_from("- `validateToken(token: string): Claims`", "None", "None");
+        let head = spec_from("- `verifyToken(token: string): Claims`", "None", "None");
+        let changes = diff_specs(&base, &head);
+        assert_eq!(kinds(&changes), vec![
+            (ChangeKind::ExportRemoved, "validateToken"),
+            (ChangeKind::ExportAdded, "verifyToken"),
+        ]);
+        assert_eq!(changes[0].severity, Severity::Major);
+        assert_eq!(changes[1].severity, Severity::Minor);
+    }
+
+    #[test]
+    fn test_parameter_changes() {
+        let base = spec_from("- `issue(userId: string, ttl: number = 60): Token`", "None", "None");
+        let required = spec_from("- `issue(userId: string, ttl: number = 60, secret: string): Token`", "None", "None");
+        assert_eq!(kinds(&diff_specs(&base, &required)), vec![(ChangeKind::ParameterAddedWithoutDefault, "issue")]);
+
+        let optional = spec_from("- `issue(userId: string, ttl: number = 60, audience: string = \"api\"): Token`", "None", "None");
+        assert_eq!(kinds(&diff_specs(&base, &optional)), vec![(ChangeKind::ParameterAddedWithDefault, "issue")]);
+
+        let retyped = spec_from("- `issue(userId: number, ttl: number): Promise<Token>`", "None", "None");
+        let changes = diff_specs(&base, &retyped);
+        let found: Vec<ChangeKind> = changes.iter().map(|c| c.kind).collect();
+        assert_eq!(found, vec![ChangeKind::ParameterTypeChanged, ChangeKind::ParameterDefaultRemoved, ChangeKind::ReturnTypeChanged]);
+        assert!(changes.iter().all(|c| c.severity == Severity::Major));
+    }
+
+    #[test]
+    fn test_rename_and_reformat_are_patches() {
+        let base = spec_from("- `issue(userId: string): Token`", "None", "None");
+        let renamed = spec_from("- `issue(subject: string): Token`", "None", "None");
+        assert_eq!(kinds(&diff_specs(&base, &renamed)), vec![(ChangeKind::ParameterRenamed, "issue")]);
+
+        let base = spec_from("- `issue(userId: String): Token`", "None", "None");
+        let reformatted = spec_from("- `Token issue(String userId)`", "None", "None");
+        let changes = diff_specs(&base, &reformatted);
+        assert_eq!(kinds(&changes), vec![(ChangeKind::SignatureReformatted, "issue")]);
+        assert_eq!(changes[0].severity, Severity::Patch);
+    }
+
+    #[test]
+    fn test_inserted_parameter_is_matched_by_name() {
+        let base = spec_from("- `f(a: A, c: C): void`", "None", "None");
+        let head = spec_from("- `f(a: A, b: B, c: C): void`", "None", "None");
+        let changes = diff_specs(&base, &head);
+        assert_eq!(kinds(&changes), vec![(ChangeKind::ParameterAddedWithoutDefault, "f")]);
+        assert_eq!(changes[0].message, "Required parameter 'b' was added to 'f'");
+
+        let removed = diff_specs(&head, &base);
+        assert_eq!(kinds(&removed), vec![(ChangeKind::ParameterRemoved, "f")]);
+        assert_eq!(removed[0].message, "Parameter 'b' was removed from 'f'");
+    }
+
+    #[test]
+    fn test_rename_is_breaking_with_named_arguments() {
+        let rename = |language: Language| {
+            let mut changes = Vec::new();
+            diff_signature(
+                "issue", "def issue(user_id: str) -> Token", "def issue(subject: str) -> Token",
+                Signature::parse("def issue(user_id: str) -> Token", "issue", language),
+                Signature::parse("def issue(subject: str) -> Token", "issue", language),
+                &mut changes,
+            );
+            assert_eq!(kinds(&changes), vec![(ChangeKind::ParameterRenamed, "issue")]);
+            changes[0].severity
+        };
+        assert_eq!(rename(Language::Python), Severity::Major);
+        assert_eq!(rename(Language::Unknown), Severity::Patch);
+        assert_eq!(ChangeKind::ParameterRenamed.severity_in(Language::Go), Severity::Patch);
+        assert_eq!(ChangeKind::ParameterRenamed.severity_in(Language::CSharp), Severity::Major);
+    }
+
+    #[test]
+    fn test_rename_severity_follows_spec_signature_syntax() {
+        let rename = |before: &str, after: &str| {
+            let base = spec_from(&format!("- `{}`", before), "None", "None");
+            let head = spec_from(&format!("- `{}`", after), "None", "None");
+            let changes = diff_specs(&base, &head);
+            assert_eq!(changes.len(), 1, "{:?}", changes);
+            assert_eq!(changes[0].kind, ChangeKind::ParameterRenamed);
+            changes[0].severity
+        };
+        assert_eq!(rename("def issue(user_id: str) -> Token", "def issue(subject: str) -> Token"), Severity::Major);
+        assert_eq!(rename("fun issue(userId: String): Token", "fun issue(subject: String): Token"), Severity::Major);
+        assert_eq!(rename("fn issue(user_id: &str) -> Token", "fn issue(subject: &str) -> Token"), Severity::Patch);
+        assert_eq!(rename("issue(userId: string): Token", "issue(subject: string): Token"), Severity::Patch);
+    }
+
+    #[test]
+    fn test_types_and_enums() {
+        let base = spec_from("### Types\n- `Claims { userId: string }`\n\n### Enums\n- `Role: Admin | User | Guest`", "None", "None");
+        let head = spec_from("### Types\n- `Claims { userId: string, role: Role }`\n\n### Enums\n- `Role: Admin | User | Owner`", "None", "None");
+        let changes = diff_specs(&base, &head);
+        assert_eq!(kinds(&changes), vec![
+            (ChangeKind::EnumVariantRemoved, "Role"),
+            (ChangeKind::TypeFieldAdded, "Claims"),
+            (ChangeKind::EnumVariantAdded, "Role"),
+        ]);
+    }
+
+    #[test]
+    fn test_contract_tightening_and_loosening() {
+        let exports = "- `validateToken(token: string): Claims`";
+        let base = spec_from(exports, "### validateToken\n- **Preconditions**: token is non-empty\n- **Postconditions**: returns claims", "None");
+        let head = spec_from(
+            exports,
+            "### validateToken\n- **Preconditions**: token is non-empty\n- **Preconditions**: token is a JWT\n- **Throws**: TokenExpiredError",
+            "None",
+        );
+        let changes = diff_specs(&base, &head);
+        assert_eq!(changes.iter().map(|c| c.kind).collect::<Vec<_>>(), vec![
+            ChangeKind::PreconditionTightened,
+            ChangeKind::PostconditionWeakened,
+            ChangeKind::ThrowsAdded,
+        ]);
+        assert_eq!(changes[0].after.as_deref(), Some("token is a JWT"));
+    }
+
+    #[test]
+    fn test_protocol_changes() {
+        let exports = "- `init(): void`\n- `start(): void`";
+        let base = spec_from(
+            exports,
+            "None",
+            "### State Machine\nStates: `Idle` | `Running` | `Paused`\n\nTransitions:\n- `Idle` + `start()` → `Running`\n- `Running` + `pause()` → `Paused`\n\n\
+             ### Lifecycle\n1. `init()` - prepare\n2. `start()` - run",
+        );
+        let head = spec_from(
+            exports,
+            "None",
+            "### State Machine\nStates: `Idle` | `Running` | `Stopped`\n\nTransitions:\n- `Idle` + `start()` → `Running`\n\n\
+             ### Lifecycle\n1. `start()` - run\n2. `init()` - prepare",
+        );
+        let changes = diff_specs(&base, &head);
+        let found: Vec<ChangeKind> = changes.iter().map(|c| c.kind).collect();
+        assert_eq!(found, vec![
+            ChangeKind::StateRemoved,
+            ChangeKind::TransitionRemoved,
+            ChangeKind::LifecycleOrderChanged,
+            ChangeKind::StateAdded,
+        ]);
+    }
+
+    #[test]
+    fn test_diff_against_git_revision() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path();
+        let git = |args: &[&str]| {
+            let output = Command::new("git").args(["-c", "user.name=Test", "-c", "user.email=test@test.com"])
+                .args(args).current_dir(root).output().unwrap();
+            assert!(output.status.success(), "git {:?} failed", args);
+        };
+        let spec = |signature: &str| format!(
+            "# auth\n\n## Purpose\nAuth.\n\n## Exports\n- `{}`\n\n## Behavior\n- a → b\n\n\
+             ## Contract\nNone\n\n## Protocol\nNone\n\n## Domain Context\nNone\n",
+            signature
+        );
+        let file = root.join("auth/CLAUDE.md");
+        std::fs::create_dir_all(root.join("auth")).unwrap();
+        std::fs::write(&file, spec("validateToken(token: string): Claims")).unwrap();
+        git(&["init", "-q"]);
+        git(&["add", "-A"]);
+        git(&["commit", "-q", "-m", "init"]);
+        std::fs::write(&file, spec("validateToken(token: string, secret: string): Claims")).unwrap();
+
+        let differ = SpecDiffer::new();
+        let report = differ.diff_against(&file, "HEAD").unwrap();
+        assert!(report.base_exists);
+        assert_eq!(report.bump, Some(Severity::Major));
+        assert_eq!(report.changes[0].kind, ChangeKind::ParameterAddedWithoutDefault);
+
+        let new_file = root.join("CLAUDE.md");
+        std::fs::write(&new_file, spec("revoke(token: string): void")).unwrap();
+        let report = differ.diff_against(&new_file, "HEAD").unwrap();
+        assert!(!report.base_exists);
+        assert_eq!(report.bump, Some(Severity::Minor));
+
+        assert!(matches!(differ.diff_against(&file, "no-such-ref"), Err(SpecDiffError::UnknownRevision(_))));
+    }
+
+    #[test]
+    fn test_contract_of_new_export_is_not_a_tightening() {
+        let base = spec_from("- `validateToken(token: string): Claims`", "None", "None");
+        let head = spec_from(
+            "- `validateToken(token: string): Claims`\n- `revoke(token: string): void`",
+            "### revoke\n- **Preconditions**: token was issued",
+            "None",
+        );
+        assert_eq!(kinds(&diff_specs(&base, &head)), vec![(ChangeKind::ExportAdded, "revoke")]);
+    }
+}
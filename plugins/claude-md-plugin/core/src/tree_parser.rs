@@ -280,7 +280,7 @@ mod tests {
         let src_info = result
             .needs_claude_md
             .iter()
-            .find(|d| d.path == PathBuf::from("src"))
+            .find(|d| d.path == Path::new("src"))
             .expect("src should need CLAUDE.md");
 
         assert_eq!(src_info.subdir_count, 2);
@@ -10,10 +10,12 @@ use claude_md_core::{TreeParser, BoundaryResolver, SchemaValidator, CodeAnalyzer
 use claude_md_core::tree_parser::TreeResult;
 use claude_md_core::boundary_resolver::BoundaryResult;
 use claude_md_core::schema_validator::ValidationResult;
-use claude_md_core::code_analyzer::AnalysisResult;
+use claude_md_core::code_analyzer::{AnalysisResult, AnalyzerBackend};
 use claude_md_core::convention_validator::ConventionValidationResult;
-use claude_md_core::compile_target_resolver::{CompileTargetResolver, DiffResult};
+use claude_md_core::compile_target_resolver::{CompileTargetResolver, DiffResult, ResolveOptions};
+use claude_md_core::compile_lock::CompileLock;
 use claude_md_core::exports_formatter;
+use claude_md_core::dependency_graph::DependencyGraph;
 use claude_md_core::code_analyzer::{
     Exports, ExportedFunction, ExportedType, ExportedClass, ExportedEnum,
     ExportedVariable, ReExport, TypeKind,
@@ -30,6 +32,7 @@ pub struct TestWorld {
     analysis_result: Option<AnalysisResult>,
     analysis_error: Option<String>,
     analyzer: Option<CodeAnalyzer>,
+    baseline_analysis_result: Option<AnalysisResult>,
     current_file_path: Option<PathBuf>,
     current_dir_path: Option<PathBuf>,
     boundary_files: Option<Vec<String>>,
@@ -45,6 +48,8 @@ pub struct TestWorld {
     format_exports_output2: Option<String>,
     // Fix schema fields
     fix_schema_added: Option<Vec<String>>,
+    // Dependency graph fields
+    dependency_graph: Option<DependencyGraph>,
 }
 
 // ============== Common Steps ==============
@@ -505,6 +510,60 @@ fn given_kotlin_directory(world: &mut TestWorld, path: String) {
     world.current_dir_path = Some(get_tests_path().join(&path));
 }
 
+#[given(expr = "a Scala file {string}")]
+fn given_scala_file(world: &mut TestWorld, path: String) {
+    world.analyzer = Some(CodeAnalyzer::new());
+    world.current_file_path = Some(get_tests_path().join(&path));
+}
+
+#[given(expr = "a C# file {string}")]
+fn given_csharp_file(world: &mut TestWorld, path: String) {
+    world.analyzer = Some(CodeAnalyzer::new());
+    world.current_file_path = Some(get_tests_path().join(&path));
+}
+
+#[given(expr = "a C# directory {string}")]
+fn given_csharp_directory(world: &mut TestWorld, path: String) {
+    world.analyzer = Some(CodeAnalyzer::new());
+    world.current_dir_path = Some(get_tests_path().join(&path));
+}
+
+#[given(expr = "a Swift file {string}")]
+fn given_swift_file(world: &mut TestWorld, path: String) {
+    world.analyzer = Some(CodeAnalyzer::new());
+    world.current_file_path = Some(get_tests_path().join(&path));
+}
+
+#[given(expr = "a Swift directory {string}")]
+fn given_swift_directory(world: &mut TestWorld, path: String) {
+    world.analyzer = Some(CodeAnalyzer::new());
+    world.current_dir_path = Some(get_tests_path().join(&path));
+}
+
+#[given(expr = "a C file {string}")]
+fn given_c_file(world: &mut TestWorld, path: String) {
+    world.analyzer = Some(CodeAnalyzer::new());
+    world.current_file_path = Some(get_tests_path().join(&path));
+}
+
+#[given(expr = "a C++ file {string}")]
+fn given_cpp_file(world: &mut TestWorld, path: String) {
+    world.analyzer = Some(CodeAnalyzer::new());
+    world.current_file_path = Some(get_tests_path().join(&path));
+}
+
+#[given(expr = "a Ruby file {string}")]
+fn given_ruby_file(world: &mut TestWorld, path: String) {
+    world.analyzer = Some(CodeAnalyzer::new());
+    world.current_file_path = Some(get_tests_path().join(&path));
+}
+
+#[given(expr = "a PHP file {string}")]
+fn given_php_file(world: &mut TestWorld, path: String) {
+    world.analyzer = Some(CodeAnalyzer::new());
+    world.current_file_path = Some(get_tests_path().join(&path));
+}
+
 #[given(expr = "a TypeScript directory {string}")]
 fn given_typescript_directory(world: &mut TestWorld, path: String) {
     world.analyzer = Some(CodeAnalyzer::new());
@@ -713,6 +772,28 @@ fn should_find_internal_deps(world: &mut TestWorld, step: &cucumber::gherkin::St
     }
 }
 
+#[then("I should NOT find external dependencies:")]
+fn should_not_find_external_deps(world: &mut TestWorld, step: &cucumber::gherkin::Step) {
+    let result = world.analysis_result.as_ref().expect("No analysis result");
+
+    if let Some(table) = &step.table {
+        for row in table.rows.iter().skip(1) {
+            let pkg = row.first().expect("No package name");
+
+            let found = result.dependencies.external.iter().any(|d| d == pkg || d.starts_with(pkg.as_str()));
+            assert!(!found, "Found external dependency '{}' that should be internal: {:?}",
+                    pkg, result.dependencies.external);
+        }
+    }
+}
+
+#[then(expr = "I should find exactly {int} internal dependencies")]
+fn should_find_exact_internal_deps(world: &mut TestWorld, count: usize) {
+    let result = world.analysis_result.as_ref().expect("No analysis result");
+    assert_eq!(result.dependencies.internal_raw.len(), count,
+               "Unexpected internal dependencies: {:?}", result.dependencies.internal_raw);
+}
+
 #[then("I should find symbols defined in __all__:")]
 fn should_find_all_symbols(world: &mut TestWorld, step: &cucumber::gherkin::Step) {
     let result = world.analysis_result.as_ref().expect("No analysis result");
@@ -811,6 +892,18 @@ fn should_find_public_enums(world: &mut TestWorld, step: &cucumber::gherkin::Ste
     }
 }
 
+#[then(expr = "the enum {string} should have variants {string}")]
+fn enum_should_have_variants(world: &mut TestWorld, name: String, variants: String) {
+    let result = world.analysis_result.as_ref().expect("No analysis result");
+
+    let exported = result.exports.enums.iter()
+        .find(|e| e.name == name)
+        .unwrap_or_else(|| panic!("Expected to find enum '{}'", name));
+    let expected: Vec<String> = variants.split(',').map(|v| v.trim().to_string()).collect();
+    assert_eq!(exported.variants.as_ref(), Some(&expected),
+               "Unexpected variants for enum '{}'", name);
+}
+
 #[then("I should find public functions:")]
 fn should_find_public_functions(world: &mut TestWorld, step: &cucumber::gherkin::Step) {
     should_find_exported_functions(world, step);
@@ -1375,7 +1468,7 @@ fn module_root_count_at_least(world: &mut TestWorld, count: usize) {
 fn module_has_project_convention_override(world: &mut TestWorld) {
     let result = world.convention_result.as_ref().expect("No convention result");
     let has_override = result.module_roots.iter().any(|m| {
-        m.project_convention_override.as_ref().map_or(false, |o| o.section_found)
+        m.project_convention_override.as_ref().is_some_and(|o| o.section_found)
     });
     assert!(has_override, "Expected at least one module with project convention override");
 }
@@ -1436,6 +1529,43 @@ fn required_sections_include(world: &mut TestWorld, step: &cucumber::gherkin::St
     }
 }
 
+#[then(expr = "IMPLEMENTS.md required sections should include:")]
+fn implements_required_sections_include(_world: &mut TestWorld, step: &cucumber::gherkin::Step) {
+    if let Some(table) = &step.table {
+        for row in &table.rows {
+            if let Some(section_name) = row.first() {
+                let found = claude_md_core::schema_validator::IMPLEMENTS_REQUIRED_SECTIONS
+                    .iter()
+                    .any(|s| s.eq_ignore_ascii_case(section_name));
+                assert!(found, "Expected '{}' to be a required IMPLEMENTS.md section. Required sections: {:?}",
+                        section_name, claude_md_core::schema_validator::IMPLEMENTS_REQUIRED_SECTIONS);
+            }
+        }
+    }
+}
+
+#[given(expr = "an IMPLEMENTS.md file with content:")]
+fn create_implements_md_file(world: &mut TestWorld, step: &cucumber::gherkin::Step) {
+    if world.temp_dir.is_none() {
+        world.temp_dir = Some(TempDir::new().expect("Failed to create temp dir"));
+    }
+    let implements_md_path = get_temp_path(world).join("IMPLEMENTS.md");
+    let content = step.docstring.as_ref().expect("No content provided");
+
+    let mut file = File::create(&implements_md_path).expect("Failed to create IMPLEMENTS.md");
+    write!(file, "{}", content).expect("Failed to write content");
+
+    world.claude_md_paths.insert("implements".to_string(), implements_md_path);
+}
+
+#[when("I validate the IMPLEMENTS.md file")]
+fn validate_the_implements_file(world: &mut TestWorld) {
+    let implements_md_path = world.claude_md_paths.get("implements").expect("No IMPLEMENTS.md path");
+
+    let validator = SchemaValidator::new();
+    world.validation_result = Some(validator.validate_implements(implements_md_path));
+}
+
 #[when("I validate the file")]
 fn validate_the_file(world: &mut TestWorld) {
     let claude_md_path = world.claude_md_paths.get("root").expect("No CLAUDE.md path");
@@ -1456,7 +1586,7 @@ fn validation_fail_with_error(world: &mut TestWorld, error_type: String) {
 fn the_error_should_mention(world: &mut TestWorld, mention: String) {
     let result = world.validation_result.as_ref().expect("No validation result");
     let found = result.errors.iter().any(|e| e.message.contains(&mention)
-        || e.section.as_ref().map_or(false, |s| s.contains(&mention)));
+        || e.section.as_ref().is_some_and(|s| s.contains(&mention)));
     assert!(found, "Expected error mentioning '{}', got: {:?}", mention, result.errors);
 }
 
@@ -1472,6 +1602,159 @@ fn parser_produces_json(_world: &mut TestWorld) {
     // Documentation step - no implementation needed
 }
 
+// ============== Tree-sitter Backend Steps ==============
+
+#[given(expr = "the {string} analyzer backend for {string}")]
+fn given_analyzer_backend(world: &mut TestWorld, backend: String, language: String) {
+    let backend: AnalyzerBackend = backend.parse().expect("Invalid backend");
+    let analyzer = world.analyzer.take().expect("No analyzer");
+    world.analyzer = Some(analyzer.with_backend(&language, backend).expect("Backend not available"));
+}
+
+#[when(expr = "I select the {string} analyzer backend for {string}")]
+fn select_analyzer_backend(world: &mut TestWorld, backend: String, language: String) {
+    let backend: AnalyzerBackend = backend.parse().expect("Invalid backend");
+    let analyzer = world.analyzer.take().expect("No analyzer");
+    match analyzer.with_backend(&language, backend) {
+        Ok(analyzer) => world.analyzer = Some(analyzer),
+        Err(e) => world.analysis_error = Some(e.to_string()),
+    }
+}
+
+#[when(expr = "I analyze the file with both backends for {string}")]
+fn analyze_with_both_backends(world: &mut TestWorld, language: String) {
+    let path = world.current_file_path.clone().expect("No file path");
+    let regex = CodeAnalyzer::new();
+    let tree_sitter = CodeAnalyzer::new()
+        .with_backend(&language, AnalyzerBackend::TreeSitter)
+        .expect("tree-sitter backend not available");
+    world.baseline_analysis_result = Some(regex.analyze_file(&path).expect("regex analysis failed"));
+    world.analysis_result = Some(tree_sitter.analyze_file(&path).expect("tree-sitter analysis failed"));
+}
+
+#[then("both backends should report the same exports and dependencies")]
+fn both_backends_match(world: &mut TestWorld) {
+    fn sorted(items: impl Iterator<Item = String>) -> Vec<String> {
+        let mut items: Vec<String> = items.collect();
+        items.sort();
+        items
+    }
+    fn summary(r: &AnalysisResult) -> Vec<(&'static str, Vec<String>)> {
+        let e = &r.exports;
+        vec![
+            ("functions", sorted(e.functions.iter().map(|f| format!("{} | {}", f.name, f.signature)))),
+            ("types", sorted(e.types.iter().map(|t| format!("{} | {:?}", t.name, t.kind)))),
+            ("classes", sorted(e.classes.iter().map(|c| format!("{} | {:?}", c.name, c.signature)))),
+            ("enums", sorted(e.enums.iter().map(|en| en.name.clone()))),
+            // Type annotations are compared loosely: regex captures may keep trailing `;`
+            ("variables", sorted(e.variables.iter().map(|v| v.name.clone()))),
+            ("re_exports", sorted(e.re_exports.iter().map(|r| format!("{} <- {}", r.name, r.source)))),
+            ("external", sorted(r.dependencies.external.iter().cloned())),
+            ("internal_raw", sorted(r.dependencies.internal_raw.iter().cloned())),
+        ]
+    }
+
+    let regex = world.baseline_analysis_result.as_ref().expect("No regex result");
+    let tree_sitter = world.analysis_result.as_ref().expect("No tree-sitter result");
+    for ((section, expected), (_, actual)) in summary(regex).into_iter().zip(summary(tree_sitter)) {
+        assert_eq!(actual, expected, "Backends disagree on {}", section);
+    }
+}
+
+#[then(expr = "the function {string} should have signature {string}")]
+fn function_should_have_signature(world: &mut TestWorld, name: String, signature: String) {
+    let result = world.analysis_result.as_ref().expect("No analysis result");
+    let function = result.exports.functions.iter().find(|f| f.name == name)
+        .unwrap_or_else(|| panic!("Function '{}' not found in {:?}", name, result.exports.functions));
+    assert_eq!(function.signature, signature);
+}
+
+#[then(expr = "the function {string} should have parameters {string}")]
+fn function_should_have_parameters(world: &mut TestWorld, name: String, params: String) {
+    let signature = parsed_signature(world, &name);
+    let actual: Vec<String> = signature.params.iter()
+        .map(|p| match &p.type_name {
+            Some(t) => format!("{}: {}", p.name, t),
+            None => p.name.clone(),
+        })
+        .collect();
+    assert_eq!(actual.join(", "), params);
+}
+
+#[then(expr = "the function {string} should return {string}")]
+fn function_should_return(world: &mut TestWorld, name: String, return_type: String) {
+    assert_eq!(parsed_signature(world, &name).return_type, Some(return_type));
+}
+
+#[then(expr = "the function {string} should be async and throwing")]
+fn function_should_be_async_and_throwing(world: &mut TestWorld, name: String) {
+    let signature = parsed_signature(world, &name);
+    assert!(signature.is_async && signature.throws, "{:?}", signature);
+}
+
+fn parsed_signature(world: &TestWorld, name: &str) -> claude_md_core::signature::Signature {
+    let result = world.analysis_result.as_ref().expect("No analysis result");
+    let function = result.exports.functions.iter().find(|f| f.name == name)
+        .unwrap_or_else(|| panic!("Function '{}' not found in {:?}", name, result.exports.functions));
+    function.parsed_signature.clone()
+        .unwrap_or_else(|| panic!("Signature of '{}' was not parsed: {}", name, function.signature))
+}
+
+#[then(expr = "the {word} {string} should be located in {string} at lines {int} to {int}")]
+fn symbol_should_be_located(world: &mut TestWorld, kind: String, name: String, file: String, start: usize, end: usize) {
+    let result = world.analysis_result.as_ref().expect("No analysis result");
+    let exports = &result.exports;
+    let location = match kind.as_str() {
+        "function" => exports.functions.iter().find(|f| f.name == name).map(|f| &f.location),
+        "type" => exports.types.iter().find(|t| t.name == name).map(|t| &t.location),
+        "class" => exports.classes.iter().find(|c| c.name == name).map(|c| &c.location),
+        "enum" => exports.enums.iter().find(|e| e.name == name).map(|e| &e.location),
+        "variable" => exports.variables.iter().find(|v| v.name == name).map(|v| &v.location),
+        "contract" => result.contracts.iter().find(|c| c.function_name == name).map(|c| &c.location),
+        other => panic!("Unknown symbol kind '{}'", other),
+    };
+    let location = location
+        .unwrap_or_else(|| panic!("{} '{}' not found", kind, name))
+        .as_ref()
+        .unwrap_or_else(|| panic!("{} '{}' has no location", kind, name));
+    assert_eq!(location.file, file, "File of {} '{}'", kind, name);
+    assert_eq!((location.start_line, location.end_line), (start, end), "Lines of {} '{}'", kind, name);
+}
+
+#[then(expr = "the error behavior {string} should be located at line {int}")]
+fn behavior_should_be_located(world: &mut TestWorld, output: String, line: usize) {
+    let result = world.analysis_result.as_ref().expect("No analysis result");
+    let behavior = result.behaviors.iter().find(|b| b.output == output)
+        .unwrap_or_else(|| panic!("Behavior '{}' not found in {:?}", output, result.behaviors));
+    let location = behavior.location.as_ref()
+        .unwrap_or_else(|| panic!("Behavior '{}' has no location", output));
+    assert_eq!(location.start_line, line, "Line of behavior '{}'", output);
+}
+
+#[then("every export should be located in an analyzed file")]
+fn every_export_located(world: &mut TestWorld) {
+    let result = world.analysis_result.as_ref().expect("No analysis result");
+    let exports = &result.exports;
+    let locations = exports.functions.iter().map(|f| (&f.name, &f.location))
+        .chain(exports.types.iter().map(|t| (&t.name, &t.location)))
+        .chain(exports.classes.iter().map(|c| (&c.name, &c.location)))
+        .chain(exports.enums.iter().map(|e| (&e.name, &e.location)))
+        .chain(exports.variables.iter().map(|v| (&v.name, &v.location)));
+    for (name, location) in locations {
+        let location = location.as_ref().unwrap_or_else(|| panic!("'{}' has no location", name));
+        assert!(result.analyzed_files.contains(&location.file),
+            "'{}' located in '{}', not in {:?}", name, location.file, result.analyzed_files);
+        assert!(location.start_line >= 1 && location.start_line <= location.end_line,
+            "'{}' has invalid line range {:?}", name, location);
+    }
+}
+
+#[then(expr = "the analysis should fail with error containing {string}")]
+fn analysis_should_fail_with(world: &mut TestWorld, fragment: String) {
+    let error = world.analysis_error.as_ref().expect("Expected an analysis error");
+    assert!(error.contains(&fragment), "Expected error containing '{}', got: {}", fragment, error);
+}
+
 // ============== Compile Target Resolver Steps ==============
 
 fn git_init(dir: &Path) {
@@ -1560,7 +1843,7 @@ fn create_committed_source_before_spec(world: &mut TestWorld, path: String) {
     let full_path = root.join(&path);
     fs::create_dir_all(full_path.parent().unwrap()).expect("mkdir failed");
     let mut f = File::create(&full_path).expect("create source failed");
-    write!(f, "// source code\n").expect("write failed");
+    writeln!(f, "// source code").expect("write failed");
     git_add(&root, &path);
 
     // Commit source with an older timestamp
@@ -1596,7 +1879,7 @@ fn create_committed_source_after_spec(world: &mut TestWorld, path: String) {
     let full_path = root.join(&path);
     fs::create_dir_all(full_path.parent().unwrap()).expect("mkdir failed");
     let mut f = File::create(&full_path).expect("create source failed");
-    write!(f, "// source code\n").expect("write failed");
+    writeln!(f, "// source code").expect("write failed");
     git_add(&root, &path);
     // Commit source with a newer timestamp than spec
     Command::new("git")
@@ -1617,6 +1900,32 @@ fn setup_non_git_test_dir(world: &mut TestWorld) {
     world.non_git_temp_dir = Some(TempDir::new().expect("Failed to create non-git temp dir"));
 }
 
+#[given(expr = "I tag the current commit as {string}")]
+fn tag_current_commit(world: &mut TestWorld, tag: String) {
+    use std::process::Command;
+    Command::new("git").args(["tag", &tag]).current_dir(get_temp_path(world))
+        .output().expect("git tag failed");
+}
+
+#[given("a test directory without git")]
+fn setup_plain_test_dir(world: &mut TestWorld) {
+    world.temp_dir = Some(TempDir::new().expect("Failed to create temp dir"));
+}
+
+#[given(expr = "a source file {string}")]
+fn create_source_file(world: &mut TestWorld, path: String) {
+    let full_path = get_temp_path(world).join(&path);
+    fs::create_dir_all(full_path.parent().unwrap()).expect("mkdir failed");
+    let mut f = File::create(&full_path).expect("create source failed");
+    writeln!(f, "// source code").expect("write failed");
+}
+
+#[given("I record the lock file")]
+fn record_lock_file(world: &mut TestWorld) {
+    let root = get_temp_path(world);
+    CompileLock::update(&root, &[]).expect("Failed to update lock file");
+}
+
 #[given("a committed root-level CLAUDE.md")]
 fn create_committed_root_claude_md(world: &mut TestWorld) {
     use std::process::Command;
@@ -1661,6 +1970,52 @@ fn resolve_compile_targets(world: &mut TestWorld) {
     world.diff_result = Some(resolver.resolve(&root));
 }
 
+#[when("I resolve compile targets with cascade")]
+fn resolve_compile_targets_cascade(world: &mut TestWorld) {
+    let root = get_temp_path(world);
+    let resolver = CompileTargetResolver::new();
+    let options = ResolveOptions { cascade: true, ..Default::default() };
+    world.diff_result = Some(resolver.resolve_with(&root, &options));
+}
+
+#[when("I resolve compile targets with --all")]
+fn resolve_compile_targets_all(world: &mut TestWorld) {
+    let root = get_temp_path(world);
+    let resolver = CompileTargetResolver::new();
+    let options = ResolveOptions { all: true, ..Default::default() };
+    world.diff_result = Some(resolver.resolve_with(&root, &options));
+}
+
+#[when(expr = "I resolve compile targets since {string}")]
+fn resolve_compile_targets_since(world: &mut TestWorld, since: String) {
+    let root = get_temp_path(world);
+    let resolver = CompileTargetResolver::new();
+    let options = ResolveOptions { since: Some(since), ..Default::default() };
+    world.diff_result = Some(resolver.resolve_with(&root, &options));
+}
+
+#[when("I resolve decompile targets")]
+fn resolve_decompile_targets(world: &mut TestWorld) {
+    let root = get_temp_path(world);
+    let resolver = CompileTargetResolver::new();
+    world.diff_result = Some(resolver.resolve_decompile(&root, false));
+}
+
+#[when("I resolve decompile targets using the lock file")]
+fn resolve_decompile_targets_with_lock(world: &mut TestWorld) {
+    let root = get_temp_path(world);
+    let resolver = CompileTargetResolver::new();
+    world.diff_result = Some(resolver.resolve_decompile(&root, true));
+}
+
+#[when("I resolve compile targets using the lock file")]
+fn resolve_compile_targets_with_lock(world: &mut TestWorld) {
+    let root = get_temp_path(world);
+    let resolver = CompileTargetResolver::new();
+    let options = ResolveOptions { use_lock: true, ..Default::default() };
+    world.diff_result = Some(resolver.resolve_with(&root, &options));
+}
+
 #[when("I resolve compile targets in the non-git directory")]
 fn resolve_compile_targets_non_git(world: &mut TestWorld) {
     let root = world.non_git_temp_dir.as_ref().expect("No non-git dir").path().to_path_buf();
@@ -1711,6 +2066,28 @@ fn should_get_warning_type(world: &mut TestWorld, warning_type: String) {
     );
 }
 
+#[then("the compile plan should be:")]
+fn compile_plan_should_be(world: &mut TestWorld, step: &cucumber::gherkin::Step) {
+    let result = world.diff_result.as_ref().expect("No diff result");
+    let table = step.table.as_ref().expect("No table");
+    let expected: Vec<(usize, Vec<String>)> = table.rows.iter().skip(1)
+        .map(|row| (
+            row[0].parse().expect("Invalid batch number"),
+            row[1].split(',').map(|d| d.trim().to_string()).collect(),
+        ))
+        .collect();
+    let actual: Vec<(usize, Vec<String>)> = result.compile_plan.iter()
+        .map(|b| (b.batch, b.dirs.clone()))
+        .collect();
+    assert_eq!(actual, expected, "Unexpected compile plan");
+}
+
+#[then("the compile plan should be empty")]
+fn compile_plan_should_be_empty(world: &mut TestWorld) {
+    let result = world.diff_result.as_ref().expect("No diff result");
+    assert!(result.compile_plan.is_empty(), "Expected no compile plan, got: {:?}", result.compile_plan);
+}
+
 #[then("the targets should be empty")]
 fn targets_should_be_empty(world: &mut TestWorld) {
     let result = world.diff_result.as_ref().expect("No diff result");
@@ -1768,6 +2145,8 @@ fn given_exports_table(world: &mut TestWorld, step: &cucumber::gherkin::Step) {
                         name: get("name"),
                         signature: get("signature"),
                         description: None,
+                        location: None,
+                        parsed_signature: None,
                     });
                 }
                 "type" => {
@@ -1777,6 +2156,7 @@ fn given_exports_table(world: &mut TestWorld, step: &cucumber::gherkin::Step) {
                         kind: TypeKind::Interface,
                         definition: if def.is_empty() { None } else { Some(def) },
                         description: None,
+                        location: None,
                     });
                 }
                 "class" => {
@@ -1785,6 +2165,7 @@ fn given_exports_table(world: &mut TestWorld, step: &cucumber::gherkin::Step) {
                         name: get("name"),
                         signature: if sig.is_empty() { None } else { Some(sig) },
                         description: None,
+                        location: None,
                     });
                 }
                 "enum" => {
@@ -1797,6 +2178,7 @@ fn given_exports_table(world: &mut TestWorld, step: &cucumber::gherkin::Step) {
                     exports.enums.push(ExportedEnum {
                         name: get("name"),
                         variants,
+                        location: None,
                     });
                 }
                 "variable" => {
@@ -1804,6 +2186,7 @@ fn given_exports_table(world: &mut TestWorld, step: &cucumber::gherkin::Step) {
                     exports.variables.push(ExportedVariable {
                         name: get("name"),
                         var_type: if vt.is_empty() { None } else { Some(vt) },
+                        location: None,
                     });
                 }
                 "re_export" => {
@@ -2002,6 +2385,72 @@ fn should_find_records_as_classes(world: &mut TestWorld, step: &cucumber::gherki
     }
 }
 
+// ============== Dependency Graph Steps ==============
+
+#[given(expr = "a module {string} depending on:")]
+fn create_module_with_deps(world: &mut TestWorld, path: String, step: &cucumber::gherkin::Step) {
+    let full_path = get_temp_path(world).join(&path);
+    fs::create_dir_all(&full_path).expect("Failed to create dir");
+
+    let deps: Vec<String> = step.table.as_ref()
+        .map(|t| t.rows.iter().skip(1).filter_map(|r| r.first().cloned()).collect())
+        .unwrap_or_default();
+    let internal = if deps.is_empty() {
+        "None".to_string()
+    } else {
+        deps.iter().map(|d| format!("- `{}` — dependency", d)).collect::<Vec<_>>().join("\n")
+    };
+    let content = format!(
+        "# Module\n\n## Purpose\nTest module\n\n## Dependencies\n### Internal\n{}\n\n### External\nNone\n",
+        internal
+    );
+
+    let mut file = File::create(full_path.join("CLAUDE.md")).expect("Failed to create CLAUDE.md");
+    write!(file, "{}", content).expect("Failed to write content");
+}
+
+#[when("I build the dependency graph")]
+fn build_dependency_graph(world: &mut TestWorld) {
+    let root = get_temp_path(world);
+    world.dependency_graph = Some(DependencyGraph::build(&root));
+}
+
+#[then(expr = "the graph should have an edge from {string} to {string}")]
+fn graph_has_edge(world: &mut TestWorld, from: String, to: String) {
+    let graph = world.dependency_graph.as_ref().expect("No dependency graph");
+    let found = graph.adjacency.get(&from).is_some_and(|deps| deps.contains(&to));
+    assert!(found, "Expected edge {} -> {}, adjacency: {:?}", from, to, graph.adjacency);
+}
+
+#[then("the graph should have no cycles")]
+fn graph_has_no_cycles(world: &mut TestWorld) {
+    let graph = world.dependency_graph.as_ref().expect("No dependency graph");
+    assert!(!graph.has_cycles(), "Expected no cycles, got: {:?}", graph.cycles);
+}
+
+#[then("the graph should report a cycle between:")]
+fn graph_reports_cycle(world: &mut TestWorld, step: &cucumber::gherkin::Step) {
+    let graph = world.dependency_graph.as_ref().expect("No dependency graph");
+    let expected: Vec<String> = step.table.as_ref()
+        .map(|t| t.rows.iter().skip(1).filter_map(|r| r.first().cloned()).collect())
+        .unwrap_or_default();
+    let found = graph.cycles.iter().any(|c| c.modules == expected);
+    assert!(found, "Expected cycle {:?}, got: {:?}", expected, graph.cycles);
+}
+
+#[then(expr = "the DOT output should contain {string}")]
+fn dot_output_contains(world: &mut TestWorld, text: String) {
+    let graph = world.dependency_graph.as_ref().expect("No dependency graph");
+    let dot = graph.to_dot();
+    assert!(dot.contains(&text), "Expected DOT output to contain '{}', got:\n{}", text, dot);
+}
+
+#[then(expr = "{string} should be a dangling dependency")]
+fn graph_has_dangling(world: &mut TestWorld, dir: String) {
+    let graph = world.dependency_graph.as_ref().expect("No dependency graph");
+    assert!(graph.dangling.contains(&dir), "Expected '{}' to be dangling, got: {:?}", dir, graph.dangling);
+}
+
 #[tokio::main]
 async fn main() {
     TestWorld::run("tests/features").await;
@@ -122,6 +122,9 @@ Feature: Code Analyze Skill
     And I should NOT find private functions:
       | name           |
       | internalHelper |
+    And the function "ValidateToken" should have parameters "tokenString: string, secret: string"
+    And the function "ValidateToken" should return "*Claims, error"
+    And the function "GenerateToken" should have parameters "userID: string, role: string, config: Config"
 
   Scenario: Extract Go type exports
     Given a Go file "fixtures/go/token.go"
@@ -146,6 +149,20 @@ Feature: Code Analyze Skill
       | package                      |
       | github.com/golang-jwt/jwt/v5 |
 
+  Scenario: Extract Go internal dependencies from go.mod module path
+    Given a Go file "fixtures/go-module/internal/auth/handler.go"
+    When I analyze the file for dependencies
+    Then I should find internal dependencies:
+      | path               |
+      | ../token           |
+      | ../../pkg/httputil |
+    And I should find external dependencies:
+      | package                      |
+      | github.com/golang-jwt/jwt/v5 |
+    And I should NOT find external dependencies:
+      | package                    |
+      | github.com/example/authsvc |
+
   # =============================================================================
   # Rust Analysis
   # =============================================================================
@@ -180,6 +197,18 @@ Feature: Code Analyze Skill
       | serde        |
       | thiserror    |
 
+  Scenario: Extract Rust internal dependencies from crate and self paths
+    Given a Rust file "fixtures/rust-crate/src/auth/mod.rs"
+    When I analyze the file for dependencies
+    Then I should find internal dependencies:
+      | path       |
+      | ../storage |
+    And I should find exactly 2 internal dependencies
+    And I should NOT find external dependencies:
+      | crate |
+      | crate |
+      | self  |
+
   # =============================================================================
   # Java Analysis
   # =============================================================================
@@ -221,6 +250,28 @@ Feature: Code Analyze Skill
       | package        |
       | io.jsonwebtoken |
 
+  Scenario: Extract Java internal dependencies from project packages
+    Given a Java file "fixtures/java-project/com/example/auth/AuthService.java"
+    When I analyze the file for dependencies
+    Then I should find internal dependencies:
+      | path     |
+      | ../token |
+      | ./model  |
+    And I should find external dependencies:
+      | package         |
+      | io.jsonwebtoken |
+    And I should NOT find external dependencies:
+      | package     |
+      | com.example |
+
+  Scenario: Java imports sharing the organization prefix without a project directory are external
+    Given a Java file "fixtures/java-project/com/example/auth/CacheClient.java"
+    When I analyze the file for dependencies
+    Then I should find exactly 0 internal dependencies
+    And I should find external dependencies:
+      | package     |
+      | com.example |
+
   Scenario: Infer Java error behavior from throws
     Given a Java file "fixtures/java/TokenService.java"
     When I analyze the file for behaviors
@@ -229,6 +280,408 @@ Feature: Code Analyze Skill
       | Expired token | TokenExpiredException |
       | Invalid token | InvalidTokenException |
 
+  # =============================================================================
+  # C# Analysis
+  # =============================================================================
+
+  Scenario: Extract C# exports by public keyword
+    Given a C# file "fixtures/csharp/TokenService.cs"
+    When I analyze the file for exports
+    Then I should find public methods:
+      | name               |
+      | ValidateToken      |
+      | GenerateTokenAsync |
+    And I should NOT find private methods:
+      | name              |
+      | MapToTokenClaims  |
+      | IsTokenExpired    |
+      | TokenService      |
+
+  Scenario: Extract C# class, record and interface exports
+    Given a C# directory "fixtures/csharp"
+    When I analyze the directory for exports
+    Then I should find public classes:
+      | name                  |
+      | TokenService          |
+      | TokenClaims           |
+      | TokenConfig           |
+      | TokenExpiredException |
+      | InvalidTokenException |
+    And I should find exported types:
+      | name          | kind      |
+      | ITokenService | interface |
+      | TokenLifetime | struct    |
+
+  Scenario: Extract C# enum exports
+    Given a C# file "fixtures/csharp/Role.cs"
+    When I analyze the file for exports
+    Then I should find public enums:
+      | name |
+      | Role |
+
+  Scenario: Extract C# dependencies from using directives
+    Given a C# file "fixtures/csharp/TokenService.cs"
+    When I analyze the file for dependencies
+    Then I should find external dependencies:
+      | package                   |
+      | Microsoft.IdentityModel   |
+      | Newtonsoft.Json           |
+    And I should NOT find external dependencies:
+      | package |
+      | System  |
+
+  Scenario: Extract C# internal dependencies from project namespaces
+    Given a C# file "fixtures/csharp-project/Auth/AuthService.cs"
+    When I analyze the file for dependencies
+    Then I should find internal dependencies:
+      | path     |
+      | ../Token |
+      | ./Models |
+    And I should find exactly 2 internal dependencies
+    And I should find external dependencies:
+      | package         |
+      | Newtonsoft.Json |
+    And I should NOT find external dependencies:
+      | package          |
+      | Example.Identity |
+
+  Scenario: Infer C# error behavior from throw new
+    Given a C# file "fixtures/csharp/TokenService.cs"
+    When I analyze the file for behaviors
+    Then I should infer error behaviors:
+      | input         | output                |
+      | Expired token | TokenExpiredException |
+      | Invalid token | InvalidTokenException |
+
+  # =============================================================================
+  # Swift Analysis
+  # =============================================================================
+
+  Scenario: Extract Swift exports by public and open keywords
+    Given a Swift file "fixtures/swift/TokenService.swift"
+    When I analyze the file for exports
+    Then I should find public functions:
+      | name          |
+      | validateToken |
+      | generateToken |
+      | default       |
+      | makeDefault   |
+    And I should NOT find private functions:
+      | name   |
+      | decode |
+      | sign   |
+    And I should find public classes:
+      | name         |
+      | TokenService |
+      | BaseService  |
+    And the function "generateToken" should have signature "func generateToken(userId: String, role: Role) async throws -> String"
+    And the function "generateToken" should have parameters "userId: String, role: Role"
+    And the function "generateToken" should return "String"
+    And the function "generateToken" should be async and throwing
+
+  Scenario: Extract Swift structs, protocols, enums and constants
+    Given a Swift file "fixtures/swift/Models.swift"
+    When I analyze the file for exports
+    Then I should find exported types:
+      | name            | kind      |
+      | TokenClaims     | struct    |
+      | TokenValidating | interface |
+      | ClaimsHandler   | type      |
+    And I should find public enums:
+      | name       |
+      | Role       |
+      | TokenError |
+    And the enum "Role" should have variants "admin, user, guest"
+    And I should find exported variables:
+      | name          |
+      | defaultIssuer |
+      | maxTokenAge   |
+    And I should NOT find exported variables:
+      | name         |
+      | internalSalt |
+
+  Scenario: Extract Swift dependencies from imports
+    Given a Swift file "fixtures/swift/TokenService.swift"
+    When I analyze the file for dependencies
+    Then I should find external dependencies:
+      | package |
+      | JWTKit  |
+      | Crypto  |
+    And I should NOT find external dependencies:
+      | package    |
+      | Foundation |
+
+  Scenario: Extract Swift internal dependencies from package targets
+    Given a Swift file "fixtures/swift-package/Sources/Auth/AuthService.swift"
+    When I analyze the file for dependencies
+    Then I should find internal dependencies:
+      | path     |
+      | ../Token |
+    And I should find exactly 1 internal dependencies
+    And I should find external dependencies:
+      | package |
+      | JWTKit  |
+
+  Scenario: Infer Swift error behavior from throw statements
+    Given a Swift file "fixtures/swift/TokenService.swift"
+    When I analyze the file for behaviors
+    Then I should infer error behaviors:
+      | input         | output             |
+      | Expired token | TokenError.expired |
+      | Invalid token | TokenError.invalid |
+
+  # =============================================================================
+  # C/C++ Analysis (headers are the export surface)
+  # =============================================================================
+
+  Scenario: Extract C++ header exports
+    Given a C++ file "fixtures/cpp/include/sdk/token.hpp"
+    When I analyze the file for exports
+    Then I should find exported functions:
+      | name                   |
+      | make_token             |
+      | is_valid_length        |
+      | TokenService::validate |
+      | TokenService::issue    |
+      | TokenService::instance |
+      | TokenService::claim    |
+      | TokenClaims::expired   |
+    And I should NOT find private functions:
+      | name                       |
+      | TokenService::sign         |
+      | TokenService::on_expired   |
+      | TokenService::TokenService |
+      | helper_counter             |
+      | internal_hash              |
+    And I should find exported classes:
+      | name         |
+      | TokenService |
+    And I should find exported types:
+      | name        | kind   |
+      | TokenClaims | struct |
+      | TokenPtr    | type   |
+    And the enum "Role" should have variants "Admin, User, Guest"
+    And the function "TokenService::validate" should have signature "TokenClaims TokenService::validate(const std::string& token) const"
+    And the function "TokenService::validate" should have parameters "token: const std::string&"
+    And the function "TokenService::validate" should return "TokenClaims"
+
+  Scenario: Extract C header typedefs, macros and extern "C" functions
+    Given a C file "fixtures/cpp/include/sdk/config.h"
+    When I analyze the file for exports
+    Then I should find exported functions:
+      | name            |
+      | sdk_config_load |
+      | sdk_config_free |
+    And I should find exported types:
+      | name         | kind   |
+      | sdk_config   | struct |
+      | sdk_config_t | type   |
+      | sdk_log_fn   | type   |
+      | sdk_handle   | type   |
+    And the enum "sdk_status" should have variants "SDK_OK, SDK_ERR_EXPIRED, SDK_ERR_INVALID"
+    And I should find exported variables:
+      | name                    |
+      | SDK_CONFIG_DEFAULT_PORT |
+
+  Scenario: Extract #define constants but not include guards or function-like macros
+    Given a C++ file "fixtures/cpp/include/sdk/token.hpp"
+    When I analyze the file for exports
+    Then I should find exported variables:
+      | name               |
+      | SDK_TOKEN_VERSION  |
+      | SDK_MAX_TOKEN_LEN  |
+      | kDefaultTtlSeconds |
+    And I should NOT find exported variables:
+      | name          |
+      | SDK_TOKEN_HPP |
+      | SDK_MIN       |
+
+  Scenario: Classify C++ includes as internal or external dependencies
+    Given a C++ file "fixtures/cpp/include/sdk/token.hpp"
+    When I analyze the file for dependencies
+    Then I should find internal dependencies:
+      | path         |
+      | ../../common |
+    And I should find exactly 1 internal dependencies
+    And I should find external dependencies:
+      | package  |
+      | openssl  |
+      | nlohmann |
+    And I should NOT find external dependencies:
+      | package |
+      | memory  |
+      | string  |
+
+  Scenario: C++ source files contribute dependencies but no exports
+    Given a C++ file "fixtures/cpp/src/token.cpp"
+    When I analyze the file for dependencies
+    Then I should return an empty analysis result:
+      | field         | expected |
+      | exports_count | 0        |
+    And I should find internal dependencies:
+      | path           |
+      | ../include/sdk |
+    And I should find external dependencies:
+      | package |
+      | curl    |
+
+  # =============================================================================
+  # Ruby Analysis
+  # =============================================================================
+
+  Scenario: Extract Ruby public methods, classes, modules and constants
+    Given a Ruby file "fixtures/ruby/token_service.rb"
+    When I analyze the file for exports
+    Then I should find public functions:
+      | name           |
+      | validate_token |
+      | generate_token |
+      | default        |
+      | revoked?       |
+    And I should NOT find private functions:
+      | name       |
+      | initialize |
+      | decode     |
+      | sign       |
+    And I should find public classes:
+      | name              |
+      | Auth              |
+      | TokenService      |
+      | TokenExpiredError |
+    And the function "default" should have signature "def self.default()"
+    And I should find exported variables:
+      | name           |
+      | DEFAULT_ISSUER |
+      | MAX_TOKEN_AGE  |
+    And I should NOT find exported variables:
+      | name         |
+      | SIGNING_SALT |
+
+  Scenario: Respect Ruby visibility sections and private symbols
+    Given a Ruby file "fixtures/ruby/session.rb"
+    When I analyze the file for exports
+    Then I should find public functions:
+      | name  |
+      | start |
+      | close |
+    And I should NOT find private functions:
+      | name        |
+      | reset_timer |
+      | refresh     |
+
+  Scenario: Extract Ruby dependencies from require statements
+    Given a Ruby file "fixtures/ruby/token_service.rb"
+    When I analyze the file for dependencies
+    Then I should find external dependencies:
+      | package        |
+      | jwt            |
+      | active_support |
+    And I should NOT find external dependencies:
+      | package |
+      | json    |
+    And I should find internal dependencies:
+      | path      |
+      | ./support |
+
+  Scenario: Resolve Ruby requires under the project lib directory
+    Given a Ruby file "fixtures/ruby-project/lib/auth/auth_service.rb"
+    When I analyze the file for dependencies
+    Then I should find internal dependencies:
+      | path     |
+      | ../token |
+    And I should find exactly 1 internal dependencies
+    And I should find external dependencies:
+      | package |
+      | jwt     |
+
+  Scenario: Infer Ruby error behavior from raise statements
+    Given a Ruby file "fixtures/ruby/token_service.rb"
+    When I analyze the file for behaviors
+    Then I should infer error behaviors:
+      | input         | output            |
+      | Expired token | TokenExpiredError |
+      | Invalid token | InvalidTokenError |
+
+  # =============================================================================
+  # PHP Analysis
+  # =============================================================================
+
+  Scenario: Extract PHP public methods, classes and constants
+    Given a PHP file "fixtures/php/TokenService.php"
+    When I analyze the file for exports
+    Then I should find public functions:
+      | name          |
+      | validateToken |
+      | generateToken |
+      | fromEnv       |
+      | revoke        |
+    And I should NOT find private functions:
+      | name        |
+      | __construct |
+      | decode      |
+      | sign        |
+    And I should find public classes:
+      | name         |
+      | TokenService |
+    And the function "fromEnv" should have signature "static function fromEnv(): self"
+    And I should find exported variables:
+      | name           |
+      | DEFAULT_ISSUER |
+      | MAX_TOKEN_AGE  |
+      | TOKEN_HEADER   |
+    And I should NOT find exported variables:
+      | name         |
+      | SIGNING_SALT |
+
+  Scenario: Extract PHP interfaces, traits, enums and functions
+    Given a PHP file "fixtures/php/Models.php"
+    When I analyze the file for exports
+    Then I should find exported types:
+      | name           | kind      |
+      | TokenValidator | interface |
+      | LogsActivity   | trait     |
+    And I should find public enums:
+      | name         |
+      | Role         |
+      | SessionState |
+    And the enum "Role" should have variants "Admin, User, Guest"
+    And I should find public functions:
+      | name               |
+      | make_token_service |
+      | label              |
+
+  Scenario: Extract PHP dependencies from use statements
+    Given a PHP file "fixtures/php/TokenService.php"
+    When I analyze the file for dependencies
+    Then I should find external dependencies:
+      | package            |
+      | Firebase\JWT       |
+      | Psr\Log            |
+      | Illuminate\Support |
+    And I should find internal dependencies:
+      | path         |
+      | ./Exceptions |
+      | ../support   |
+
+  Scenario: Resolve PHP project namespaces to relative directories
+    Given a PHP file "fixtures/php-project/src/Auth/AuthService.php"
+    When I analyze the file for dependencies
+    Then I should find internal dependencies:
+      | path     |
+      | ../Token |
+    And I should find exactly 1 internal dependencies
+    And I should find external dependencies:
+      | package      |
+      | Firebase\JWT |
+
+  Scenario: Infer PHP error behavior from throw statements
+    Given a PHP file "fixtures/php/TokenService.php"
+    When I analyze the file for behaviors
+    Then I should infer error behaviors:
+      | input         | output                |
+      | Expired token | TokenExpiredException |
+      | Invalid token | InvalidTokenException |
+
   # =============================================================================
   # Kotlin Analysis
   # =============================================================================
@@ -267,6 +720,17 @@ Feature: Code Analyze Skill
       | package        |
       | io.jsonwebtoken |
 
+  Scenario: Extract Kotlin internal dependencies from project packages
+    Given a Kotlin file "fixtures/kotlin-project/com/example/auth/AuthService.kt"
+    When I analyze the file for dependencies
+    Then I should find internal dependencies:
+      | path     |
+      | ../token |
+      | ./model  |
+    And I should NOT find external dependencies:
+      | package     |
+      | com.example |
+
   Scenario: Infer Kotlin error behavior from Result type
     Given a Kotlin file "fixtures/kotlin/TokenService.kt"
     When I analyze the file for behaviors
@@ -276,6 +740,84 @@ Feature: Code Analyze Skill
       | Expired token   | Result.failure(TokenExpiredException) |
       | Invalid token   | Result.failure(InvalidTokenException) |
 
+  # =============================================================================
+  # Scala Analysis
+  # =============================================================================
+
+  Scenario: Extract Scala defs, classes, traits and objects
+    Given a Scala file "fixtures/scala-project/com/example/auth/TokenService.scala"
+    When I analyze the file for exports
+    Then I should find public functions:
+      | name          |
+      | validateToken |
+      | generateToken |
+      | isRevoked     |
+      | apply         |
+    And I should NOT find private functions:
+      | name   |
+      | decode |
+      | sign   |
+    And I should find public classes:
+      | name                  |
+      | TokenService          |
+      | TokenExpiredException |
+    And I should find exported types:
+      | name           | kind  |
+      | TokenValidator | trait |
+      | ClaimsHandler  | type  |
+    And the function "generateToken" should have signature "def generateToken(userId: String, role: R)(implicit ec: ExecutionContext): Future[String]"
+    And the function "isRevoked" should have signature "def isRevoked: Boolean"
+    And the function "generateToken" should have parameters "userId: String, role: R, ec: ExecutionContext"
+    And the function "generateToken" should return "Future[String]"
+    And the function "isRevoked" should have parameters ""
+    And I should find exported variables:
+      | name          |
+      | DefaultIssuer |
+      | MaxTokenAge   |
+    And I should NOT find exported variables:
+      | name        |
+      | SigningSalt |
+
+  Scenario: Extract Scala case classes, sealed traits and Scala 3 enums
+    Given a Scala file "fixtures/scala/Session.scala"
+    When I analyze the file for exports
+    Then I should find exported types:
+      | name         | kind      |
+      | Session      | dataclass |
+      | SessionState | trait     |
+    And I should find public enums:
+      | name  |
+      | Role  |
+      | Color |
+    And the enum "Role" should have variants "Admin, User, Guest"
+    And the enum "Color" should have variants "Red, Green"
+
+  Scenario: Classify Scala imports relative to the project package
+    Given a Scala file "fixtures/scala-project/com/example/auth/TokenService.scala"
+    When I analyze the file for dependencies
+    Then I should find internal dependencies:
+      | path      |
+      | ./model   |
+      | ../token  |
+      | ../config |
+    And I should find exactly 3 internal dependencies
+    And I should find external dependencies:
+      | package  |
+      | io.circe |
+      | pdi.jwt  |
+    And I should NOT find external dependencies:
+      | package |
+      | scala   |
+      | java    |
+
+  Scenario: Infer Scala error behavior from thrown exceptions
+    Given a Scala file "fixtures/scala-project/com/example/auth/TokenService.scala"
+    When I analyze the file for behaviors
+    Then I should infer error behaviors:
+      | input         | output                |
+      | Expired token | TokenExpiredException |
+      | Invalid token | InvalidTokenException |
+
   # =============================================================================
   # Contract Extraction
   # =============================================================================
@@ -323,6 +865,48 @@ Feature: Code Analyze Skill
       | preconditions             | postconditions         | throws                |
       | token must be non-empty   | TokenClaims object     | InvalidTokenException |
 
+  Scenario: Extract contract from C# XML doc comments
+    Given a C# file "fixtures/csharp/Contracts.cs"
+    When I analyze the file for contracts
+    Then I should find contract for "ValidateToken":
+      | preconditions             | postconditions         | throws                |
+      | token must be non-empty   | TokenClaims object     | InvalidTokenException |
+    And I should find contract for "ProcessOrder":
+      | preconditions | postconditions          | throws            |
+      |               | Receipt with OrderId    | ArgumentException |
+
+  Scenario: Extract contract from Swift doc comments
+    Given a Swift file "fixtures/swift/Contracts.swift"
+    When I analyze the file for contracts
+    Then I should find contract for "validateToken":
+      | preconditions                 | postconditions     | throws             |
+      | token must be non-empty       | TokenClaims object | TokenError.invalid |
+      | the signing key is configured |                    |                    |
+    And I should find contract for "parseReceipt":
+      | preconditions | postconditions                     | throws       |
+      |               | the receipt total is non-negative  | ReceiptError |
+
+  Scenario: Extract contract from Scaladoc
+    Given a Scala file "fixtures/scala-project/com/example/auth/TokenService.scala"
+    When I analyze the file for contracts
+    Then I should find contract for "validateToken":
+      | preconditions           | postconditions     | throws                |
+      | token must be non-empty | TokenClaims object | InvalidTokenException |
+
+  Scenario: Extract contract from Ruby YARD tags
+    Given a Ruby file "fixtures/ruby/token_service.rb"
+    When I analyze the file for contracts
+    Then I should find contract for "validate_token":
+      | preconditions           | postconditions     | throws            |
+      | token must be non-empty | TokenClaims object | InvalidTokenError |
+
+  Scenario: Extract contract from PHPDoc
+    Given a PHP file "fixtures/php/TokenService.php"
+    When I analyze the file for contracts
+    Then I should find contract for "validateToken":
+      | preconditions           | postconditions     | throws                |
+      | token must be non-empty | TokenClaims object | InvalidTokenException |
+
   Scenario: Extract contract from KDoc
     Given a Kotlin file "fixtures/kotlin/Contracts.kt"
     When I analyze the file for contracts
@@ -414,6 +998,61 @@ Feature: Code Analyze Skill
       | LOADED   |
       | ERROR    |
 
+  Scenario: Extract C# protocol from state enum and lifecycle tags
+    Given a C# file "fixtures/csharp/StateMachine.cs"
+    When I analyze the file for protocol
+    Then I should find states:
+      | state   |
+      | Idle    |
+      | Loading |
+      | Loaded  |
+      | Error   |
+    And I should find lifecycle methods:
+      | method  |
+      | Init    |
+      | Start   |
+      | Dispose |
+
+  Scenario: Extract Swift protocol from state enum
+    Given a Swift file "fixtures/swift/StateMachine.swift"
+    When I analyze the file for protocol
+    Then I should find states:
+      | state   |
+      | idle    |
+      | loading |
+      | loaded  |
+      | failed  |
+
+  Scenario: Extract Scala protocol from sealed trait hierarchy
+    Given a Scala file "fixtures/scala/Session.scala"
+    When I analyze the file for protocol
+    Then I should find states:
+      | state   |
+      | Idle    |
+      | Loading |
+      | Loaded  |
+      | Failed  |
+
+  Scenario: Extract Ruby protocol from AASM states
+    Given a Ruby file "fixtures/ruby/session.rb"
+    When I analyze the file for protocol
+    Then I should find states:
+      | state   |
+      | idle    |
+      | loading |
+      | loaded  |
+      | failed  |
+
+  Scenario: Extract PHP protocol from state enum
+    Given a PHP file "fixtures/php/Models.php"
+    When I analyze the file for protocol
+    Then I should find states:
+      | state   |
+      | Idle    |
+      | Loading |
+      | Loaded  |
+      | Failed  |
+
   Scenario: Extract Kotlin protocol from enum class
     Given a Kotlin file "fixtures/kotlin/StateMachine.kt"
     When I analyze the file for protocol
@@ -498,6 +1137,51 @@ Feature: Code Analyze Skill
       | Expired token | TokenError::Expired  |
       | Invalid token | TokenError::Invalid  |
 
+  # =============================================================================
+  # Source Locations
+  # =============================================================================
+
+  Scenario: Locate TypeScript exports, contracts and throw sites
+    Given a TypeScript file "fixtures/typescript/index.ts"
+    When I analyze the file for behaviors
+    Then the function "validateToken" should be located in "index.ts" at lines 11 to 21
+    And the function "generateToken" should be located in "index.ts" at lines 23 to 27
+    And the class "TokenExpiredError" should be located in "index.ts" at lines 29 to 34
+    And the contract "validateToken" should be located in "index.ts" at lines 11 to 21
+    And the error behavior "TokenExpiredError" should be located at line 17
+    And the error behavior "InvalidTokenError" should be located at line 19
+
+  Scenario: Locate Python functions by indentation
+    Given a Python file "fixtures/python/auth.py"
+    When I analyze the file for exports
+    Then the function "validate_token" should be located in "auth.py" at lines 17 to 38
+    And the class "AuthResult" should be located in "auth.py" at lines 10 to 14
+
+  Scenario: Locate Rust enums from their declaration, not their uses
+    Given a Rust file "fixtures/rust/lib.rs"
+    When I analyze the file for behaviors
+    Then the enum "Role" should be located in "lib.rs" at lines 18 to 22
+    And the type "Claims" should be located in "lib.rs" at lines 9 to 14
+    And the function "validate_token" should be located in "lib.rs" at lines 48 to 63
+    And the error behavior "TokenError::Expired" should be located at line 34
+
+  Scenario: Locate Go functions at their declaration when a call comes first
+    Given a Go file "fixtures/go/call_order.go"
+    When I analyze the file for exports
+    Then the function "Process" should be located in "call_order.go" at lines 7 to 9
+
+  Scenario: Locate Ruby methods through their closing end
+    Given a Ruby file "fixtures/ruby/token_service.rb"
+    When I analyze the file for exports
+    Then the function "validate_token" should be located in "token_service.rb" at lines 27 to 34
+    And the class "TokenService" should be located in "token_service.rb" at lines 17 to 57
+    And the variable "DEFAULT_ISSUER" should be located in "token_service.rb" at lines 9 to 9
+
+  Scenario: Every export of a directory is located in the file that defines it
+    Given a Python directory "fixtures/python"
+    When I analyze the directory
+    Then every export should be located in an analyzed file
+
   # =============================================================================
   # Export Candidates - TypeScript
   # =============================================================================
@@ -630,6 +1314,31 @@ Feature: Code Analyze Skill
       | name       |
       | UserRecord |
 
+  # =============================================================================
+  # Export Candidates - C#
+  # =============================================================================
+
+  Scenario: Extract C# const and static readonly constants
+    Given a C# file "fixtures/csharp/ExportCandidates.cs"
+    When I analyze the file for exports
+    Then I should find exported variables:
+      | name           |
+      | MaxRetries     |
+      | ApiBaseUrl     |
+      | DefaultTimeout |
+    And I should NOT find exported variables:
+      | name          |
+      | InternalLimit |
+    And the function "Parse" should have signature "T Parse<T>(string raw)"
+
+  Scenario: Extract C# positional record
+    Given a C# file "fixtures/csharp/TokenClaims.cs"
+    When I analyze the file for exports
+    Then I should find records as classes:
+      | name        |
+      | TokenClaims |
+      | TokenConfig |
+
   # =============================================================================
   # Export Candidates - Kotlin
   # =============================================================================
@@ -756,3 +1465,18 @@ Feature: Code Analyze Skill
       | dependencies.internal | 0           |
       | behaviors          | 3              |
       | analyzed_files     | 5              |
+
+  Scenario: Generate complete C# analysis JSON
+    Given a C# directory "fixtures/csharp"
+    And a boundary file specifying direct_files: ["TokenService.cs", "ITokenService.cs", "TokenClaims.cs", "Role.cs", "Exceptions.cs"]
+    When I run the complete code-analyze workflow
+    Then the output JSON should match "fixtures/expected/csharp-analysis.json"
+    And the result should include:
+      | field              | expected_count |
+      | exports.functions  | 2              |
+      | exports.types      | 2              |
+      | exports.classes    | 5              |
+      | dependencies.external | 2           |
+      | dependencies.internal | 0           |
+      | behaviors          | 3              |
+      | analyzed_files     | 5              |
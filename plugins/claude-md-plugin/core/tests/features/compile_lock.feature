@@ -0,0 +1,81 @@
+Feature: Lock-based Compile Target Resolution
+
+  As a developer compiling outside a full git checkout,
+  I want staleness decided from content hashes in claude-md.lock,
+  So that tarballs, shallow clones and multi-spec commits still compile incrementally.
+
+  Background:
+    Given a test directory without git
+
+  Scenario: Missing lock file targets every module
+    Given a spec file "src/auth/CLAUDE.md" with basic content
+    And a source file "src/auth/token.ts"
+    When I resolve compile targets using the lock file
+    Then "src/auth" should be a compile target with reason "not-in-lock"
+    And I should get a warning of type "no-lock-file"
+
+  Scenario: Unchanged module is up-to-date after recording the lock
+    Given a spec file "src/auth/CLAUDE.md" with basic content
+    And a source file "src/auth/token.ts"
+    And I record the lock file
+    When I resolve compile targets using the lock file
+    Then "src/auth" should be skipped with reason "up-to-date"
+    And the targets should be empty
+
+  Scenario: Editing CLAUDE.md after recording makes it a target
+    Given a spec file "src/auth/CLAUDE.md" with basic content
+    And a source file "src/auth/token.ts"
+    And I record the lock file
+    And I modify "src/auth/CLAUDE.md" without staging
+    When I resolve compile targets using the lock file
+    Then "src/auth" should be a compile target with reason "spec-changed"
+
+  Scenario: Editing IMPLEMENTS.md after recording makes it a target
+    Given a spec file "src/auth/CLAUDE.md" with basic content
+    And a spec file "src/auth/IMPLEMENTS.md" with basic content
+    And a source file "src/auth/token.ts"
+    And I record the lock file
+    And I modify "src/auth/IMPLEMENTS.md" without staging
+    When I resolve compile targets using the lock file
+    Then "src/auth" should be a compile target with reason "spec-changed"
+
+  Scenario: Editing only source code keeps the module skipped
+    Given a spec file "src/auth/CLAUDE.md" with basic content
+    And a source file "src/auth/token.ts"
+    And I record the lock file
+    And I modify "src/auth/token.ts" without staging
+    When I resolve compile targets using the lock file
+    Then "src/auth" should be skipped with reason "source-changed"
+
+  Scenario: Module added after recording is not in the lock
+    Given a spec file "src/auth/CLAUDE.md" with basic content
+    And a source file "src/auth/token.ts"
+    And I record the lock file
+    And a spec file "src/api/CLAUDE.md" with basic content
+    And a source file "src/api/routes.ts"
+    When I resolve compile targets using the lock file
+    Then "src/api" should be a compile target with reason "not-in-lock"
+    And "src/auth" should be skipped with reason "up-to-date"
+
+  Scenario: --all works without git or a lock file
+    Given a spec file "src/auth/CLAUDE.md" with basic content
+    And a source file "src/auth/token.ts"
+    When I resolve compile targets with --all
+    Then "src/auth" should be a compile target with reason "forced"
+
+  Scenario: Decompile — source edited after recording is a target
+    Given a spec file "src/auth/CLAUDE.md" with basic content
+    And a source file "src/auth/token.ts"
+    And I record the lock file
+    And I modify "src/auth/token.ts" without staging
+    When I resolve decompile targets using the lock file
+    Then "src/auth" should be a compile target with reason "source-changed"
+
+  Scenario: Decompile — spec edited after recording is left to compile
+    Given a spec file "src/auth/CLAUDE.md" with basic content
+    And a source file "src/auth/token.ts"
+    And I record the lock file
+    And I modify "src/auth/CLAUDE.md" without staging
+    When I resolve decompile targets using the lock file
+    Then "src/auth" should be skipped with reason "spec-pending-compile"
+    And the targets should be empty
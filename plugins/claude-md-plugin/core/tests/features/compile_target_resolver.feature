@@ -85,3 +85,78 @@ Feature: Compile Target Resolution (Incremental Diff)
     When I resolve compile targets
     Then "core/domain" should be a compile target with reason "spec-newer"
     And I should get a dependency warning for "core/domain" affecting "src/auth"
+
+  Scenario: Cascade mode targets transitive dependents in leaf-first batches
+    Given a committed spec file "core/domain/CLAUDE.md"
+    And a committed source file "core/domain/model.ts" before the spec
+    And a committed spec file "src/auth/CLAUDE.md" depending on "core/domain/CLAUDE.md"
+    And a committed source file "src/auth/token.ts" after the spec
+    And a committed spec file "src/api/CLAUDE.md" depending on "src/auth/CLAUDE.md"
+    And a committed source file "src/api/routes.ts" after the spec
+    When I resolve compile targets with cascade
+    Then "core/domain" should be a compile target with reason "spec-newer"
+    And "src/auth" should be a compile target with reason "cascade"
+    And "src/api" should be a compile target with reason "cascade"
+    And the compile plan should be:
+      | batch | dirs        |
+      | 1     | core/domain |
+      | 2     | src/auth    |
+      | 3     | src/api     |
+
+  Scenario: Without cascade the dependent stays up-to-date
+    Given a committed spec file "core/domain/CLAUDE.md"
+    And a committed source file "core/domain/model.ts" before the spec
+    And a committed spec file "src/auth/CLAUDE.md" depending on "core/domain/CLAUDE.md"
+    And a committed source file "src/auth/token.ts" after the spec
+    When I resolve compile targets
+    Then "src/auth" should be skipped with reason "up-to-date"
+    And the compile plan should be empty
+
+  Scenario: --all targets every module regardless of state
+    Given a committed spec file "core/domain/CLAUDE.md"
+    And a committed source file "core/domain/model.ts" before the spec
+    And a committed spec file "src/auth/CLAUDE.md"
+    And a committed source file "src/auth/token.ts" after the spec
+    When I resolve compile targets with --all
+    Then "core/domain" should be a compile target with reason "forced"
+    And "src/auth" should be a compile target with reason "forced"
+
+  Scenario: --since targets specs committed after the ref even when sources are newer
+    Given a committed spec file "core/domain/CLAUDE.md"
+    And a committed source file "core/domain/model.ts" after the spec
+    And I tag the current commit as "base"
+    And a committed spec file "src/auth/CLAUDE.md"
+    And a committed source file "src/auth/token.ts" after the spec
+    When I resolve compile targets since "base"
+    Then "src/auth" should be a compile target with reason "changed-since"
+    And "core/domain" should be skipped with reason "up-to-date"
+
+  Scenario: --since with an unknown ref warns and is ignored
+    Given a committed spec file "src/auth/CLAUDE.md"
+    And a committed source file "src/auth/token.ts" after the spec
+    When I resolve compile targets since "no-such-ref"
+    Then "src/auth" should be skipped with reason "up-to-date"
+    And I should get a warning of type "invalid-since-ref"
+
+  Scenario: Decompile — source commit newer than spec is a target (source-newer)
+    Given a committed spec file "src/auth/CLAUDE.md"
+    And a committed source file "src/auth/token.ts" after the spec
+    And a committed spec file "core/domain/CLAUDE.md"
+    And a committed source file "core/domain/model.ts" before the spec
+    When I resolve decompile targets
+    Then "src/auth" should be a compile target with reason "source-newer"
+    And "core/domain" should be skipped with reason "up-to-date"
+
+  Scenario: Decompile — uncommitted source edits are a target (source-modified)
+    Given a committed spec file "core/domain/CLAUDE.md"
+    And a committed source file "core/domain/model.ts" before the spec
+    And I modify "core/domain/model.ts" without staging
+    When I resolve decompile targets
+    Then "core/domain" should be a compile target with reason "source-modified"
+
+  Scenario: Decompile — spec with uncommitted edits is left to compile
+    Given a committed spec file "src/auth/CLAUDE.md"
+    And a committed source file "src/auth/token.ts" after the spec
+    And I modify "src/auth/CLAUDE.md" without staging
+    When I resolve decompile targets
+    Then "src/auth" should be skipped with reason "spec-modified"
@@ -0,0 +1,46 @@
+Feature: Module Dependency Graph
+
+  As an architecture reviewer,
+  I want a module graph built from CLAUDE.md Dependencies > Internal entries,
+  So that cyclic module specs are caught before /compile.
+
+  Background:
+    Given a clean test directory
+
+  Scenario: Edges follow internal CLAUDE.md dependencies
+    Given a module "core/domain" depending on:
+      | dependency |
+    And a module "src/auth" depending on:
+      | dependency            |
+      | core/domain/CLAUDE.md |
+    And a module "src/api" depending on:
+      | dependency            |
+      | src/auth/CLAUDE.md    |
+      | core/domain/CLAUDE.md |
+    When I build the dependency graph
+    Then the graph should have an edge from "src/auth" to "core/domain"
+    And the graph should have an edge from "src/api" to "src/auth"
+    And the graph should have no cycles
+
+  Scenario: Mutual dependencies are reported as a cycle
+    Given a module "src/orders" depending on:
+      | dependency             |
+      | src/billing/CLAUDE.md  |
+    And a module "src/billing" depending on:
+      | dependency            |
+      | src/orders/CLAUDE.md  |
+    And a module "src/util" depending on:
+      | dependency |
+    When I build the dependency graph
+    Then the graph should report a cycle between:
+      | module      |
+      | src/billing |
+      | src/orders  |
+    And the DOT output should contain '"src/orders" -> "src/billing" [color=red];'
+
+  Scenario: Dependency on a directory without CLAUDE.md is dangling
+    Given a module "src/auth" depending on:
+      | dependency             |
+      | src/legacy/CLAUDE.md   |
+    When I build the dependency graph
+    Then "src/legacy" should be a dangling dependency
@@ -227,3 +227,64 @@ Feature: Schema Rules SSOT
       """
     When I validate the file
     Then validation should pass
+
+  Scenario: IMPLEMENTS.md required sections are defined from YAML SSOT
+    When I check the required sections
+    Then IMPLEMENTS.md required sections should include:
+      | Dependencies Direction  |
+      | Implementation Approach |
+      | Technology Choices      |
+      | Error Handling          |
+      | State Management        |
+
+  Scenario: Valid IMPLEMENTS.md with all required sections passes validation
+    Given an IMPLEMENTS.md file with content:
+      """
+      # auth/IMPLEMENTS.md
+
+      ## Dependencies Direction
+
+      ### External
+      - `jsonwebtoken@9.0.0`: JWT 검증
+
+      ## Implementation Approach
+      - HMAC-SHA256 기반 토큰 검증
+
+      ## Technology Choices
+
+      | 선택 | 대안 | 선택 이유 |
+      |------|------|----------|
+      | jsonwebtoken | jose | 기존 코드 호환 |
+
+      ## Error Handling
+      None
+
+      ## State Management
+      None
+      """
+    When I validate the IMPLEMENTS.md file
+    Then validation should pass
+
+  Scenario: IMPLEMENTS.md Implementation Approach does not allow None
+    Given an IMPLEMENTS.md file with content:
+      """
+      # auth/IMPLEMENTS.md
+
+      ## Dependencies Direction
+      - `jsonwebtoken@9.0.0`: JWT 검증
+
+      ## Implementation Approach
+      None
+
+      ## Technology Choices
+      None
+
+      ## Error Handling
+      None
+
+      ## State Management
+      None
+      """
+    When I validate the IMPLEMENTS.md file
+    Then validation should fail with error "InvalidSectionContent"
+    And the error should mention "Implementation Approach"
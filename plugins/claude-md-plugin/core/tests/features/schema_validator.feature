@@ -279,6 +279,73 @@ Feature: Schema Validation
     When I validate the schema
     Then validation should pass
 
+  Scenario: Headings inside fenced code blocks are not sections
+    Given CLAUDE.md with content:
+      """
+      # Test Module
+
+      ## Purpose
+      Test module. Setup:
+
+      ```bash
+      # install dependencies
+      npm install
+      ```
+
+      ## Exports
+      - `process(input: string): Output`
+
+      ```python
+      ## Behavior
+      print("not a section")
+      ```
+
+      ## Domain Context
+      None
+
+      ## Contract
+      None
+
+      ## Protocol
+      None
+      """
+    When I validate the schema
+    Then validation should fail
+    And error should mention "Missing required section: Behavior"
+
+  Scenario: Setext headings and HTML comments follow CommonMark
+    Given CLAUDE.md with content:
+      """
+      Test Module
+      ===========
+
+      Purpose
+      -------
+      Test module.
+
+      <!--
+      ## Exports
+      -->
+
+      Exports
+      -------
+      - `process(input: string): Output`
+
+      ## Behavior
+      - valid input → expected output
+
+      ## Domain Context
+      None
+
+      ## Contract
+      None
+
+      ## Protocol
+      None
+      """
+    When I validate the schema
+    Then validation should pass
+
   Scenario: Enum-only exports pass validation
     Given CLAUDE.md with content:
       """
@@ -0,0 +1,143 @@
+Feature: Tree-sitter Analyzer Backend
+  As a maintainer of the code analyzer
+  I want a syntax-tree backend selectable per language
+  So that I can compare it with the regex backend before switching the default
+
+  Scenario Outline: Tree-sitter backend matches the regex backend on existing fixtures
+    Given a <language> file "<file>"
+    When I analyze the file with both backends for "<backend_language>"
+    Then both backends should report the same exports and dependencies
+
+    Examples:
+      | language   | backend_language | file                                     |
+      | Rust       | rust             | fixtures/rust/lib.rs                     |
+      | Rust       | rust             | fixtures/rust/contracts.rs               |
+      | Rust       | rust             | fixtures/rust/export_candidates.rs       |
+      | Rust       | rust             | fixtures/rust/state_machine.rs           |
+      | Rust       | rust             | fixtures/rust/adt_state.rs               |
+      | Rust       | rust             | fixtures/rust-crate/src/auth/mod.rs      |
+      | Rust       | rust             | fixtures/rust-crate/src/storage/cache.rs |
+      | TypeScript | typescript       | fixtures/typescript/index.ts             |
+      | TypeScript | typescript       | fixtures/typescript/types.ts             |
+      | TypeScript | typescript       | fixtures/typescript/arrow-functions.ts   |
+      | TypeScript | typescript       | fixtures/typescript/default-export.ts    |
+      | TypeScript | typescript       | fixtures/typescript/re-exports.ts        |
+      | TypeScript | typescript       | fixtures/typescript/export-candidates.ts |
+      | TypeScript | typescript       | fixtures/typescript/state-machine.ts     |
+      | Go         | go               | fixtures/go/token.go                     |
+      | Go         | go               | fixtures/go/export_candidates.go         |
+      | Go         | go               | fixtures/go-module/internal/auth/handler.go |
+      | Python     | python           | fixtures/python/auth.py                  |
+      | Python     | python           | fixtures/python/export_candidates.py     |
+      | Python     | python           | fixtures/python/union_state.py           |
+      | Python     | python           | fixtures/python/__init__.py              |
+      | Java       | java             | fixtures/java/TokenService.java          |
+      | Java       | java             | fixtures/java/ExportCandidates.java      |
+      | Java       | java             | fixtures/java/StateMachine.java          |
+      | Java       | java             | fixtures/java/UserRecord.java            |
+      | Java       | java             | fixtures/java-project/com/example/auth/AuthService.java |
+
+  Scenario: Tree-sitter Rust backend respects comments, strings, multi-line parameters and module privacy
+    Given a Rust file "fixtures/rust/syntax_edge_cases.rs"
+    And the "tree-sitter" analyzer backend for "rust"
+    When I analyze the file for exports
+    Then I should find exported functions:
+      | name            | signature                                                    |
+      | create_session  | fn create_session(user_id: &str, ttl_seconds: u64) -> Session |
+      | public_endpoint | fn public_endpoint()                                          |
+    And the function "create_session" should have signature "fn create_session(user_id: &str, ttl_seconds: u64) -> Session"
+    And I should NOT find private functions:
+      | name               |
+      | commented_out      |
+      | in_string_literal  |
+      | hidden_helper      |
+      | crate_only         |
+    And I should find pub types:
+      | name    | kind   |
+      | Session | struct |
+
+  Scenario: Tree-sitter TypeScript backend respects comments, strings and multi-line parameters
+    Given a TypeScript file "fixtures/typescript/syntax-edge-cases.ts"
+    And the "tree-sitter" analyzer backend for "typescript"
+    When I analyze the file for exports
+    Then the function "createSession" should have signature "createSession(userId: string, ttlSeconds: number): Session"
+    And I should NOT find private functions:
+      | name            |
+      | commentedOut    |
+      | inStringLiteral |
+    And I should find exported types:
+      | name    | kind      |
+      | Session | interface |
+    And I should find exported variables:
+      | name          |
+      | SessionSchema |
+
+  Scenario: Tree-sitter Go backend respects comments, strings, multi-line parameters and grouped declarations
+    Given a Go file "fixtures/go/syntax_edge_cases.go"
+    And the "tree-sitter" analyzer backend for "go"
+    When I analyze the file for exports
+    Then the function "CreateSession" should have signature "func CreateSession(userID string, ttl time.Duration) *Session, error"
+    And I should NOT find private functions:
+      | name            |
+      | CommentedOut    |
+      | InStringLiteral |
+      | Refresh         |
+      | hiddenHelper    |
+    And I should find exported types:
+      | name    | kind   |
+      | Session | struct |
+      | State   | type   |
+    And I should find exported variables:
+      | name         |
+      | StateActive  |
+      | StateRevoked |
+      | ErrExpired   |
+    And I should NOT find exported variables:
+      | name      |
+      | errHidden |
+
+  Scenario: Tree-sitter Python backend respects comments, strings, decorators and nesting
+    Given a Python file "fixtures/python/syntax_edge_cases.py"
+    And the "tree-sitter" analyzer backend for "python"
+    When I analyze the file for exports
+    Then the function "create_session" should have signature "create_session(user_id: str, ttl_seconds: int) -> Session"
+    And I should find exported functions:
+      | name          |
+      | cached_lookup |
+      | refresh       |
+    And I should NOT find private functions:
+      | name                |
+      | commented_out       |
+      | in_string_literal   |
+      | nested_helper       |
+      | method_not_exported |
+
+  Scenario: Tree-sitter Java backend respects comments, strings, nesting and sealed classes
+    Given a Java file "fixtures/java/SyntaxEdgeCases.java"
+    And the "tree-sitter" analyzer backend for "java"
+    When I analyze the file for exports
+    Then the function "createSession" should have signature "Session createSession(String userId, long ttlSeconds)"
+    And I should NOT find private functions:
+      | name            |
+      | commentedOut    |
+      | inStringLiteral |
+      | hiddenHelper    |
+    And I should find exported classes:
+      | name            |
+      | SyntaxEdgeCases |
+      | Active          |
+    And I should find external dependencies:
+      | package     |
+      | com.example |
+
+  Scenario: Tree-sitter backend reports item spans
+    Given a Rust file "fixtures/rust/lib.rs"
+    And the "tree-sitter" analyzer backend for "rust"
+    When I analyze the file for exports
+    Then the function "validate_token" should be located in "lib.rs" at lines 48 to 63
+    And the enum "Role" should be located in "lib.rs" at lines 18 to 22
+
+  Scenario: Unsupported backend for a language is rejected
+    Given a Kotlin file "fixtures/kotlin/TokenService.kt"
+    When I select the "tree-sitter" analyzer backend for "kotlin"
+    Then the analysis should fail with error containing "not available"
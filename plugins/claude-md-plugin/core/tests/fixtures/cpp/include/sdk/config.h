@@ -0,0 +1,31 @@
+#pragma once
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define SDK_CONFIG_DEFAULT_PORT 8443
+
+typedef struct sdk_config {
+    const char* issuer;
+    uint32_t ttl_seconds;
+} sdk_config_t;
+
+typedef enum {
+    SDK_OK = 0,
+    SDK_ERR_EXPIRED,
+    SDK_ERR_INVALID,
+} sdk_status;
+
+typedef void (*sdk_log_fn)(int level, const char* message);
+typedef uint64_t sdk_handle;
+
+sdk_status sdk_config_load(sdk_config_t* out, const char* path);
+void sdk_config_free(sdk_config_t* config);
+
+#ifdef __cplusplus
+}
+#endif
@@ -0,0 +1,73 @@
+#ifndef SDK_TOKEN_HPP
+#define SDK_TOKEN_HPP
+
+#include <memory>
+#include <string>
+#include <openssl/evp.h>
+#include <nlohmann/json.hpp>
+#include "sdk/config.h"
+#include "../../common/log.h"
+
+#define SDK_TOKEN_VERSION 3
+#define SDK_MAX_TOKEN_LEN (4 * 1024)
+#define SDK_MIN(a, b) ((a) < (b) ? (a) : (b))
+
+namespace sdk {
+
+constexpr int kDefaultTtlSeconds = 3600;
+
+using TokenPtr = std::shared_ptr<class Token>;
+
+enum class Role : int {
+    Admin,
+    User = 2,
+    Guest,
+};
+
+/// Parsed token claims.
+struct TokenClaims {
+    std::string user_id;
+    Role role;
+    long expires_at;
+
+    bool expired(long now) const;
+};
+
+/**
+ * Validates and issues JWT tokens.
+ */
+class SDK_API TokenService final : public ITokenIssuer {
+public:
+    explicit TokenService(const Config& config);
+    ~TokenService() override;
+    TokenService(const TokenService&) = delete;
+
+    TokenClaims validate(const std::string& token) const;
+    std::string issue(const std::string& user_id, Role role);
+    static TokenService& instance();
+    bool operator==(const TokenService& other) const;
+
+    template <typename T>
+    T claim(const std::string& name) const {
+        return T{};
+    }
+
+protected:
+    virtual void on_expired(const std::string& token);
+
+private:
+    std::string sign(const std::string& payload);
+    Config config_;
+};
+
+TokenPtr make_token(const std::string& raw);
+inline bool is_valid_length(const std::string& raw) { return raw.size() < SDK_MAX_TOKEN_LEN; }
+static int helper_counter();
+
+namespace detail {
+int internal_hash(const char* data);
+}
+
+}  // namespace sdk
+
+#endif  // SDK_TOKEN_HPP
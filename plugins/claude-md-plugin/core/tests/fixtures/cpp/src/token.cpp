@@ -0,0 +1,15 @@
+#include "sdk/token.hpp"
+
+#include <vector>
+#include <curl/curl.h>
+#include "internal/hash.h"
+
+namespace sdk {
+
+static int helper(int x) { return x; }
+
+TokenClaims TokenService::validate(const std::string& token) const {
+    return TokenClaims{};
+}
+
+}  // namespace sdk
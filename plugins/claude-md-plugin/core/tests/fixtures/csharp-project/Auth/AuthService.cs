@@ -0,0 +1,24 @@
+using System.Linq;
+using Example.Identity.Auth.Models;
+using Example.Identity.Token;
+using static Example.Identity.Token.TokenGuard;
+using Newtonsoft.Json;
+
+namespace Example.Identity.Auth;
+
+public class AuthService
+{
+    private readonly TokenService _tokenService;
+
+    public AuthService(TokenService tokenService)
+    {
+        _tokenService = tokenService;
+    }
+
+    public User? Authenticate(string token)
+    {
+        EnsureNotEmpty(token);
+        var claims = _tokenService.Validate(token);
+        return new User(claims.UserId);
+    }
+}
@@ -0,0 +1,3 @@
+namespace Example.Identity.Auth.Models;
+
+public record User(string Id, string Name);
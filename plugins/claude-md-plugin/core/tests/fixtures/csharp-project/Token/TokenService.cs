@@ -0,0 +1,9 @@
+namespace Example.Identity.Token;
+
+public class TokenService
+{
+    public TokenClaims Validate(string token)
+    {
+        return new TokenClaims(token);
+    }
+}
@@ -0,0 +1,44 @@
+using System;
+
+namespace Example.Token;
+
+/// <summary>
+/// Contract examples for the C# code analyzer.
+/// </summary>
+public class Contracts
+{
+    /// <summary>
+    /// Validates a JWT token and returns the claims.
+    /// </summary>
+    /// <param name="token">JWT token (must be non-empty)</param>
+    /// <returns>TokenClaims object with valid UserId</returns>
+    /// <exception cref="T:Example.Token.InvalidTokenException">If malformed or expired</exception>
+    public TokenClaims ValidateToken(string token)
+    {
+        if (string.IsNullOrEmpty(token))
+        {
+            throw new InvalidTokenException("Token is required", null);
+        }
+
+        return new TokenClaims("user123", Role.Admin);
+    }
+
+    /// <summary>
+    /// Processes an order and returns a receipt.
+    /// </summary>
+    /// <param name="order">Order with id and items</param>
+    /// <returns>
+    /// Receipt with OrderId and Total
+    /// </returns>
+    /// <exception cref="ArgumentException">If the order is invalid</exception>
+    [Obsolete("Use ProcessOrderAsync")]
+    public Receipt ProcessOrder(Order order)
+    {
+        if (string.IsNullOrEmpty(order.Id))
+        {
+            throw new ArgumentException("Order ID required", nameof(order));
+        }
+
+        return new Receipt(order.Id, 100);
+    }
+}
@@ -0,0 +1,17 @@
+namespace Example.Token;
+
+/// <summary>
+/// Thrown when a token has expired.
+/// </summary>
+public class TokenExpiredException : Exception
+{
+    public TokenExpiredException(string message, Exception inner) : base(message, inner) { }
+}
+
+/// <summary>
+/// Thrown when a token is malformed or its signature is invalid.
+/// </summary>
+public class InvalidTokenException : Exception
+{
+    public InvalidTokenException(string message, Exception inner) : base(message, inner) { }
+}
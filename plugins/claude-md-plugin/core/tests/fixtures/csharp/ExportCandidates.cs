@@ -0,0 +1,17 @@
+namespace Example.Token;
+
+/// <summary>
+/// Public constants and helpers.
+/// </summary>
+public static class TokenDefaults
+{
+    public const int MaxRetries = 3;
+    public const string ApiBaseUrl = "https://api.example.com";
+    public static readonly TimeSpan DefaultTimeout = TimeSpan.FromSeconds(30);
+    private const int InternalLimit = 10;
+
+    public static T Parse<T>(string raw) where T : new()
+    {
+        return new T();
+    }
+}
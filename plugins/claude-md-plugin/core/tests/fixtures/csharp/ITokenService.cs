@@ -0,0 +1,10 @@
+namespace Example.Token;
+
+/// <summary>
+/// Token operations exposed to other modules.
+/// </summary>
+public interface ITokenService
+{
+    TokenClaims ValidateToken(string token);
+    Task<string> GenerateTokenAsync(string userId, Role role);
+}
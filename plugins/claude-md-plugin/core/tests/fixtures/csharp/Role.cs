@@ -0,0 +1,11 @@
+namespace Example.Token;
+
+/// <summary>
+/// User roles.
+/// </summary>
+public enum Role
+{
+    Admin,
+    User,
+    Guest,
+}
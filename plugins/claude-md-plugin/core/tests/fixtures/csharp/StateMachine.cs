@@ -0,0 +1,37 @@
+namespace Example.Loader;
+
+/// <summary>
+/// Loading states.
+/// </summary>
+public enum LoadState
+{
+    Idle,
+    Loading = 1,
+    // Data is available
+    Loaded,
+    Error,
+}
+
+/// <summary>
+/// Resource loader with an explicit lifecycle.
+/// </summary>
+public class ResourceLoader
+{
+    /// <summary>Prepares the loader.</summary>
+    /// <lifecycle>1</lifecycle>
+    public void Init()
+    {
+    }
+
+    /// <summary>Releases resources.</summary>
+    /// <lifecycle>3</lifecycle>
+    public void Dispose()
+    {
+    }
+
+    /// <summary>Starts loading.</summary>
+    /// <lifecycle>2</lifecycle>
+    public void Start()
+    {
+    }
+}
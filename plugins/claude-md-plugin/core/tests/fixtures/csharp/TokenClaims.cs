@@ -0,0 +1,24 @@
+namespace Example.Token;
+
+/// <summary>
+/// JWT token claims.
+/// </summary>
+public sealed record TokenClaims(string UserId, Role Role);
+
+/// <summary>
+/// Token configuration settings.
+/// </summary>
+public record TokenConfig
+{
+    public string Secret { get; init; } = "";
+    public int ExpiresInSeconds { get; init; } = 3600;
+}
+
+/// <summary>
+/// Validity window of an issued token.
+/// </summary>
+public readonly struct TokenLifetime
+{
+    public DateTime IssuedAt { get; init; }
+    public DateTime ExpiresAt { get; init; }
+}
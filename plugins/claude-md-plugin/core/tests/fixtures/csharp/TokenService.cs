@@ -0,0 +1,63 @@
+using System;
+using System.IdentityModel.Tokens.Jwt;
+using Microsoft.IdentityModel.Tokens;
+using Newtonsoft.Json;
+
+namespace Example.Token
+{
+    /// <summary>
+    /// Service for JWT token operations.
+    /// </summary>
+    public class TokenService : ITokenService
+    {
+        private readonly TokenConfig _config;
+
+        public TokenService(TokenConfig config)
+        {
+            _config = config;
+        }
+
+        /// <summary>
+        /// Validates a JWT token and returns the claims.
+        /// </summary>
+        /// <param name="token">The JWT token to validate</param>
+        /// <returns>The decoded token claims</returns>
+        /// <exception cref="TokenExpiredException">If the token has expired</exception>
+        /// <exception cref="InvalidTokenException">If the token is invalid</exception>
+        public TokenClaims ValidateToken(string token)
+        {
+            try
+            {
+                var principal = new JwtSecurityTokenHandler().ValidateToken(token, Parameters(), out _);
+                return MapToTokenClaims(principal.Claims);
+            }
+            catch (SecurityTokenExpiredException e)
+            {
+                throw new TokenExpiredException("Token has expired", e);
+            }
+            catch (Exception e)
+            {
+                throw new InvalidTokenException("Invalid token", e);
+            }
+        }
+
+        /// <summary>
+        /// Generates a new JWT token.
+        /// </summary>
+        public async Task<string> GenerateTokenAsync(string userId, Role role)
+        {
+            var payload = JsonConvert.SerializeObject(new { userId, role });
+            return await Task.FromResult(payload);
+        }
+
+        private TokenClaims MapToTokenClaims(IEnumerable<Claim> claims)
+        {
+            return new TokenClaims(claims.First().Value, Role.User);
+        }
+
+        private bool IsTokenExpired(DateTime expiresAt)
+        {
+            return expiresAt < DateTime.UtcNow;
+        }
+    }
+}
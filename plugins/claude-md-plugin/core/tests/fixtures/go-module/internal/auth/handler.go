@@ -0,0 +1,18 @@
+package auth
+
+import (
+	"fmt"
+
+	"github.com/example/authsvc/internal/token"
+	"github.com/example/authsvc/pkg/httputil"
+	"github.com/golang-jwt/jwt/v5"
+)
+
+// Authenticate validates the bearer token of a request.
+func Authenticate(header string) (*token.Claims, error) {
+	raw, err := httputil.BearerToken(header)
+	if err != nil {
+		return nil, fmt.Errorf("missing bearer token: %w", err)
+	}
+	return token.Parse(raw, jwt.SigningMethodHS256)
+}
@@ -0,0 +1,14 @@
+package httputil
+
+import (
+	"errors"
+	"strings"
+)
+
+// BearerToken extracts the token from an Authorization header.
+func BearerToken(header string) (string, error) {
+	if !strings.HasPrefix(header, "Bearer ") {
+		return "", errors.New("not a bearer token")
+	}
+	return strings.TrimPrefix(header, "Bearer "), nil
+}
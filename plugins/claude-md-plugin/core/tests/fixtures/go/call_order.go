@@ -0,0 +1,9 @@
+package auth
+
+func helper() error {
+	return Process()
+}
+
+func Process() error {
+	return nil
+}
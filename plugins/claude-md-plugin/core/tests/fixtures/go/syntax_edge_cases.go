@@ -0,0 +1,40 @@
+// Constructs that line-oriented regex analysis gets wrong.
+package session
+
+import (
+	"errors"
+	"time"
+)
+
+// func CommentedOut() bool { return true }
+
+// Session is returned by CreateSession.
+type Session struct {
+	UserID string
+	TTL    time.Duration
+}
+
+// State of a session.
+type State int
+
+const (
+	StateActive State = iota
+	StateRevoked
+)
+
+var (
+	ErrExpired = errors.New("session expired")
+	errHidden  = errors.New("hidden")
+)
+
+func CreateSession(
+	userID string,
+	ttl time.Duration, // refresh window
+) (*Session, error) {
+	_ = "func InStringLiteral() {}"
+	return &Session{UserID: userID, TTL: ttl}, nil
+}
+
+func (s *Session) Refresh() {}
+
+func hiddenHelper() {}
@@ -0,0 +1,19 @@
+package com.example.auth;
+
+import com.example.auth.model.User;
+import com.example.token.TokenService;
+import io.jsonwebtoken.Claims;
+import java.util.Optional;
+
+public class AuthService {
+    private final TokenService tokenService;
+
+    public AuthService(TokenService tokenService) {
+        this.tokenService = tokenService;
+    }
+
+    public Optional<User> authenticate(String token) {
+        Claims claims = tokenService.validate(token);
+        return Optional.of(new User(claims.getSubject()));
+    }
+}
@@ -0,0 +1,9 @@
+package com.example.auth;
+
+import com.example.sharedlib.Cache;
+
+public class CacheClient {
+    public Cache open() {
+        return null;
+    }
+}
@@ -0,0 +1,3 @@
+package com.example.auth.model;
+
+public record User(String id, String name) {}
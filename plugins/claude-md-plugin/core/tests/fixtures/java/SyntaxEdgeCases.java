@@ -0,0 +1,24 @@
+// Constructs that line-oriented regex analysis gets wrong.
+package com.example.session;
+
+import static java.util.Objects.requireNonNull;
+import com.example.cache.*;
+
+public sealed class SyntaxEdgeCases permits SyntaxEdgeCases.Active {
+
+    // public void commentedOut() {}
+
+    public Session createSession(
+            String userId,
+            long ttlSeconds // refresh window
+    ) {
+        String example = "public void inStringLiteral() {}";
+        return new Session(requireNonNull(userId), ttlSeconds);
+    }
+
+    private static class Hidden {
+        public void hiddenHelper() {}
+    }
+
+    public static final class Active extends SyntaxEdgeCases {}
+}
@@ -0,0 +1,13 @@
+package com.example.auth
+
+import com.example.auth.model.User
+import com.example.token.TokenService
+import io.jsonwebtoken.Claims
+import kotlin.collections.List
+
+class AuthService(private val tokenService: TokenService) {
+    fun authenticate(token: String): User? {
+        val claims: Claims = tokenService.validate(token) ?: return null
+        return User(claims.subject)
+    }
+}
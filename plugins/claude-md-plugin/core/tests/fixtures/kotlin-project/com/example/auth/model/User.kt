@@ -0,0 +1,3 @@
+package com.example.auth.model
+
+data class User(val id: String, val name: String)
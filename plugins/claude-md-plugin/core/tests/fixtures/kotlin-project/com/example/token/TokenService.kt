@@ -0,0 +1,5 @@
+package com.example.token
+
+class TokenService {
+    fun validate(token: String): Boolean = token.isNotEmpty()
+}
@@ -0,0 +1,19 @@
+<?php
+
+namespace App\Auth;
+
+use App\Auth\Session;
+use App\Token\TokenService;
+use Firebase\JWT\JWT;
+
+class AuthService
+{
+    public function __construct(private TokenService $tokens)
+    {
+    }
+
+    public function login(string $userId): string
+    {
+        return $this->tokens->issue($userId);
+    }
+}
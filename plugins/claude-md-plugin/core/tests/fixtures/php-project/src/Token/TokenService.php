@@ -0,0 +1,11 @@
+<?php
+
+namespace App\Token;
+
+class TokenService
+{
+    public function issue(string $userId): string
+    {
+        return 'token-' . $userId;
+    }
+}
@@ -0,0 +1,47 @@
+<?php
+
+namespace App\Auth;
+
+interface TokenValidator
+{
+    public function validateToken(string $token): TokenClaims;
+}
+
+trait LogsActivity
+{
+    public function logActivity(string $message): void
+    {
+    }
+}
+
+enum Role: string
+{
+    case Admin = 'admin';
+    case User = 'user';
+    case Guest = 'guest';
+
+    public function label(): string
+    {
+        return match ($this) {
+            Role::Admin => 'Administrator',
+            default => ucfirst($this->value),
+        };
+    }
+}
+
+enum SessionState
+{
+    case Idle;
+    case Loading;
+    case Loaded;
+    case Failed;
+}
+
+abstract class BaseService
+{
+}
+
+function make_token_service(string $secret): TokenService
+{
+    return new TokenService($secret, new NullLogger());
+}
@@ -0,0 +1,74 @@
+<?php
+
+declare(strict_types=1);
+
+namespace App\Auth;
+
+use App\Auth\Exceptions\InvalidTokenException;
+use App\Auth\Exceptions\TokenExpiredException;
+use Firebase\JWT\JWT;
+use Firebase\JWT\Key;
+use Psr\Log\LoggerInterface as Logger;
+use Illuminate\Support\{Arr, Str};
+
+require_once __DIR__ . '/../support/helpers.php';
+
+define('TOKEN_HEADER', 'Authorization');
+
+final class TokenService extends BaseService implements TokenValidator
+{
+    use LogsActivity;
+
+    public const DEFAULT_ISSUER = 'auth-service';
+    const MAX_TOKEN_AGE = 3600;
+    private const SIGNING_SALT = 'internal';
+
+    public function __construct(private string $secret, private Logger $logger)
+    {
+    }
+
+    /**
+     * Validate a JWT token and return its claims.
+     *
+     * @param string $token JWT token string (must be non-empty)
+     * @return TokenClaims TokenClaims object
+     * @throws InvalidTokenException
+     */
+    public function validateToken(string $token): TokenClaims
+    {
+        if ($token === '') {
+            throw new InvalidTokenException('empty token');
+        }
+
+        $claims = $this->decode($token);
+        if ($claims->exp < time()) {
+            throw new TokenExpiredException('token expired');
+        }
+
+        return $claims;
+    }
+
+    public function generateToken(string $userId, Role $role = Role::User): string
+    {
+        return $this->sign(['sub' => $userId, 'role' => $role->value]);
+    }
+
+    public static function fromEnv(): self
+    {
+        return new self(getenv('JWT_SECRET'), new NullLogger());
+    }
+
+    function revoke(string $token): void
+    {
+    }
+
+    private function decode(string $token): TokenClaims
+    {
+        return TokenClaims::fromArray((array) JWT::decode($token, new Key($this->secret, 'HS256')));
+    }
+
+    protected function sign(array $payload): string
+    {
+        return JWT::encode($payload, $this->secret, 'HS256');
+    }
+}
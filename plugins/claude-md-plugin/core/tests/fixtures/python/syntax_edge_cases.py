@@ -0,0 +1,28 @@
+# Constructs that line-oriented regex analysis gets wrong.
+
+import functools
+
+# def commented_out() -> bool: ...
+
+
+class Session:
+    def method_not_exported(self):
+        pass
+
+
+def create_session(
+    user_id: str,
+    ttl_seconds: int,  # refresh window
+) -> Session:
+    example = "def in_string_literal(): pass"
+    return Session()
+
+
+@functools.lru_cache
+def cached_lookup(key: str) -> str:
+    return key
+
+
+async def refresh(session: Session) -> None:
+    def nested_helper():
+        pass
@@ -0,0 +1,11 @@
+require 'jwt'
+require 'token/token_service'
+require_relative 'session_store'
+
+module Auth
+  class AuthService
+    def login(user_id)
+      Token::TokenService.new.issue(user_id)
+    end
+  end
+end
@@ -0,0 +1,7 @@
+module Token
+  class TokenService
+    def issue(user_id)
+      "token-#{user_id}"
+    end
+  end
+end
@@ -0,0 +1,31 @@
+require 'aasm'
+
+class Session
+  include AASM
+
+  aasm do
+    state :idle, initial: true
+    state :loading, :loaded, :failed
+  end
+
+  def start
+    load! if idle?
+  end
+
+  protected
+
+  def reset_timer
+    @timer = nil
+  end
+
+  public
+
+  def close
+    @closed = true
+  end
+
+  def refresh
+    reset_timer
+  end
+  private :refresh
+end
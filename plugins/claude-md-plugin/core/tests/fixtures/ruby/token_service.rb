@@ -0,0 +1,58 @@
+# frozen_string_literal: true
+
+require 'json'
+require 'jwt'
+require 'active_support/core_ext/numeric/time'
+require_relative 'support/clock'
+
+module Auth
+  DEFAULT_ISSUER = 'auth-service'
+  MAX_TOKEN_AGE = 3600
+  SIGNING_SALT = 'internal'
+  private_constant :SIGNING_SALT
+
+  class TokenExpiredError < StandardError; end
+  class InvalidTokenError < StandardError; end
+
+  class TokenService < BaseService
+    def initialize(secret)
+      @secret = secret
+    end
+
+    # Validate a JWT token and return its claims.
+    #
+    # @param token [String] JWT token string (must be non-empty)
+    # @return [TokenClaims] TokenClaims object
+    # @raise [InvalidTokenError] if the token is malformed
+    def validate_token(token)
+      raise InvalidTokenError, 'empty token' if token.empty?
+
+      claims = decode(token)
+      raise TokenExpiredError, 'token expired' if claims.expired?
+
+      claims
+    end
+
+    def generate_token(user_id, role: :user)
+      sign({ sub: user_id, role: role })
+    end
+
+    def self.default
+      new(ENV.fetch('JWT_SECRET'))
+    end
+
+    def revoked?(token)
+      false
+    end
+
+    private
+
+    def decode(token)
+      JWT.decode(token, @secret)
+    end
+
+    def sign(payload)
+      JWT.encode(payload, @secret)
+    end
+  end
+end
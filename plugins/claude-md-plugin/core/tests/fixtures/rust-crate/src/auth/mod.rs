@@ -0,0 +1,18 @@
+mod session;
+
+use serde::Deserialize;
+
+use crate::storage::{cache::Cache, Store};
+use crate::token::Claims;
+use self::session::Session;
+
+/// Login request payload.
+#[derive(Deserialize)]
+pub struct LoginRequest {
+    pub token: String,
+}
+
+/// Open a session for the given claims.
+pub fn open_session(claims: &Claims, _store: &Store, _cache: &Cache) -> Session {
+    Session { user_id: claims.user_id.clone() }
+}
@@ -0,0 +1,4 @@
+/// An authenticated session.
+pub struct Session {
+    pub user_id: String,
+}
@@ -0,0 +1,2 @@
+/// In-memory session cache.
+pub struct Cache;
@@ -0,0 +1,4 @@
+pub mod cache;
+
+/// Persistent session store.
+pub struct Store;
@@ -0,0 +1,4 @@
+/// Validated token claims.
+pub struct Claims {
+    pub user_id: String,
+}
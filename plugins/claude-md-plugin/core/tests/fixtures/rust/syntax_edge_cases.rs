@@ -0,0 +1,34 @@
+//! Constructs that line-oriented regex analysis gets wrong.
+
+use serde::Serialize;
+
+// pub fn commented_out() -> bool { true }
+
+/// Session handle returned by `create_session`.
+#[derive(Serialize)]
+pub struct Session {
+    pub user_id: String,
+    pub ttl_seconds: u64,
+}
+
+pub fn create_session(
+    user_id: &str,
+    ttl_seconds: u64, // refresh window
+) -> Session {
+    let _example = "pub fn in_string_literal() {}";
+    Session { user_id: user_id.to_string(), ttl_seconds }
+}
+
+mod internal {
+    pub fn hidden_helper() -> u32 {
+        42
+    }
+
+    pub struct HiddenState;
+}
+
+pub mod api {
+    pub fn public_endpoint() {}
+}
+
+pub(crate) fn crate_only() {}
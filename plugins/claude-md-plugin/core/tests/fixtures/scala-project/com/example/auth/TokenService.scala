@@ -0,0 +1,55 @@
+package com.example
+package auth
+
+import com.example.auth.model.TokenClaims
+import com.example.token.{TokenStore, TokenCodec}
+import com.example.config._
+import io.circe.Json
+import pdi.jwt.{Jwt, JwtAlgorithm}
+import scala.concurrent.Future
+import java.time.Instant
+
+class TokenExpiredException(message: String) extends RuntimeException(message)
+class InvalidTokenException(message: String) extends RuntimeException(message)
+
+val DefaultIssuer: String = "auth-service"
+private val SigningSalt = "internal"
+
+type ClaimsHandler = TokenClaims => Unit
+
+trait TokenValidator {
+  def validateToken(token: String): TokenClaims
+}
+
+class TokenService(secret: String) extends TokenValidator {
+
+  /** Validate a JWT token and return its claims.
+    *
+    * @param token JWT token string (must be non-empty)
+    * @return TokenClaims object
+    * @throws[InvalidTokenException] if the token is malformed
+    */
+  override def validateToken(token: String): TokenClaims = {
+    if (token.isEmpty) throw new InvalidTokenException("empty token")
+    val claims = decode(token)
+    if (claims.expiresAt.isBefore(Instant.now())) throw new TokenExpiredException("token expired")
+    claims
+  }
+
+  def generateToken[R <: Role](userId: String, role: R)(implicit ec: ExecutionContext): Future[String] =
+    Future(sign(Map("sub" -> userId, "role" -> role.toString)))
+
+  def isRevoked: Boolean = false
+
+  private def decode(token: String): TokenClaims = TokenCodec.decode(token, secret)
+
+  protected def sign(payload: Map[String, String]): String = Jwt.encode(payload.toString, secret, JwtAlgorithm.HS256)
+}
+
+object TokenService {
+  val MaxTokenAge: Int = 3600
+
+  def apply(secret: String): TokenService = new TokenService(secret)
+}
+
+private[auth] object TokenInternals
@@ -0,0 +1,3 @@
+package com.example.auth.model
+
+case class TokenClaims(subject: String, expiresAt: Long)
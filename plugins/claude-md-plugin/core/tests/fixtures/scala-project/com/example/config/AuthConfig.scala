@@ -0,0 +1,3 @@
+package com.example.config
+
+case class AuthConfig(secret: String)
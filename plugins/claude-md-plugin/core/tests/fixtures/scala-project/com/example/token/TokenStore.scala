@@ -0,0 +1,5 @@
+package com.example.token
+
+trait TokenStore {
+  def save(token: String): Unit
+}
@@ -0,0 +1,22 @@
+package com.example.session
+
+final case class Session(id: String, userId: String)
+
+sealed trait SessionState
+case object Idle extends SessionState
+case object Loading extends SessionState
+final case class Loaded(session: Session) extends SessionState
+final case class Failed(reason: Throwable) extends SessionState
+
+enum Role:
+  case Admin, User
+  case Guest
+
+  def label: String = this match
+    case Admin => "Administrator"
+    case _ => toString
+
+enum Color(val rgb: Int) {
+  case Red extends Color(0xFF0000)
+  case Green extends Color(0x00FF00)
+}
@@ -0,0 +1,13 @@
+// swift-tools-version:5.9
+import PackageDescription
+
+let package = Package(
+    name: "Identity",
+    dependencies: [
+        .package(url: "https://github.com/vapor/jwt-kit.git", from: "4.0.0"),
+    ],
+    targets: [
+        .target(name: "Auth", dependencies: ["Token", .product(name: "JWTKit", package: "jwt-kit")]),
+        .target(name: "Token"),
+    ]
+)
@@ -0,0 +1,11 @@
+import Foundation
+import JWTKit
+import Token
+
+public struct AuthService {
+    private let tokens: TokenService
+
+    public func authenticate(_ token: String) throws -> String {
+        try tokens.validate(token)
+    }
+}
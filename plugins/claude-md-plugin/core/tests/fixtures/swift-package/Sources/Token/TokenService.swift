@@ -0,0 +1,5 @@
+public struct TokenService {
+    public func validate(_ token: String) throws -> String {
+        token
+    }
+}
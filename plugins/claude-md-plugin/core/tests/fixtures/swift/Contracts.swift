@@ -0,0 +1,25 @@
+import Foundation
+
+public struct Contracts {
+    /// Validates a JWT token and returns the claims.
+    ///
+    /// - Parameters:
+    ///   - token: JWT token (must be non-empty)
+    ///   - leeway: Allowed clock skew in seconds
+    /// - Returns: TokenClaims object with valid userId
+    /// - Throws: `TokenError.invalid` if malformed or expired
+    /// - Precondition: the signing key is configured
+    @discardableResult
+    public func validateToken(_ token: String, leeway: Int = 0) throws -> TokenClaims {
+        guard !token.isEmpty else { throw TokenError.invalid(reason: "empty") }
+        return TokenClaims(userId: "user123", role: .admin)
+    }
+
+    /// Parses a receipt.
+    ///
+    /// - Parameter data: Raw receipt bytes
+    /// - Postcondition: the receipt total is non-negative
+    public func parseReceipt(_ data: Data) throws(ReceiptError) -> Receipt {
+        throw ReceiptError.malformed
+    }
+}
@@ -0,0 +1,42 @@
+import Foundation
+
+/// Validates tokens.
+public protocol TokenValidating {
+    func validateToken(_ token: String) throws -> TokenClaims
+}
+
+/// JWT token claims.
+public struct TokenClaims: Codable, Equatable {
+    public let userId: String
+    public let role: Role
+}
+
+/// Token configuration.
+struct TokenConfig {
+    var secret = "secret"
+}
+
+/// User roles.
+public enum Role: String, Codable {
+    case admin, user
+    case guest = "guest"
+
+    public var isPrivileged: Bool {
+        switch self {
+        case .admin: return true
+        case .user, .guest: return false
+        }
+    }
+}
+
+/// Errors raised while handling tokens.
+public enum TokenError: Error {
+    case expired
+    case invalid(reason: String)
+}
+
+public typealias ClaimsHandler = (TokenClaims) -> Void
+
+public let defaultIssuer: String = "example.com"
+public let maxTokenAge = 3600
+let internalSalt = "salt"
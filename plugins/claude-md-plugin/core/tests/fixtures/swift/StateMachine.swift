@@ -0,0 +1,23 @@
+import Combine
+
+/// States of a resource load.
+public enum LoadState: Equatable {
+    case idle
+    case loading(progress: Double)
+    case loaded(Data), failed(Error)
+
+    public var isTerminal: Bool {
+        switch self {
+        case .loaded, .failed: return true
+        default: return false
+        }
+    }
+}
+
+public final class ResourceLoader: ObservableObject {
+    @Published public private(set) var state: LoadState = .idle
+
+    public func start() {
+        state = .loading(progress: 0)
+    }
+}
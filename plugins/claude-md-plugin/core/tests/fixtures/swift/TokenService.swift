@@ -0,0 +1,51 @@
+import Foundation
+import JWTKit
+@testable import Crypto
+
+/// Service for JWT token operations.
+public final class TokenService: TokenValidating {
+    private let config: TokenConfig
+
+    public init(config: TokenConfig) {
+        self.config = config
+    }
+
+    /// Validates a JWT token and returns the claims.
+    ///
+    /// - Parameter token: The JWT token to validate
+    /// - Returns: The decoded token claims
+    /// - Throws: `TokenError.expired` if the token has expired
+    public func validateToken(_ token: String) throws -> TokenClaims {
+        let payload = try decode(token)
+        guard payload.exp > Date() else {
+            throw TokenError.expired
+        }
+        guard !payload.sub.isEmpty else {
+            throw TokenError.invalid(reason: "missing subject")
+        }
+        return TokenClaims(userId: payload.sub, role: .user)
+    }
+
+    /// Generates a new JWT token.
+    public func generateToken(userId: String, role: Role) async throws -> String {
+        try await sign(userId: userId, role: role)
+    }
+
+    public static func `default`() -> TokenService {
+        TokenService(config: TokenConfig())
+    }
+
+    private func decode(_ token: String) throws -> Payload {
+        try JWTSigner.hs256(key: config.secret).verify(token)
+    }
+
+    func sign(userId: String, role: Role) async throws -> String {
+        ""
+    }
+}
+
+open class BaseService {
+    open class func makeDefault() -> BaseService {
+        BaseService()
+    }
+}
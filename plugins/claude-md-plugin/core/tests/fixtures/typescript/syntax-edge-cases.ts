@@ -0,0 +1,24 @@
+// Constructs that line-oriented regex analysis gets wrong.
+
+import { z } from 'zod';
+
+// export function commentedOut(): void {}
+
+/*
+export class CommentedOutClass {}
+*/
+
+export function createSession(
+  userId: string,
+  ttlSeconds: number, // refresh window
+): Session {
+  const example = "export function inStringLiteral() {}";
+  return { userId, ttlSeconds };
+}
+
+export interface Session {
+  userId: string;
+  ttlSeconds: number;
+}
+
+export const SessionSchema = z.object({ userId: z.string() });